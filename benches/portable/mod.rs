@@ -1,14 +1,22 @@
-use std::{mem, simd::u64x4, simd::u64x8};
+use std::{mem, simd::Simd, simd::f64x8, simd::u64x4, simd::u64x8};
 
-use criterion::{BenchmarkId, Criterion, Throughput, measurement::Measurement};
+use criterion::{BenchmarkGroup, BenchmarkId, Criterion, Throughput, measurement::Measurement};
 use rand_core::SeedableRng;
-use simd_rand::portable::{FrandX4, FrandX8, SimdRandX4, SimdRandX8, Xoshiro256PlusX4, Xoshiro256PlusX8};
+use simd_rand::SimdRng;
+use simd_rand::portable::{
+    ChaCha8X8, ChaCha12X8, ChaCha20X8, ExponentBits, F64Conversion, FrandX4, FrandX8, Jsf64X8, Mt19937_64X8, Multiply,
+    Pcg64DxsmX8, Philox2x64X8, Philox4x32X8, RomuTrioX8, Sfc64X8, SimdRandX4, SimdRandX8, SplitMix64X8, WyRandX8,
+    Xoroshiro128PlusPlusX8, Xoroshiro128PlusX8, Xoshiro256PlusPlus, Xoshiro256PlusX4, Xoshiro256PlusX8,
+    Xoshiro512PlusPlusX8, Xoshiro512StarStarX8,
+};
 use std::hint::black_box;
 
 pub fn add_benchmarks<M: Measurement, const ITERATIONS: usize>(c: &mut Criterion<M>, suffix: &str) {
     let group_prefix = "Portable";
     add_u64x4_benchmarks::<_, ITERATIONS>(c, group_prefix, suffix);
     add_u64x8_benchmarks::<_, ITERATIONS>(c, group_prefix, suffix);
+    add_f64x8_benchmarks::<_, ITERATIONS>(c, group_prefix, suffix);
+    add_lane_count_benchmarks::<_, ITERATIONS>(c, group_prefix, suffix);
 }
 
 #[allow(clippy::items_after_statements)]
@@ -51,7 +59,8 @@ fn add_u64x4_benchmarks<M: Measurement, const ITERATIONS: usize>(
     group.finish();
 }
 
-#[allow(clippy::items_after_statements)]
+// One benchmark block per generator
+#[allow(clippy::items_after_statements, clippy::too_many_lines)]
 fn add_u64x8_benchmarks<M: Measurement, const ITERATIONS: usize>(
     c: &mut Criterion<M>,
     group_prefix: &str,
@@ -79,6 +88,134 @@ fn add_u64x8_benchmarks<M: Measurement, const ITERATIONS: usize>(
             b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
         });
 
+        let name = BenchmarkId::new(format!("Xoroshiro128+/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| {
+            let mut rng = Xoroshiro128PlusX8::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data = u64x8::default();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("Xoroshiro128++/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| {
+            let mut rng = Xoroshiro128PlusPlusX8::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data = u64x8::default();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("Xoshiro512++/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| {
+            let mut rng = Xoshiro512PlusPlusX8::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data = u64x8::default();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("Xoshiro512**/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| {
+            let mut rng = Xoshiro512StarStarX8::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data = u64x8::default();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("PCG64-DXSM/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| {
+            let mut rng = Pcg64DxsmX8::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data = u64x8::default();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("Philox4x32-10/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| {
+            let mut rng = Philox4x32X8::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data = u64x8::default();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("Philox2x64-10/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| {
+            let mut rng = Philox2x64X8::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data = u64x8::default();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("SplitMix64/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| {
+            let mut rng = SplitMix64X8::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data = u64x8::default();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("WyRand/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| {
+            let mut rng = WyRandX8::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data = u64x8::default();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("RomuTrio/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| {
+            let mut rng = RomuTrioX8::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data = u64x8::default();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("SFC64/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| {
+            let mut rng = Sfc64X8::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data = u64x8::default();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("JSF64/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| {
+            let mut rng = Jsf64X8::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data = u64x8::default();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("ChaCha8/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| {
+            let mut rng = ChaCha8X8::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data = u64x8::default();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("ChaCha12/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| {
+            let mut rng = ChaCha12X8::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data = u64x8::default();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("ChaCha20/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| {
+            let mut rng = ChaCha20X8::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data = u64x8::default();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("MT19937-64/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| {
+            let mut rng = Mt19937_64X8::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data = u64x8::default();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
         let name = BenchmarkId::new(format!("frand/{suffix}"), iterations);
         group.bench_with_input(name, &iterations, |b, i| {
             let mut rng = FrandX8::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
@@ -90,3 +227,83 @@ fn add_u64x8_benchmarks<M: Measurement, const ITERATIONS: usize>(
 
     group.finish();
 }
+
+// Every conversion on the cheapest and on a multiply bound generator, the conversion is a larger share of the former
+fn add_f64x8_benchmarks<M: Measurement, const ITERATIONS: usize>(
+    c: &mut Criterion<M>,
+    group_prefix: &str,
+    suffix: &str,
+) {
+    let mut group = c.benchmark_group(format!("{group_prefix}/f64x8"));
+    group.throughput(Throughput::Bytes((ITERATIONS * mem::size_of::<f64x8>()) as u64));
+
+    bench_f64x8::<_, ITERATIONS, Multiply, _>(&mut group, &format!("Xoshiro256+/Multiply/{suffix}"), || {
+        Xoshiro256PlusX8::seed_from_u64(0x0DDB1A5E5BAD5EEDu64)
+    });
+    bench_f64x8::<_, ITERATIONS, ExponentBits, _>(&mut group, &format!("Xoshiro256+/ExponentBits/{suffix}"), || {
+        Xoshiro256PlusX8::seed_from_u64(0x0DDB1A5E5BAD5EEDu64)
+    });
+    bench_f64x8::<_, ITERATIONS, Multiply, _>(&mut group, &format!("frand/Multiply/{suffix}"), || {
+        FrandX8::seed_from_u64(0x0DDB1A5E5BAD5EEDu64)
+    });
+    bench_f64x8::<_, ITERATIONS, ExponentBits, _>(&mut group, &format!("frand/ExponentBits/{suffix}"), || {
+        FrandX8::seed_from_u64(0x0DDB1A5E5BAD5EEDu64)
+    });
+
+    group.finish();
+}
+
+fn bench_f64x8<M: Measurement, const ITERATIONS: usize, C: F64Conversion, R: SimdRandX8>(
+    group: &mut BenchmarkGroup<'_, M>,
+    name: &str,
+    make_rng: impl Fn() -> R,
+) {
+    group.bench_function(name, |b| {
+        let mut rng = make_rng();
+        let mut data = f64x8::default();
+
+        b.iter(|| {
+            for _ in 0..black_box(ITERATIONS) {
+                *black_box(&mut data) = rng.next_f64x8_with::<C>();
+            }
+        });
+    });
+}
+
+// The same generator at every lane count, wider vectors hide more of the step's latency
+fn add_lane_count_benchmarks<M: Measurement, const ITERATIONS: usize>(
+    c: &mut Criterion<M>,
+    group_prefix: &str,
+    suffix: &str,
+) {
+    let mut group = c.benchmark_group(format!("{group_prefix}/lanes"));
+
+    bench_lane_count::<_, 2, ITERATIONS>(&mut group, suffix);
+    bench_lane_count::<_, 4, ITERATIONS>(&mut group, suffix);
+    bench_lane_count::<_, 8, ITERATIONS>(&mut group, suffix);
+    bench_lane_count::<_, 16, ITERATIONS>(&mut group, suffix);
+    bench_lane_count::<_, 32, ITERATIONS>(&mut group, suffix);
+
+    group.finish();
+}
+
+fn bench_lane_count<M: Measurement, const LANES: usize, const ITERATIONS: usize>(
+    group: &mut BenchmarkGroup<'_, M>,
+    suffix: &str,
+) where
+    Xoshiro256PlusPlus<LANES>: SeedableRng,
+{
+    group.throughput(Throughput::Bytes((ITERATIONS * LANES * mem::size_of::<u64>()) as u64));
+
+    let name = BenchmarkId::new(format!("Xoshiro256++/{suffix}"), LANES);
+    group.bench_function(name, |b| {
+        let mut rng = Xoshiro256PlusPlus::<LANES>::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+        let mut data = Simd::<u64, LANES>::default();
+
+        b.iter(|| {
+            for _ in 0..black_box(ITERATIONS) {
+                *black_box(&mut data) = rng.next_u64_vec();
+            }
+        });
+    });
+}
@@ -1,7 +1,7 @@
 use std::{arch::x86_64::*, mem};
 
 use criterion::{BenchmarkId, Criterion, Throughput, measurement::Measurement};
-use rand_core::SeedableRng;
+use rand_core::{RngCore, SeedableRng};
 use simd_rand::specific::avx2::*;
 use std::hint::black_box;
 
@@ -11,9 +11,12 @@ pub fn add_benchmarks<M: Measurement, const ITERATIONS: usize>(c: &mut Criterion
     let group_prefix = "AVX2";
     add_m256i_benchmarks::<_, ITERATIONS>(c, group_prefix, suffix);
     add_m256d_benchmarks::<_, ITERATIONS>(c, group_prefix, suffix);
+    add_m256d_conversion_benchmarks::<_, ITERATIONS>(c, group_prefix, suffix);
+    add_fill_bytes_benchmarks(c, group_prefix, suffix);
 }
 
-#[allow(clippy::items_after_statements)]
+// One benchmark block per generator
+#[allow(clippy::items_after_statements, clippy::too_many_lines)]
 fn add_m256i_benchmarks<M: Measurement, const ITERATIONS: usize>(
     c: &mut Criterion<M>,
     group_prefix: &str,
@@ -49,6 +52,126 @@ fn add_m256i_benchmarks<M: Measurement, const ITERATIONS: usize>(
             b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
         });
 
+        let name = BenchmarkId::new(format!("Xoshiro256**/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| unsafe {
+            let mut rng = Xoshiro256StarStarX4::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data: __m256i = _mm256_setzero_si256();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("Xoshiro512++/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| unsafe {
+            let mut rng = Xoshiro512PlusPlusX4::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data: __m256i = _mm256_setzero_si256();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("Xoshiro512**/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| unsafe {
+            let mut rng = Xoshiro512StarStarX4::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data: __m256i = _mm256_setzero_si256();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("PCG64-DXSM/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| unsafe {
+            let mut rng = Pcg64DxsmX4::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data: __m256i = _mm256_setzero_si256();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("Philox4x32-10/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| unsafe {
+            let mut rng = Philox4x32X4::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data: __m256i = _mm256_setzero_si256();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("Philox2x64-10/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| unsafe {
+            let mut rng = Philox2x64X4::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data: __m256i = _mm256_setzero_si256();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("SplitMix64/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| unsafe {
+            let mut rng = SplitMix64X4::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data: __m256i = _mm256_setzero_si256();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("WyRand/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| unsafe {
+            let mut rng = WyRandX4::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data: __m256i = _mm256_setzero_si256();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("RomuTrio/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| unsafe {
+            let mut rng = RomuTrioX4::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data: __m256i = _mm256_setzero_si256();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("SFC64/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| unsafe {
+            let mut rng = Sfc64X4::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data: __m256i = _mm256_setzero_si256();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("JSF64/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| unsafe {
+            let mut rng = Jsf64X4::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data: __m256i = _mm256_setzero_si256();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("ChaCha8/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| unsafe {
+            let mut rng = ChaCha8X4::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data: __m256i = _mm256_setzero_si256();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("ChaCha12/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| unsafe {
+            let mut rng = ChaCha12X4::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data: __m256i = _mm256_setzero_si256();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("ChaCha20/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| unsafe {
+            let mut rng = ChaCha20X4::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data: __m256i = _mm256_setzero_si256();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("MT19937-64/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| unsafe {
+            let mut rng = Mt19937_64X4::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data: __m256i = _mm256_setzero_si256();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
         let name = BenchmarkId::new(format!("Xoshiro256+/{suffix}"), iterations);
         group.bench_with_input(name, &iterations, |b, i| unsafe {
             let mut rng = Xoshiro256PlusX4::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
@@ -69,7 +192,8 @@ fn add_m256i_benchmarks<M: Measurement, const ITERATIONS: usize>(
     group.finish();
 }
 
-#[allow(clippy::items_after_statements)]
+// One benchmark block per generator
+#[allow(clippy::items_after_statements, clippy::too_many_lines)]
 fn add_m256d_benchmarks<M: Measurement, const ITERATIONS: usize>(
     c: &mut Criterion<M>,
     group_prefix: &str,
@@ -105,6 +229,126 @@ fn add_m256d_benchmarks<M: Measurement, const ITERATIONS: usize>(
             b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
         });
 
+        let name = BenchmarkId::new(format!("Xoshiro256**/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| unsafe {
+            let mut rng = Xoshiro256StarStarX4::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data: __m256d = _mm256_setzero_pd();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("Xoshiro512++/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| unsafe {
+            let mut rng = Xoshiro512PlusPlusX4::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data: __m256d = _mm256_setzero_pd();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("Xoshiro512**/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| unsafe {
+            let mut rng = Xoshiro512StarStarX4::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data: __m256d = _mm256_setzero_pd();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("PCG64-DXSM/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| unsafe {
+            let mut rng = Pcg64DxsmX4::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data: __m256d = _mm256_setzero_pd();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("Philox4x32-10/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| unsafe {
+            let mut rng = Philox4x32X4::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data: __m256d = _mm256_setzero_pd();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("Philox2x64-10/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| unsafe {
+            let mut rng = Philox2x64X4::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data: __m256d = _mm256_setzero_pd();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("SplitMix64/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| unsafe {
+            let mut rng = SplitMix64X4::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data: __m256d = _mm256_setzero_pd();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("WyRand/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| unsafe {
+            let mut rng = WyRandX4::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data: __m256d = _mm256_setzero_pd();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("RomuTrio/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| unsafe {
+            let mut rng = RomuTrioX4::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data: __m256d = _mm256_setzero_pd();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("SFC64/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| unsafe {
+            let mut rng = Sfc64X4::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data: __m256d = _mm256_setzero_pd();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("JSF64/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| unsafe {
+            let mut rng = Jsf64X4::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data: __m256d = _mm256_setzero_pd();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("ChaCha8/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| unsafe {
+            let mut rng = ChaCha8X4::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data: __m256d = _mm256_setzero_pd();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("ChaCha12/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| unsafe {
+            let mut rng = ChaCha12X4::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data: __m256d = _mm256_setzero_pd();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("ChaCha20/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| unsafe {
+            let mut rng = ChaCha20X4::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data: __m256d = _mm256_setzero_pd();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("MT19937-64/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| unsafe {
+            let mut rng = Mt19937_64X4::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data: __m256d = _mm256_setzero_pd();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
         let name = BenchmarkId::new(format!("Xoshiro256+/{suffix}"), iterations);
         group.bench_with_input(name, &iterations, |b, i| unsafe {
             let mut rng = Xoshiro256PlusX4::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
@@ -124,3 +368,76 @@ fn add_m256d_benchmarks<M: Measurement, const ITERATIONS: usize>(
 
     group.finish();
 }
+
+// The cost of the f64 conversion on top of the generator, against converting the words one by one
+#[allow(clippy::cast_precision_loss)]
+fn add_m256d_conversion_benchmarks<M: Measurement, const ITERATIONS: usize>(
+    c: &mut Criterion<M>,
+    group_prefix: &str,
+    suffix: &str,
+) {
+    let mut group = c.benchmark_group(format!("{group_prefix}/m256d conversion"));
+    group.throughput(Throughput::Bytes((ITERATIONS * mem::size_of::<__m256d>()) as u64));
+
+    group.bench_function(format!("Xoshiro256+/None/{suffix}"), |b| {
+        let mut rng = Xoshiro256PlusX4::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+        let mut data = unsafe { _mm256_setzero_si256() };
+
+        b.iter(|| {
+            for _ in 0..black_box(ITERATIONS) {
+                *black_box(&mut data) = rng.next_m256i();
+            }
+        });
+    });
+
+    group.bench_function(format!("Xoshiro256+/Vector/{suffix}"), |b| {
+        let mut rng = Xoshiro256PlusX4::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+        let mut data = unsafe { _mm256_setzero_pd() };
+
+        b.iter(|| {
+            for _ in 0..black_box(ITERATIONS) {
+                *black_box(&mut data) = rng.next_m256d();
+            }
+        });
+    });
+
+    group.bench_function(format!("Xoshiro256+/Scalar/{suffix}"), |b| {
+        let mut rng = Xoshiro256PlusX4::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+        let mut data = F64x4::default();
+
+        b.iter(|| {
+            for _ in 0..black_box(ITERATIONS) {
+                let words = rng.next_u64x4();
+                *black_box(&mut data) = words.map(|x| (x >> 11) as f64 * (1.0 / (1u64 << 53) as f64)).into();
+            }
+        });
+    });
+
+    group.finish();
+}
+
+fn add_fill_bytes_benchmarks<M: Measurement>(c: &mut Criterion<M>, group_prefix: &str, suffix: &str) {
+    let mut group = c.benchmark_group(format!("{group_prefix}/fill_bytes"));
+
+    // Spans requests that fit in the buffer up to ones that stream several buffers worth straight into `dest`
+    let sizes = [
+        DEFAULT_BUFFER_SIZE / 4,
+        DEFAULT_BUFFER_SIZE,
+        DEFAULT_BUFFER_SIZE * 4,
+        DEFAULT_BUFFER_SIZE * 32,
+    ];
+
+    for size in sizes {
+        group.throughput(Throughput::Bytes(size as u64));
+
+        let name = BenchmarkId::new(format!("Shishua/{suffix}"), size);
+        group.bench_with_input(name, &size, |b, size| {
+            let mut rng = Shishua::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data = vec![0u8; *size];
+
+            b.iter(|| rng.fill_bytes(black_box(&mut data)));
+        });
+    }
+
+    group.finish();
+}
@@ -9,9 +9,12 @@ pub fn add_benchmarks<M: Measurement, const ITERATIONS: usize>(c: &mut Criterion
     let group_prefix = "AVX512";
     add_m512i_benchmarks::<_, ITERATIONS>(c, group_prefix, suffix);
     add_m512d_benchmarks::<_, ITERATIONS>(c, group_prefix, suffix);
+    add_mul_hi_benchmarks::<_, ITERATIONS>(c, group_prefix, suffix);
+    add_rotate_benchmarks::<_, ITERATIONS>(c, group_prefix, suffix);
 }
 
-#[allow(clippy::items_after_statements)]
+// One benchmark block per generator
+#[allow(clippy::items_after_statements, clippy::too_many_lines)]
 fn add_m512i_benchmarks<M: Measurement, const ITERATIONS: usize>(
     c: &mut Criterion<M>,
     group_prefix: &str,
@@ -39,6 +42,126 @@ fn add_m512i_benchmarks<M: Measurement, const ITERATIONS: usize>(
             b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
         });
 
+        let name = BenchmarkId::new(format!("Xoshiro256**/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| unsafe {
+            let mut rng = Xoshiro256StarStarX8::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data: __m512i = _mm512_setzero_si512();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("Xoshiro512++/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| unsafe {
+            let mut rng = Xoshiro512PlusPlusX8::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data: __m512i = _mm512_setzero_si512();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("Xoshiro512**/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| unsafe {
+            let mut rng = Xoshiro512StarStarX8::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data: __m512i = _mm512_setzero_si512();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("PCG64-DXSM/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| unsafe {
+            let mut rng = Pcg64DxsmX8::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data: __m512i = _mm512_setzero_si512();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("Philox4x32-10/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| unsafe {
+            let mut rng = Philox4x32X8::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data: __m512i = _mm512_setzero_si512();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("Philox2x64-10/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| unsafe {
+            let mut rng = Philox2x64X8::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data: __m512i = _mm512_setzero_si512();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("SplitMix64/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| unsafe {
+            let mut rng = SplitMix64X8::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data: __m512i = _mm512_setzero_si512();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("WyRand/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| unsafe {
+            let mut rng = WyRandX8::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data: __m512i = _mm512_setzero_si512();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("RomuTrio/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| unsafe {
+            let mut rng = RomuTrioX8::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data: __m512i = _mm512_setzero_si512();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("SFC64/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| unsafe {
+            let mut rng = Sfc64X8::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data: __m512i = _mm512_setzero_si512();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("JSF64/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| unsafe {
+            let mut rng = Jsf64X8::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data: __m512i = _mm512_setzero_si512();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("ChaCha8/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| unsafe {
+            let mut rng = ChaCha8X8::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data: __m512i = _mm512_setzero_si512();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("ChaCha12/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| unsafe {
+            let mut rng = ChaCha12X8::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data: __m512i = _mm512_setzero_si512();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("ChaCha20/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| unsafe {
+            let mut rng = ChaCha20X8::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data: __m512i = _mm512_setzero_si512();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("MT19937-64/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| unsafe {
+            let mut rng = Mt19937_64X8::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data: __m512i = _mm512_setzero_si512();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
         let name = BenchmarkId::new(format!("Xoshiro256+/{suffix}"), iterations);
         group.bench_with_input(name, &iterations, |b, i| unsafe {
             let mut rng = Xoshiro256PlusX8::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
@@ -47,6 +170,22 @@ fn add_m512i_benchmarks<M: Measurement, const ITERATIONS: usize>(
             b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
         });
 
+        let name = BenchmarkId::new(format!("Xoroshiro128+/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| unsafe {
+            let mut rng = Xoroshiro128PlusX8::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data: __m512i = _mm512_setzero_si512();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("Xoroshiro128++/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| unsafe {
+            let mut rng = Xoroshiro128PlusPlusX8::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data: __m512i = _mm512_setzero_si512();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
         let name = BenchmarkId::new(format!("frand/{suffix}"), iterations);
         group.bench_with_input(name, &iterations, |b, i| unsafe {
             let mut rng = FrandX8::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
@@ -59,7 +198,8 @@ fn add_m512i_benchmarks<M: Measurement, const ITERATIONS: usize>(
     group.finish();
 }
 
-#[allow(clippy::items_after_statements)]
+// One benchmark block per generator
+#[allow(clippy::items_after_statements, clippy::too_many_lines)]
 fn add_m512d_benchmarks<M: Measurement, const ITERATIONS: usize>(
     c: &mut Criterion<M>,
     group_prefix: &str,
@@ -87,6 +227,126 @@ fn add_m512d_benchmarks<M: Measurement, const ITERATIONS: usize>(
             b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
         });
 
+        let name = BenchmarkId::new(format!("Xoshiro256**/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| unsafe {
+            let mut rng = Xoshiro256StarStarX8::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data: __m512d = _mm512_setzero_pd();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("Xoshiro512++/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| unsafe {
+            let mut rng = Xoshiro512PlusPlusX8::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data: __m512d = _mm512_setzero_pd();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("Xoshiro512**/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| unsafe {
+            let mut rng = Xoshiro512StarStarX8::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data: __m512d = _mm512_setzero_pd();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("PCG64-DXSM/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| unsafe {
+            let mut rng = Pcg64DxsmX8::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data: __m512d = _mm512_setzero_pd();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("Philox4x32-10/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| unsafe {
+            let mut rng = Philox4x32X8::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data: __m512d = _mm512_setzero_pd();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("Philox2x64-10/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| unsafe {
+            let mut rng = Philox2x64X8::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data: __m512d = _mm512_setzero_pd();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("SplitMix64/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| unsafe {
+            let mut rng = SplitMix64X8::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data: __m512d = _mm512_setzero_pd();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("WyRand/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| unsafe {
+            let mut rng = WyRandX8::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data: __m512d = _mm512_setzero_pd();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("RomuTrio/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| unsafe {
+            let mut rng = RomuTrioX8::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data: __m512d = _mm512_setzero_pd();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("SFC64/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| unsafe {
+            let mut rng = Sfc64X8::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data: __m512d = _mm512_setzero_pd();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("JSF64/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| unsafe {
+            let mut rng = Jsf64X8::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data: __m512d = _mm512_setzero_pd();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("ChaCha8/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| unsafe {
+            let mut rng = ChaCha8X8::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data: __m512d = _mm512_setzero_pd();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("ChaCha12/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| unsafe {
+            let mut rng = ChaCha12X8::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data: __m512d = _mm512_setzero_pd();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("ChaCha20/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| unsafe {
+            let mut rng = ChaCha20X8::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data: __m512d = _mm512_setzero_pd();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("MT19937-64/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| unsafe {
+            let mut rng = Mt19937_64X8::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data: __m512d = _mm512_setzero_pd();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
         let name = BenchmarkId::new(format!("Xoshiro256+/{suffix}"), iterations);
         group.bench_with_input(name, &iterations, |b, i| unsafe {
             let mut rng = Xoshiro256PlusX8::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
@@ -95,6 +355,22 @@ fn add_m512d_benchmarks<M: Measurement, const ITERATIONS: usize>(
             b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
         });
 
+        let name = BenchmarkId::new(format!("Xoroshiro128+/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| unsafe {
+            let mut rng = Xoroshiro128PlusX8::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data: __m512d = _mm512_setzero_pd();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
+        let name = BenchmarkId::new(format!("Xoroshiro128++/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| unsafe {
+            let mut rng = Xoroshiro128PlusPlusX8::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
+            let mut data: __m512d = _mm512_setzero_pd();
+
+            b.iter(|| execute(&mut rng, black_box(&mut data), black_box(*i)));
+        });
+
         let name = BenchmarkId::new(format!("frand/{suffix}"), iterations);
         group.bench_with_input(name, &iterations, |b, i| unsafe {
             let mut rng = FrandX8::seed_from_u64(0x0DDB1A5E5BAD5EEDu64);
@@ -106,3 +382,182 @@ fn add_m512d_benchmarks<M: Measurement, const ITERATIONS: usize>(
 
     group.finish();
 }
+
+/// Copy of the IFMA `mul_hi` of `simd_rand::specific::avx512`, which is private.
+#[cfg(target_feature = "avx512ifma")]
+#[inline(always)]
+fn mul_hi_ifma(a: __m512i, b: __m512i) -> __m512i {
+    unsafe {
+        let zero = _mm512_setzero_si512();
+        let a1 = _mm512_srli_epi64::<52>(a);
+        let b1 = _mm512_srli_epi64::<52>(b);
+
+        let c1 = _mm512_add_epi64(
+            _mm512_madd52hi_epu64(zero, a, b),
+            _mm512_add_epi64(_mm512_madd52lo_epu64(zero, a1, b), _mm512_madd52lo_epu64(zero, a, b1)),
+        );
+        let c2 = _mm512_add_epi64(
+            _mm512_madd52lo_epu64(zero, a1, b1),
+            _mm512_add_epi64(_mm512_madd52hi_epu64(zero, a1, b), _mm512_madd52hi_epu64(zero, a, b1)),
+        );
+
+        _mm512_add_epi64(_mm512_slli_epi64::<40>(c2), _mm512_srli_epi64::<12>(c1))
+    }
+}
+
+/// Copy of the `vpmuludq` fallback `mul_hi` of `simd_rand::specific::avx512`.
+#[inline(always)]
+fn mul_hi_partial(a: __m512i, b: __m512i) -> __m512i {
+    unsafe {
+        let mask = _mm512_set1_epi64(0xFFFF_FFFF);
+        let a_hi = _mm512_srli_epi64::<32>(a);
+        let b_hi = _mm512_srli_epi64::<32>(b);
+
+        let lo_lo = _mm512_mul_epu32(a, b);
+        let lo_hi = _mm512_mul_epu32(a, b_hi);
+        let hi_lo = _mm512_mul_epu32(a_hi, b);
+        let hi_hi = _mm512_mul_epu32(a_hi, b_hi);
+
+        let middle = _mm512_add_epi64(
+            _mm512_add_epi64(_mm512_srli_epi64::<32>(lo_lo), _mm512_and_si512(lo_hi, mask)),
+            _mm512_and_si512(hi_lo, mask),
+        );
+        _mm512_add_epi64(
+            _mm512_add_epi64(hi_hi, _mm512_srli_epi64::<32>(lo_hi)),
+            _mm512_add_epi64(_mm512_srli_epi64::<32>(hi_lo), _mm512_srli_epi64::<32>(middle)),
+        )
+    }
+}
+
+// The high half of the 64-bit product, IFMA against the `vpmuludq` partial products. Every step depends on the
+// previous one, like the WyRand and PCG64-DXSM state updates.
+#[allow(clippy::items_after_statements)]
+fn add_mul_hi_benchmarks<M: Measurement, const ITERATIONS: usize>(
+    c: &mut Criterion<M>,
+    group_prefix: &str,
+    suffix: &str,
+) {
+    let mut group = c.benchmark_group(format!("{group_prefix}/mul_hi"));
+
+    let iterations: Vec<_> = (0..4).map(|v| (v + 1) * ITERATIONS).collect();
+
+    for iterations in iterations {
+        group.throughput(Throughput::Bytes((iterations * mem::size_of::<__m512i>()) as u64));
+
+        #[inline(always)]
+        fn execute(mul_hi: impl Fn(__m512i, __m512i) -> __m512i, data: &mut __m512i, i: usize) {
+            unsafe {
+                let multiplier = _mm512_set1_epi64(0xa076_1d64_78bd_642f_u64.cast_signed());
+                for _ in 0..i {
+                    *data = _mm512_add_epi64(mul_hi(*black_box(&mut *data), multiplier), multiplier);
+                }
+            }
+        }
+
+        #[cfg(target_feature = "avx512ifma")]
+        {
+            let name = BenchmarkId::new(format!("IFMA/{suffix}"), iterations);
+            group.bench_with_input(name, &iterations, |b, i| unsafe {
+                let mut data: __m512i = _mm512_set1_epi64(0x0DDB1A5E5BAD5EED);
+
+                b.iter(|| execute(mul_hi_ifma, black_box(&mut data), black_box(*i)));
+            });
+        }
+
+        let name = BenchmarkId::new(format!("vpmuludq/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| unsafe {
+            let mut data: __m512i = _mm512_set1_epi64(0x0DDB1A5E5BAD5EED);
+
+            b.iter(|| execute(mul_hi_partial, black_box(&mut data), black_box(*i)));
+        });
+    }
+
+    group.finish();
+}
+
+/// `vpermb` indices rotating every 32-bit lane left by `BYTES` bytes.
+#[cfg(target_feature = "avx512vbmi")]
+#[allow(clippy::cast_possible_truncation)]
+const fn rotate_bytes_indices<const BYTES: usize>() -> [u8; 64] {
+    let mut indices = [0u8; 64];
+    let mut i = 0;
+    while i < 64 {
+        indices[i] = ((i & !3) | ((i + 4 - BYTES) & 3)) as u8;
+        i += 1;
+    }
+    indices
+}
+
+// The byte rotations of the ChaCha quarter round, `vprold` against the VBMI byte permute. VBMI is no faster, so
+// the ChaCha generators only use `vprold`.
+#[allow(clippy::items_after_statements)]
+fn add_rotate_benchmarks<M: Measurement, const ITERATIONS: usize>(
+    c: &mut Criterion<M>,
+    group_prefix: &str,
+    suffix: &str,
+) {
+    let mut group = c.benchmark_group(format!("{group_prefix}/rotate"));
+
+    let iterations: Vec<_> = (0..4).map(|v| (v + 1) * ITERATIONS).collect();
+
+    for iterations in iterations {
+        group.throughput(Throughput::Bytes((iterations * mem::size_of::<__m512i>()) as u64));
+
+        // Half of a quarter round, the add and xor keep the rotations on the critical path
+        #[inline(always)]
+        fn execute(
+            rotate_16: impl Fn(__m512i) -> __m512i,
+            rotate_8: impl Fn(__m512i) -> __m512i,
+            data: &mut [__m512i; 2],
+            i: usize,
+        ) {
+            unsafe {
+                let [mut a, mut d] = *black_box(&mut *data);
+                for _ in 0..i {
+                    a = _mm512_add_epi32(a, d);
+                    d = rotate_16(_mm512_xor_si512(d, a));
+                    a = _mm512_add_epi32(a, d);
+                    d = rotate_8(_mm512_xor_si512(d, a));
+                }
+                *data = [a, d];
+            }
+        }
+
+        let name = BenchmarkId::new(format!("vprold/{suffix}"), iterations);
+        group.bench_with_input(name, &iterations, |b, i| unsafe {
+            let mut data = [_mm512_set1_epi32(0x0DDB_1A5E), _mm512_set1_epi32(0x5BAD_5EED)];
+
+            b.iter(|| {
+                execute(
+                    |x| _mm512_rol_epi32::<16>(x),
+                    |x| _mm512_rol_epi32::<8>(x),
+                    black_box(&mut data),
+                    black_box(*i),
+                );
+            });
+        });
+
+        #[cfg(target_feature = "avx512vbmi")]
+        {
+            let name = BenchmarkId::new(format!("VBMI/{suffix}"), iterations);
+            group.bench_with_input(name, &iterations, |b, i| unsafe {
+                const ROTATE_16: [u8; 64] = rotate_bytes_indices::<2>();
+                const ROTATE_8: [u8; 64] = rotate_bytes_indices::<1>();
+                let rotate_16 = _mm512_loadu_si512(ROTATE_16.as_ptr().cast());
+                let rotate_8 = _mm512_loadu_si512(ROTATE_8.as_ptr().cast());
+                let mut data = [_mm512_set1_epi32(0x0DDB_1A5E), _mm512_set1_epi32(0x5BAD_5EED)];
+
+                b.iter(|| {
+                    execute(
+                        |x| _mm512_permutexvar_epi8(rotate_16, x),
+                        |x| _mm512_permutexvar_epi8(rotate_8, x),
+                        black_box(&mut data),
+                        black_box(*i),
+                    );
+                });
+            });
+        }
+    }
+
+    group.finish();
+}
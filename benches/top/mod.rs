@@ -3,9 +3,9 @@ use std::{mem, simd::u64x8};
 use criterion::{BenchmarkGroup, Criterion, Throughput, measurement::Measurement};
 use frand::Rand;
 use rand_core::{RngCore, SeedableRng};
-use simd_rand::portable::SimdRandX8;
 #[cfg(feature = "specific")]
 use simd_rand::specific;
+use simd_rand::{Interleaved, SimdRng, portable::SimdRandX8};
 #[cfg(all(feature = "specific", target_arch = "x86_64"))]
 use std::arch::x86_64::*;
 
@@ -18,6 +18,21 @@ macro_rules! for_each_top_scalar_case {
         $m!(bench_scalar_rngcore, "biski64", |seed| {
             biski64::Biski64Rng::from_seed_for_stream(seed, 0, 1)
         });
+        $m!(bench_scalar_rngcore, "simd_rand/Scalar/WyRand", |seed| {
+            simd_rand::scalar::WyRand::from_state([seed])
+        });
+        $m!(bench_scalar_rngcore, "simd_rand/Scalar/RomuTrio", |seed| {
+            simd_rand::scalar::RomuTrio::seed_from_u64(seed)
+        });
+        $m!(bench_scalar_rngcore, "simd_rand/Scalar/MT19937-64", |seed| {
+            simd_rand::scalar::Mt19937_64::new(seed)
+        });
+        $m!(bench_scalar_rngcore, "simd_rand/Scalar/SFC64", |seed| {
+            simd_rand::scalar::Sfc64::new(seed)
+        });
+        $m!(bench_scalar_rngcore, "simd_rand/Scalar/JSF64", |seed| {
+            simd_rand::scalar::Jsf64::new(seed)
+        });
     };
 }
 
@@ -26,12 +41,42 @@ macro_rules! for_each_top_portable_x8_case {
         $m!(bench_portable_x8, "simd_rand/Portable/Xoshiro256+X8", |seed| {
             simd_rand::portable::Xoshiro256PlusX8::seed_from_u64(seed)
         });
+        $m!(
+            bench_portable_interleaved_x8,
+            "simd_rand/Portable/Xoshiro256+X8 Interleaved x4",
+            |seed| { Interleaved::<simd_rand::portable::Xoshiro256PlusX8, 4>::seed_from_u64(seed) }
+        );
         $m!(bench_portable_x8, "simd_rand/Portable/FrandX8", |seed| {
             simd_rand::portable::FrandX8::seed_from_u64(seed)
         });
+        $m!(
+            bench_portable_interleaved_x8,
+            "simd_rand/Portable/FrandX8 Interleaved x2",
+            |seed| { Interleaved::<simd_rand::portable::FrandX8, 2>::seed_from_u64(seed) }
+        );
+        $m!(
+            bench_portable_interleaved_x8,
+            "simd_rand/Portable/FrandX8 Interleaved x4",
+            |seed| { Interleaved::<simd_rand::portable::FrandX8, 4>::seed_from_u64(seed) }
+        );
         $m!(bench_portable_x8, "simd_rand/Portable/Biski64X8", |seed| {
             simd_rand::portable::Biski64X8::seed_from_u64(seed)
         });
+        $m!(bench_portable_x8, "simd_rand/Portable/WyRandX8", |seed| {
+            simd_rand::portable::WyRandX8::seed_from_u64(seed)
+        });
+        $m!(bench_portable_x8, "simd_rand/Portable/RomuTrioX8", |seed| {
+            simd_rand::portable::RomuTrioX8::seed_from_u64(seed)
+        });
+        $m!(bench_portable_x8, "simd_rand/Portable/MT19937-64X8", |seed| {
+            simd_rand::portable::Mt19937_64X8::new(seed)
+        });
+        $m!(bench_portable_x8, "simd_rand/Portable/SFC64X8", |seed| {
+            simd_rand::portable::Sfc64X8::seed_from_u64(seed)
+        });
+        $m!(bench_portable_x8, "simd_rand/Portable/JSF64X8", |seed| {
+            simd_rand::portable::Jsf64X8::seed_from_u64(seed)
+        });
     };
 }
 
@@ -47,12 +92,42 @@ macro_rules! for_each_top_specific_x8_case {
         $m!(bench_specific_x8, "simd_rand/Specific/Xoshiro256+X8", |seed| {
             simd_rand::specific::avx512::Xoshiro256PlusX8::seed_from_u64(seed)
         });
+        $m!(
+            bench_specific_interleaved_x8,
+            "simd_rand/Specific/Xoshiro256+X8 Interleaved x4",
+            |seed| { Interleaved::<simd_rand::specific::avx512::Xoshiro256PlusX8, 4>::seed_from_u64(seed) }
+        );
         $m!(bench_specific_x8, "simd_rand/Specific/FrandX8", |seed| {
             simd_rand::specific::avx512::FrandX8::seed_from_u64(seed)
         });
+        $m!(
+            bench_specific_interleaved_x8,
+            "simd_rand/Specific/FrandX8 Interleaved x2",
+            |seed| { Interleaved::<simd_rand::specific::avx512::FrandX8, 2>::seed_from_u64(seed) }
+        );
+        $m!(
+            bench_specific_interleaved_x8,
+            "simd_rand/Specific/FrandX8 Interleaved x4",
+            |seed| { Interleaved::<simd_rand::specific::avx512::FrandX8, 4>::seed_from_u64(seed) }
+        );
         $m!(bench_specific_x8, "simd_rand/Specific/Biski64X8", |seed| {
             simd_rand::specific::avx512::Biski64X8::seed_from_u64(seed)
         });
+        $m!(bench_specific_x8, "simd_rand/Specific/WyRandX8", |seed| {
+            simd_rand::specific::avx512::WyRandX8::seed_from_u64(seed)
+        });
+        $m!(bench_specific_x8, "simd_rand/Specific/RomuTrioX8", |seed| {
+            simd_rand::specific::avx512::RomuTrioX8::seed_from_u64(seed)
+        });
+        $m!(bench_specific_x8, "simd_rand/Specific/MT19937-64X8", |seed| {
+            simd_rand::specific::avx512::Mt19937_64X8::new(seed)
+        });
+        $m!(bench_specific_x8, "simd_rand/Specific/SFC64X8", |seed| {
+            simd_rand::specific::avx512::Sfc64X8::seed_from_u64(seed)
+        });
+        $m!(bench_specific_x8, "simd_rand/Specific/JSF64X8", |seed| {
+            simd_rand::specific::avx512::Jsf64X8::seed_from_u64(seed)
+        });
     };
 }
 
@@ -123,6 +198,37 @@ fn bench_portable_x8<M: Measurement, const ITERATIONS: usize, R: SimdRandX8>(
     });
 }
 
+// `K` generators stepped back to back, the same number of vectors as the single generator cases
+fn bench_portable_interleaved_x8<
+    M: Measurement,
+    const ITERATIONS: usize,
+    R: SimdRng<U64Vec = u64x8>,
+    const K: usize,
+>(
+    group: &mut BenchmarkGroup<'_, M>,
+    label: &str,
+    init: u64,
+    make_rng: impl Fn(u64) -> Interleaved<R, K> + Copy,
+) {
+    const { assert!(ITERATIONS.is_multiple_of(K)) };
+
+    group.bench_function(label, |b| {
+        let mut rng = make_rng(init);
+
+        b.iter(|| {
+            let mut data = u64x8::splat(init);
+
+            for _ in 0..ITERATIONS / K {
+                for vector in rng.next_u64_vecs() {
+                    data += vector;
+                }
+            }
+
+            data
+        });
+    });
+}
+
 #[cfg(all(
     feature = "specific",
     target_arch = "x86_64",
@@ -152,6 +258,44 @@ fn bench_specific_x8<M: Measurement, const ITERATIONS: usize, R: specific::avx51
     });
 }
 
+#[cfg(all(
+    feature = "specific",
+    target_arch = "x86_64",
+    target_feature = "avx512f",
+    target_feature = "avx512dq",
+    target_feature = "avx512vl"
+))]
+fn bench_specific_interleaved_x8<
+    M: Measurement,
+    const ITERATIONS: usize,
+    R: SimdRng<U64Vec = specific::avx512::U64x8>,
+    const K: usize,
+>(
+    group: &mut BenchmarkGroup<'_, M>,
+    label: &str,
+    init: u64,
+    init_i: i64,
+    make_rng: impl Fn(u64) -> Interleaved<R, K> + Copy,
+) {
+    const { assert!(ITERATIONS.is_multiple_of(K)) };
+
+    group.bench_function(label, |b| unsafe {
+        let mut rng = make_rng(init);
+
+        b.iter(|| {
+            let mut data = _mm512_set1_epi64(init_i);
+
+            for _ in 0..ITERATIONS / K {
+                for vector in rng.next_u64_vecs() {
+                    data = _mm512_add_epi64(data, vector.into());
+                }
+            }
+
+            data
+        });
+    });
+}
+
 fn add_scalar_top_benchmarks<M: Measurement, const ITERATIONS: usize>(group: &mut BenchmarkGroup<'_, M>, init: u64) {
     macro_rules! register_scalar_case {
         (bench_scalar_rngcore, $label:literal, $make_rng:expr) => {
@@ -167,6 +311,9 @@ fn add_scalar_top_benchmarks<M: Measurement, const ITERATIONS: usize>(group: &mu
 
 fn add_portable_top_benchmarks<M: Measurement, const ITERATIONS: usize>(group: &mut BenchmarkGroup<'_, M>, init: u64) {
     macro_rules! register_portable_case {
+        (bench_portable_interleaved_x8, $label:literal, $make_rng:expr) => {
+            bench_portable_interleaved_x8::<_, ITERATIONS, _, _>(group, $label, init, $make_rng);
+        };
         ($helper:ident, $label:literal, $make_rng:expr) => {
             $helper::<_, ITERATIONS, _>(group, $label, init, $make_rng);
         };
@@ -188,6 +335,9 @@ fn add_specific_top_benchmarks<M: Measurement, const ITERATIONS: usize>(
     init_i: i64,
 ) {
     macro_rules! register_specific_case {
+        (bench_specific_interleaved_x8, $label:literal, $make_rng:expr) => {
+            bench_specific_interleaved_x8::<_, ITERATIONS, _, _>(group, $label, init, init_i, $make_rng);
+        };
         ($helper:ident, $label:literal, $make_rng:expr) => {
             $helper::<_, ITERATIONS, _>(group, $label, init, init_i, $make_rng);
         };
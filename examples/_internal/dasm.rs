@@ -4,7 +4,7 @@
 use biski64::Biski64Rng;
 use frand::Rand;
 use rand_core::{RngCore, SeedableRng};
-use simd_rand::portable::{SimdRandX4 as PortableSimdRandX4, SimdRandX8 as PortableSimdRandX8};
+use simd_rand::portable::{ExponentBits, SimdRandX4 as PortableSimdRandX4, SimdRandX8 as PortableSimdRandX8};
 use simd_rand::specific::avx2::SimdRand as SpecificSimdRandX4;
 #[cfg(all(
     target_arch = "x86_64",
@@ -14,7 +14,7 @@ use simd_rand::specific::avx2::SimdRand as SpecificSimdRandX4;
 ))]
 use simd_rand::specific::avx512::SimdRand as SpecificSimdRandX8;
 use std::hint::black_box;
-use std::simd::{u64x4, u64x8};
+use std::simd::{f64x4, f64x8, u64x4, u64x8};
 
 type Shishua = simd_rand::specific::avx2::Shishua<{ simd_rand::specific::avx2::DEFAULT_BUFFER_SIZE }>;
 
@@ -93,6 +93,23 @@ extern "Rust" fn do_u64x8_biski_baseline(rng: &mut Biski64Rng) -> u64x8 {
     ])
 }
 
+// The conversion is a type parameter, which the wrapper macro below can't pass through
+#[unsafe(no_mangle)]
+#[inline(never)]
+extern "Rust" fn do_f64x4_xoshiro256plus_portable_exponent_bits(
+    rng: &mut simd_rand::portable::Xoshiro256PlusX4,
+) -> f64x4 {
+    rng.next_f64x4_with::<ExponentBits>()
+}
+
+#[unsafe(no_mangle)]
+#[inline(never)]
+extern "Rust" fn do_f64x8_xoshiro256plus_portable_exponent_bits(
+    rng: &mut simd_rand::portable::Xoshiro256PlusX8,
+) -> f64x8 {
+    rng.next_f64x8_with::<ExponentBits>()
+}
+
 macro_rules! define_dasm_wrapper {
     ($binding:ident, $fn_name:ident, $rng_ty:path, $ret_ty:ty, $method:ident) => {
         #[unsafe(no_mangle)]
@@ -311,6 +328,20 @@ macro_rules! for_each_dasm_case {
             std::simd::f64x4,
             next_f64x4
         );
+        $m!(
+            rng_portable_x8,
+            do_f64x8_xoshiro256plus_portable,
+            simd_rand::portable::Xoshiro256PlusX8,
+            std::simd::f64x8,
+            next_f64x8
+        );
+        $m!(
+            rng_portable_frand_x8,
+            do_f64x8_portable_frand,
+            simd_rand::portable::FrandX8,
+            std::simd::f64x8,
+            next_f64x8
+        );
     };
 }
 
@@ -332,6 +363,12 @@ fn main() {
     ));
     black_box(do_u64x8_frand_baseline(&mut rng_frand));
     black_box(do_u64x8_biski_baseline(&mut rng_biski));
+    black_box(do_f64x4_xoshiro256plus_portable_exponent_bits(
+        &mut simd_rand::portable::Xoshiro256PlusX4::seed_from_u64(0),
+    ));
+    black_box(do_f64x8_xoshiro256plus_portable_exponent_bits(
+        &mut simd_rand::portable::Xoshiro256PlusX8::seed_from_u64(0),
+    ));
 
     macro_rules! call_dasm_rng {
         ($binding:ident, $fn_name:ident, $rng_ty:path, $ret_ty:ty, $method:ident) => {{
@@ -0,0 +1,1039 @@
+//! The generators of the internal tools and tests, each as an endless source of `u64` words.
+
+use frand::Rand;
+use rand_core::{RngCore, SeedableRng};
+use simd_rand::portable::{SimdRandX4 as PortableSimdRandX4, SimdRandX8 as PortableSimdRandX8};
+#[cfg(all(feature = "specific", target_arch = "x86_64", target_feature = "avx2"))]
+use simd_rand::specific::avx2::SimdRand as SpecificSimdRandX4;
+#[cfg(all(
+    feature = "specific",
+    target_arch = "x86_64",
+    target_feature = "avx512f",
+    target_feature = "avx512dq",
+    target_feature = "avx512vl"
+))]
+use simd_rand::specific::avx512::SimdRand as SpecificSimdRandX8;
+
+#[cfg(all(feature = "specific", target_arch = "x86_64", target_feature = "avx2"))]
+type Shishua = simd_rand::specific::avx2::Shishua<{ simd_rand::specific::avx2::DEFAULT_BUFFER_SIZE }>;
+
+/// Fills a buffer, whose length is a multiple of 8, with the next words of a generator.
+pub type Source = Box<dyn FnMut(&mut [u64])>;
+
+/// How a generator is seeded.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Seeding {
+    /// `seed_from_u64`, or its equivalent for the reference crates
+    FromU64(u64),
+    /// `from_seed` (or the raw state) as consecutive `u64` words counting up from the value, so that the lanes of a
+    /// vector differ only in their low bits
+    Sequential(u64),
+    /// `from_seed` (or the raw state) with the value as the first `u64` word and every other word 0
+    LowEntropy(u64),
+}
+
+impl Seeding {
+    fn seed<R: SeedableRng>(self) -> R {
+        if let Self::FromU64(seed) = self {
+            return R::seed_from_u64(seed);
+        }
+
+        let mut seed = R::Seed::default();
+        for (i, bytes) in seed.as_mut().chunks_mut(8).enumerate() {
+            bytes.copy_from_slice(&self.word(i).to_le_bytes()[..bytes.len()]);
+        }
+        R::from_seed(seed)
+    }
+
+    /// The raw state words, for the generators constructed from their state.
+    fn words<const N: usize>(self) -> [u64; N] {
+        core::array::from_fn(|i| self.word(i))
+    }
+
+    const fn word(self, i: usize) -> u64 {
+        match self {
+            Self::Sequential(start) => start.wrapping_add(i as u64),
+            Self::FromU64(seed) | Self::LowEntropy(seed) => {
+                if i == 0 {
+                    seed
+                } else {
+                    0
+                }
+            }
+        }
+    }
+}
+
+pub struct RngCase {
+    pub name: &'static str,
+    /// The number of interleaved streams in the output, 1 for the scalar generators
+    pub lanes: usize,
+    pub source: fn(Seeding) -> Source,
+}
+
+pub const RNG_CASES: &[RngCase] = &[
+    RngCase {
+        name: "scalar-biski64",
+        lanes: 1,
+        source: |seeding| {
+            let mut rng = match seeding {
+                Seeding::FromU64(seed) => biski64::Biski64Rng::from_seed_for_stream(seed, 0, 1),
+                seeding => seeding.seed(),
+            };
+            Box::new(move |buffer| fill_scalar_rngcore(&mut rng, buffer))
+        },
+    },
+    RngCase {
+        name: "scalar-frand",
+        lanes: 1,
+        source: |seeding| {
+            // Upstream has no raw state constructor, the first word is always hashed
+            let mut rng = frand::Rand::with_seed(seeding.word(0));
+            Box::new(move |buffer| fill_scalar_frand(&mut rng, buffer))
+        },
+    },
+    RngCase {
+        name: "scalar-xoshiro256plus",
+        lanes: 1,
+        source: |seeding| {
+            let mut rng = seeding.seed::<rand_xoshiro::Xoshiro256Plus>();
+            Box::new(move |buffer| fill_scalar_rngcore(&mut rng, buffer))
+        },
+    },
+    RngCase {
+        name: "scalar-xoshiro256plusplus",
+        lanes: 1,
+        source: |seeding| {
+            let mut rng = seeding.seed::<rand_xoshiro::Xoshiro256PlusPlus>();
+            Box::new(move |buffer| fill_scalar_rngcore(&mut rng, buffer))
+        },
+    },
+    RngCase {
+        name: "scalar-xoshiro256starstar",
+        lanes: 1,
+        source: |seeding| {
+            let mut rng = seeding.seed::<rand_xoshiro::Xoshiro256StarStar>();
+            Box::new(move |buffer| fill_scalar_rngcore(&mut rng, buffer))
+        },
+    },
+    RngCase {
+        name: "scalar-xoshiro512plusplus",
+        lanes: 1,
+        source: |seeding| {
+            let mut rng = seeding.seed::<rand_xoshiro::Xoshiro512PlusPlus>();
+            Box::new(move |buffer| fill_scalar_rngcore(&mut rng, buffer))
+        },
+    },
+    RngCase {
+        name: "scalar-xoshiro512starstar",
+        lanes: 1,
+        source: |seeding| {
+            let mut rng = seeding.seed::<rand_xoshiro::Xoshiro512StarStar>();
+            Box::new(move |buffer| fill_scalar_rngcore(&mut rng, buffer))
+        },
+    },
+    RngCase {
+        name: "scalar-pcg64dxsm",
+        lanes: 1,
+        source: |seeding| {
+            let mut rng = seeding.seed::<rand_pcg::Pcg64Dxsm>();
+            Box::new(move |buffer| fill_scalar_rngcore(&mut rng, buffer))
+        },
+    },
+    RngCase {
+        name: "scalar-philox4x32",
+        lanes: 1,
+        source: |seeding| {
+            let mut rng = match seeding {
+                Seeding::FromU64(seed) => simd_rand::scalar::Philox4x32::from_state([seed, 0, 0, 0]),
+                seeding => simd_rand::scalar::Philox4x32::from_state(seeding.words()),
+            };
+            Box::new(move |buffer| fill_scalar_rngcore(&mut rng, buffer))
+        },
+    },
+    RngCase {
+        name: "scalar-philox2x64",
+        lanes: 1,
+        source: |seeding| {
+            let mut rng = match seeding {
+                Seeding::FromU64(seed) => simd_rand::scalar::Philox2x64::from_state([seed, 0, 0, 0]),
+                seeding => simd_rand::scalar::Philox2x64::from_state(seeding.words()),
+            };
+            Box::new(move |buffer| fill_scalar_rngcore(&mut rng, buffer))
+        },
+    },
+    RngCase {
+        name: "scalar-chacha8",
+        lanes: 1,
+        source: |seeding| {
+            let mut rng = seeding.seed::<rand_chacha::ChaCha8Rng>();
+            Box::new(move |buffer| fill_scalar_rngcore(&mut rng, buffer))
+        },
+    },
+    RngCase {
+        name: "scalar-splitmix64",
+        lanes: 1,
+        source: |seeding| {
+            let mut rng = simd_rand::scalar::SplitMix64::from_state(seeding.words());
+            Box::new(move |buffer| fill_scalar_rngcore(&mut rng, buffer))
+        },
+    },
+    RngCase {
+        name: "scalar-wyrand",
+        lanes: 1,
+        source: |seeding| {
+            let mut rng = simd_rand::scalar::WyRand::from_state(seeding.words());
+            Box::new(move |buffer| fill_scalar_rngcore(&mut rng, buffer))
+        },
+    },
+    RngCase {
+        name: "scalar-romutrio",
+        lanes: 1,
+        source: |seeding| {
+            let mut rng = seeding.seed::<simd_rand::portable::RomuTrioX4>().lane(0);
+            Box::new(move |buffer| fill_scalar_rngcore(&mut rng, buffer))
+        },
+    },
+    RngCase {
+        name: "scalar-mt19937-64",
+        lanes: 1,
+        source: |seeding| {
+            let mut rng = match seeding {
+                Seeding::FromU64(seed) => simd_rand::scalar::Mt19937_64::new(seed),
+                seeding => seeding.seed(),
+            };
+            Box::new(move |buffer| fill_scalar_rngcore(&mut rng, buffer))
+        },
+    },
+    RngCase {
+        name: "scalar-sfc64",
+        lanes: 1,
+        source: |seeding| {
+            let mut rng = match seeding {
+                Seeding::FromU64(seed) => simd_rand::scalar::Sfc64::new(seed),
+                seeding => simd_rand::scalar::Sfc64::from_state(seeding.words()),
+            };
+            Box::new(move |buffer| fill_scalar_rngcore(&mut rng, buffer))
+        },
+    },
+    RngCase {
+        name: "scalar-jsf64",
+        lanes: 1,
+        source: |seeding| {
+            let mut rng = match seeding {
+                Seeding::FromU64(seed) => simd_rand::scalar::Jsf64::new(seed),
+                seeding => simd_rand::scalar::Jsf64::from_state(seeding.words()),
+            };
+            Box::new(move |buffer| fill_scalar_rngcore(&mut rng, buffer))
+        },
+    },
+    RngCase {
+        name: "scalar-xoroshiro128plus",
+        lanes: 1,
+        source: |seeding| {
+            let mut rng = seeding.seed::<rand_xoshiro::Xoroshiro128Plus>();
+            Box::new(move |buffer| fill_scalar_rngcore(&mut rng, buffer))
+        },
+    },
+    RngCase {
+        name: "scalar-xoroshiro128plusplus",
+        lanes: 1,
+        source: |seeding| {
+            let mut rng = seeding.seed::<rand_xoshiro::Xoroshiro128PlusPlus>();
+            Box::new(move |buffer| fill_scalar_rngcore(&mut rng, buffer))
+        },
+    },
+    RngCase {
+        name: "portable-biski64-x4",
+        lanes: 4,
+        source: |seeding| {
+            let mut rng = seeding.seed::<simd_rand::portable::Biski64X4>();
+            Box::new(move |buffer| fill_portable_x4(&mut rng, buffer))
+        },
+    },
+    RngCase {
+        name: "portable-biski64-x8",
+        lanes: 8,
+        source: |seeding| {
+            let mut rng = seeding.seed::<simd_rand::portable::Biski64X8>();
+            Box::new(move |buffer| fill_portable_x8(&mut rng, buffer))
+        },
+    },
+    RngCase {
+        name: "portable-frand-x4",
+        lanes: 4,
+        source: |seeding| {
+            let mut rng = seeding.seed::<simd_rand::portable::FrandX4>();
+            Box::new(move |buffer| fill_portable_x4(&mut rng, buffer))
+        },
+    },
+    RngCase {
+        name: "portable-frand-x8",
+        lanes: 8,
+        source: |seeding| {
+            let mut rng = seeding.seed::<simd_rand::portable::FrandX8>();
+            Box::new(move |buffer| fill_portable_x8(&mut rng, buffer))
+        },
+    },
+    RngCase {
+        name: "portable-xoshiro256plus-x4",
+        lanes: 4,
+        source: |seeding| {
+            let mut rng = seeding.seed::<simd_rand::portable::Xoshiro256PlusX4>();
+            Box::new(move |buffer| fill_portable_x4(&mut rng, buffer))
+        },
+    },
+    RngCase {
+        name: "portable-xoshiro256plus-x8",
+        lanes: 8,
+        source: |seeding| {
+            let mut rng = seeding.seed::<simd_rand::portable::Xoshiro256PlusX8>();
+            Box::new(move |buffer| fill_portable_x8(&mut rng, buffer))
+        },
+    },
+    RngCase {
+        name: "portable-xoshiro256plusplus-x4",
+        lanes: 4,
+        source: |seeding| {
+            let mut rng = seeding.seed::<simd_rand::portable::Xoshiro256PlusPlusX4>();
+            Box::new(move |buffer| fill_portable_x4(&mut rng, buffer))
+        },
+    },
+    RngCase {
+        name: "portable-xoshiro256starstar-x4",
+        lanes: 4,
+        source: |seeding| {
+            let mut rng = seeding.seed::<simd_rand::portable::Xoshiro256StarStarX4>();
+            Box::new(move |buffer| fill_portable_x4(&mut rng, buffer))
+        },
+    },
+    RngCase {
+        name: "portable-xoshiro512plusplus-x4",
+        lanes: 4,
+        source: |seeding| {
+            let mut rng = seeding.seed::<simd_rand::portable::Xoshiro512PlusPlusX4>();
+            Box::new(move |buffer| fill_portable_x4(&mut rng, buffer))
+        },
+    },
+    RngCase {
+        name: "portable-xoshiro512starstar-x4",
+        lanes: 4,
+        source: |seeding| {
+            let mut rng = seeding.seed::<simd_rand::portable::Xoshiro512StarStarX4>();
+            Box::new(move |buffer| fill_portable_x4(&mut rng, buffer))
+        },
+    },
+    RngCase {
+        name: "portable-pcg64dxsm-x4",
+        lanes: 4,
+        source: |seeding| {
+            let mut rng = seeding.seed::<simd_rand::portable::Pcg64DxsmX4>();
+            Box::new(move |buffer| fill_portable_x4(&mut rng, buffer))
+        },
+    },
+    RngCase {
+        name: "portable-philox4x32-x4",
+        lanes: 4,
+        source: |seeding| {
+            let mut rng = seeding.seed::<simd_rand::portable::Philox4x32X4>();
+            Box::new(move |buffer| fill_portable_x4(&mut rng, buffer))
+        },
+    },
+    RngCase {
+        name: "portable-philox2x64-x4",
+        lanes: 4,
+        source: |seeding| {
+            let mut rng = seeding.seed::<simd_rand::portable::Philox2x64X4>();
+            Box::new(move |buffer| fill_portable_x4(&mut rng, buffer))
+        },
+    },
+    RngCase {
+        name: "portable-chacha8-x4",
+        lanes: 4,
+        source: |seeding| {
+            let mut rng = seeding.seed::<simd_rand::portable::ChaCha8X4>();
+            Box::new(move |buffer| fill_portable_x4(&mut rng, buffer))
+        },
+    },
+    RngCase {
+        name: "portable-splitmix64-x4",
+        lanes: 4,
+        source: |seeding| {
+            let mut rng = seeding.seed::<simd_rand::portable::SplitMix64X4>();
+            Box::new(move |buffer| fill_portable_x4(&mut rng, buffer))
+        },
+    },
+    RngCase {
+        name: "portable-wyrand-x4",
+        lanes: 4,
+        source: |seeding| {
+            let mut rng = seeding.seed::<simd_rand::portable::WyRandX4>();
+            Box::new(move |buffer| fill_portable_x4(&mut rng, buffer))
+        },
+    },
+    RngCase {
+        name: "portable-romutrio-x4",
+        lanes: 4,
+        source: |seeding| {
+            let mut rng = seeding.seed::<simd_rand::portable::RomuTrioX4>();
+            Box::new(move |buffer| fill_portable_x4(&mut rng, buffer))
+        },
+    },
+    RngCase {
+        name: "portable-mt19937-64-x4",
+        lanes: 4,
+        source: |seeding| {
+            let mut rng = match seeding {
+                Seeding::FromU64(seed) => simd_rand::portable::Mt19937_64X4::new(seed),
+                seeding => seeding.seed(),
+            };
+            Box::new(move |buffer| fill_portable_x4(&mut rng, buffer))
+        },
+    },
+    RngCase {
+        name: "portable-sfc64-x4",
+        lanes: 4,
+        source: |seeding| {
+            let mut rng = seeding.seed::<simd_rand::portable::Sfc64X4>();
+            Box::new(move |buffer| fill_portable_x4(&mut rng, buffer))
+        },
+    },
+    RngCase {
+        name: "portable-jsf64-x4",
+        lanes: 4,
+        source: |seeding| {
+            let mut rng = seeding.seed::<simd_rand::portable::Jsf64X4>();
+            Box::new(move |buffer| fill_portable_x4(&mut rng, buffer))
+        },
+    },
+    RngCase {
+        name: "portable-xoshiro256plusplus-x8",
+        lanes: 8,
+        source: |seeding| {
+            let mut rng = seeding.seed::<simd_rand::portable::Xoshiro256PlusPlusX8>();
+            Box::new(move |buffer| fill_portable_x8(&mut rng, buffer))
+        },
+    },
+    RngCase {
+        name: "portable-xoshiro256starstar-x8",
+        lanes: 8,
+        source: |seeding| {
+            let mut rng = seeding.seed::<simd_rand::portable::Xoshiro256StarStarX8>();
+            Box::new(move |buffer| fill_portable_x8(&mut rng, buffer))
+        },
+    },
+    RngCase {
+        name: "portable-xoshiro512plusplus-x8",
+        lanes: 8,
+        source: |seeding| {
+            let mut rng = seeding.seed::<simd_rand::portable::Xoshiro512PlusPlusX8>();
+            Box::new(move |buffer| fill_portable_x8(&mut rng, buffer))
+        },
+    },
+    RngCase {
+        name: "portable-xoshiro512starstar-x8",
+        lanes: 8,
+        source: |seeding| {
+            let mut rng = seeding.seed::<simd_rand::portable::Xoshiro512StarStarX8>();
+            Box::new(move |buffer| fill_portable_x8(&mut rng, buffer))
+        },
+    },
+    RngCase {
+        name: "portable-pcg64dxsm-x8",
+        lanes: 8,
+        source: |seeding| {
+            let mut rng = seeding.seed::<simd_rand::portable::Pcg64DxsmX8>();
+            Box::new(move |buffer| fill_portable_x8(&mut rng, buffer))
+        },
+    },
+    RngCase {
+        name: "portable-philox4x32-x8",
+        lanes: 8,
+        source: |seeding| {
+            let mut rng = seeding.seed::<simd_rand::portable::Philox4x32X8>();
+            Box::new(move |buffer| fill_portable_x8(&mut rng, buffer))
+        },
+    },
+    RngCase {
+        name: "portable-philox2x64-x8",
+        lanes: 8,
+        source: |seeding| {
+            let mut rng = seeding.seed::<simd_rand::portable::Philox2x64X8>();
+            Box::new(move |buffer| fill_portable_x8(&mut rng, buffer))
+        },
+    },
+    RngCase {
+        name: "portable-chacha8-x8",
+        lanes: 8,
+        source: |seeding| {
+            let mut rng = seeding.seed::<simd_rand::portable::ChaCha8X8>();
+            Box::new(move |buffer| fill_portable_x8(&mut rng, buffer))
+        },
+    },
+    RngCase {
+        name: "portable-splitmix64-x8",
+        lanes: 8,
+        source: |seeding| {
+            let mut rng = seeding.seed::<simd_rand::portable::SplitMix64X8>();
+            Box::new(move |buffer| fill_portable_x8(&mut rng, buffer))
+        },
+    },
+    RngCase {
+        name: "portable-wyrand-x8",
+        lanes: 8,
+        source: |seeding| {
+            let mut rng = seeding.seed::<simd_rand::portable::WyRandX8>();
+            Box::new(move |buffer| fill_portable_x8(&mut rng, buffer))
+        },
+    },
+    RngCase {
+        name: "portable-romutrio-x8",
+        lanes: 8,
+        source: |seeding| {
+            let mut rng = seeding.seed::<simd_rand::portable::RomuTrioX8>();
+            Box::new(move |buffer| fill_portable_x8(&mut rng, buffer))
+        },
+    },
+    RngCase {
+        name: "portable-mt19937-64-x8",
+        lanes: 8,
+        source: |seeding| {
+            let mut rng = match seeding {
+                Seeding::FromU64(seed) => simd_rand::portable::Mt19937_64X8::new(seed),
+                seeding => seeding.seed(),
+            };
+            Box::new(move |buffer| fill_portable_x8(&mut rng, buffer))
+        },
+    },
+    RngCase {
+        name: "portable-sfc64-x8",
+        lanes: 8,
+        source: |seeding| {
+            let mut rng = seeding.seed::<simd_rand::portable::Sfc64X8>();
+            Box::new(move |buffer| fill_portable_x8(&mut rng, buffer))
+        },
+    },
+    RngCase {
+        name: "portable-jsf64-x8",
+        lanes: 8,
+        source: |seeding| {
+            let mut rng = seeding.seed::<simd_rand::portable::Jsf64X8>();
+            Box::new(move |buffer| fill_portable_x8(&mut rng, buffer))
+        },
+    },
+    RngCase {
+        name: "portable-xoroshiro128plus-x8",
+        lanes: 8,
+        source: |seeding| {
+            let mut rng = seeding.seed::<simd_rand::portable::Xoroshiro128PlusX8>();
+            Box::new(move |buffer| fill_portable_x8(&mut rng, buffer))
+        },
+    },
+    RngCase {
+        name: "portable-xoroshiro128plusplus-x8",
+        lanes: 8,
+        source: |seeding| {
+            let mut rng = seeding.seed::<simd_rand::portable::Xoroshiro128PlusPlusX8>();
+            Box::new(move |buffer| fill_portable_x8(&mut rng, buffer))
+        },
+    },
+    #[cfg(all(feature = "specific", target_arch = "x86_64", target_feature = "avx2"))]
+    RngCase {
+        name: "specific-biski64-x4",
+        lanes: 4,
+        source: |seeding| {
+            let mut rng = seeding.seed::<simd_rand::specific::avx2::Biski64X4>();
+            Box::new(move |buffer| fill_specific_x4(&mut rng, buffer))
+        },
+    },
+    #[cfg(all(feature = "specific", target_arch = "x86_64", target_feature = "avx2"))]
+    RngCase {
+        name: "specific-frand-x4",
+        lanes: 4,
+        source: |seeding| {
+            let mut rng = seeding.seed::<simd_rand::specific::avx2::FrandX4>();
+            Box::new(move |buffer| fill_specific_x4(&mut rng, buffer))
+        },
+    },
+    #[cfg(all(feature = "specific", target_arch = "x86_64", target_feature = "avx2"))]
+    RngCase {
+        name: "specific-xoshiro256plus-x4",
+        lanes: 4,
+        source: |seeding| {
+            let mut rng = seeding.seed::<simd_rand::specific::avx2::Xoshiro256PlusX4>();
+            Box::new(move |buffer| fill_specific_x4(&mut rng, buffer))
+        },
+    },
+    #[cfg(all(feature = "specific", target_arch = "x86_64", target_feature = "avx2"))]
+    RngCase {
+        name: "specific-xoshiro256plusplus-x4",
+        lanes: 4,
+        source: |seeding| {
+            let mut rng = seeding.seed::<simd_rand::specific::avx2::Xoshiro256PlusPlusX4>();
+            Box::new(move |buffer| fill_specific_x4(&mut rng, buffer))
+        },
+    },
+    #[cfg(all(feature = "specific", target_arch = "x86_64", target_feature = "avx2"))]
+    RngCase {
+        name: "specific-xoshiro256starstar-x4",
+        lanes: 4,
+        source: |seeding| {
+            let mut rng = seeding.seed::<simd_rand::specific::avx2::Xoshiro256StarStarX4>();
+            Box::new(move |buffer| fill_specific_x4(&mut rng, buffer))
+        },
+    },
+    #[cfg(all(feature = "specific", target_arch = "x86_64", target_feature = "avx2"))]
+    RngCase {
+        name: "specific-xoshiro512plusplus-x4",
+        lanes: 4,
+        source: |seeding| {
+            let mut rng = seeding.seed::<simd_rand::specific::avx2::Xoshiro512PlusPlusX4>();
+            Box::new(move |buffer| fill_specific_x4(&mut rng, buffer))
+        },
+    },
+    #[cfg(all(feature = "specific", target_arch = "x86_64", target_feature = "avx2"))]
+    RngCase {
+        name: "specific-xoshiro512starstar-x4",
+        lanes: 4,
+        source: |seeding| {
+            let mut rng = seeding.seed::<simd_rand::specific::avx2::Xoshiro512StarStarX4>();
+            Box::new(move |buffer| fill_specific_x4(&mut rng, buffer))
+        },
+    },
+    #[cfg(all(feature = "specific", target_arch = "x86_64", target_feature = "avx2"))]
+    RngCase {
+        name: "specific-pcg64dxsm-x4",
+        lanes: 4,
+        source: |seeding| {
+            let mut rng = seeding.seed::<simd_rand::specific::avx2::Pcg64DxsmX4>();
+            Box::new(move |buffer| fill_specific_x4(&mut rng, buffer))
+        },
+    },
+    #[cfg(all(feature = "specific", target_arch = "x86_64", target_feature = "avx2"))]
+    RngCase {
+        name: "specific-philox4x32-x4",
+        lanes: 4,
+        source: |seeding| {
+            let mut rng = seeding.seed::<simd_rand::specific::avx2::Philox4x32X4>();
+            Box::new(move |buffer| fill_specific_x4(&mut rng, buffer))
+        },
+    },
+    #[cfg(all(feature = "specific", target_arch = "x86_64", target_feature = "avx2"))]
+    RngCase {
+        name: "specific-philox2x64-x4",
+        lanes: 4,
+        source: |seeding| {
+            let mut rng = seeding.seed::<simd_rand::specific::avx2::Philox2x64X4>();
+            Box::new(move |buffer| fill_specific_x4(&mut rng, buffer))
+        },
+    },
+    #[cfg(all(feature = "specific", target_arch = "x86_64", target_feature = "avx2"))]
+    RngCase {
+        name: "specific-chacha8-x4",
+        lanes: 4,
+        source: |seeding| {
+            let mut rng = seeding.seed::<simd_rand::specific::avx2::ChaCha8X4>();
+            Box::new(move |buffer| fill_specific_x4(&mut rng, buffer))
+        },
+    },
+    #[cfg(all(feature = "specific", target_arch = "x86_64", target_feature = "avx2"))]
+    RngCase {
+        name: "specific-splitmix64-x4",
+        lanes: 4,
+        source: |seeding| {
+            let mut rng = seeding.seed::<simd_rand::specific::avx2::SplitMix64X4>();
+            Box::new(move |buffer| fill_specific_x4(&mut rng, buffer))
+        },
+    },
+    #[cfg(all(feature = "specific", target_arch = "x86_64", target_feature = "avx2"))]
+    RngCase {
+        name: "specific-wyrand-x4",
+        lanes: 4,
+        source: |seeding| {
+            let mut rng = seeding.seed::<simd_rand::specific::avx2::WyRandX4>();
+            Box::new(move |buffer| fill_specific_x4(&mut rng, buffer))
+        },
+    },
+    #[cfg(all(feature = "specific", target_arch = "x86_64", target_feature = "avx2"))]
+    RngCase {
+        name: "specific-romutrio-x4",
+        lanes: 4,
+        source: |seeding| {
+            let mut rng = seeding.seed::<simd_rand::specific::avx2::RomuTrioX4>();
+            Box::new(move |buffer| fill_specific_x4(&mut rng, buffer))
+        },
+    },
+    #[cfg(all(feature = "specific", target_arch = "x86_64", target_feature = "avx2"))]
+    RngCase {
+        name: "specific-mt19937-64-x4",
+        lanes: 4,
+        source: |seeding| {
+            let mut rng = match seeding {
+                Seeding::FromU64(seed) => simd_rand::specific::avx2::Mt19937_64X4::new(seed),
+                seeding => seeding.seed(),
+            };
+            Box::new(move |buffer| fill_specific_x4(&mut rng, buffer))
+        },
+    },
+    #[cfg(all(feature = "specific", target_arch = "x86_64", target_feature = "avx2"))]
+    RngCase {
+        name: "specific-sfc64-x4",
+        lanes: 4,
+        source: |seeding| {
+            let mut rng = seeding.seed::<simd_rand::specific::avx2::Sfc64X4>();
+            Box::new(move |buffer| fill_specific_x4(&mut rng, buffer))
+        },
+    },
+    #[cfg(all(feature = "specific", target_arch = "x86_64", target_feature = "avx2"))]
+    RngCase {
+        name: "specific-jsf64-x4",
+        lanes: 4,
+        source: |seeding| {
+            let mut rng = seeding.seed::<simd_rand::specific::avx2::Jsf64X4>();
+            Box::new(move |buffer| fill_specific_x4(&mut rng, buffer))
+        },
+    },
+    #[cfg(all(feature = "specific", target_arch = "x86_64", target_feature = "avx2"))]
+    RngCase {
+        name: "specific-shishua-x4",
+        lanes: 4,
+        source: |seeding| {
+            let mut rng = seeding.seed::<Shishua>();
+            Box::new(move |buffer| fill_specific_x4(&mut rng, buffer))
+        },
+    },
+    #[cfg(all(
+        feature = "specific",
+        target_arch = "x86_64",
+        target_feature = "avx512f",
+        target_feature = "avx512dq",
+        target_feature = "avx512vl"
+    ))]
+    RngCase {
+        name: "specific-biski64-x8",
+        lanes: 8,
+        source: |seeding| {
+            let mut rng = seeding.seed::<simd_rand::specific::avx512::Biski64X8>();
+            Box::new(move |buffer| fill_specific_x8(&mut rng, buffer))
+        },
+    },
+    #[cfg(all(
+        feature = "specific",
+        target_arch = "x86_64",
+        target_feature = "avx512f",
+        target_feature = "avx512dq",
+        target_feature = "avx512vl"
+    ))]
+    RngCase {
+        name: "specific-frand-x8",
+        lanes: 8,
+        source: |seeding| {
+            let mut rng = seeding.seed::<simd_rand::specific::avx512::FrandX8>();
+            Box::new(move |buffer| fill_specific_x8(&mut rng, buffer))
+        },
+    },
+    #[cfg(all(
+        feature = "specific",
+        target_arch = "x86_64",
+        target_feature = "avx512f",
+        target_feature = "avx512dq",
+        target_feature = "avx512vl"
+    ))]
+    RngCase {
+        name: "specific-xoshiro256plus-x8",
+        lanes: 8,
+        source: |seeding| {
+            let mut rng = seeding.seed::<simd_rand::specific::avx512::Xoshiro256PlusX8>();
+            Box::new(move |buffer| fill_specific_x8(&mut rng, buffer))
+        },
+    },
+    #[cfg(all(
+        feature = "specific",
+        target_arch = "x86_64",
+        target_feature = "avx512f",
+        target_feature = "avx512dq",
+        target_feature = "avx512vl"
+    ))]
+    RngCase {
+        name: "specific-xoshiro256plusplus-x8",
+        lanes: 8,
+        source: |seeding| {
+            let mut rng = seeding.seed::<simd_rand::specific::avx512::Xoshiro256PlusPlusX8>();
+            Box::new(move |buffer| fill_specific_x8(&mut rng, buffer))
+        },
+    },
+    #[cfg(all(
+        feature = "specific",
+        target_arch = "x86_64",
+        target_feature = "avx512f",
+        target_feature = "avx512dq",
+        target_feature = "avx512vl"
+    ))]
+    RngCase {
+        name: "specific-xoshiro256starstar-x8",
+        lanes: 8,
+        source: |seeding| {
+            let mut rng = seeding.seed::<simd_rand::specific::avx512::Xoshiro256StarStarX8>();
+            Box::new(move |buffer| fill_specific_x8(&mut rng, buffer))
+        },
+    },
+    #[cfg(all(
+        feature = "specific",
+        target_arch = "x86_64",
+        target_feature = "avx512f",
+        target_feature = "avx512dq",
+        target_feature = "avx512vl"
+    ))]
+    RngCase {
+        name: "specific-xoshiro512plusplus-x8",
+        lanes: 8,
+        source: |seeding| {
+            let mut rng = seeding.seed::<simd_rand::specific::avx512::Xoshiro512PlusPlusX8>();
+            Box::new(move |buffer| fill_specific_x8(&mut rng, buffer))
+        },
+    },
+    #[cfg(all(
+        feature = "specific",
+        target_arch = "x86_64",
+        target_feature = "avx512f",
+        target_feature = "avx512dq",
+        target_feature = "avx512vl"
+    ))]
+    RngCase {
+        name: "specific-xoshiro512starstar-x8",
+        lanes: 8,
+        source: |seeding| {
+            let mut rng = seeding.seed::<simd_rand::specific::avx512::Xoshiro512StarStarX8>();
+            Box::new(move |buffer| fill_specific_x8(&mut rng, buffer))
+        },
+    },
+    #[cfg(all(
+        feature = "specific",
+        target_arch = "x86_64",
+        target_feature = "avx512f",
+        target_feature = "avx512dq",
+        target_feature = "avx512vl"
+    ))]
+    RngCase {
+        name: "specific-pcg64dxsm-x8",
+        lanes: 8,
+        source: |seeding| {
+            let mut rng = seeding.seed::<simd_rand::specific::avx512::Pcg64DxsmX8>();
+            Box::new(move |buffer| fill_specific_x8(&mut rng, buffer))
+        },
+    },
+    #[cfg(all(
+        feature = "specific",
+        target_arch = "x86_64",
+        target_feature = "avx512f",
+        target_feature = "avx512dq",
+        target_feature = "avx512vl"
+    ))]
+    RngCase {
+        name: "specific-philox4x32-x8",
+        lanes: 8,
+        source: |seeding| {
+            let mut rng = seeding.seed::<simd_rand::specific::avx512::Philox4x32X8>();
+            Box::new(move |buffer| fill_specific_x8(&mut rng, buffer))
+        },
+    },
+    #[cfg(all(
+        feature = "specific",
+        target_arch = "x86_64",
+        target_feature = "avx512f",
+        target_feature = "avx512dq",
+        target_feature = "avx512vl"
+    ))]
+    RngCase {
+        name: "specific-philox2x64-x8",
+        lanes: 8,
+        source: |seeding| {
+            let mut rng = seeding.seed::<simd_rand::specific::avx512::Philox2x64X8>();
+            Box::new(move |buffer| fill_specific_x8(&mut rng, buffer))
+        },
+    },
+    #[cfg(all(
+        feature = "specific",
+        target_arch = "x86_64",
+        target_feature = "avx512f",
+        target_feature = "avx512dq",
+        target_feature = "avx512vl"
+    ))]
+    RngCase {
+        name: "specific-chacha8-x8",
+        lanes: 8,
+        source: |seeding| {
+            let mut rng = seeding.seed::<simd_rand::specific::avx512::ChaCha8X8>();
+            Box::new(move |buffer| fill_specific_x8(&mut rng, buffer))
+        },
+    },
+    #[cfg(all(
+        feature = "specific",
+        target_arch = "x86_64",
+        target_feature = "avx512f",
+        target_feature = "avx512dq",
+        target_feature = "avx512vl"
+    ))]
+    RngCase {
+        name: "specific-splitmix64-x8",
+        lanes: 8,
+        source: |seeding| {
+            let mut rng = seeding.seed::<simd_rand::specific::avx512::SplitMix64X8>();
+            Box::new(move |buffer| fill_specific_x8(&mut rng, buffer))
+        },
+    },
+    #[cfg(all(
+        feature = "specific",
+        target_arch = "x86_64",
+        target_feature = "avx512f",
+        target_feature = "avx512dq",
+        target_feature = "avx512vl"
+    ))]
+    RngCase {
+        name: "specific-wyrand-x8",
+        lanes: 8,
+        source: |seeding| {
+            let mut rng = seeding.seed::<simd_rand::specific::avx512::WyRandX8>();
+            Box::new(move |buffer| fill_specific_x8(&mut rng, buffer))
+        },
+    },
+    #[cfg(all(
+        feature = "specific",
+        target_arch = "x86_64",
+        target_feature = "avx512f",
+        target_feature = "avx512dq",
+        target_feature = "avx512vl"
+    ))]
+    RngCase {
+        name: "specific-romutrio-x8",
+        lanes: 8,
+        source: |seeding| {
+            let mut rng = seeding.seed::<simd_rand::specific::avx512::RomuTrioX8>();
+            Box::new(move |buffer| fill_specific_x8(&mut rng, buffer))
+        },
+    },
+    #[cfg(all(
+        feature = "specific",
+        target_arch = "x86_64",
+        target_feature = "avx512f",
+        target_feature = "avx512dq",
+        target_feature = "avx512vl"
+    ))]
+    RngCase {
+        name: "specific-mt19937-64-x8",
+        lanes: 8,
+        source: |seeding| {
+            let mut rng = match seeding {
+                Seeding::FromU64(seed) => simd_rand::specific::avx512::Mt19937_64X8::new(seed),
+                seeding => seeding.seed(),
+            };
+            Box::new(move |buffer| fill_specific_x8(&mut rng, buffer))
+        },
+    },
+    #[cfg(all(
+        feature = "specific",
+        target_arch = "x86_64",
+        target_feature = "avx512f",
+        target_feature = "avx512dq",
+        target_feature = "avx512vl"
+    ))]
+    RngCase {
+        name: "specific-sfc64-x8",
+        lanes: 8,
+        source: |seeding| {
+            let mut rng = seeding.seed::<simd_rand::specific::avx512::Sfc64X8>();
+            Box::new(move |buffer| fill_specific_x8(&mut rng, buffer))
+        },
+    },
+    #[cfg(all(
+        feature = "specific",
+        target_arch = "x86_64",
+        target_feature = "avx512f",
+        target_feature = "avx512dq",
+        target_feature = "avx512vl"
+    ))]
+    RngCase {
+        name: "specific-jsf64-x8",
+        lanes: 8,
+        source: |seeding| {
+            let mut rng = seeding.seed::<simd_rand::specific::avx512::Jsf64X8>();
+            Box::new(move |buffer| fill_specific_x8(&mut rng, buffer))
+        },
+    },
+    #[cfg(all(
+        feature = "specific",
+        target_arch = "x86_64",
+        target_feature = "avx512f",
+        target_feature = "avx512dq",
+        target_feature = "avx512vl"
+    ))]
+    RngCase {
+        name: "specific-xoroshiro128plus-x8",
+        lanes: 8,
+        source: |seeding| {
+            let mut rng = seeding.seed::<simd_rand::specific::avx512::Xoroshiro128PlusX8>();
+            Box::new(move |buffer| fill_specific_x8(&mut rng, buffer))
+        },
+    },
+    #[cfg(all(
+        feature = "specific",
+        target_arch = "x86_64",
+        target_feature = "avx512f",
+        target_feature = "avx512dq",
+        target_feature = "avx512vl"
+    ))]
+    RngCase {
+        name: "specific-xoroshiro128plusplus-x8",
+        lanes: 8,
+        source: |seeding| {
+            let mut rng = seeding.seed::<simd_rand::specific::avx512::Xoroshiro128PlusPlusX8>();
+            Box::new(move |buffer| fill_specific_x8(&mut rng, buffer))
+        },
+    },
+];
+
+fn fill_scalar_frand(rng: &mut Rand, buffer: &mut [u64]) {
+    for value in buffer {
+        *value = rng.r#gen::<u64>();
+    }
+}
+
+fn fill_scalar_rngcore(rng: &mut impl RngCore, buffer: &mut [u64]) {
+    for value in buffer {
+        *value = rng.next_u64();
+    }
+}
+
+fn fill_portable_x4(rng: &mut impl PortableSimdRandX4, buffer: &mut [u64]) {
+    for chunk in buffer.chunks_exact_mut(4) {
+        rng.next_u64x4().copy_to_slice(chunk);
+    }
+}
+
+fn fill_portable_x8(rng: &mut impl PortableSimdRandX8, buffer: &mut [u64]) {
+    for chunk in buffer.chunks_exact_mut(8) {
+        rng.next_u64x8().copy_to_slice(chunk);
+    }
+}
+
+#[cfg(all(feature = "specific", target_arch = "x86_64", target_feature = "avx2"))]
+fn fill_specific_x4(rng: &mut impl SpecificSimdRandX4, buffer: &mut [u64]) {
+    for chunk in buffer.chunks_exact_mut(4) {
+        let values = rng.next_u64x4();
+        chunk.copy_from_slice(&*values);
+    }
+}
+
+#[cfg(all(
+    feature = "specific",
+    target_arch = "x86_64",
+    target_feature = "avx512f",
+    target_feature = "avx512dq",
+    target_feature = "avx512vl"
+))]
+fn fill_specific_x8(rng: &mut impl SpecificSimdRandX8, buffer: &mut [u64]) {
+    for chunk in buffer.chunks_exact_mut(8) {
+        let values = rng.next_u64x8();
+        chunk.copy_from_slice(&*values);
+    }
+}
@@ -0,0 +1,270 @@
+//! Streams any generator of the internal tools to stdout, for external test suites such as PractRand (`le`, the
+//! default) and TestU01 (`be`, read by `ufile_CreateReadBin` or `external/testu01_stdin.c`).
+
+#![cfg_attr(feature = "portable", feature(portable_simd))]
+
+use core::str::FromStr;
+use std::io::{self, ErrorKind, Write};
+use std::process::ExitCode;
+
+use rng_cases::{RNG_CASES, RngCase, Seeding, Source};
+
+mod rng_cases;
+
+const DEFAULT_RNG: &str = "portable-frand-x8";
+
+const WORDS: usize = 512;
+
+const OPTIONS: &str = "\
+options:
+  --lane N          only lane N of a SIMD generator
+  --xor-lanes       the XOR of the lanes of every vector
+  --low32           only the low 32 bits of every word
+  --f64             the f64 values in [0, 1) of next_f64, as their IEEE 754 bits
+  --reverse-bytes   every output word with its bytes in reverse order
+  --format F        le (default), be or hex; be writes 32-bit words most significant byte first, a 64-bit word
+                    as its high and then its low half, and hex writes a word per line
+  --bytes N         stop after N bytes, with an optional K, M, G or T suffix for powers of 1024
+  --seeding S       seed-from-u64 (default), sequential or low-entropy; the last two pass from_seed
+                    (or the raw state) u64 words counting up from the seed, or the seed and then zeros";
+
+fn usage(program: &str) -> String {
+    let names: Vec<_> = RNG_CASES.iter().map(|case| case.name).collect();
+    format!(
+        "usage: {program} [options] [{}] [seed]\n\
+         seed may be decimal or 0x-prefixed hex\n\
+         {OPTIONS}",
+        names.join("|")
+    )
+}
+
+fn parse_seed(raw: &str) -> Result<u64, String> {
+    raw.strip_prefix("0x").or_else(|| raw.strip_prefix("0X")).map_or_else(
+        || u64::from_str(raw).map_err(|err| format!("invalid seed '{raw}': {err}")),
+        |hex| u64::from_str_radix(hex, 16).map_err(|err| format!("invalid hex seed '{raw}': {err}")),
+    )
+}
+
+/// Which lanes of the generator make up the stream.
+#[derive(Clone, Copy)]
+enum Lanes {
+    Interleaved,
+    One(usize),
+    Xor,
+}
+
+/// What every word of the stream is written as.
+#[derive(Clone, Copy)]
+enum Words {
+    U64,
+    Low32,
+    F64,
+}
+
+/// How every word of the stream is written.
+#[derive(Clone, Copy)]
+enum Format {
+    Le,
+    Be,
+    Hex,
+}
+
+#[derive(Clone, Copy)]
+struct Stream {
+    lanes: Lanes,
+    words: Words,
+    reverse_bytes: bool,
+    format: Format,
+}
+
+impl Stream {
+    /// Appends the bytes of the stream for the next `words` of a generator with `lanes` lanes.
+    fn encode(self, words: &[u64], lanes: usize, out: &mut Vec<u8>) {
+        match self.lanes {
+            Lanes::Interleaved => words.iter().for_each(|&word| self.encode_word(word, out)),
+            Lanes::One(lane) => words[lane..]
+                .iter()
+                .step_by(lanes)
+                .for_each(|&word| self.encode_word(word, out)),
+            Lanes::Xor => words
+                .chunks_exact(lanes)
+                .for_each(|vector| self.encode_word(vector.iter().fold(0, |xor, word| xor ^ word), out)),
+        }
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn encode_word(self, word: u64, out: &mut Vec<u8>) {
+        const DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+        // The value in the low `width` bytes
+        let (value, width) = match self.words {
+            Words::U64 => (word, 8),
+            Words::Low32 => (word & 0xFFFF_FFFF, 4),
+            Words::F64 => (to_f64(word).to_bits(), 8),
+        };
+        let value = if self.reverse_bytes {
+            value.swap_bytes() >> (64 - 8 * width)
+        } else {
+            value
+        };
+
+        match self.format {
+            Format::Le => out.extend_from_slice(&value.to_le_bytes()[..width]),
+            Format::Be => out.extend_from_slice(&value.to_be_bytes()[8 - width..]),
+            Format::Hex => {
+                out.extend(
+                    (0..2 * width)
+                        .rev()
+                        .map(|nibble| DIGITS[(value >> (4 * nibble)) as usize & 0xF]),
+                );
+                out.push(b'\n');
+            }
+        }
+    }
+}
+
+/// The conversion of `next_f64`, which every backend matches bit for bit.
+#[allow(clippy::cast_precision_loss)]
+fn to_f64(word: u64) -> f64 {
+    (word >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+}
+
+/// Writes the stream until `limit` bytes are written or the reader goes away.
+fn write_loop(mut source: Source, stream: Stream, lanes: usize, limit: u64, out: &mut dyn Write) -> io::Result<()> {
+    let mut words = [0; WORDS];
+    let mut bytes = Vec::with_capacity(WORDS * 17);
+    let mut remaining = limit;
+
+    while remaining > 0 {
+        source(&mut words);
+        bytes.clear();
+        stream.encode(&words, lanes, &mut bytes);
+
+        let len = usize::try_from(remaining).map_or(bytes.len(), |remaining| remaining.min(bytes.len()));
+        match out.write_all(&bytes[..len]) {
+            Ok(()) => remaining -= len as u64,
+            Err(err) if err.kind() == ErrorKind::BrokenPipe => return Ok(()),
+            Err(err) => return Err(err),
+        }
+    }
+
+    match out.flush() {
+        Err(err) if err.kind() == ErrorKind::BrokenPipe => Ok(()),
+        result => result,
+    }
+}
+
+fn run(case: &RngCase, seeding: Seeding, stream: Stream, limit: u64, out: &mut dyn Write) -> io::Result<()> {
+    write_loop((case.source)(seeding), stream, case.lanes, limit, out)
+}
+
+fn parse_seeding(raw: &str) -> Result<fn(u64) -> Seeding, String> {
+    match raw {
+        "seed-from-u64" => Ok(Seeding::FromU64),
+        "sequential" => Ok(Seeding::Sequential),
+        "low-entropy" => Ok(Seeding::LowEntropy),
+        _ => Err(format!("unknown seeding '{raw}'")),
+    }
+}
+
+fn parse_format(raw: &str) -> Result<Format, String> {
+    match raw {
+        "le" => Ok(Format::Le),
+        "be" => Ok(Format::Be),
+        "hex" => Ok(Format::Hex),
+        _ => Err(format!("unknown format '{raw}'")),
+    }
+}
+
+fn parse_bytes(raw: &str) -> Result<u64, String> {
+    let (digits, shift) = [('K', 10), ('M', 20), ('G', 30), ('T', 40)]
+        .into_iter()
+        .find_map(|(suffix, shift)| raw.strip_suffix(suffix).map(|digits| (digits, shift)))
+        .unwrap_or((raw, 0));
+    u64::from_str(digits)
+        .ok()
+        .and_then(|count| count.checked_mul(1 << shift))
+        .ok_or_else(|| format!("invalid byte count '{raw}'"))
+}
+
+fn try_main() -> Result<(), String> {
+    let mut args = std::env::args();
+    let program = args.next().unwrap_or_else(|| String::from("rngstream"));
+    let with_usage = |err: String| format!("{err}\n{}", usage(&program));
+
+    let mut stream = Stream {
+        lanes: Lanes::Interleaved,
+        words: Words::U64,
+        reverse_bytes: false,
+        format: Format::Le,
+    };
+    let mut seeding: fn(u64) -> Seeding = Seeding::FromU64;
+    let mut limit = u64::MAX;
+    let mut positional = Vec::new();
+    while let Some(arg) = args.next() {
+        let mut value = |flag: &str| args.next().ok_or_else(|| with_usage(format!("{flag} needs a value")));
+        match arg.as_str() {
+            "--lane" => {
+                let raw = value("--lane")?;
+                let lane = usize::from_str(&raw).map_err(|err| with_usage(format!("invalid lane '{raw}': {err}")))?;
+                stream.lanes = Lanes::One(lane);
+            }
+            "--xor-lanes" => stream.lanes = Lanes::Xor,
+            "--low32" => stream.words = Words::Low32,
+            "--f64" => stream.words = Words::F64,
+            "--reverse-bytes" => stream.reverse_bytes = true,
+            "--format" => stream.format = parse_format(&value("--format")?).map_err(with_usage)?,
+            "--bytes" => limit = parse_bytes(&value("--bytes")?).map_err(with_usage)?,
+            "--seeding" => seeding = parse_seeding(&value("--seeding")?).map_err(with_usage)?,
+            flag if flag.starts_with("--") => return Err(with_usage(format!("unknown option '{flag}'"))),
+            _ => positional.push(arg),
+        }
+    }
+
+    let mut positional = positional.into_iter();
+    let case = positional.next().map_or_else(
+        || {
+            RNG_CASES
+                .iter()
+                .find(|case| case.name == DEFAULT_RNG)
+                .ok_or_else(|| with_usage(format!("default RNG '{DEFAULT_RNG}' is unavailable")))
+        },
+        |raw| {
+            RNG_CASES
+                .iter()
+                .find(|case| case.name == raw)
+                .ok_or_else(|| with_usage(format!("unknown RNG '{raw}'")))
+        },
+    )?;
+    let seed = positional
+        .next()
+        .map_or(Ok(0), |raw| parse_seed(&raw))
+        .map_err(with_usage)?;
+
+    if let Some(extra) = positional.next() {
+        return Err(with_usage(format!("unexpected argument '{extra}'")));
+    }
+    if let Lanes::One(lane) = stream.lanes
+        && lane >= case.lanes
+    {
+        return Err(format!(
+            "{} has {} lane(s), there is no lane {lane}",
+            case.name, case.lanes
+        ));
+    }
+
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+    run(case, seeding(seed), stream, limit, &mut out).map_err(|err| format!("failed to write random stream: {err}"))?;
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    match try_main() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
+        }
+    }
+}
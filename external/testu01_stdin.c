@@ -0,0 +1,59 @@
+/* Runs a TestU01 battery on the 32-bit words read from stdin, most significant
+   byte first, as written by `rngstream --format be`. Built against TestU01 by
+   `make gettestu01`.
+
+   usage: testu01_stdin smallcrush|crush|bigcrush [name] */
+
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+
+#include "bbattery.h"
+#include "unif01.h"
+
+static unsigned char buffer[1 << 16];
+static size_t length = 0;
+static size_t position = 0;
+
+static unsigned int next_bits(void) {
+    if (position + 4 > length) {
+        /* fread only returns short at the end of the stream */
+        length = fread(buffer, 1, sizeof buffer, stdin);
+        length -= length % 4;
+        position = 0;
+        if (length == 0) {
+            fprintf(stderr, "testu01_stdin: the stream ended before the battery\n");
+            exit(EXIT_FAILURE);
+        }
+    }
+
+    const unsigned char *word = buffer + position;
+    position += 4;
+    return (unsigned int)word[0] << 24 | (unsigned int)word[1] << 16 | (unsigned int)word[2] << 8 |
+           (unsigned int)word[3];
+}
+
+int main(int argc, char **argv) {
+    if (argc < 2 || argc > 3) {
+        fprintf(stderr, "usage: %s smallcrush|crush|bigcrush [name]\n", argv[0]);
+        return EXIT_FAILURE;
+    }
+
+    void (*battery)(unif01_Gen *);
+    if (strcmp(argv[1], "smallcrush") == 0) {
+        battery = bbattery_SmallCrush;
+    } else if (strcmp(argv[1], "crush") == 0) {
+        battery = bbattery_Crush;
+    } else if (strcmp(argv[1], "bigcrush") == 0) {
+        battery = bbattery_BigCrush;
+    } else {
+        fprintf(stderr, "unknown battery '%s'\n", argv[1]);
+        return EXIT_FAILURE;
+    }
+
+    char *name = argc == 3 ? argv[2] : "stdin";
+    unif01_Gen *gen = unif01_CreateExternGenBits(name, next_bits);
+    battery(gen);
+    unif01_DeleteExternGenBits(gen);
+    return EXIT_SUCCESS;
+}
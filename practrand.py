@@ -13,9 +13,11 @@ from pathlib import Path
 ROOT = Path(__file__).resolve().parent
 DEFAULT_OUT_DIR = ROOT / "out"
 DEFAULT_RNG_TEST = ROOT / "external/PractRand/PractRand/RNG_test"
-DEFAULT_BINARY = ROOT / "target/release/examples/practrand"
+DEFAULT_TESTU01 = ROOT / "external/TestU01/testu01_stdin"
+DEFAULT_BINARY = ROOT / "target/release/examples/rngstream"
 DEFAULT_PRACTRAND_ARGS = ("stdin64", "-multithreaded")
-BUILD_CMD = ("cargo", "+nightly", "build", "--example", "practrand", "--features", "portable", "--release")
+TESTU01_BATTERIES = ("smallcrush", "crush", "bigcrush")
+BUILD_CMD = ("cargo", "+nightly", "build", "--example", "rngstream", "--features", "portable", "--release")
 
 
 @dataclass(frozen=True)
@@ -39,9 +41,28 @@ CASES = (
 
 def parse_args() -> argparse.Namespace:
     parser = argparse.ArgumentParser(
-        description="Run PractRand against the scalar and vectorized biski64/xoshiro256+/frand streams."
+        description="Run PractRand or a TestU01 battery against the scalar and vectorized biski64/xoshiro256+/frand "
+        "streams, or the generators given with --rng."
+    )
+    parser.add_argument("--seed", required=True, help="Seed passed through to the Rust rngstream example.")
+    parser.add_argument(
+        "--battery",
+        choices=("practrand", *TESTU01_BATTERIES),
+        default="practrand",
+        help="PractRand RNG_test, or a TestU01 battery run by testu01_stdin.",
+    )
+    parser.add_argument(
+        "--rng",
+        action="append",
+        default=[],
+        help="Generator name of the rngstream example to test instead of the default cases. Repeat for several.",
+    )
+    parser.add_argument(
+        "--stream-arg",
+        action="append",
+        default=[],
+        help="Argument forwarded to the rngstream example, e.g. --stream-arg=--low32. Repeat for multiple arguments.",
     )
-    parser.add_argument("--seed", required=True, help="Seed passed through to the Rust practrand example.")
     parser.add_argument(
         "--out-dir", type=Path, default=DEFAULT_OUT_DIR, help="Root directory that will receive a per-run output folder."
     )
@@ -51,11 +72,17 @@ def parse_args() -> argparse.Namespace:
         default=DEFAULT_RNG_TEST,
         help="Path to the PractRand RNG_test binary. Assumes getpractrand has already been run.",
     )
+    parser.add_argument(
+        "--testu01",
+        type=Path,
+        default=DEFAULT_TESTU01,
+        help="Path to the testu01_stdin binary. Assumes gettestu01 has already been run.",
+    )
     parser.add_argument(
         "--binary",
         type=Path,
         default=DEFAULT_BINARY,
-        help="Path to the built Rust practrand example binary.",
+        help="Path to the built Rust rngstream example binary.",
     )
     parser.add_argument(
         "--practrand-arg",
@@ -90,7 +117,7 @@ def build(binary: Path, out_dir: Path) -> None:
     if return_code != 0:
         raise SystemExit(f"build failed; see {build_log}")
 
-    ensure_executable(binary, "built practrand example")
+    ensure_executable(binary, "built rngstream example")
 
 
 def ensure_executable(path: Path, label: str) -> None:
@@ -107,9 +134,9 @@ def display_path(path: Path) -> str:
         return str(path)
 
 
-def make_run_dir(out_root: Path, seed: str) -> Path:
+def make_run_dir(out_root: Path, battery: str, seed: str) -> Path:
     timestamp = time.strftime("%Y%m%d-%H%M%S")
-    run_dir = out_root / f"{timestamp}-seed-{seed.replace('/', '_')}"
+    run_dir = out_root / f"{timestamp}-{battery}-seed-{seed.replace('/', '_')}"
     run_dir.mkdir(parents=True, exist_ok=False)
     return run_dir
 
@@ -120,12 +147,25 @@ def normalize_practrand_args(practrand_args: tuple[str, ...], seed: str) -> tupl
     return (*practrand_args, "-seed", seed)
 
 
-def run_case(
+def commands(
     case: Case,
     seed: str,
     binary: Path,
-    rng_test: Path,
+    stream_args: tuple[str, ...],
+    battery: str,
+    tester: Path,
     practrand_args: tuple[str, ...],
+) -> tuple[list[str], list[str]]:
+    """The generator and tester command lines of a case. TestU01 reads big-endian 32-bit words."""
+    if battery == "practrand":
+        return [str(binary), *stream_args, case.rng, seed], [str(tester), *practrand_args]
+    return [str(binary), "--format", "be", *stream_args, case.rng, seed], [str(tester), battery, case.name]
+
+
+def run_case(
+    case: Case,
+    producer_cmd: list[str],
+    consumer_cmd: list[str],
     run_dir: Path,
 ) -> tuple[str, float, Path]:
     log_path = run_dir / f"{case.name}.log"
@@ -133,11 +173,11 @@ def run_case(
     status = "ok"
 
     with log_path.open("w", encoding="utf-8") as log_file:
-        log_file.write(f"$ {binary} {case.rng} {seed}\n")
-        log_file.write(f"$ {rng_test} {' '.join(practrand_args)}\n\n")
+        log_file.write(f"$ {' '.join(producer_cmd)}\n")
+        log_file.write(f"$ {' '.join(consumer_cmd)}\n\n")
 
         producer = subprocess.Popen(
-            [str(binary), case.rng, seed],
+            producer_cmd,
             cwd=ROOT,
             stdout=subprocess.PIPE,
             stderr=log_file,
@@ -145,7 +185,7 @@ def run_case(
         assert producer.stdout is not None
 
         consumer = subprocess.Popen(
-            [str(rng_test), *practrand_args],
+            consumer_cmd,
             cwd=ROOT,
             stdin=producer.stdout,
             stdout=subprocess.PIPE,
@@ -167,7 +207,7 @@ def run_case(
         producer_code = producer.wait()
 
         if producer_code != 0 or consumer_code != 0:
-            status = f"failed (generator={producer_code}, practrand={consumer_code})"
+            status = f"failed (generator={producer_code}, tester={consumer_code})"
 
     return status, time.monotonic() - start, log_path
 
@@ -185,26 +225,34 @@ def write_summary(run_dir: Path, results: list[tuple[Case, str, float, Path]]) -
 def main() -> int:
     args = parse_args()
     out_root = args.out_dir.resolve()
-    rng_test = args.rng_test.resolve()
     binary = args.binary.resolve()
     practrand_args = normalize_practrand_args(tuple(args.practrand_arg) or DEFAULT_PRACTRAND_ARGS, args.seed)
+    cases = tuple(Case(rng, rng) for rng in args.rng) or CASES
     out_root.mkdir(parents=True, exist_ok=True)
-    run_dir = make_run_dir(out_root, args.seed)
+    run_dir = make_run_dir(out_root, args.battery, args.seed)
 
-    ensure_executable(rng_test, "PractRand RNG_test")
+    if args.battery == "practrand":
+        tester = args.rng_test.resolve()
+        ensure_executable(tester, "PractRand RNG_test")
+    else:
+        tester = args.testu01.resolve()
+        ensure_executable(tester, "TestU01 testu01_stdin")
 
     if not args.skip_build:
         build(binary, run_dir)
     else:
-        ensure_executable(binary, "practrand example binary")
+        ensure_executable(binary, "rngstream example binary")
 
     results: list[tuple[Case, str, float, Path]] = []
 
     try:
         print(f"output: {display_path(run_dir)}", flush=True)
-        for case in CASES:
+        for case in cases:
             print(f"==> {case.name}", flush=True)
-            status, duration, log_path = run_case(case, args.seed, binary, rng_test, practrand_args, run_dir)
+            producer_cmd, consumer_cmd = commands(
+                case, args.seed, binary, tuple(args.stream_arg), args.battery, tester, practrand_args
+            )
+            status, duration, log_path = run_case(case, producer_cmd, consumer_cmd, run_dir)
             results.append((case, status, duration, log_path))
             print(f"<== {case.name}: {status} ({duration:.2f}s)", flush=True)
     finally:
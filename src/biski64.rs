@@ -1,25 +1,14 @@
 #[cfg(all(test, feature = "portable"))]
 use rand_core::RngCore;
 
+use crate::splitmix64;
+
 pub const FAST_LOOP_INCREMENT: u64 = 0x9999999999999999;
 
-const SPLITMIX_INCREMENT: u64 = 0x9e3779b97f4a7c15;
-const SPLITMIX_MUL_0: u64 = 0xbf58476d1ce4e5b9;
-const SPLITMIX_MUL_1: u64 = 0x94d049bb133111eb;
 const WARMUP_ROUNDS: usize = 16;
 
 #[inline(always)]
-const fn splitmix64_next(state: &mut u64) -> u64 {
-    *state = state.wrapping_add(SPLITMIX_INCREMENT);
-
-    let mut z = *state;
-    z = (z ^ (z >> 30)).wrapping_mul(SPLITMIX_MUL_0);
-    z = (z ^ (z >> 27)).wrapping_mul(SPLITMIX_MUL_1);
-    z ^ (z >> 31)
-}
-
-#[inline(always)]
-const fn advance_state(fast_loop: &mut u64, mix: &mut u64, loop_mix: &mut u64) {
+pub const fn advance_state(fast_loop: &mut u64, mix: &mut u64, loop_mix: &mut u64) {
     let previous_fast_loop = *fast_loop;
     let previous_mix = *mix;
 
@@ -36,9 +25,9 @@ const fn seed_base_state(seed: u64) -> [u64; 3] {
     let mut loop_mix = 0;
 
     while fast_loop == 0 && mix == 0 && loop_mix == 0 {
-        fast_loop = splitmix64_next(&mut splitmix_state);
-        mix = splitmix64_next(&mut splitmix_state);
-        loop_mix = splitmix64_next(&mut splitmix_state);
+        fast_loop = splitmix64::next(&mut splitmix_state);
+        mix = splitmix64::next(&mut splitmix_state);
+        loop_mix = splitmix64::next(&mut splitmix_state);
     }
 
     [fast_loop, mix, loop_mix]
@@ -67,7 +56,7 @@ pub fn seed_from_bytes(seed_bytes: &[u8]) -> u64 {
         let mut word = [0; 8];
         word.copy_from_slice(chunk);
         state ^= u64::from_le_bytes(word);
-        state = splitmix64_next(&mut state);
+        state = splitmix64::next(&mut state);
     }
 
     let remainder = chunks.remainder();
@@ -75,7 +64,7 @@ pub fn seed_from_bytes(seed_bytes: &[u8]) -> u64 {
         let mut tail = [0; 8];
         tail[..remainder.len()].copy_from_slice(remainder);
         state ^= u64::from_le_bytes(tail);
-        state = splitmix64_next(&mut state);
+        state = splitmix64::next(&mut state);
     }
 
     state
@@ -0,0 +1,215 @@
+/// `"expand 32-byte k"`, the first row of every block.
+pub const CONSTANTS: [u32; 4] = [0x61707865, 0x3320646e, 0x79622d32, 0x6b206574];
+pub const BLOCK_WORDS: usize = 16;
+
+// Word positions wrap with the 64-bit block counter, 16 words per block
+const WORD_POS_MASK: u128 = (1 << 68) - 1;
+
+/// The 16 input words of a block for `key` and `stream`, with the block counter words (12 and 13) left zero.
+pub const fn input_words(key: &[u32; 8], stream: u64) -> [u32; 16] {
+    let [stream_lo, stream_hi] = split(stream);
+
+    [
+        CONSTANTS[0],
+        CONSTANTS[1],
+        CONSTANTS[2],
+        CONSTANTS[3],
+        key[0],
+        key[1],
+        key[2],
+        key[3],
+        key[4],
+        key[5],
+        key[6],
+        key[7],
+        0,
+        0,
+        stream_lo,
+        stream_hi,
+    ]
+}
+
+/// The low and high words of the block counters `counter..counter + BLOCKS`, one lane per block.
+pub fn counter_lanes<const BLOCKS: usize>(counter: u64) -> [[u32; BLOCKS]; 2] {
+    let counters: [u64; BLOCKS] = core::array::from_fn(|block| counter.wrapping_add(block as u64));
+
+    [
+        counters.map(|counter| split(counter)[0]),
+        counters.map(|counter| split(counter)[1]),
+    ]
+}
+
+/// Packs pairs of output words into `u64`s, low word first, the same as `next_u64` on the word stream.
+pub fn pack_words<const WORDS: usize, const LANES: usize>(words: [u32; WORDS]) -> [u64; LANES] {
+    assert_eq!(LANES * 2, WORDS);
+
+    core::array::from_fn(|lane| u64::from(words[lane * 2]) | u64::from(words[lane * 2 + 1]) << 32)
+}
+
+// Splitting the word into its halves is the point
+#[allow(clippy::cast_possible_truncation)]
+const fn split(word: u64) -> [u32; 2] {
+    [word as u32, (word >> 32) as u32]
+}
+
+/// Key, stream and block counter of a ChaCha generator, along with a buffer of `WORDS` output words.
+///
+/// The output is the little-endian word stream of consecutive blocks, consumed exactly like `rand_core`'s `BlockRng`
+/// does, so a generator reading it matches `rand_chacha` whatever the buffer size. The `generate` callbacks write
+/// `WORDS / 16` consecutive blocks starting at the given block counter.
+#[derive(Clone)]
+pub struct BlockBuffer<const WORDS: usize> {
+    key: [u32; 8],
+    stream: u64,
+    // Block counter of the first block after the buffer
+    counter: u64,
+    words: [u32; WORDS],
+    index: usize,
+}
+
+impl<const WORDS: usize> BlockBuffer<WORDS> {
+    const BLOCKS: u64 = (WORDS / BLOCK_WORDS) as u64;
+
+    pub fn new(seed: [u8; 32]) -> Self {
+        let (chunks, _) = seed.as_chunks::<4>();
+
+        Self {
+            key: core::array::from_fn(|index| u32::from_le_bytes(chunks[index])),
+            stream: 0,
+            counter: 0,
+            words: [0; WORDS],
+            index: WORDS,
+        }
+    }
+
+    pub fn seed(&self) -> [u8; 32] {
+        let mut seed = [0; 32];
+        for (chunk, word) in seed.chunks_exact_mut(4).zip(self.key) {
+            chunk.copy_from_slice(&word.to_le_bytes());
+        }
+
+        seed
+    }
+
+    pub const fn stream(&self) -> u64 {
+        self.stream
+    }
+
+    /// Switches to `stream`, continuing at the same word position.
+    pub fn set_stream(&mut self, stream: u64, generate: impl FnMut(&[u32; 8], u64, u64, &mut [u32; WORDS])) {
+        let word_pos = self.word_pos();
+        self.stream = stream;
+        self.set_word_pos(word_pos, generate);
+    }
+
+    pub const fn word_pos(&self) -> u128 {
+        let buffer_start = self.counter.wrapping_sub(Self::BLOCKS);
+
+        (buffer_start as u128 * BLOCK_WORDS as u128 + self.index as u128) & WORD_POS_MASK
+    }
+
+    // The block is the word position over 16, which fits 64 bits after masking
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn set_word_pos(&mut self, word_pos: u128, generate: impl FnMut(&[u32; 8], u64, u64, &mut [u32; WORDS])) {
+        let word_pos = word_pos & WORD_POS_MASK;
+        let offset = (word_pos % BLOCK_WORDS as u128) as usize;
+
+        self.counter = (word_pos / BLOCK_WORDS as u128) as u64;
+        self.index = WORDS;
+        if offset > 0 {
+            self.refill(generate);
+            self.index = offset;
+        }
+    }
+
+    /// The key as four little-endian words, followed by the stream and the low and high word position words.
+    // Splitting the word position into its halves is the point
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn state(&self) -> [u64; 7] {
+        let key: [u64; 4] = pack_words(self.key);
+        let word_pos = self.word_pos();
+
+        [
+            key[0],
+            key[1],
+            key[2],
+            key[3],
+            self.stream,
+            word_pos as u64,
+            (word_pos >> 64) as u64,
+        ]
+    }
+
+    pub fn from_state(state: [u64; 7], generate: impl FnMut(&[u32; 8], u64, u64, &mut [u32; WORDS])) -> Self {
+        let [key0, key1, key2, key3, stream, word_pos_lo, word_pos_hi] = state;
+        let mut seed = [0; 32];
+        for (chunk, word) in seed.chunks_exact_mut(8).zip([key0, key1, key2, key3]) {
+            chunk.copy_from_slice(&word.to_le_bytes());
+        }
+
+        let mut buffer = Self::new(seed);
+        buffer.stream = stream;
+        buffer.set_word_pos(u128::from(word_pos_lo) | u128::from(word_pos_hi) << 64, generate);
+        buffer
+    }
+
+    #[inline(always)]
+    pub fn next_words<const N: usize>(
+        &mut self,
+        generate: impl FnMut(&[u32; 8], u64, u64, &mut [u32; WORDS]),
+    ) -> [u32; N] {
+        let mut words = [0; N];
+
+        if let Some(buffered) = self.words.get(self.index..self.index + N) {
+            words.copy_from_slice(buffered);
+            self.index += N;
+        } else {
+            self.read_words(&mut words, generate);
+        }
+
+        words
+    }
+
+    /// Fills `dest` with whole words, a trailing partial word is consumed entirely like `BlockRng::fill_bytes` does.
+    pub fn fill_bytes(&mut self, dest: &mut [u8], mut generate: impl FnMut(&[u32; 8], u64, u64, &mut [u32; WORDS])) {
+        for chunk in dest.chunks_mut(4) {
+            if self.index == WORDS {
+                self.refill(&mut generate);
+            }
+
+            chunk.copy_from_slice(&self.words[self.index].to_le_bytes()[..chunk.len()]);
+            self.index += 1;
+        }
+    }
+
+    #[inline(never)] // Reads straddling a refill are the rare case, keep the buffered hotpath small
+    fn read_words(&mut self, dest: &mut [u32], mut generate: impl FnMut(&[u32; 8], u64, u64, &mut [u32; WORDS])) {
+        let mut filled = 0;
+
+        while filled < dest.len() {
+            if self.index == WORDS {
+                self.refill(&mut generate);
+            }
+
+            let count = (WORDS - self.index).min(dest.len() - filled);
+            dest[filled..filled + count].copy_from_slice(&self.words[self.index..self.index + count]);
+            self.index += count;
+            filled += count;
+        }
+    }
+
+    fn refill(&mut self, mut generate: impl FnMut(&[u32; 8], u64, u64, &mut [u32; WORDS])) {
+        generate(&self.key, self.counter, self.stream, &mut self.words);
+        self.counter = self.counter.wrapping_add(Self::BLOCKS);
+        self.index = 0;
+    }
+}
+
+impl<const WORDS: usize> PartialEq for BlockBuffer<WORDS> {
+    /// Generators are equal when they will produce the same stream from here on.
+    fn eq(&self, other: &Self) -> bool {
+        self.state() == other.state()
+    }
+}
+
+impl<const WORDS: usize> Eq for BlockBuffer<WORDS> {}
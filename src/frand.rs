@@ -1,43 +1,29 @@
-const HASH_MUL: u64 = 4997996261773036203;
-
-#[must_use]
-pub const fn hash_seed(seed: u64) -> u64 {
-    let seed = (seed ^ (seed >> 32)).wrapping_mul(HASH_MUL);
-    let seed = (seed ^ (seed >> 32)).wrapping_mul(HASH_MUL);
-    seed ^ (seed >> 32)
-}
+pub mod test_support {
+    use rand_core::{RngCore, SeedableRng};
 
-#[must_use]
-pub fn repeated_seed_bytes<const BYTES: usize>(seed: u64) -> [u8; BYTES] {
-    let mut bytes = [0u8; BYTES];
+    const HASH_MUL: u64 = 4997996261773036203;
+    const REFERENCE_STEPS: usize = if cfg!(miri) { 32 } else { 1024 };
 
-    for chunk in bytes.chunks_exact_mut(8) {
-        chunk.copy_from_slice(&seed.to_le_bytes());
+    /// The counter `frand::Rand::with_seed` starts from, upstream hashes the seed.
+    pub const fn upstream_state(seed: u64) -> u64 {
+        let seed = (seed ^ (seed >> 32)).wrapping_mul(HASH_MUL);
+        let seed = (seed ^ (seed >> 32)).wrapping_mul(HASH_MUL);
+        seed ^ (seed >> 32)
     }
 
-    bytes
-}
+    fn repeated_state_bytes<const BYTES: usize>(seed: u64) -> [u8; BYTES] {
+        let mut bytes = [0u8; BYTES];
 
-#[must_use]
-pub fn hash_seed_bytes<const BYTES: usize>(seed: &[u8]) -> [u8; BYTES] {
-    let mut bytes = [0u8; BYTES];
-    assert_eq!(seed.len(), BYTES);
+        for chunk in bytes.chunks_exact_mut(8) {
+            chunk.copy_from_slice(&upstream_state(seed).to_le_bytes());
+        }
 
-    for (src, dst) in seed.chunks_exact(8).zip(bytes.chunks_exact_mut(8)) {
-        let mut word = [0u8; 8];
-        word.copy_from_slice(src);
-        dst.copy_from_slice(&hash_seed(u64::from_le_bytes(word)).to_le_bytes());
+        bytes
     }
 
-    bytes
-}
-
-#[cfg(test)]
-pub mod test_support {
-    const REFERENCE_STEPS: usize = if cfg!(miri) { 32 } else { 1024 };
-
+    /// Every lane starts from the state of `frand::Rand::with_seed(1)`.
     pub fn ref_seed_x4() -> [u8; 32] {
-        super::repeated_seed_bytes::<32>(1)
+        repeated_state_bytes::<32>(1)
     }
 
     #[cfg(any(
@@ -51,18 +37,24 @@ pub mod test_support {
         )
     ))]
     pub fn ref_seed_x8() -> [u8; 64] {
-        super::repeated_seed_bytes::<64>(1)
+        repeated_state_bytes::<64>(1)
     }
 
-    pub fn assert_seed_from_u64_matches_upstream<const LANES: usize, R>(
+    /// Lane `i` starts from the next output of a scalar SplitMix64 seeded with the `i`th output of the stream from
+    /// `seed`, as [`expand_seed`](crate::splitmix64::expand_seed) lays out.
+    pub fn assert_seed_from_u64_matches_splitmix64<const LANES: usize, R>(
         seed: u64,
         mut rng: R,
         mut next: impl FnMut(&mut R) -> [u64; LANES],
     ) {
-        let mut reference = ::frand::Rand::with_seed(seed);
+        let mut stream = rand_xoshiro::SplitMix64::seed_from_u64(seed);
+        let mut lanes: [crate::scalar::Frand; LANES] = core::array::from_fn(|_| {
+            let state = rand_xoshiro::SplitMix64::seed_from_u64(stream.next_u64()).next_u64();
+            crate::scalar::Frand::from_state([state])
+        });
 
         for _ in 0..REFERENCE_STEPS {
-            assert_eq!(next(&mut rng), [reference.r#gen::<u64>(); LANES]);
+            assert_eq!(next(&mut rng), lanes.each_mut().map(RngCore::next_u64));
         }
     }
 }
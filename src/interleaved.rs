@@ -0,0 +1,110 @@
+use rand_core::SeedableRng;
+
+use crate::SimdRng;
+
+/// `K` independent generators of type `R` emitting their vectors round-robin.
+///
+/// A single state vector is a dependency chain: every step of Frand waits for its multiply, every step of Xoshiro for
+/// the shifts and xors of the previous one, so one generator leaves most of the vector ports idle. The steps of
+/// independent generators do not depend on each other and overlap, [`Self::next_u64_vecs`] steps all `K` of them back
+/// to back, which is where the throughput comes from. Through [`SimdRng`] the output is the same stream, vector `i`
+/// coming from generator `i % K`.
+///
+/// ```rust
+/// # #[cfg(feature = "portable")]
+/// # {
+/// use simd_rand::{Interleaved, portable::FrandX8};
+///
+/// let mut rng = Interleaved::<FrandX8, 4>::seed_from_u64(42);
+///
+/// let mut ones = 0;
+/// for _ in 0..1024 {
+///     for vector in rng.next_u64_vecs() {
+///         ones += AsRef::<[u64]>::as_ref(&vector).iter().map(|word| word.count_ones()).sum::<u32>();
+///     }
+/// }
+/// # assert!(ones.abs_diff(1024 * 4 * 8 * 32) < 1024 * 4 * 8);
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Interleaved<R, const K: usize> {
+    rngs: [R; K],
+    // Generator providing the next vector through `SimdRng`
+    index: usize,
+}
+
+impl<R: SimdRng, const K: usize> Interleaved<R, K> {
+    /// Interleaves `rngs`, starting with `rngs[0]`.
+    ///
+    /// The generators should be seeded independently, copies of the same generator emit every vector `K` times.
+    #[must_use]
+    pub const fn new(rngs: [R; K]) -> Self {
+        const { assert!(K > 0, "Interleaving needs at least one generator") };
+
+        Self { rngs, index: 0 }
+    }
+
+    /// Seeds generator `i` with output `i` of a SplitMix64 seeded with `seed`.
+    #[must_use]
+    pub fn seed_from_u64(seed: u64) -> Self
+    where
+        R: SeedableRng,
+    {
+        Self::new(crate::splitmix64::lane_states::<K>(seed).map(R::seed_from_u64))
+    }
+
+    /// The generators, each continuing its own stream.
+    #[must_use]
+    pub fn into_inner(self) -> [R; K] {
+        self.rngs
+    }
+
+    /// The next vector of every generator, in round-robin order.
+    ///
+    /// Starting from the first generator, which is the case unless the [`SimdRng`] methods were called a number of
+    /// times that is not a multiple of `K`, this steps every generator once with nothing in between.
+    #[inline(always)]
+    pub fn next_u64_vecs(&mut self) -> [R::U64Vec; K] {
+        if self.index == 0 {
+            core::array::from_fn(|i| self.rngs[i].next_u64_vec())
+        } else {
+            core::array::from_fn(|_| self.next_u64_vec())
+        }
+    }
+
+    /// Same as [`Self::next_u64_vecs`] with floats in `[0, 1)`.
+    #[inline(always)]
+    pub fn next_f64_vecs(&mut self) -> [R::F64Vec; K] {
+        if self.index == 0 {
+            core::array::from_fn(|i| self.rngs[i].next_f64_vec())
+        } else {
+            core::array::from_fn(|_| self.next_f64_vec())
+        }
+    }
+
+    #[inline(always)]
+    const fn advance(&mut self) -> usize {
+        let index = self.index;
+        self.index = if index + 1 == K { 0 } else { index + 1 };
+        index
+    }
+}
+
+impl<R: SimdRng, const K: usize> SimdRng for Interleaved<R, K> {
+    const LANES: usize = R::LANES;
+
+    type U64Vec = R::U64Vec;
+    type F64Vec = R::F64Vec;
+
+    #[inline(always)]
+    fn next_u64_vec(&mut self) -> Self::U64Vec {
+        let index = self.advance();
+        self.rngs[index].next_u64_vec()
+    }
+
+    #[inline(always)]
+    fn next_f64_vec(&mut self) -> Self::F64Vec {
+        let index = self.advance();
+        self.rngs[index].next_f64_vec()
+    }
+}
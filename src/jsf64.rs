@@ -0,0 +1,4 @@
+/// The `a` word of every freshly seeded generator, `0xf1ea5eed` in Bob Jenkins' `raninit`.
+pub const SEED_A: u64 = 0xf1ea5eed;
+/// Outputs discarded after seeding, as `raninit` does.
+pub const WARMUP_ROUNDS: usize = 20;
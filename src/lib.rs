@@ -48,10 +48,34 @@
 //! # fn main() {}
 //! ```
 //!
+//! Every generator exposes `state()`/`from_state()`, which round-trip its internal state as a plain `[u64; N]` array,
+//! so long running jobs can checkpoint and resume the exact same stream.
+//! `seed_from_u64` fills the seed of the lane based generators with the output of `SplitMix64X4`/`SplitMix64X8`,
+//! so every backend of a generator starts from the same state for the same `u64`. Biski64 and Frand keep the seeding
+//! of their reference implementations instead, and so does `Mt19937_64X4`/`Mt19937_64X8`: a single MT19937-64 stream,
+//! bit-identical to the reference `genrand64_int64` and `std::mt19937_64`, with the twist vectorized.
+//! With the `serde` feature enabled, the generators also implement `Serialize` and `Deserialize` through that state.
+//! Equivalent `portable`, `avx2` and `avx512` generators convert into each other with `From`, and an X8 generator
+//! splits into `[X4; 2]` (lanes 0..4 and 4..8) and merges back, continuing every lane's stream.
+//! Single lanes can be handed to scalar code paths with `lane(i)`/`into_lanes()`, which return the generators in
+//! the [`scalar`] module positioned at the same point in the stream, and `from_lanes()` assembles them back into a vector generator.
+//! Code that should run on any backend can take a [`SimdRng`], which every vectorized generator implements with its
+//! lane count and vector types, the `core::simd` vectors for `portable` and the aligned arrays of the `specific` modules.
+//! Most `portable` generators are generic over their lane count, e.g. `Xoshiro256PlusPlus<LANES>`, with `X2` to `X32`
+//! aliases that also get the seed, state and serde APIs.
+//! [`Interleaved`] runs several generators of the same type round-robin, so that the steps of their independent state
+//! vectors overlap where a single one is bound by the latency of its dependency chain.
+//! The `portable` `next_f64x4_with`/`next_f64x8_with` take the float conversion as a type parameter, `Multiply` for
+//! the 53-bit values of `next_f64x4`/`next_f64x8`, or the cheaper 52-bit `ExponentBits`.
+//!
 //! The `portable` module will be available on any architecture, e.g. even on `x86_64` with only AVX2 you can still use `Xoshiro256PlusPluxX8` which uses
 //! 8-lane/512bit vectors (u64x8 from `core::simd`). The compiler is able to make it reasonably fast even if using only 256bit wide registers (AVX2) in the generated code.
 //!
 //! The `specific` submodules (AVX2 and AVX512 currently) are only compiled in depending on target arch/features.
+//! With `avx512ifma` enabled as well, the AVX512 generators that need the high half of a 64-bit product (WyRand,
+//! PCG64-DXSM and Philox2x64) compute it with IFMA, with the same output. There are no VBMI or GFNI paths: the only
+//! byte permutes are the ChaCha rotations by 8 and 16 bits, which are slower as VBMI permutes than as `vprold` (see
+//! the `AVX512/rotate` bench), and no generator has a GF(2) byte transform.
 //!
 //! In general, use the `portable` module. The only risk/drawback to using the `portable` module is that in principle
 //! the compiler isn't _forced_ to use the "optimal" instructions and registers for your hardware. In practice, it probably will though.
@@ -96,6 +120,18 @@
 //!                         thrpt:  [188.41 GiB/s 188.49 GiB/s 188.57 GiB/s]
 //! ```
 //!
+//! ## Seeding
+//!
+//! `from_seed` takes the raw state of every lane. `seed_from_u64` expands the `u64` with SplitMix64, so the lanes
+//! start from different states and every backend of a generator produces the same stream for the same seed.
+//!
+//! This is a semver-breaking change, the next release has to bump the version to 0.2.0: it changed the output of
+//! existing types for the same seed:
+//! * `Xoshiro256PlusX4/X8` and `Xoshiro256PlusPlusX4/X8` used the default `seed_from_u64` of `rand_core`, their
+//!   `from_seed` is unchanged
+//! * `FrandX4/X8` repeated `frand::Rand::with_seed` in every lane for `seed_from_u64`, and `from_seed` hashed every
+//!   state word like `with_seed` does, so both `seed_from_u64` and `from_seed` produce different streams now
+//!
 //! ## Safety
 //!
 //! There is a decent amount of `unsafe` used, due to direct use of hardware intrisics (e.g. `__m256{i|d}` for AVX2).
@@ -116,12 +152,39 @@ extern crate alloc;
 #[macro_use]
 extern crate std;
 
+pub use interleaved::Interleaved;
+pub use simdrng::SimdRng;
+
 mod biski64;
+#[cfg(any(
+    feature = "portable",
+    all(feature = "specific", target_arch = "x86_64", target_feature = "avx2")
+))]
+mod chacha;
+#[cfg(test)]
 mod frand;
+mod interleaved;
+mod jsf64;
+mod mt19937_64;
+mod pcg64;
+mod philox;
+mod romutrio;
+mod sfc64;
+mod splitmix64;
+mod wyrand;
 
 #[cfg(feature = "portable")]
 pub mod portable;
+pub mod scalar;
+#[cfg(feature = "serde")]
+mod serde;
+mod simdrng;
 #[cfg(feature = "specific")]
 pub mod specific;
+#[cfg(any(
+    feature = "portable",
+    all(feature = "specific", target_arch = "x86_64", target_feature = "avx2")
+))]
+mod state;
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,264 @@
+pub const NN: usize = 312;
+pub const MM: usize = 156;
+pub const MATRIX_A: u64 = 0xb5026f5aa96619e9;
+/// Most significant 33 bits.
+pub const UPPER_MASK: u64 = 0xffffffff80000000;
+/// Least significant 31 bits.
+pub const LOWER_MASK: u64 = 0x7fffffff;
+
+/// The seed `std::mt19937_64` and `genrand64_int64` use when they are not seeded explicitly.
+pub const DEFAULT_SEED: u64 = 5489;
+
+// Words of `Buffer::state`, the state array followed by the index of the next word
+pub const STATE_WORDS: usize = NN + 1;
+
+/// The reference tempering of a state word into an output word.
+#[inline(always)]
+#[must_use]
+pub const fn temper(x: u64) -> u64 {
+    let x = x ^ ((x >> 29) & 0x5555555555555555);
+    let x = x ^ ((x << 17) & 0x71d67fffeda60000);
+    let x = x ^ ((x << 37) & 0xfff7eee000000000);
+    x ^ (x >> 43)
+}
+
+/// Twists word `i` of the state, reading the words after it as the reference does when going through them in order.
+#[inline(always)]
+pub const fn twist_word(mt: &mut [u64; NN], i: usize) {
+    let y = (mt[i] & UPPER_MASK) | (mt[(i + 1) % NN] & LOWER_MASK);
+    mt[i] = mt[(i + MM) % NN] ^ (y >> 1) ^ ((y & 1).wrapping_neg() & MATRIX_A);
+}
+
+/// Twists the whole state one word at a time, exactly like the reference.
+pub fn twist_scalar(mt: &mut [u64; NN]) {
+    for i in 0..NN {
+        twist_word(mt, i);
+    }
+}
+
+/// Twists the whole state, `LANES` words at a time where possible.
+///
+/// `vector(mt, i, source)` twists words `i..i + LANES`, combining them with words `source..source + LANES`. It may
+/// read `mt[i + LANES]`, every call is in bounds for that. The words left over at the end of each half of the state
+/// go through [`twist_word`], the last one wraps around to word 0 which has to be twisted already.
+#[inline(always)]
+pub fn twist<const LANES: usize>(mt: &mut [u64; NN], mut vector: impl FnMut(&mut [u64; NN], usize, usize)) {
+    let mut i = 0;
+
+    // The first half combines with words of the second half that are not twisted yet
+    while i + LANES <= MM {
+        vector(mt, i, i + MM);
+        i += LANES;
+    }
+    while i < MM {
+        twist_word(mt, i);
+        i += 1;
+    }
+
+    // The second half combines with the twisted words of the first half, which are at least `MM` words back
+    while i + LANES < NN {
+        vector(mt, i, i - MM);
+        i += LANES;
+    }
+    while i < NN {
+        twist_word(mt, i);
+        i += 1;
+    }
+}
+
+/// State array and position of an MT19937-64 generator, the `mt` and `mti` of the reference implementation.
+///
+/// The state array is the output buffer: it holds the next `NN` words before tempering and is twisted again once
+/// they are all spent, so reads of any width continue the same stream. The `twist` callbacks go through [`twist`].
+#[derive(Clone, PartialEq, Eq)]
+pub struct Buffer {
+    mt: [u64; NN],
+    index: usize,
+}
+
+impl Buffer {
+    /// `init_genrand64(seed)`, the seeding of `std::mt19937_64(seed)`.
+    pub fn new(seed: u64) -> Self {
+        let mut mt = [0; NN];
+        mt[0] = seed;
+        for i in 1..NN {
+            mt[i] = 6364136223846793005u64
+                .wrapping_mul(mt[i - 1] ^ (mt[i - 1] >> 62))
+                .wrapping_add(i as u64);
+        }
+
+        Self { mt, index: NN }
+    }
+
+    /// `init_by_array64(key)`.
+    pub fn from_key(key: &[u64]) -> Self {
+        let mut buffer = Self::new(19650218);
+        let mt = &mut buffer.mt;
+
+        let mut i = 1;
+        let mut j = 0;
+        for _ in 0..NN.max(key.len()) {
+            mt[i] = (mt[i] ^ (mt[i - 1] ^ (mt[i - 1] >> 62)).wrapping_mul(3935559000370003845))
+                .wrapping_add(key[j])
+                .wrapping_add(j as u64);
+            i += 1;
+            j += 1;
+            if i >= NN {
+                mt[0] = mt[NN - 1];
+                i = 1;
+            }
+            if j >= key.len() {
+                j = 0;
+            }
+        }
+        for _ in 0..NN - 1 {
+            mt[i] = (mt[i] ^ (mt[i - 1] ^ (mt[i - 1] >> 62)).wrapping_mul(2862933555777941757)).wrapping_sub(i as u64);
+            i += 1;
+            if i >= NN {
+                mt[0] = mt[NN - 1];
+                i = 1;
+            }
+        }
+        // MSB is 1, assuring a non-zero initial array
+        mt[0] = 1 << 63;
+
+        buffer
+    }
+
+    /// The state array followed by the index of the next word in it.
+    pub fn state(&self) -> [u64; STATE_WORDS] {
+        let mut state = [0; STATE_WORDS];
+        state[..NN].copy_from_slice(&self.mt);
+        state[NN] = self.index as u64;
+        state
+    }
+
+    pub fn from_state(state: &[u64; STATE_WORDS]) -> Self {
+        let index = usize::try_from(state[NN]).unwrap_or(usize::MAX);
+        assert!(index <= NN, "The MT19937-64 index must be <= {NN}");
+
+        let mut mt = [0; NN];
+        mt.copy_from_slice(&state[..NN]);
+
+        Self { mt, index }
+    }
+
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The next `N` words of the state array, before tempering.
+    #[inline(always)]
+    pub fn next_words<const N: usize>(&mut self, twist: impl FnMut(&mut [u64; NN])) -> [u64; N] {
+        let mut words = [0; N];
+
+        if let Some(buffered) = self.mt.get(self.index..self.index + N) {
+            words.copy_from_slice(buffered);
+            self.index += N;
+        } else {
+            self.read_words(&mut words, twist);
+        }
+
+        words
+    }
+
+    #[inline(never)] // Reads straddling a twist are the rare case, keep the buffered hotpath small
+    fn read_words(&mut self, dest: &mut [u64], mut twist: impl FnMut(&mut [u64; NN])) {
+        let mut filled = 0;
+
+        while filled < dest.len() {
+            if self.index == NN {
+                twist(&mut self.mt);
+                self.index = 0;
+            }
+
+            let count = (NN - self.index).min(dest.len() - filled);
+            dest[filled..filled + count].copy_from_slice(&self.mt[self.index..self.index + count]);
+            self.index += count;
+            filled += count;
+        }
+    }
+}
+
+/// Implements `Serialize`/`Deserialize` through `state`/`from_state`, rejecting an index past the state array.
+#[cfg(feature = "serde")]
+macro_rules! impl_state_serde {
+    ($rng:ty) => {
+        impl serde::Serialize for $rng {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                crate::serde::serialize_state(&self.state(), serializer)
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $rng {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let state: [u64; crate::mt19937_64::STATE_WORDS] = crate::serde::deserialize_state(deserializer)?;
+                if state[crate::mt19937_64::NN] > crate::mt19937_64::NN as u64 {
+                    return Err(serde::de::Error::custom(
+                        "MT19937-64 index exceeds the state size",
+                    ));
+                }
+
+                Ok(Self::from_state(state))
+            }
+        }
+    };
+}
+
+#[cfg(feature = "serde")]
+pub(crate) use impl_state_serde;
+
+#[cfg(test)]
+mod tests {
+    use super::{Buffer, MM, NN, twist, twist_scalar};
+
+    // The reference twist, with the three loops of `genrand64_int64`
+    fn reference_twist(mt: &mut [u64; NN]) {
+        let mag01 = [0, super::MATRIX_A];
+        let mut i = 0;
+        while i < NN - MM {
+            let x = (mt[i] & super::UPPER_MASK) | (mt[i + 1] & super::LOWER_MASK);
+            mt[i] = mt[i + MM] ^ (x >> 1) ^ mag01[(x & 1) as usize];
+            i += 1;
+        }
+        while i < NN - 1 {
+            let x = (mt[i] & super::UPPER_MASK) | (mt[i + 1] & super::LOWER_MASK);
+            mt[i] = mt[i + MM - NN] ^ (x >> 1) ^ mag01[(x & 1) as usize];
+            i += 1;
+        }
+        let x = (mt[NN - 1] & super::UPPER_MASK) | (mt[0] & super::LOWER_MASK);
+        mt[NN - 1] = mt[MM - 1] ^ (x >> 1) ^ mag01[(x & 1) as usize];
+    }
+
+    fn chunked_twist<const LANES: usize>(mt: &mut [u64; NN]) {
+        twist::<LANES>(mt, |mt, i, source| {
+            // Everything is read before anything is written, like a vector kernel
+            let current: [u64; LANES] = core::array::from_fn(|lane| mt[i + lane]);
+            let next: [u64; LANES] = core::array::from_fn(|lane| mt[i + lane + 1]);
+            let sources: [u64; LANES] = core::array::from_fn(|lane| mt[source + lane]);
+            for lane in 0..LANES {
+                let y = (current[lane] & super::UPPER_MASK) | (next[lane] & super::LOWER_MASK);
+                mt[i + lane] = sources[lane] ^ (y >> 1) ^ ((y & 1).wrapping_neg() & super::MATRIX_A);
+            }
+        });
+    }
+
+    #[test]
+    fn twists_match_the_reference() {
+        let mut reference = Buffer::from_key(&[0x12345, 0x23456, 0x34567, 0x45678]).mt;
+        let mut scalar = reference;
+        let mut x4 = reference;
+        let mut x8 = reference;
+
+        for _ in 0..4 {
+            reference_twist(&mut reference);
+            twist_scalar(&mut scalar);
+            chunked_twist::<4>(&mut x4);
+            chunked_twist::<8>(&mut x8);
+
+            assert_eq!(scalar, reference);
+            assert_eq!(x4, reference);
+            assert_eq!(x8, reference);
+        }
+    }
+}
@@ -0,0 +1,88 @@
+/// The 64-bit "cheap multiplier", used by both the LCG step and the DXSM output permutation.
+pub const MULTIPLIER: u64 = 0xda942042e4dd58b5;
+
+#[inline(always)]
+pub const fn step(state: u128, increment: u128) -> u128 {
+    state.wrapping_mul(MULTIPLIER as u128).wrapping_add(increment)
+}
+
+#[inline(always)]
+// Splitting the 128-bit state into its 64-bit halves is the point
+#[allow(clippy::cast_possible_truncation)]
+pub const fn output_dxsm(state: u128) -> u64 {
+    let mut hi = (state >> 64) as u64;
+    let lo = state as u64 | 1;
+
+    hi ^= hi >> 32;
+    hi = hi.wrapping_mul(MULTIPLIER);
+    hi ^= hi >> 48;
+    hi.wrapping_mul(lo)
+}
+
+// Splitting the 128-bit words into their 64-bit halves is the point
+#[allow(clippy::cast_possible_truncation)]
+pub const fn to_words(state: u128, increment: u128) -> [u64; 4] {
+    [
+        state as u64,
+        (state >> 64) as u64,
+        increment as u64,
+        (increment >> 64) as u64,
+    ]
+}
+
+pub const fn from_words(words: [u64; 4]) -> (u128, u128) {
+    let [state_lo, state_hi, increment_lo, increment_hi] = words;
+
+    (join(state_lo, state_hi), join(increment_lo, increment_hi))
+}
+
+const fn join(lo: u64, hi: u64) -> u128 {
+    ((hi as u128) << 64) | lo as u128
+}
+
+/// Same as `rand_pcg`'s `from_state_incr`, moves away from the initial value and takes one step.
+pub const fn initial_state(state: u128, increment: u128) -> u128 {
+    step(state.wrapping_add(increment), increment)
+}
+
+/// Builds the `state_lo`, `state_hi`, `increment_lo`, `increment_hi` vectors of `LANES` freshly seeded generators.
+fn lanes_state<const LANES: usize, const WORDS: usize>(
+    states: [u128; LANES],
+    increments: [u128; LANES],
+) -> [u64; WORDS] {
+    assert_eq!(LANES * 4, WORDS);
+
+    let mut state = [0; WORDS];
+    for (lane, (lane_state, increment)) in states.into_iter().zip(increments).enumerate() {
+        let words = to_words(initial_state(lane_state, increment), increment);
+        for (field, word) in words.into_iter().enumerate() {
+            state[field * LANES + lane] = word;
+        }
+    }
+
+    state
+}
+
+/// Reads the seed as `state_lo`, `state_hi`, `increment_lo` and `increment_hi` vectors,
+/// lane `i` is seeded the same as `rand_pcg::Pcg64Dxsm::from_seed` with those four words.
+pub fn seed_state<const LANES: usize, const WORDS: usize>(seed: &[u8]) -> [u64; WORDS] {
+    let (chunks, remainder) = seed.as_chunks::<8>();
+    assert!(remainder.is_empty());
+    assert_eq!(chunks.len(), WORDS);
+
+    let word = |index: usize| u64::from_le_bytes(chunks[index]);
+    let states: [u128; LANES] = core::array::from_fn(|lane| join(word(lane), word(LANES + lane)));
+    // The increment must be odd, hence we discard one bit
+    let increments: [u128; LANES] =
+        core::array::from_fn(|lane| join(word(2 * LANES + lane), word(3 * LANES + lane)) | 1);
+
+    lanes_state(states, increments)
+}
+
+/// Lane `i` is seeded the same as `rand_pcg::Pcg64Dxsm::new(states[i], streams[i])`.
+pub fn stream_state<const LANES: usize, const WORDS: usize>(
+    states: [u128; LANES],
+    streams: [u128; LANES],
+) -> [u64; WORDS] {
+    lanes_state(states, streams.map(|stream| (stream << 1) | 1))
+}
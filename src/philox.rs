@@ -0,0 +1,185 @@
+pub const ROUNDS: usize = 10;
+
+pub const PHILOX_M4X32_0: u32 = 0xD2511F53;
+pub const PHILOX_M4X32_1: u32 = 0xCD9E8D57;
+pub const PHILOX_W32_0: u32 = 0x9E3779B9;
+pub const PHILOX_W32_1: u32 = 0xBB67AE85;
+
+pub const PHILOX_M2X64: u64 = 0xD2B74407B1CE6E93;
+pub const PHILOX_W64: u64 = 0x9E3779B97F4A7C15;
+
+/// Philox4x32-10 bijection, the same as Random123's `philox4x32(ctr, key)`.
+// The 32x32 -> 64-bit product is split into its halves on purpose
+#[allow(clippy::cast_possible_truncation)]
+pub const fn philox4x32(key: [u32; 2], counter: [u32; 4]) -> [u32; 4] {
+    let [mut k0, mut k1] = key;
+    let mut x = counter;
+
+    let mut round = 0;
+    while round < ROUNDS {
+        if round > 0 {
+            k0 = k0.wrapping_add(PHILOX_W32_0);
+            k1 = k1.wrapping_add(PHILOX_W32_1);
+        }
+
+        let product0 = PHILOX_M4X32_0 as u64 * x[0] as u64;
+        let product1 = PHILOX_M4X32_1 as u64 * x[2] as u64;
+        x = [
+            (product1 >> 32) as u32 ^ x[1] ^ k0,
+            product1 as u32,
+            (product0 >> 32) as u32 ^ x[3] ^ k1,
+            product0 as u32,
+        ];
+
+        round += 1;
+    }
+
+    x
+}
+
+/// Philox2x64-10 bijection, the same as Random123's `philox2x64(ctr, key)`.
+// The 64x64 -> 128-bit product is split into its halves on purpose
+#[allow(clippy::cast_possible_truncation)]
+pub const fn philox2x64(key: u64, counter: [u64; 2]) -> [u64; 2] {
+    let mut key = key;
+    let mut x = counter;
+
+    let mut round = 0;
+    while round < ROUNDS {
+        if round > 0 {
+            key = key.wrapping_add(PHILOX_W64);
+        }
+
+        let product = PHILOX_M2X64 as u128 * x[0] as u128;
+        x = [(product >> 64) as u64 ^ x[1] ^ key, product as u64];
+
+        round += 1;
+    }
+
+    x
+}
+
+/// Packs `[k0, k1]` into one word, `k0` in the low half.
+pub const fn key4x32_to_word(key: [u32; 2]) -> u64 {
+    key[0] as u64 | (key[1] as u64) << 32
+}
+
+// Splitting the word into its halves is the point
+#[allow(clippy::cast_possible_truncation)]
+pub const fn key4x32_from_word(word: u64) -> [u32; 2] {
+    [word as u32, (word >> 32) as u32]
+}
+
+/// Output words of a block, `x0 | x1 << 32` and `x2 | x3 << 32`.
+pub const fn block4x32_to_words(block: [u32; 4]) -> [u64; 2] {
+    [
+        block[0] as u64 | (block[1] as u64) << 32,
+        block[2] as u64 | (block[3] as u64) << 32,
+    ]
+}
+
+/// Counter words are little-endian, `counter[0]` holds the lowest bits.
+pub const fn counter4x32_to_u128(counter: [u32; 4]) -> u128 {
+    counter[0] as u128 | (counter[1] as u128) << 32 | (counter[2] as u128) << 64 | (counter[3] as u128) << 96
+}
+
+// Splitting the counter into its words is the point
+#[allow(clippy::cast_possible_truncation)]
+pub const fn counter4x32_from_u128(counter: u128) -> [u32; 4] {
+    [
+        counter as u32,
+        (counter >> 32) as u32,
+        (counter >> 64) as u32,
+        (counter >> 96) as u32,
+    ]
+}
+
+pub const fn counter2x64_to_u128(counter: [u64; 2]) -> u128 {
+    counter[0] as u128 | (counter[1] as u128) << 64
+}
+
+// Splitting the counter into its words is the point
+#[allow(clippy::cast_possible_truncation)]
+pub const fn counter2x64_from_u128(counter: u128) -> [u64; 2] {
+    [counter as u64, (counter >> 64) as u64]
+}
+
+/// The low and high words of `BLOCKS` consecutive counters starting at `counter`, one block after the other.
+pub fn counter_words<const BLOCKS: usize, const WORDS: usize>(counter: u128) -> [u64; WORDS] {
+    assert_eq!(BLOCKS * 2, WORDS);
+
+    core::array::from_fn(|index| {
+        let [lo, hi] = counter2x64_from_u128(counter.wrapping_add((index / 2) as u128));
+        if index % 2 == 0 { lo } else { hi }
+    })
+}
+
+/// Splits output words back into the `[x0, x1, x2, x3]` blocks they were packed from.
+// Splitting the words into their halves is the point
+#[allow(clippy::cast_possible_truncation)]
+pub fn words_to_blocks4x32<const BLOCKS: usize, const WORDS: usize>(words: [u64; WORDS]) -> [[u32; 4]; BLOCKS] {
+    assert_eq!(BLOCKS * 2, WORDS);
+
+    core::array::from_fn(|block| {
+        let [lo, hi] = [words[block * 2], words[block * 2 + 1]];
+        [lo as u32, (lo >> 32) as u32, hi as u32, (hi >> 32) as u32]
+    })
+}
+
+/// Groups output words into the `[x0, x1]` blocks they were taken from.
+pub fn words_to_blocks2x64<const BLOCKS: usize, const WORDS: usize>(words: [u64; WORDS]) -> [[u64; 2]; BLOCKS] {
+    assert_eq!(BLOCKS * 2, WORDS);
+
+    core::array::from_fn(|block| [words[block * 2], words[block * 2 + 1]])
+}
+
+/// Reads the seed as the key word followed by the low and high counter words.
+pub fn seed_state(seed: &[u8]) -> [u64; 3] {
+    let (chunks, remainder) = seed.as_chunks::<8>();
+    assert!(remainder.is_empty());
+    assert_eq!(chunks.len(), 3);
+
+    [
+        u64::from_le_bytes(chunks[0]),
+        u64::from_le_bytes(chunks[1]),
+        u64::from_le_bytes(chunks[2]),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{philox2x64, philox4x32};
+
+    // Known-answer vectors from Random123's `kat_vectors`
+    #[test]
+    fn philox4x32_known_answers() {
+        assert_eq!(
+            philox4x32([0, 0], [0, 0, 0, 0]),
+            [0x6627e8d5, 0xe169c58d, 0xbc57ac4c, 0x9b00dbd8]
+        );
+        assert_eq!(
+            philox4x32([u32::MAX, u32::MAX], [u32::MAX; 4]),
+            [0x408f276d, 0x41c83b0e, 0xa20bc7c6, 0x6d5451fd]
+        );
+        assert_eq!(
+            philox4x32(
+                [0xa4093822, 0x299f31d0],
+                [0x243f6a88, 0x85a308d3, 0x13198a2e, 0x03707344]
+            ),
+            [0xd16cfe09, 0x94fdcceb, 0x5001e420, 0x24126ea1]
+        );
+    }
+
+    #[test]
+    fn philox2x64_known_answers() {
+        assert_eq!(philox2x64(0, [0, 0]), [0xca00a0459843d731, 0x66c24222c9a845b5]);
+        assert_eq!(
+            philox2x64(u64::MAX, [u64::MAX, u64::MAX]),
+            [0x65b021d60cd8310f, 0x4d02f3222f86df20]
+        );
+        assert_eq!(
+            philox2x64(0xa4093822299f31d0, [0x243f6a8885a308d3, 0x13198a2e03707344]),
+            [0x0a5e742c2997341c, 0xb0f883d38000de5d]
+        );
+    }
+}
@@ -0,0 +1,190 @@
+use core::simd::Simd;
+
+use crate::biski64::{FAST_LOOP_INCREMENT, seed_from_bytes, seed_state, seed_stream_states};
+
+use super::lanes::{LaneGenerator, impl_lanes};
+use super::rotate_left;
+
+/// Biski64 with `LANES` independent lanes, each the stream of [`Biski64`](crate::scalar::Biski64) from its own state.
+///
+/// `seed_from_u64` and `from_rng` start the lanes as the parallel streams of upstream's `from_seed_for_stream`, while
+/// `from_seed` seeds every lane on its own from its word of the seed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Biski64<const LANES: usize> {
+    fast_loop: Simd<u64, LANES>,
+    mix: Simd<u64, LANES>,
+    loop_mix: Simd<u64, LANES>,
+}
+
+impl<const LANES: usize> Biski64<LANES> {
+    fn from_lane_states(states: [[u64; 3]; LANES]) -> Self {
+        Self {
+            fast_loop: Simd::from_array(states.map(|state| state[0])),
+            mix: Simd::from_array(states.map(|state| state[1])),
+            loop_mix: Simd::from_array(states.map(|state| state[2])),
+        }
+    }
+}
+
+impl<const LANES: usize> LaneGenerator<LANES, 3, 1> for Biski64<LANES> {
+    #[inline(always)]
+    fn vectors(&self) -> [Simd<u64, LANES>; 3] {
+        [self.fast_loop, self.mix, self.loop_mix]
+    }
+
+    #[inline(always)]
+    fn from_vectors(vectors: [Simd<u64, LANES>; 3]) -> Self {
+        let [fast_loop, mix, loop_mix] = vectors;
+
+        Self {
+            fast_loop,
+            mix,
+            loop_mix,
+        }
+    }
+
+    /// Seeds lane `i` with word `i` of the seed, the same as upstream's `from_seed_for_stream(word, 0, 1)`.
+    #[inline(always)]
+    fn from_seed_vectors([seed]: [Simd<u64, LANES>; 1]) -> Self {
+        Self::from_lane_states(seed.to_array().map(seed_state))
+    }
+
+    /// Starts lane `i` as stream `i` of `LANES` parallel streams from `seed`.
+    #[inline(always)]
+    fn from_u64(seed: u64) -> Self {
+        Self::from_lane_states(seed_stream_states(seed))
+    }
+
+    /// Folds all of the bytes into one `u64` and starts the parallel streams from it.
+    #[inline(always)]
+    fn from_rng_seed(seed: &[u8]) -> Self {
+        Self::from_u64(seed_from_bytes(seed))
+    }
+
+    #[inline(always)]
+    fn next_vector(&mut self) -> Simd<u64, LANES> {
+        let fast_loop = self.fast_loop;
+        let mix = self.mix;
+        let loop_mix = self.loop_mix;
+
+        self.fast_loop = fast_loop + Simd::splat(FAST_LOOP_INCREMENT);
+        self.mix = rotate_left(mix, 16) + rotate_left(loop_mix, 40);
+        self.loop_mix = fast_loop ^ mix;
+
+        mix + loop_mix
+    }
+}
+
+impl_lanes!(
+    Biski64,
+    scalar = crate::scalar::Biski64,
+    fields = 3,
+    seed_vectors = 1,
+    widths = [
+        Biski64X2(Biski64X2Seed),
+        Biski64X4(Biski64X4Seed),
+        Biski64X8(Biski64X8Seed),
+        Biski64X16(Biski64X16Seed),
+        Biski64X32(Biski64X32Seed),
+    ],
+);
+
+#[cfg(test)]
+mod tests {
+    use rand_core::SeedableRng;
+
+    use super::{Biski64X4, Biski64X4Seed, Biski64X8};
+    use crate::biski64::{
+        FixedBytesRng, assert_from_rng_matches_parallel_streams, assert_rngs_match,
+        assert_seed_from_u64_matches_parallel_streams, reference_sequence,
+    };
+    use crate::portable::{SimdRandX4, SimdRandX8};
+
+    #[test]
+    fn seed_from_u64_matches_upstream_parallel_streams() {
+        assert_seed_from_u64_matches_parallel_streams::<4, _>(42, Biski64X4::seed_from_u64(42), |rng| {
+            rng.next_u64x4().to_array()
+        });
+        assert_seed_from_u64_matches_parallel_streams::<8, _>(42, Biski64X8::seed_from_u64(42), |rng| {
+            rng.next_u64x8().to_array()
+        });
+    }
+
+    #[test]
+    fn from_rng_matches_upstream_parallel_streams() {
+        let seed = [
+            0xEF, 0xCD, 0xAB, 0x89, 0x67, 0x45, 0x23, 0x01, 0x18, 0x17, 0x16, 0x15, 0x14, 0x13, 0x12, 0x11, 0x28, 0x27,
+            0x26, 0x25, 0x24, 0x23, 0x22, 0x21, 0x38, 0x37, 0x36, 0x35, 0x34, 0x33, 0x32, 0x31,
+        ];
+        assert_from_rng_matches_parallel_streams::<4, 32, _>(seed, Biski64X4::from_rng, |rng| {
+            rng.next_u64x4().to_array()
+        });
+
+        let seed = [
+            0xEF, 0xCD, 0xAB, 0x89, 0x67, 0x45, 0x23, 0x01, 0x18, 0x17, 0x16, 0x15, 0x14, 0x13, 0x12, 0x11, 0x28, 0x27,
+            0x26, 0x25, 0x24, 0x23, 0x22, 0x21, 0x38, 0x37, 0x36, 0x35, 0x34, 0x33, 0x32, 0x31, 0x48, 0x47, 0x46, 0x45,
+            0x44, 0x43, 0x42, 0x41, 0x58, 0x57, 0x56, 0x55, 0x54, 0x53, 0x52, 0x51, 0x68, 0x67, 0x66, 0x65, 0x64, 0x63,
+            0x62, 0x61, 0x78, 0x77, 0x76, 0x75, 0x74, 0x73, 0x72, 0x71,
+        ];
+        assert_from_rng_matches_parallel_streams::<8, 64, _>(seed, Biski64X8::from_rng, |rng| {
+            rng.next_u64x8().to_array()
+        });
+    }
+
+    #[test]
+    fn from_rng_uses_seed_bytes_beyond_first_word() {
+        let seed_a = [0u8; 32];
+        let mut seed_b = seed_a;
+        seed_b[31] = 1;
+
+        let mut rng_a = Biski64X4::from_rng(&mut FixedBytesRng::new(seed_a));
+        let mut rng_b = Biski64X4::from_rng(&mut FixedBytesRng::new(seed_b));
+
+        assert_ne!(rng_a.next_u64x4().to_array(), rng_b.next_u64x4().to_array());
+    }
+
+    #[test]
+    fn try_from_rng_matches_from_rng() {
+        let seed = [7u8; 32];
+
+        assert_rngs_match::<4, _>(
+            Biski64X4::from_rng(&mut FixedBytesRng::new(seed)),
+            Biski64X4::try_from_rng(&mut FixedBytesRng::new(seed)).unwrap(),
+            |rng| rng.next_u64x4().to_array(),
+        );
+
+        let seed = [7u8; 64];
+
+        assert_rngs_match::<8, _>(
+            Biski64X8::from_rng(&mut FixedBytesRng::new(seed)),
+            Biski64X8::try_from_rng(&mut FixedBytesRng::new(seed)).unwrap(),
+            |rng| rng.next_u64x8().to_array(),
+        );
+    }
+
+    #[test]
+    fn asymmetric_seeds_match_scalar_reference() {
+        let seed_words = [
+            0x0000000000000000_u64,
+            0x0123456789ABCDEF_u64,
+            0x1112131415161718_u64,
+            0xFFFFFFFFFFFFFFFF_u64,
+        ];
+        let mut seed = [0u8; 32];
+        for (index, word) in seed_words.iter().enumerate() {
+            seed[(index * 8)..((index + 1) * 8)].copy_from_slice(&word.to_le_bytes());
+        }
+
+        let mut rng = Biski64X4::from_seed(Biski64X4Seed::from(seed));
+        let vectors = [
+            rng.next_u64x4().to_array(),
+            rng.next_u64x4().to_array(),
+            rng.next_u64x4().to_array(),
+            rng.next_u64x4().to_array(),
+        ];
+
+        for (lane, seed_word) in seed_words.into_iter().enumerate() {
+            assert_eq!(vectors.map(|vector| vector[lane]), reference_sequence::<4>(seed_word));
+        }
+    }
+}
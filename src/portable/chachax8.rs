@@ -0,0 +1,182 @@
+use core::{
+    fmt,
+    simd::{u32x16, u64x8},
+};
+
+use rand_core::{CryptoRng, RngCore, SeedableRng};
+
+use super::{SimdRandX8, transpose};
+use crate::chacha::{BLOCK_WORDS, BlockBuffer, counter_lanes, input_words, pack_words};
+
+// One block per 32-bit lane
+const BLOCKS: usize = 16;
+const BUFFER_WORDS: usize = BLOCKS * BLOCK_WORDS;
+
+/// ChaCha with `ROUNDS` rounds, computing 16 blocks per refill of its output buffer.
+///
+/// The output is the same as `rand_chacha`'s generators for the same seed, stream and word position,
+/// through [`SimdRandX8`] as well as [`RngCore`], so vectors of 8 words continue the stream of [`RngCore::next_u64`].
+#[derive(Clone, PartialEq, Eq)]
+pub struct ChaChaX8<const ROUNDS: usize> {
+    buffer: BlockBuffer<BUFFER_WORDS>,
+}
+
+/// ChaCha with 8 rounds, the stream of `rand_chacha::ChaCha8Rng`.
+pub type ChaCha8X8 = ChaChaX8<8>;
+/// ChaCha with 12 rounds, the stream of `rand_chacha::ChaCha12Rng`.
+pub type ChaCha12X8 = ChaChaX8<12>;
+/// ChaCha with 20 rounds, the stream of `rand_chacha::ChaCha20Rng`.
+pub type ChaCha20X8 = ChaChaX8<20>;
+
+impl<const ROUNDS: usize> ChaChaX8<ROUNDS> {
+    /// Returns the seed the generator was created from, its key.
+    #[must_use]
+    pub fn seed(&self) -> [u8; 32] {
+        self.buffer.seed()
+    }
+
+    /// Returns the stream, the 64-bit nonce.
+    #[must_use]
+    pub const fn stream(&self) -> u64 {
+        self.buffer.stream()
+    }
+
+    /// Switches to `stream`, continuing at the same word position.
+    pub fn set_stream(&mut self, stream: u64) {
+        self.buffer.set_stream(stream, blocks::<ROUNDS>);
+    }
+
+    /// Returns the position of the next 32-bit word in the stream.
+    #[must_use]
+    pub const fn word_pos(&self) -> u128 {
+        self.buffer.word_pos()
+    }
+
+    /// Moves to the 32-bit word at `word_pos`, which wraps at 2^68.
+    pub fn set_word_pos(&mut self, word_pos: u128) {
+        self.buffer.set_word_pos(word_pos, blocks::<ROUNDS>);
+    }
+
+    /// Returns the generator state as the key in four words, followed by the stream and the low and high
+    /// word position words.
+    ///
+    /// The buffered output is not part of the snapshot, [`ChaChaX8::from_state`] regenerates it.
+    #[must_use]
+    pub fn state(&self) -> [u64; 7] {
+        self.buffer.state()
+    }
+
+    /// Restores a generator from a snapshot taken with [`ChaChaX8::state`].
+    #[must_use]
+    pub fn from_state(state: [u64; 7]) -> Self {
+        Self {
+            buffer: BlockBuffer::from_state(state, blocks::<ROUNDS>),
+        }
+    }
+}
+
+impl<const ROUNDS: usize> fmt::Debug for ChaChaX8<ROUNDS> {
+    // The key is left out, it is the secret of a cryptographic generator
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ChaChaX8")
+            .field("rounds", &ROUNDS)
+            .field("stream", &self.stream())
+            .field("word_pos", &self.word_pos())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "serde")]
+crate::serde::impl_state_serde!(ChaCha8X8, 7);
+#[cfg(feature = "serde")]
+crate::serde::impl_state_serde!(ChaCha12X8, 7);
+#[cfg(feature = "serde")]
+crate::serde::impl_state_serde!(ChaCha20X8, 7);
+
+impl<const ROUNDS: usize> SeedableRng for ChaChaX8<ROUNDS> {
+    type Seed = [u8; 32];
+
+    /// Uses the seed as the key, starting at word 0 of stream 0.
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self {
+            buffer: BlockBuffer::new(seed),
+        }
+    }
+}
+
+impl<const ROUNDS: usize> SimdRandX8 for ChaChaX8<ROUNDS> {
+    #[inline(always)]
+    fn next_u64x8(&mut self) -> u64x8 {
+        u64x8::from_array(pack_words(self.buffer.next_words::<16>(blocks::<ROUNDS>)))
+    }
+}
+
+super::simdrand::impl_simd_rng_x8!(impl<const ROUNDS: usize> ChaChaX8<ROUNDS>);
+
+impl<const ROUNDS: usize> RngCore for ChaChaX8<ROUNDS> {
+    #[inline(always)]
+    fn next_u32(&mut self) -> u32 {
+        let [word] = self.buffer.next_words::<1>(blocks::<ROUNDS>);
+        word
+    }
+
+    #[inline(always)]
+    fn next_u64(&mut self) -> u64 {
+        let [lo, hi] = self.buffer.next_words::<2>(blocks::<ROUNDS>);
+        u64::from(lo) | u64::from(hi) << 32
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.buffer.fill_bytes(dest, blocks::<ROUNDS>);
+    }
+}
+
+impl<const ROUNDS: usize> CryptoRng for ChaChaX8<ROUNDS> {}
+
+/// Writes blocks `counter..counter + 16` into `out`, one after the other.
+#[inline(always)]
+fn blocks<const ROUNDS: usize>(key: &[u32; 8], counter: u64, stream: u64, out: &mut [u32; BUFFER_WORDS]) {
+    // Vector `i` holds word `i` of every block, lane `j` being block `counter + j`
+    let [counter_lo, counter_hi] = counter_lanes::<BLOCKS>(counter);
+    let mut input = input_words(key, stream).map(u32x16::splat);
+    input[12] = u32x16::from_array(counter_lo);
+    input[13] = u32x16::from_array(counter_hi);
+
+    let mut x = input;
+    for _ in 0..ROUNDS / 2 {
+        quarter_round(&mut x, 0, 4, 8, 12);
+        quarter_round(&mut x, 1, 5, 9, 13);
+        quarter_round(&mut x, 2, 6, 10, 14);
+        quarter_round(&mut x, 3, 7, 11, 15);
+        quarter_round(&mut x, 0, 5, 10, 15);
+        quarter_round(&mut x, 1, 6, 11, 12);
+        quarter_round(&mut x, 2, 7, 8, 13);
+        quarter_round(&mut x, 3, 4, 9, 14);
+    }
+    for (x, input) in x.iter_mut().zip(input) {
+        *x += input;
+    }
+
+    for (block, words) in out.chunks_exact_mut(BLOCK_WORDS).zip(transpose(x)) {
+        words.copy_to_slice(block);
+    }
+}
+
+#[inline(always)]
+// Word names follow the ChaCha specification
+#[allow(clippy::many_single_char_names)]
+fn quarter_round(x: &mut [u32x16; 16], a: usize, b: usize, c: usize, d: usize) {
+    x[a] += x[b];
+    x[d] = rotate_left(x[d] ^ x[a], 16);
+    x[c] += x[d];
+    x[b] = rotate_left(x[b] ^ x[c], 12);
+    x[a] += x[b];
+    x[d] = rotate_left(x[d] ^ x[a], 8);
+    x[c] += x[d];
+    x[b] = rotate_left(x[b] ^ x[c], 7);
+}
+
+#[inline(always)]
+fn rotate_left(x: u32x16, k: u32) -> u32x16 {
+    (x << u32x16::splat(k)) | (x >> u32x16::splat(32 - k))
+}
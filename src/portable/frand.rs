@@ -0,0 +1,76 @@
+use core::simd::Simd;
+
+use super::lanes::{LaneGenerator, impl_lanes};
+
+const INCREMENT: u64 = 12964901029718341801;
+const MUL_XOR: u64 = 149988720821803190;
+const SHIFT: u64 = 32;
+
+/// Frand with `LANES` independent lanes, each the stream of [`Frand`](crate::scalar::Frand) from its own counter.
+///
+/// The step is an addition, a xor, a multiply and a shift, all of which map directly to vector instructions.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Frand<const LANES: usize> {
+    seed: Simd<u64, LANES>,
+}
+
+impl<const LANES: usize> LaneGenerator<LANES, 1, 1> for Frand<LANES> {
+    #[inline(always)]
+    fn vectors(&self) -> [Simd<u64, LANES>; 1] {
+        [self.seed]
+    }
+
+    #[inline(always)]
+    fn from_vectors(vectors: [Simd<u64, LANES>; 1]) -> Self {
+        let [seed] = vectors;
+
+        Self { seed }
+    }
+
+    /// Reads the counter of every lane straight from the seed.
+    #[inline(always)]
+    fn from_seed_vectors(seed: [Simd<u64, LANES>; 1]) -> Self {
+        Self::from_vectors(seed)
+    }
+
+    #[inline(always)]
+    fn next_vector(&mut self) -> Simd<u64, LANES> {
+        let value = self.seed + Simd::splat(INCREMENT);
+        self.seed = value;
+        let value = value * (Simd::splat(MUL_XOR) ^ value);
+        value ^ (value >> Simd::splat(SHIFT))
+    }
+}
+
+impl_lanes!(
+    Frand,
+    scalar = crate::scalar::Frand,
+    fields = 1,
+    seed_vectors = 1,
+    widths = [
+        FrandX2(FrandX2Seed),
+        FrandX4(FrandX4Seed),
+        FrandX8(FrandX8Seed),
+        FrandX16(FrandX16Seed),
+        FrandX32(FrandX32Seed),
+    ],
+);
+
+#[cfg(test)]
+mod tests {
+    use rand_core::SeedableRng;
+
+    use super::{FrandX4, FrandX8};
+    use crate::frand::test_support::assert_seed_from_u64_matches_splitmix64;
+    use crate::portable::{SimdRandX4, SimdRandX8};
+
+    #[test]
+    fn seed_from_u64_matches_splitmix64() {
+        assert_seed_from_u64_matches_splitmix64::<4, _>(42, FrandX4::seed_from_u64(42), |rng| {
+            rng.next_u64x4().to_array()
+        });
+        assert_seed_from_u64_matches_splitmix64::<8, _>(42, FrandX8::seed_from_u64(42), |rng| {
+            rng.next_u64x8().to_array()
+        });
+    }
+}
@@ -0,0 +1,75 @@
+use core::simd::Simd;
+
+use crate::jsf64::{SEED_A, WARMUP_ROUNDS};
+
+use super::lanes::{LaneGenerator, impl_lanes};
+use super::rotate_left;
+
+/// JSF64 with `LANES` independent lanes, each the stream of [`Jsf64`](crate::scalar::Jsf64) from its own state.
+///
+/// The step is only additions, subtractions, rotations and a xor, all of which map directly to vector instructions.
+///
+/// Unlike SFC64, JSF64 has no counter to give the lanes separate streams, they are apart only by their seed words.
+/// Lanes seeded with the same word run the same stream, `seed_from_u64` gives every lane a different one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Jsf64<const LANES: usize> {
+    a: Simd<u64, LANES>,
+    b: Simd<u64, LANES>,
+    c: Simd<u64, LANES>,
+    d: Simd<u64, LANES>,
+}
+
+impl<const LANES: usize> LaneGenerator<LANES, 4, 1> for Jsf64<LANES> {
+    #[inline(always)]
+    fn vectors(&self) -> [Simd<u64, LANES>; 4] {
+        [self.a, self.b, self.c, self.d]
+    }
+
+    #[inline(always)]
+    fn from_vectors(vectors: [Simd<u64, LANES>; 4]) -> Self {
+        let [a, b, c, d] = vectors;
+
+        Self { a, b, c, d }
+    }
+
+    /// Seeds lane `i` with word `i` of the seed like Bob Jenkins' `raninit`, the same as [`Jsf64::new`](crate::scalar::Jsf64::new).
+    #[inline(always)]
+    fn from_seed_vectors([seed]: [Simd<u64, LANES>; 1]) -> Self {
+        let mut rng = Self {
+            a: Simd::splat(SEED_A),
+            b: seed,
+            c: seed,
+            d: seed,
+        };
+        for _ in 0..WARMUP_ROUNDS {
+            rng.next_vector();
+        }
+
+        rng
+    }
+
+    #[inline(always)]
+    fn next_vector(&mut self) -> Simd<u64, LANES> {
+        let e = self.a - rotate_left(self.b, 7);
+        self.a = self.b ^ rotate_left(self.c, 13);
+        self.b = self.c + rotate_left(self.d, 37);
+        self.c = self.d + e;
+        self.d = e + self.a;
+
+        self.d
+    }
+}
+
+impl_lanes!(
+    Jsf64,
+    scalar = crate::scalar::Jsf64,
+    fields = 4,
+    seed_vectors = 1,
+    widths = [
+        Jsf64X2(Jsf64X2Seed),
+        Jsf64X4(Jsf64X4Seed),
+        Jsf64X8(Jsf64X8Seed),
+        Jsf64X16(Jsf64X16Seed),
+        Jsf64X32(Jsf64X32Seed),
+    ],
+);
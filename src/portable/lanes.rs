@@ -0,0 +1,293 @@
+use core::simd::Simd;
+
+use crate::splitmix64::{lane_states, next};
+
+/// A generator running `LANES` independent streams of a scalar generator, one per vector lane.
+///
+/// Its state is `FIELDS` vectors, the state words of the scalar generator, and it is seeded from `SEED_VECTORS`
+/// vectors. The lane count is a plain const parameter, [`impl_lanes!`] builds the public API on top of it for every
+/// lane count along with the fixed width aliases.
+pub trait LaneGenerator<const LANES: usize, const FIELDS: usize, const SEED_VECTORS: usize>: Sized {
+    fn vectors(&self) -> [Simd<u64, LANES>; FIELDS];
+
+    fn from_vectors(vectors: [Simd<u64, LANES>; FIELDS]) -> Self;
+
+    /// Runs the seeding of the scalar generator on every lane, `from_seed` reads the vectors one after the other.
+    fn from_seed_vectors(seed: [Simd<u64, LANES>; SEED_VECTORS]) -> Self;
+
+    /// Fills the seed vectors with the output of a SplitMix64 with as many lanes, seeded with `seed`.
+    #[inline(always)]
+    fn from_u64(seed: u64) -> Self {
+        Self::from_seed_vectors(splitmix_vectors(seed))
+    }
+
+    /// Seeds the generator from the bytes `from_rng` fills a seed with, the same as `from_seed` unless overridden.
+    #[inline(always)]
+    fn from_rng_seed(seed: &[u8]) -> Self {
+        Self::from_seed_vectors(read_seed_vectors(seed))
+    }
+
+    fn next_vector(&mut self) -> Simd<u64, LANES>;
+}
+
+/// The first `N` output vectors of a SplitMix64 with `LANES` lanes seeded with `seed`, the same words as
+/// [`expand_seed`](crate::splitmix64::expand_seed) in the same order.
+#[inline(always)]
+#[must_use]
+pub fn splitmix_vectors<const LANES: usize, const N: usize>(seed: u64) -> [Simd<u64, LANES>; N] {
+    let mut states = lane_states::<LANES>(seed);
+
+    core::array::from_fn(|_| Simd::from_array(states.each_mut().map(next)))
+}
+
+/// Reads `N` vectors of little endian words from `seed`, one after the other.
+#[inline(always)]
+#[must_use]
+pub fn read_seed_vectors<const LANES: usize, const N: usize>(seed: &[u8]) -> [Simd<u64, LANES>; N] {
+    assert_eq!(seed.len(), N * LANES * 8);
+
+    core::array::from_fn(|index| super::read_u64_into_vec(&seed[(index * LANES * 8)..((index + 1) * LANES * 8)]))
+}
+
+/// The state words of lane `lane`, one from every vector.
+#[must_use]
+pub fn lane_words<const LANES: usize, const FIELDS: usize>(
+    vectors: &[Simd<u64, LANES>; FIELDS],
+    lane: usize,
+) -> [u64; FIELDS] {
+    assert!(lane < LANES, "lane index {lane} out of range for {LANES} lanes");
+
+    vectors.map(|vector| vector[lane])
+}
+
+/// Inverse of [`lane_words`], assembles the vectors from the state words of every lane.
+#[must_use]
+pub fn lanes_to_vectors<const LANES: usize, const FIELDS: usize>(
+    lanes: &[[u64; FIELDS]; LANES],
+) -> [Simd<u64, LANES>; FIELDS] {
+    core::array::from_fn(|field| Simd::from_array(lanes.map(|words| words[field])))
+}
+
+/// Implements the public API of a [`LaneGenerator`] named `$rng`, for any lane count and for the 2, 4, 8, 16 and
+/// 32 lane aliases with their seed types.
+///
+/// Every lane count gets `lane`/`into_lanes`/`from_lanes` and [`SimdRng`](crate::SimdRng). The aliases also get
+/// `state`/`from_state`, `SeedableRng`, serde, and conversions from and into two aliases with half the lanes, since
+/// those need the state and seed sizes as concrete array lengths. The 4 and 8 lane aliases implement
+/// [`SimdRandX4`](super::SimdRandX4) and [`SimdRandX8`](super::SimdRandX8).
+macro_rules! impl_lanes {
+    (
+        $rng:ident,
+        scalar = $scalar:ty,
+        fields = $fields:literal,
+        seed_vectors = $seed_vectors:literal,
+        widths = [
+            $x2:ident($x2_seed:ident),
+            $x4:ident($x4_seed:ident),
+            $x8:ident($x8_seed:ident),
+            $x16:ident($x16_seed:ident),
+            $x32:ident($x32_seed:ident) $(,)?
+        ] $(,)?
+    ) => {
+        impl<const LANES: usize> $rng<LANES> {
+            /// Returns a scalar generator positioned at the same point in the stream of lane `lane`.
+            ///
+            /// # Panics
+            ///
+            /// If `lane` is not less than `LANES`.
+            #[must_use]
+            pub fn lane(&self, lane: usize) -> $scalar {
+                <$scalar>::from_state(crate::portable::lanes::lane_words(
+                    &crate::portable::lanes::LaneGenerator::vectors(self),
+                    lane,
+                ))
+            }
+
+            /// Splits the generator into one scalar generator per lane, each continuing its lane's stream.
+            #[must_use]
+            pub fn into_lanes(self) -> [$scalar; LANES] {
+                core::array::from_fn(|lane| self.lane(lane))
+            }
+
+            /// Assembles a generator from scalar generators, lane `i` continues the stream of `lanes[i]`.
+            #[must_use]
+            pub fn from_lanes(lanes: [$scalar; LANES]) -> Self {
+                crate::portable::lanes::LaneGenerator::from_vectors(crate::portable::lanes::lanes_to_vectors(
+                    &lanes.map(|lane| lane.state()),
+                ))
+            }
+        }
+
+        impl<const LANES: usize> crate::SimdRng for $rng<LANES> {
+            const LANES: usize = LANES;
+
+            type U64Vec = core::simd::Simd<u64, LANES>;
+            type F64Vec = core::simd::Simd<f64, LANES>;
+
+            #[inline(always)]
+            fn next_u64_vec(&mut self) -> Self::U64Vec {
+                crate::portable::lanes::LaneGenerator::next_vector(self)
+            }
+
+            #[inline(always)]
+            fn next_f64_vec(&mut self) -> Self::F64Vec {
+                <crate::portable::Multiply as crate::portable::F64Conversion>::to_f64(
+                    crate::portable::lanes::LaneGenerator::next_vector(self),
+                )
+            }
+        }
+
+        impl crate::portable::SimdRandX4 for $rng<4> {
+            #[inline(always)]
+            fn next_u64x4(&mut self) -> core::simd::u64x4 {
+                crate::portable::lanes::LaneGenerator::next_vector(self)
+            }
+        }
+
+        impl crate::portable::SimdRandX8 for $rng<8> {
+            #[inline(always)]
+            fn next_u64x8(&mut self) -> core::simd::u64x8 {
+                crate::portable::lanes::LaneGenerator::next_vector(self)
+            }
+        }
+
+        crate::portable::lanes::impl_lanes!(@width $rng, $fields, $seed_vectors, 2, $x2, $x2_seed);
+        crate::portable::lanes::impl_lanes!(@width $rng, $fields, $seed_vectors, 4, $x4, $x4_seed);
+        crate::portable::lanes::impl_lanes!(@width $rng, $fields, $seed_vectors, 8, $x8, $x8_seed);
+        crate::portable::lanes::impl_lanes!(@width $rng, $fields, $seed_vectors, 16, $x16, $x16_seed);
+        crate::portable::lanes::impl_lanes!(@width $rng, $fields, $seed_vectors, 32, $x32, $x32_seed);
+
+        crate::portable::lanes::impl_lanes!(@halves $fields, 4, $x4, $x2);
+        crate::portable::lanes::impl_lanes!(@halves $fields, 8, $x8, $x4);
+        crate::portable::lanes::impl_lanes!(@halves $fields, 16, $x16, $x8);
+        crate::portable::lanes::impl_lanes!(@halves $fields, 32, $x32, $x16);
+    };
+
+    (@width $rng:ident, $fields:literal, $seed_vectors:literal, $lanes:literal, $alias:ident, $seed:ident) => {
+        #[doc = concat!("[`", stringify!($rng), "`] with ", stringify!($lanes), " lanes.")]
+        pub type $alias = $rng<$lanes>;
+
+        #[doc = concat!("Seed of [`", stringify!($alias), "`], ", stringify!($seed_vectors), " vectors of ", stringify!($lanes), " little endian words.")]
+        #[derive(Clone)]
+        pub struct $seed([u8; $seed_vectors * $lanes * 8]);
+
+        impl $seed {
+            #[must_use]
+            pub const fn new(seed: [u8; $seed_vectors * $lanes * 8]) -> Self {
+                Self(seed)
+            }
+        }
+
+        impl From<[u8; $seed_vectors * $lanes * 8]> for $seed {
+            fn from(val: [u8; $seed_vectors * $lanes * 8]) -> Self {
+                Self::new(val)
+            }
+        }
+
+        impl From<&[u8]> for $seed {
+            fn from(val: &[u8]) -> Self {
+                assert_eq!(val.len(), $seed_vectors * $lanes * 8);
+                let mut seed = [0u8; $seed_vectors * $lanes * 8];
+                seed.copy_from_slice(val);
+                Self::new(seed)
+            }
+        }
+
+        impl core::ops::Deref for $seed {
+            type Target = [u8; $seed_vectors * $lanes * 8];
+
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+        impl core::ops::DerefMut for $seed {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                &mut self.0
+            }
+        }
+
+        impl Default for $seed {
+            fn default() -> Self {
+                Self([0; $seed_vectors * $lanes * 8])
+            }
+        }
+
+        impl AsRef<[u8]> for $seed {
+            fn as_ref(&self) -> &[u8] {
+                &self.0
+            }
+        }
+
+        impl AsMut<[u8]> for $seed {
+            fn as_mut(&mut self) -> &mut [u8] {
+                &mut self.0
+            }
+        }
+
+        impl $alias {
+            /// Returns the generator state, the lane words of every state vector one vector after the other.
+            ///
+            /// Pass it to [`Self::from_state`] to resume the exact same stream later.
+            #[must_use]
+            pub fn state(&self) -> [u64; $fields * $lanes] {
+                crate::portable::vectors_to_state(crate::portable::lanes::LaneGenerator::vectors(self))
+            }
+
+            /// Restores a generator from a snapshot taken with [`Self::state`].
+            #[must_use]
+            pub fn from_state(state: [u64; $fields * $lanes]) -> Self {
+                crate::portable::lanes::LaneGenerator::from_vectors(crate::portable::state_to_vectors(&state))
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        crate::serde::impl_state_serde!($alias, $fields * $lanes);
+
+        impl rand_core::SeedableRng for $alias {
+            type Seed = $seed;
+
+            fn from_seed(seed: Self::Seed) -> Self {
+                crate::portable::lanes::LaneGenerator::from_seed_vectors(crate::portable::lanes::read_seed_vectors(
+                    &seed[..],
+                ))
+            }
+
+            fn seed_from_u64(seed: u64) -> Self {
+                crate::portable::lanes::LaneGenerator::from_u64(seed)
+            }
+
+            fn from_rng(rng: &mut impl rand_core::RngCore) -> Self {
+                let mut seed = Self::Seed::default();
+                rng.fill_bytes(seed.as_mut());
+                crate::portable::lanes::LaneGenerator::from_rng_seed(&seed[..])
+            }
+
+            fn try_from_rng<R: rand_core::TryRngCore>(rng: &mut R) -> Result<Self, R::Error> {
+                let mut seed = Self::Seed::default();
+                rng.try_fill_bytes(seed.as_mut())?;
+                Ok(crate::portable::lanes::LaneGenerator::from_rng_seed(&seed[..]))
+            }
+        }
+    };
+
+    (@halves $fields:literal, $lanes:literal, $alias:ident, $half:ident) => {
+        impl From<[$half; 2]> for $alias {
+            #[doc = concat!("Merges two [`", stringify!($half), "`] generators into one, the first provides the low lanes and the second the high lanes.")]
+            fn from(halves: [$half; 2]) -> Self {
+                Self::from_state(crate::state::merge_lanes::<{ $lanes / 2 }, { $fields * $lanes }, { $fields * $lanes / 2 }>(
+                    &halves.map(|half| half.state()),
+                ))
+            }
+        }
+
+        impl From<$alias> for [$half; 2] {
+            /// Splits the generator into its low and high lanes, each continuing its own streams.
+            fn from(rng: $alias) -> Self {
+                crate::state::split_lanes::<{ $lanes / 2 }, { $fields * $lanes }, { $fields * $lanes / 2 }>(&rng.state())
+                    .map($half::from_state)
+            }
+        }
+    };
+}
+
+pub(crate) use impl_lanes;
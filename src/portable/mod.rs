@@ -1,28 +1,61 @@
 use core::mem;
 use core::{
-    ops::{BitOr, Shl, Shr},
+    ops::{Add, BitAnd, BitOr, Mul, Shl, Shr},
     simd::Simd,
 };
 
-pub use biski64x4::*;
-pub use biski64x8::*;
-pub use frandx4::*;
-pub use frandx8::*;
+pub use biski64::*;
+pub use chachax4::*;
+pub use chachax8::*;
+pub use frand::*;
+pub use jsf64::*;
+pub use mt19937_64x4::*;
+pub use mt19937_64x8::*;
+pub use pcg64dxsmx4::*;
+pub use pcg64dxsmx8::*;
+pub use philox2x64x4::*;
+pub use philox2x64x8::*;
+pub use philox4x32x4::*;
+pub use philox4x32x8::*;
+pub use romutrio::*;
+pub use sfc64::*;
 pub use simdrand::*;
-pub use xoshiro256plusplusx4::*;
-pub use xoshiro256plusplusx8::*;
-pub use xoshiro256plusx4::*;
-pub use xoshiro256plusx8::*;
-
-mod biski64x4;
-mod biski64x8;
-mod frandx4;
-mod frandx8;
+pub use splitmix64::*;
+pub use wyrand::*;
+pub use xoroshiro128plus::*;
+pub use xoroshiro128plusplus::*;
+pub use xoshiro256plus::*;
+pub use xoshiro256plusplus::*;
+pub use xoshiro256starstar::*;
+pub use xoshiro512plusplus::*;
+pub use xoshiro512starstar::*;
+
+mod biski64;
+mod chachax4;
+mod chachax8;
+mod frand;
+mod jsf64;
+mod lanes;
+mod mt19937_64x4;
+mod mt19937_64x8;
+mod pcg64dxsmx4;
+mod pcg64dxsmx8;
+mod philox2x64x4;
+mod philox2x64x8;
+mod philox4x32x4;
+mod philox4x32x8;
+mod romutrio;
+mod sfc64;
 mod simdrand;
-mod xoshiro256plusplusx4;
-mod xoshiro256plusplusx8;
-mod xoshiro256plusx4;
-mod xoshiro256plusx8;
+mod splitmix64;
+mod wyrand;
+mod xoroshiro128plus;
+mod xoroshiro128plusplus;
+mod xoshiro256plus;
+mod xoshiro256plusplus;
+mod xoshiro256starstar;
+mod xoshiro512plusplus;
+mod xoshiro512starstar;
 
 #[inline(always)]
 fn read_u64_into_vec<const N: usize>(src: &[u8]) -> Simd<u64, N> {
@@ -45,6 +78,29 @@ fn read_u64_array<const N: usize>(src: &[u8]) -> [u64; N] {
     values
 }
 
+#[inline(always)]
+fn vectors_to_state<const LANES: usize, const VECTORS: usize, const WORDS: usize>(
+    vectors: [Simd<u64, LANES>; VECTORS],
+) -> [u64; WORDS] {
+    assert_eq!(LANES * VECTORS, WORDS);
+
+    let mut state = [0; WORDS];
+    for (words, vector) in state.chunks_exact_mut(LANES).zip(vectors) {
+        words.copy_from_slice(vector.as_array());
+    }
+
+    state
+}
+
+#[inline(always)]
+fn state_to_vectors<const LANES: usize, const VECTORS: usize, const WORDS: usize>(
+    state: &[u64; WORDS],
+) -> [Simd<u64, LANES>; VECTORS] {
+    assert_eq!(LANES * VECTORS, WORDS);
+
+    core::array::from_fn(|index| Simd::from_slice(&state[(index * LANES)..((index + 1) * LANES)]))
+}
+
 #[inline(always)]
 // Multiple trait bounds on the SIMD value are required; clippy sees them as repetition
 #[allow(clippy::type_repetition_in_bounds)]
@@ -59,3 +115,43 @@ where
     let right = x >> Simd::<u64, N>::splat(64 - k);
     left | right
 }
+
+#[inline(always)]
+// Multiple trait bounds on the SIMD value are required; clippy sees them as repetition
+#[allow(clippy::type_repetition_in_bounds)]
+fn mul_hi<const N: usize>(a: Simd<u64, N>, b: Simd<u64, N>) -> Simd<u64, N>
+where
+    Simd<u64, N>: Mul<Simd<u64, N>, Output = Simd<u64, N>>,
+    Simd<u64, N>: Add<Simd<u64, N>, Output = Simd<u64, N>>,
+    Simd<u64, N>: Shr<Simd<u64, N>, Output = Simd<u64, N>>,
+    Simd<u64, N>: BitAnd<Simd<u64, N>, Output = Simd<u64, N>>,
+{
+    // High 64 bits of the 128-bit product, composed from 32x32 -> 64-bit partial products
+    let mask = Simd::<u64, N>::splat(0xFFFF_FFFF);
+    let shift = Simd::<u64, N>::splat(32);
+    let (a_lo, a_hi) = (a & mask, a >> shift);
+    let (b_lo, b_hi) = (b & mask, b >> shift);
+
+    let lo_lo = a_lo * b_lo;
+    let lo_hi = a_lo * b_hi;
+    let hi_lo = a_hi * b_lo;
+    let hi_hi = a_hi * b_hi;
+
+    let middle = (lo_lo >> shift) + (lo_hi & mask) + (hi_lo & mask);
+    hi_hi + (lo_hi >> shift) + (hi_lo >> shift) + (middle >> shift)
+}
+
+#[inline(always)]
+fn transpose<const N: usize>(rows: [Simd<u32, N>; N]) -> [Simd<u32, N>; N] {
+    // Interleaving row `i` with row `i + N / 2`, log2(N) times over, turns rows into columns
+    let mut rows = rows;
+    for _ in 0..N.trailing_zeros() {
+        let mut next = rows;
+        for index in 0..N / 2 {
+            (next[2 * index], next[2 * index + 1]) = rows[index].interleave(rows[index + N / 2]);
+        }
+        rows = next;
+    }
+
+    rows
+}
@@ -0,0 +1,155 @@
+use core::{fmt, simd::u64x8};
+
+use rand_core::{RngCore, SeedableRng, impls};
+
+use super::SimdRandX8;
+use crate::mt19937_64::{
+    Buffer, DEFAULT_SEED, LOWER_MASK, MATRIX_A, NN, STATE_WORDS, UPPER_MASK, temper as temper_word,
+};
+
+/// MT19937-64 twisting its state 8 words at a time, handing out 8 consecutive outputs per [`SimdRandX8`] call.
+///
+/// The output is the same as the reference `genrand64_int64` and `std::mt19937_64` for the same seed,
+/// through [`SimdRandX8`] as well as [`RngCore`], so vectors of 8 words continue the stream of [`RngCore::next_u64`].
+#[derive(Clone, PartialEq, Eq)]
+pub struct Mt19937_64X8 {
+    buffer: Buffer,
+}
+
+impl Mt19937_64X8 {
+    /// Seeds the generator like `init_genrand64(seed)` and `std::mt19937_64(seed)`.
+    #[must_use]
+    pub fn new(seed: u64) -> Self {
+        Self {
+            buffer: Buffer::new(seed),
+        }
+    }
+
+    /// Seeds the generator like `init_by_array64(key)`.
+    ///
+    /// # Panics
+    ///
+    /// If `key` is empty.
+    #[must_use]
+    pub fn from_key(key: &[u64]) -> Self {
+        assert!(!key.is_empty(), "The MT19937-64 key must not be empty");
+
+        Self {
+            buffer: Buffer::from_key(key),
+        }
+    }
+
+    /// Returns the generator state as the 312 words of the state array followed by the index of the next word,
+    /// the `mt` and `mti` of the reference implementation.
+    #[must_use]
+    pub fn state(&self) -> [u64; STATE_WORDS] {
+        self.buffer.state()
+    }
+
+    /// Restores a generator from a snapshot taken with [`Mt19937_64X8::state`].
+    ///
+    /// # Panics
+    ///
+    /// If the index in the snapshot is larger than 312.
+    #[must_use]
+    pub fn from_state(state: [u64; STATE_WORDS]) -> Self {
+        Self {
+            buffer: Buffer::from_state(&state),
+        }
+    }
+}
+
+impl Default for Mt19937_64X8 {
+    /// Seeds the generator with 5489, like a default constructed `std::mt19937_64`.
+    fn default() -> Self {
+        Self::new(DEFAULT_SEED)
+    }
+}
+
+impl fmt::Debug for Mt19937_64X8 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Mt19937_64X8")
+            .field("index", &self.buffer.index())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "serde")]
+crate::mt19937_64::impl_state_serde!(Mt19937_64X8);
+
+impl From<crate::scalar::Mt19937_64> for Mt19937_64X8 {
+    fn from(rng: crate::scalar::Mt19937_64) -> Self {
+        Self::from_state(rng.state())
+    }
+}
+
+impl From<Mt19937_64X8> for crate::scalar::Mt19937_64 {
+    fn from(rng: Mt19937_64X8) -> Self {
+        Self::from_state(rng.state())
+    }
+}
+
+impl SeedableRng for Mt19937_64X8 {
+    type Seed = [u8; 8];
+
+    /// Seeds the generator with the little-endian `u64` in `seed`, see [`Mt19937_64X8::new`].
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self::new(u64::from_le_bytes(seed))
+    }
+
+    /// Seeds the generator with `state` directly, see [`Mt19937_64X8::new`].
+    fn seed_from_u64(state: u64) -> Self {
+        Self::new(state)
+    }
+}
+
+impl SimdRandX8 for Mt19937_64X8 {
+    #[inline(always)]
+    fn next_u64x8(&mut self) -> u64x8 {
+        temper(u64x8::from_array(self.buffer.next_words::<8>(twist)))
+    }
+}
+
+super::simdrand::impl_simd_rng_x8!(Mt19937_64X8);
+
+impl RngCore for Mt19937_64X8 {
+    // The low half of the output, as casting the output of `std::mt19937_64` does
+    #[allow(clippy::cast_possible_truncation)]
+    #[inline(always)]
+    fn next_u32(&mut self) -> u32 {
+        self.next_u64() as u32
+    }
+
+    #[inline(always)]
+    fn next_u64(&mut self) -> u64 {
+        let [word] = self.buffer.next_words::<1>(twist);
+        temper_word(word)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        impls::fill_bytes_via_next(self, dest);
+    }
+}
+
+fn twist(mt: &mut [u64; NN]) {
+    crate::mt19937_64::twist::<8>(mt, |mt, i, source| {
+        let current = u64x8::from_slice(&mt[i..i + 8]);
+        let next = u64x8::from_slice(&mt[i + 1..i + 9]);
+        let source = u64x8::from_slice(&mt[source..source + 8]);
+
+        let y = (current & u64x8::splat(UPPER_MASK)) | (next & u64x8::splat(LOWER_MASK));
+        // All ones where the low bit of `y` is set
+        let odd = u64x8::splat(0) - (y & u64x8::splat(1));
+        let x = source ^ (y >> 1) ^ (odd & u64x8::splat(MATRIX_A));
+
+        x.copy_to_slice(&mut mt[i..i + 8]);
+    });
+}
+
+#[inline(always)]
+fn temper(x: u64x8) -> u64x8 {
+    let x = x ^ ((x >> 29) & u64x8::splat(0x5555555555555555));
+    let x = x ^ ((x << 17) & u64x8::splat(0x71d67fffeda60000));
+    let x = x ^ ((x << 37) & u64x8::splat(0xfff7eee000000000));
+    x ^ (x >> 43)
+}
@@ -0,0 +1,191 @@
+use core::{
+    ops::{Deref, DerefMut},
+    simd::{simd_swizzle, u64x8},
+};
+
+use rand_core::SeedableRng;
+
+use super::{SimdRandX8, mul_hi};
+use crate::philox::{
+    PHILOX_M2X64, PHILOX_W64, ROUNDS, counter_words, counter2x64_from_u128, counter2x64_to_u128, seed_state,
+    words_to_blocks2x64,
+};
+
+#[derive(Clone, Default)]
+pub struct Philox2x64X8Seed([u8; 24]);
+
+impl Philox2x64X8Seed {
+    #[must_use]
+    pub const fn new(seed: [u8; 24]) -> Self {
+        Self(seed)
+    }
+}
+
+impl From<[u8; 24]> for Philox2x64X8Seed {
+    fn from(val: [u8; 24]) -> Self {
+        Self::new(val)
+    }
+}
+
+impl From<&[u8]> for Philox2x64X8Seed {
+    fn from(val: &[u8]) -> Self {
+        assert_eq!(val.len(), 24);
+        let mut seed = [0u8; 24];
+        seed.copy_from_slice(val);
+        Self::new(seed)
+    }
+}
+
+impl Deref for Philox2x64X8Seed {
+    type Target = [u8; 24];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl DerefMut for Philox2x64X8Seed {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl AsRef<[u8]> for Philox2x64X8Seed {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsMut<[u8]> for Philox2x64X8Seed {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+/// Philox2x64-10 producing 8 words per call from blocks `counter..counter + 4` of a single key.
+///
+/// Since the blocks are consecutive, the output is the stream of [`crate::scalar::Philox2x64`] whatever the backend or
+/// vector width, and the counter gives random access into it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Philox2x64X8 {
+    key: u64,
+    counter: u128,
+}
+
+impl Philox2x64X8 {
+    /// Creates a generator starting at block `counter`, the same stream as [`crate::scalar::Philox2x64::new`].
+    #[must_use]
+    pub const fn new(key: u64, counter: [u64; 2]) -> Self {
+        Self {
+            key,
+            counter: counter2x64_to_u128(counter),
+        }
+    }
+
+    /// Returns blocks `counter..counter + 4` for `key`, evaluated together in one vector pass.
+    #[must_use]
+    pub fn blocks(key: u64, counter: [u64; 2]) -> [[u64; 2]; 4] {
+        words_to_blocks2x64(Self::new(key, counter).next_u64x8().to_array())
+    }
+
+    /// Returns the key.
+    #[must_use]
+    pub const fn key(&self) -> u64 {
+        self.key
+    }
+
+    /// Returns the counter of the block the next output starts at.
+    #[must_use]
+    pub const fn counter(&self) -> [u64; 2] {
+        counter2x64_from_u128(self.counter)
+    }
+
+    /// Moves the stream to block `counter`, every block can be reached directly.
+    pub const fn set_counter(&mut self, counter: [u64; 2]) {
+        self.counter = counter2x64_to_u128(counter);
+    }
+
+    /// Returns the generator state as the key word followed by the low and high counter words.
+    ///
+    /// Pass it to [`Philox2x64X8::from_state`] to resume the exact same stream later.
+    #[must_use]
+    pub const fn state(&self) -> [u64; 3] {
+        let [counter_lo, counter_hi] = counter2x64_from_u128(self.counter);
+
+        [self.key, counter_lo, counter_hi]
+    }
+
+    /// Restores a generator from a snapshot taken with [`Philox2x64X8::state`].
+    #[must_use]
+    pub const fn from_state(state: [u64; 3]) -> Self {
+        let [key, counter_lo, counter_hi] = state;
+
+        Self {
+            key,
+            counter: counter2x64_to_u128([counter_lo, counter_hi]),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+crate::serde::impl_state_serde!(Philox2x64X8, 3);
+
+impl SeedableRng for Philox2x64X8 {
+    type Seed = Philox2x64X8Seed;
+
+    /// Reads the key word followed by the low and high counter words.
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self::from_state(seed_state(&seed[..]))
+    }
+}
+
+impl SimdRandX8 for Philox2x64X8 {
+    fn next_u64x8(&mut self) -> u64x8 {
+        // Lane pairs hold the `x0` and `x1` words of one block
+        let mut x = u64x8::from_array(counter_words::<4, 8>(self.counter));
+        self.counter = self.counter.wrapping_add(4);
+
+        let multiplier = u64x8::splat(PHILOX_M2X64);
+        let mut key = u64x8::splat(self.key);
+        for round in 0..ROUNDS {
+            if round > 0 {
+                key += u64x8::splat(PHILOX_W64);
+            }
+
+            let lo = x * multiplier;
+            let hi = mul_hi(x, multiplier);
+            // [hi(x0) ^ x1 ^ key, lo(x0)] for every block
+            x = simd_swizzle!(
+                hi ^ simd_swizzle!(x, [1, 0, 3, 2, 5, 4, 7, 6]) ^ key,
+                lo,
+                [0, 8, 2, 10, 4, 12, 6, 14]
+            );
+        }
+
+        x
+    }
+}
+
+super::simdrand::impl_simd_rng_x8!(Philox2x64X8);
+
+#[cfg(test)]
+mod tests {
+    use rand_core::RngCore;
+
+    use super::{Philox2x64X8, SimdRandX8};
+    use crate::scalar::Philox2x64;
+
+    #[test]
+    fn matches_scalar_stream() {
+        // Starts a few blocks before the low counter word wraps
+        let key = 0xa4093822299f31d0;
+        let counter = [u64::MAX - 2, 0x13198a2e03707344];
+        let mut rng = Philox2x64X8::new(key, counter);
+        let mut reference = Philox2x64::new(key, counter);
+
+        for _ in 0..64 {
+            let expected: [u64; 8] = core::array::from_fn(|_| reference.next_u64());
+            assert_eq!(rng.next_u64x8().to_array(), expected);
+        }
+        assert_eq!(rng.counter(), reference.counter());
+    }
+}
@@ -0,0 +1,61 @@
+use core::simd::Simd;
+
+use crate::romutrio::MULTIPLIER;
+
+use super::lanes::{LaneGenerator, impl_lanes};
+use super::rotate_left;
+
+/// RomuTrio with `LANES` independent lanes, each the stream of [`RomuTrio`](crate::scalar::RomuTrio) from its own state.
+///
+/// The step is a multiply, two subtractions and two rotations, all of which map directly to vector instructions.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RomuTrio<const LANES: usize> {
+    x: Simd<u64, LANES>,
+    y: Simd<u64, LANES>,
+    z: Simd<u64, LANES>,
+}
+
+impl<const LANES: usize> LaneGenerator<LANES, 3, 3> for RomuTrio<LANES> {
+    #[inline(always)]
+    fn vectors(&self) -> [Simd<u64, LANES>; 3] {
+        [self.x, self.y, self.z]
+    }
+
+    #[inline(always)]
+    fn from_vectors(vectors: [Simd<u64, LANES>; 3]) -> Self {
+        let [x, y, z] = vectors;
+
+        Self { x, y, z }
+    }
+
+    /// Reads the state vectors straight from the seed.
+    #[inline(always)]
+    fn from_seed_vectors(seed: [Simd<u64, LANES>; 3]) -> Self {
+        Self::from_vectors(seed)
+    }
+
+    #[inline(always)]
+    fn next_vector(&mut self) -> Simd<u64, LANES> {
+        let (x, y, z) = (self.x, self.y, self.z);
+
+        self.x = z * Simd::splat(MULTIPLIER);
+        self.y = rotate_left(y - x, 12);
+        self.z = rotate_left(z - y, 44);
+
+        x
+    }
+}
+
+impl_lanes!(
+    RomuTrio,
+    scalar = crate::scalar::RomuTrio,
+    fields = 3,
+    seed_vectors = 3,
+    widths = [
+        RomuTrioX2(RomuTrioX2Seed),
+        RomuTrioX4(RomuTrioX4Seed),
+        RomuTrioX8(RomuTrioX8Seed),
+        RomuTrioX16(RomuTrioX16Seed),
+        RomuTrioX32(RomuTrioX32Seed),
+    ],
+);
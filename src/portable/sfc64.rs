@@ -0,0 +1,112 @@
+use core::simd::Simd;
+
+use crate::sfc64::{WARMUP_ROUNDS, lane_counters};
+
+use super::lanes::{LaneGenerator, impl_lanes};
+use super::rotate_left;
+
+/// SFC64 with `LANES` independent lanes, each the stream of [`Sfc64`](crate::scalar::Sfc64) from its own state.
+///
+/// Every lane has its own `counter` word, which guarantees a period of at least 2^64 per lane whatever the other
+/// words are. The counters start apart, lane `i` is stream `i` of [`Sfc64::with_stream`](crate::scalar::Sfc64::with_stream),
+/// so lanes seeded with the same word still run separate streams.
+/// The step is only additions, shifts, a rotation and a xor, all of which map directly to vector instructions.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Sfc64<const LANES: usize> {
+    a: Simd<u64, LANES>,
+    b: Simd<u64, LANES>,
+    c: Simd<u64, LANES>,
+    counter: Simd<u64, LANES>,
+}
+
+impl<const LANES: usize> Sfc64<LANES> {
+    /// Seeds lane `i` like [`Sfc64::with_stream`](crate::scalar::Sfc64::with_stream) with `seeds[i]` and stream
+    /// `stream * LANES + i`.
+    ///
+    /// `from_seed` is stream 0, other streams keep the lanes of generators seeded with the same words apart.
+    #[must_use]
+    pub fn with_stream(seeds: [u64; LANES], stream: u64) -> Self {
+        Self::seeded(Simd::from_array(seeds), Simd::from_array(lane_counters(stream)))
+    }
+
+    #[inline(always)]
+    fn seeded(seed: Simd<u64, LANES>, counter: Simd<u64, LANES>) -> Self {
+        let mut rng = Self {
+            a: seed,
+            b: seed,
+            c: seed,
+            counter,
+        };
+        for _ in 0..WARMUP_ROUNDS {
+            rng.next_vector();
+        }
+
+        rng
+    }
+}
+
+impl<const LANES: usize> LaneGenerator<LANES, 4, 1> for Sfc64<LANES> {
+    #[inline(always)]
+    fn vectors(&self) -> [Simd<u64, LANES>; 4] {
+        [self.a, self.b, self.c, self.counter]
+    }
+
+    #[inline(always)]
+    fn from_vectors(vectors: [Simd<u64, LANES>; 4]) -> Self {
+        let [a, b, c, counter] = vectors;
+
+        Self { a, b, c, counter }
+    }
+
+    /// Seeds lane `i` with word `i` of the seed, stream 0 of [`Sfc64::with_stream`](Self::with_stream).
+    #[inline(always)]
+    fn from_seed_vectors([seed]: [Simd<u64, LANES>; 1]) -> Self {
+        Self::seeded(seed, Simd::from_array(lane_counters(0)))
+    }
+
+    #[inline(always)]
+    fn next_vector(&mut self) -> Simd<u64, LANES> {
+        let tmp = self.a + self.b + self.counter;
+        self.counter += Simd::splat(1);
+        self.a = self.b ^ (self.b >> Simd::splat(11));
+        self.b = self.c + (self.c << Simd::splat(3));
+        self.c = rotate_left(self.c, 24) + tmp;
+
+        tmp
+    }
+}
+
+impl_lanes!(
+    Sfc64,
+    scalar = crate::scalar::Sfc64,
+    fields = 4,
+    seed_vectors = 1,
+    widths = [
+        Sfc64X2(Sfc64X2Seed),
+        Sfc64X4(Sfc64X4Seed),
+        Sfc64X8(Sfc64X8Seed),
+        Sfc64X16(Sfc64X16Seed),
+        Sfc64X32(Sfc64X32Seed),
+    ],
+);
+
+#[cfg(test)]
+mod tests {
+    use rand_core::RngCore;
+
+    use super::Sfc64X4;
+    use crate::portable::SimdRandX4;
+
+    #[test]
+    fn lanes_with_the_same_seed_diverge() {
+        let mut rng = Sfc64X4::with_stream([0x0123456789abcdef; 4], 3);
+        let mut lanes: [_; 4] =
+            core::array::from_fn(|lane| crate::scalar::Sfc64::with_stream(0x0123456789abcdef, 12 + lane as u64));
+
+        for _ in 0..64 {
+            let vector = rng.next_u64x4().to_array();
+            assert_eq!(vector, lanes.each_mut().map(RngCore::next_u64));
+            assert!((1..4).all(|lane| vector[lane] != vector[0]), "{vector:?}");
+        }
+    }
+}
@@ -1,41 +1,171 @@
-use core::simd::{f64x4, f64x8, u64x4, u64x8};
+use core::simd::{
+    Simd, f64x4, f64x8,
+    num::{SimdFloat, SimdUint},
+    u64x4, u64x8,
+};
+
+/// Conversion of random `u64` lanes into `f64` lanes in `[0, 1)`.
+///
+/// Selects the conversion of [`SimdRandX4::next_f64x4_with`] and [`SimdRandX8::next_f64x8_with`], both are whole
+/// vector operations for any lane count.
+pub trait F64Conversion {
+    fn to_f64<const LANES: usize>(v: Simd<u64, LANES>) -> Simd<f64, LANES>;
+}
+
+/// Vigna's recommended conversion: (x >> 11) * 2^-53 (<https://prng.di.unimi.it/>).
+///
+/// Every multiple of 2^-53 in `[0, 1)`, the same values as the `specific` backends and the default of
+/// [`SimdRandX4::next_f64x4`]/[`SimdRandX8::next_f64x8`]. Where there is no vector instruction for the unsigned
+/// 64-bit to float conversion, e.g. on x86 without AVX-512DQ, the same values are assembled from the bits.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Multiply;
+
+impl F64Conversion for Multiply {
+    #[allow(clippy::cast_precision_loss)]
+    #[inline(always)]
+    fn to_f64<const LANES: usize>(v: Simd<u64, LANES>) -> Simd<f64, LANES> {
+        if cfg!(any(target_feature = "avx512dq", target_arch = "aarch64")) {
+            // The top 53 bits convert exactly
+            (v >> Simd::splat(11)).cast::<f64>() * Simd::splat(1.0 / (1u64 << 53) as f64)
+        } else {
+            multiply_from_bits(v)
+        }
+    }
+}
+
+/// [`Multiply`] without a conversion, LLVM lowers the unsigned conversion lane by lane on AVX2.
+///
+/// Below the top bit are 52 bits, as the mantissa of a float in `[0.5, 1)` that is 0.5 + (x >> 11 mod 2^52) * 2^-53.
+/// With the top bit set that is the result, without it the result is 0.5 less, and subtracting 0.5 is exact.
+#[inline(always)]
+fn multiply_from_bits<const LANES: usize>(v: Simd<u64, LANES>) -> Simd<f64, LANES> {
+    let half = Simd::splat(0.5f64.to_bits());
+    let mantissa = (v >> Simd::splat(11)) & Simd::splat((1 << 52) - 1);
+    // All ones without the top bit, the bits of 0.5 or of 0.0
+    let subtrahend = ((v >> Simd::splat(63)) - Simd::splat(1)) & half;
+
+    Simd::<f64, LANES>::from_bits(mantissa | half) - Simd::<f64, LANES>::from_bits(subtrahend)
+}
+
+/// The top 52 bits as the mantissa of a float in `[1, 2)`, minus 1.0.
+///
+/// Every multiple of 2^-52 in `[0, 1)`, one bit less than [`Multiply`], in exchange for a shift, an `or` and a
+/// subtraction with no conversion at all.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ExponentBits;
+
+impl F64Conversion for ExponentBits {
+    #[inline(always)]
+    fn to_f64<const LANES: usize>(v: Simd<u64, LANES>) -> Simd<f64, LANES> {
+        // The exponent of 1.0, subtracting it is exact
+        let one = Simd::splat(1.0f64.to_bits());
+        Simd::<f64, LANES>::from_bits((v >> Simd::splat(12)) | one) - Simd::splat(1.0)
+    }
+}
 
 pub trait SimdRandX4 {
     fn next_u64x4(&mut self) -> u64x4;
 
-    // Vigna's recommended conversion: (x >> 11) * 2^-53 (https://prng.di.unimi.it/)
-    #[allow(clippy::cast_precision_loss)]
+    /// Floats in `[0, 1)` through the [`Multiply`] conversion.
     #[inline(always)]
     fn next_f64x4(&mut self) -> f64x4 {
-        let v = self.next_u64x4();
-
-        f64x4::from_array([
-            (v[0] >> 11) as f64 * (1.0 / (1u64 << 53) as f64),
-            (v[1] >> 11) as f64 * (1.0 / (1u64 << 53) as f64),
-            (v[2] >> 11) as f64 * (1.0 / (1u64 << 53) as f64),
-            (v[3] >> 11) as f64 * (1.0 / (1u64 << 53) as f64),
-        ])
+        self.next_f64x4_with::<Multiply>()
+    }
+
+    /// Floats in `[0, 1)` through the conversion `C`.
+    #[inline(always)]
+    fn next_f64x4_with<C: F64Conversion>(&mut self) -> f64x4 {
+        C::to_f64(self.next_u64x4())
     }
 }
 
 pub trait SimdRandX8 {
     fn next_u64x8(&mut self) -> u64x8;
 
-    // Vigna's recommended conversion: (x >> 11) * 2^-53 (https://prng.di.unimi.it/)
-    #[allow(clippy::cast_precision_loss)]
+    /// Floats in `[0, 1)` through the [`Multiply`] conversion.
     #[inline(always)]
     fn next_f64x8(&mut self) -> f64x8 {
-        let v = self.next_u64x8();
-
-        f64x8::from_array([
-            (v[0] >> 11) as f64 * (1.0 / (1u64 << 53) as f64),
-            (v[1] >> 11) as f64 * (1.0 / (1u64 << 53) as f64),
-            (v[2] >> 11) as f64 * (1.0 / (1u64 << 53) as f64),
-            (v[3] >> 11) as f64 * (1.0 / (1u64 << 53) as f64),
-            (v[4] >> 11) as f64 * (1.0 / (1u64 << 53) as f64),
-            (v[5] >> 11) as f64 * (1.0 / (1u64 << 53) as f64),
-            (v[6] >> 11) as f64 * (1.0 / (1u64 << 53) as f64),
-            (v[7] >> 11) as f64 * (1.0 / (1u64 << 53) as f64),
-        ])
+        self.next_f64x8_with::<Multiply>()
+    }
+
+    /// Floats in `[0, 1)` through the conversion `C`.
+    #[inline(always)]
+    fn next_f64x8_with<C: F64Conversion>(&mut self) -> f64x8 {
+        C::to_f64(self.next_u64x8())
+    }
+}
+
+/// Implements [`SimdRng`](crate::SimdRng) for a generator through its [`SimdRandX4`] implementation.
+macro_rules! impl_simd_rng_x4 {
+    (impl<$(const $param:ident: $kind:ty),*> $rng:ty) => {
+        impl<$(const $param: $kind),*> crate::SimdRng for $rng {
+            const LANES: usize = 4;
+
+            type U64Vec = core::simd::u64x4;
+            type F64Vec = core::simd::f64x4;
+
+            #[inline(always)]
+            fn next_u64_vec(&mut self) -> Self::U64Vec {
+                crate::portable::SimdRandX4::next_u64x4(self)
+            }
+
+            #[inline(always)]
+            fn next_f64_vec(&mut self) -> Self::F64Vec {
+                crate::portable::SimdRandX4::next_f64x4(self)
+            }
+        }
+    };
+    ($rng:ty) => {
+        crate::portable::impl_simd_rng_x4!(impl<> $rng);
+    };
+}
+
+pub(crate) use impl_simd_rng_x4;
+
+/// Implements [`SimdRng`](crate::SimdRng) for a generator through its [`SimdRandX8`] implementation.
+macro_rules! impl_simd_rng_x8 {
+    (impl<$(const $param:ident: $kind:ty),*> $rng:ty) => {
+        impl<$(const $param: $kind),*> crate::SimdRng for $rng {
+            const LANES: usize = 8;
+
+            type U64Vec = core::simd::u64x8;
+            type F64Vec = core::simd::f64x8;
+
+            #[inline(always)]
+            fn next_u64_vec(&mut self) -> Self::U64Vec {
+                crate::portable::SimdRandX8::next_u64x8(self)
+            }
+
+            #[inline(always)]
+            fn next_f64_vec(&mut self) -> Self::F64Vec {
+                crate::portable::SimdRandX8::next_f64x8(self)
+            }
+        }
+    };
+    ($rng:ty) => {
+        crate::portable::impl_simd_rng_x8!(impl<> $rng);
+    };
+}
+
+pub(crate) use impl_simd_rng_x8;
+
+#[cfg(test)]
+mod tests {
+    use core::simd::{num::SimdFloat, u64x8};
+
+    use rand_core::SeedableRng;
+
+    use super::{SimdRandX8, multiply_from_bits};
+    use crate::portable::Xoshiro256PlusX8;
+    use crate::simdrng::test_support::{F64_EDGES, reference_f64_bits};
+
+    #[test]
+    fn multiply_from_bits_matches_conversion() {
+        let mut rng = Xoshiro256PlusX8::seed_from_u64(42);
+
+        for v in (0..4096).map(|_| rng.next_u64x8()).chain(F64_EDGES.map(u64x8::splat)) {
+            let expected = v.to_array().map(reference_f64_bits);
+            assert_eq!(multiply_from_bits(v).to_bits().to_array(), expected);
+        }
     }
 }
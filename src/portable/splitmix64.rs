@@ -0,0 +1,102 @@
+use core::simd::Simd;
+
+use crate::splitmix64::{GAMMA, MUL_0, MUL_1, lane_states};
+
+use super::lanes::{LaneGenerator, impl_lanes};
+
+/// SplitMix64 with `LANES` independent lanes, each the stream of [`SplitMix64`](crate::scalar::SplitMix64) from its own state.
+///
+/// Seeded with [`SeedableRng::seed_from_u64`](rand_core::SeedableRng::seed_from_u64), the lanes start at the first
+/// `LANES` outputs of the scalar stream of the seed, and the output vectors are what the other generators'
+/// `seed_from_u64` fill their seeds with.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SplitMix64<const LANES: usize> {
+    x: Simd<u64, LANES>,
+}
+
+impl<const LANES: usize> LaneGenerator<LANES, 1, 1> for SplitMix64<LANES> {
+    #[inline(always)]
+    fn vectors(&self) -> [Simd<u64, LANES>; 1] {
+        [self.x]
+    }
+
+    #[inline(always)]
+    fn from_vectors(vectors: [Simd<u64, LANES>; 1]) -> Self {
+        let [x] = vectors;
+
+        Self { x }
+    }
+
+    /// Reads the `x` word of every lane.
+    #[inline(always)]
+    fn from_seed_vectors([x]: [Simd<u64, LANES>; 1]) -> Self {
+        Self { x }
+    }
+
+    /// Starts the lanes at the first `LANES` outputs of the scalar SplitMix64 stream of `seed`.
+    #[inline(always)]
+    fn from_u64(seed: u64) -> Self {
+        Self {
+            x: Simd::from_array(lane_states(seed)),
+        }
+    }
+
+    #[inline(always)]
+    fn next_vector(&mut self) -> Simd<u64, LANES> {
+        self.x += Simd::splat(GAMMA);
+
+        let z = self.x;
+        let z = (z ^ (z >> Simd::splat(30))) * Simd::splat(MUL_0);
+        let z = (z ^ (z >> Simd::splat(27))) * Simd::splat(MUL_1);
+        z ^ (z >> Simd::splat(31))
+    }
+}
+
+impl_lanes!(
+    SplitMix64,
+    scalar = crate::scalar::SplitMix64,
+    fields = 1,
+    seed_vectors = 1,
+    widths = [
+        SplitMix64X2(SplitMix64X2Seed),
+        SplitMix64X4(SplitMix64X4Seed),
+        SplitMix64X8(SplitMix64X8Seed),
+        SplitMix64X16(SplitMix64X16Seed),
+        SplitMix64X32(SplitMix64X32Seed),
+    ],
+);
+
+#[cfg(test)]
+mod tests {
+    use rand_core::SeedableRng;
+
+    use super::{SplitMix64X4, SplitMix64X8, SplitMix64X16};
+    use crate::{
+        SimdRng,
+        portable::{Xoroshiro128PlusPlusX8, Xoshiro256PlusPlusX4, Xoshiro512PlusPlusX16},
+    };
+
+    #[test]
+    fn seed_from_u64_of_other_generators_uses_output() {
+        let mut splitmix = SplitMix64X4::seed_from_u64(42);
+        let vectors: [[u64; 4]; 4] = core::array::from_fn(|_| splitmix.next_u64_vec().to_array());
+        assert_eq!(
+            Xoshiro256PlusPlusX4::seed_from_u64(42).state().as_slice(),
+            vectors.as_flattened()
+        );
+
+        let mut splitmix = SplitMix64X8::seed_from_u64(42);
+        let vectors: [[u64; 8]; 2] = core::array::from_fn(|_| splitmix.next_u64_vec().to_array());
+        assert_eq!(
+            Xoroshiro128PlusPlusX8::seed_from_u64(42).state().as_slice(),
+            vectors.as_flattened()
+        );
+
+        let mut splitmix = SplitMix64X16::seed_from_u64(42);
+        let vectors: [[u64; 16]; 8] = core::array::from_fn(|_| splitmix.next_u64_vec().to_array());
+        assert_eq!(
+            Xoshiro512PlusPlusX16::seed_from_u64(42).state().as_slice(),
+            vectors.as_flattened()
+        );
+    }
+}
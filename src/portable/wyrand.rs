@@ -0,0 +1,57 @@
+use core::simd::Simd;
+
+use crate::wyrand::{INCREMENT, XOR};
+
+use super::lanes::{LaneGenerator, impl_lanes};
+use super::mul_hi;
+
+/// WyRand with `LANES` independent lanes, each the stream of [`WyRand`](crate::scalar::WyRand) from its own state.
+///
+/// There is no 64x64 -> 128-bit vector multiply, so the high half of the product is composed from 32-bit partial
+/// products.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WyRand<const LANES: usize> {
+    s: Simd<u64, LANES>,
+}
+
+impl<const LANES: usize> LaneGenerator<LANES, 1, 1> for WyRand<LANES> {
+    #[inline(always)]
+    fn vectors(&self) -> [Simd<u64, LANES>; 1] {
+        [self.s]
+    }
+
+    #[inline(always)]
+    fn from_vectors(vectors: [Simd<u64, LANES>; 1]) -> Self {
+        let [s] = vectors;
+
+        Self { s }
+    }
+
+    /// Reads the state vectors straight from the seed.
+    #[inline(always)]
+    fn from_seed_vectors(seed: [Simd<u64, LANES>; 1]) -> Self {
+        Self::from_vectors(seed)
+    }
+
+    #[inline(always)]
+    fn next_vector(&mut self) -> Simd<u64, LANES> {
+        self.s += Simd::splat(INCREMENT);
+
+        let t = self.s ^ Simd::splat(XOR);
+        mul_hi(self.s, t) ^ (self.s * t)
+    }
+}
+
+impl_lanes!(
+    WyRand,
+    scalar = crate::scalar::WyRand,
+    fields = 1,
+    seed_vectors = 1,
+    widths = [
+        WyRandX2(WyRandX2Seed),
+        WyRandX4(WyRandX4Seed),
+        WyRandX8(WyRandX8Seed),
+        WyRandX16(WyRandX16Seed),
+        WyRandX32(WyRandX32Seed),
+    ],
+);
@@ -0,0 +1,134 @@
+use core::simd::Simd;
+
+use super::lanes::{LaneGenerator, impl_lanes};
+use super::rotate_left;
+
+const JUMP: [u64; 2] = [0xdf900294d8f554a5, 0x170865df4b3201fc];
+const LONG_JUMP: [u64; 2] = [0xd2a98b26625eee7b, 0xdddf9b1090aa7ac1];
+
+/// Xoroshiro128+ over `LANES` lanes, only 2 state vectors compared to 4 for the Xoshiro256 generators.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Xoroshiro128Plus<const LANES: usize> {
+    s0: Simd<u64, LANES>,
+    s1: Simd<u64, LANES>,
+}
+
+impl<const LANES: usize> Xoroshiro128Plus<LANES> {
+    /// Advances every lane by 2^64 steps, the same as `jump` on the scalar generator.
+    pub fn jump(&mut self) {
+        self.jump_by(JUMP);
+    }
+
+    /// Advances every lane by 2^96 steps, the same as `long_jump` on the scalar generator.
+    pub fn long_jump(&mut self) {
+        self.jump_by(LONG_JUMP);
+    }
+
+    fn jump_by(&mut self, polynomial: [u64; 2]) {
+        let mut s = [Simd::splat(0); 2];
+
+        for word in polynomial {
+            for bit in 0..64 {
+                if word & (1 << bit) != 0 {
+                    for (acc, word) in s.iter_mut().zip(self.vectors()) {
+                        *acc ^= word;
+                    }
+                }
+                self.next_vector();
+            }
+        }
+
+        *self = Self::from_vectors(s);
+    }
+}
+
+impl<const LANES: usize> LaneGenerator<LANES, 2, 2> for Xoroshiro128Plus<LANES> {
+    #[inline(always)]
+    fn vectors(&self) -> [Simd<u64, LANES>; 2] {
+        [self.s0, self.s1]
+    }
+
+    #[inline(always)]
+    fn from_vectors(vectors: [Simd<u64, LANES>; 2]) -> Self {
+        let [s0, s1] = vectors;
+
+        Self { s0, s1 }
+    }
+
+    /// Reads the state vectors straight from the seed.
+    #[inline(always)]
+    fn from_seed_vectors(seed: [Simd<u64, LANES>; 2]) -> Self {
+        Self::from_vectors(seed)
+    }
+
+    #[inline(always)]
+    fn next_vector(&mut self) -> Simd<u64, LANES> {
+        let result = self.s0 + self.s1;
+
+        self.s1 ^= self.s0;
+        self.s0 = rotate_left(self.s0, 24) ^ self.s1 ^ (self.s1 << Simd::splat(16));
+        self.s1 = rotate_left(self.s1, 37);
+
+        result
+    }
+}
+
+impl_lanes!(
+    Xoroshiro128Plus,
+    scalar = crate::scalar::Xoroshiro128Plus,
+    fields = 2,
+    seed_vectors = 2,
+    widths = [
+        Xoroshiro128PlusX2(Xoroshiro128PlusX2Seed),
+        Xoroshiro128PlusX4(Xoroshiro128PlusX4Seed),
+        Xoroshiro128PlusX8(Xoroshiro128PlusX8Seed),
+        Xoroshiro128PlusX16(Xoroshiro128PlusX16Seed),
+        Xoroshiro128PlusX32(Xoroshiro128PlusX32Seed),
+    ],
+);
+
+#[cfg(test)]
+mod tests {
+    use rand_core::{RngCore, SeedableRng};
+
+    use super::Xoroshiro128Plus;
+    use crate::SimdRng;
+
+    fn reference_lanes<const LANES: usize>(rng: &Xoroshiro128Plus<LANES>) -> [rand_xoshiro::Xoroshiro128Plus; LANES] {
+        core::array::from_fn(|lane| {
+            let mut seed = [0u8; 16];
+            for (chunk, word) in seed.chunks_exact_mut(8).zip(rng.lane(lane).state()) {
+                chunk.copy_from_slice(&word.to_le_bytes());
+            }
+            rand_xoshiro::Xoroshiro128Plus::from_seed(seed)
+        })
+    }
+
+    fn assert_jumps_match_scalar_reference<const LANES: usize>() {
+        let mut rng = Xoroshiro128Plus::<LANES>::from_lanes(core::array::from_fn(|lane| {
+            crate::scalar::Xoroshiro128Plus::from_state(core::array::from_fn(|word| (word * LANES + lane) as u64 + 1))
+        }));
+        let mut reference = reference_lanes(&rng);
+
+        rng.jump();
+        reference.iter_mut().for_each(rand_xoshiro::Xoroshiro128Plus::jump);
+        assert_eq!(
+            rng.next_u64_vec().to_array(),
+            reference.each_mut().map(RngCore::next_u64)
+        );
+
+        rng.long_jump();
+        reference.iter_mut().for_each(rand_xoshiro::Xoroshiro128Plus::long_jump);
+        assert_eq!(
+            rng.next_u64_vec().to_array(),
+            reference.each_mut().map(RngCore::next_u64)
+        );
+    }
+
+    #[test]
+    fn jumps_match_scalar_reference() {
+        assert_jumps_match_scalar_reference::<4>();
+        assert_jumps_match_scalar_reference::<8>();
+        assert_jumps_match_scalar_reference::<16>();
+    }
+}
@@ -0,0 +1,65 @@
+use core::simd::Simd;
+
+use super::lanes::{LaneGenerator, impl_lanes};
+use super::rotate_left;
+
+/// Xoshiro256+ over `LANES` lanes, each the stream of [`Xoshiro256Plus`](crate::scalar::Xoshiro256Plus) from its own state.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Xoshiro256Plus<const LANES: usize> {
+    s0: Simd<u64, LANES>,
+    s1: Simd<u64, LANES>,
+    s2: Simd<u64, LANES>,
+    s3: Simd<u64, LANES>,
+}
+
+impl<const LANES: usize> LaneGenerator<LANES, 4, 4> for Xoshiro256Plus<LANES> {
+    #[inline(always)]
+    fn vectors(&self) -> [Simd<u64, LANES>; 4] {
+        [self.s0, self.s1, self.s2, self.s3]
+    }
+
+    #[inline(always)]
+    fn from_vectors(vectors: [Simd<u64, LANES>; 4]) -> Self {
+        let [s0, s1, s2, s3] = vectors;
+
+        Self { s0, s1, s2, s3 }
+    }
+
+    /// Reads the state vectors straight from the seed.
+    #[inline(always)]
+    fn from_seed_vectors(seed: [Simd<u64, LANES>; 4]) -> Self {
+        Self::from_vectors(seed)
+    }
+
+    #[inline(always)]
+    fn next_vector(&mut self) -> Simd<u64, LANES> {
+        let result = self.s0 + self.s3;
+
+        let t = self.s1 << Simd::splat(17);
+
+        self.s2 ^= self.s0;
+        self.s3 ^= self.s1;
+        self.s1 ^= self.s2;
+        self.s0 ^= self.s3;
+
+        self.s2 ^= t;
+
+        self.s3 = rotate_left(self.s3, 45);
+
+        result
+    }
+}
+
+impl_lanes!(
+    Xoshiro256Plus,
+    scalar = crate::scalar::Xoshiro256Plus,
+    fields = 4,
+    seed_vectors = 4,
+    widths = [
+        Xoshiro256PlusX2(Xoshiro256PlusX2Seed),
+        Xoshiro256PlusX4(Xoshiro256PlusX4Seed),
+        Xoshiro256PlusX8(Xoshiro256PlusX8Seed),
+        Xoshiro256PlusX16(Xoshiro256PlusX16Seed),
+        Xoshiro256PlusX32(Xoshiro256PlusX32Seed),
+    ],
+);
@@ -0,0 +1,65 @@
+use core::simd::Simd;
+
+use super::lanes::{LaneGenerator, impl_lanes};
+use super::rotate_left;
+
+/// Xoshiro256++ over `LANES` lanes, each the stream of [`Xoshiro256PlusPlus`](crate::scalar::Xoshiro256PlusPlus) from its own state.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Xoshiro256PlusPlus<const LANES: usize> {
+    s0: Simd<u64, LANES>,
+    s1: Simd<u64, LANES>,
+    s2: Simd<u64, LANES>,
+    s3: Simd<u64, LANES>,
+}
+
+impl<const LANES: usize> LaneGenerator<LANES, 4, 4> for Xoshiro256PlusPlus<LANES> {
+    #[inline(always)]
+    fn vectors(&self) -> [Simd<u64, LANES>; 4] {
+        [self.s0, self.s1, self.s2, self.s3]
+    }
+
+    #[inline(always)]
+    fn from_vectors(vectors: [Simd<u64, LANES>; 4]) -> Self {
+        let [s0, s1, s2, s3] = vectors;
+
+        Self { s0, s1, s2, s3 }
+    }
+
+    /// Reads the state vectors straight from the seed.
+    #[inline(always)]
+    fn from_seed_vectors(seed: [Simd<u64, LANES>; 4]) -> Self {
+        Self::from_vectors(seed)
+    }
+
+    #[inline(always)]
+    fn next_vector(&mut self) -> Simd<u64, LANES> {
+        let result = rotate_left(self.s0 + self.s3, 23) + self.s0;
+
+        let t = self.s1 << Simd::splat(17);
+
+        self.s2 ^= self.s0;
+        self.s3 ^= self.s1;
+        self.s1 ^= self.s2;
+        self.s0 ^= self.s3;
+
+        self.s2 ^= t;
+
+        self.s3 = rotate_left(self.s3, 45);
+
+        result
+    }
+}
+
+impl_lanes!(
+    Xoshiro256PlusPlus,
+    scalar = crate::scalar::Xoshiro256PlusPlus,
+    fields = 4,
+    seed_vectors = 4,
+    widths = [
+        Xoshiro256PlusPlusX2(Xoshiro256PlusPlusX2Seed),
+        Xoshiro256PlusPlusX4(Xoshiro256PlusPlusX4Seed),
+        Xoshiro256PlusPlusX8(Xoshiro256PlusPlusX8Seed),
+        Xoshiro256PlusPlusX16(Xoshiro256PlusPlusX16Seed),
+        Xoshiro256PlusPlusX32(Xoshiro256PlusPlusX32Seed),
+    ],
+);
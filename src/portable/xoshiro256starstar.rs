@@ -0,0 +1,68 @@
+use core::simd::Simd;
+
+use super::lanes::{LaneGenerator, impl_lanes};
+use super::rotate_left;
+
+/// Xoshiro256** over `LANES` lanes, each the stream of [`Xoshiro256StarStar`](crate::scalar::Xoshiro256StarStar) from its own state.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Xoshiro256StarStar<const LANES: usize> {
+    s0: Simd<u64, LANES>,
+    s1: Simd<u64, LANES>,
+    s2: Simd<u64, LANES>,
+    s3: Simd<u64, LANES>,
+}
+
+impl<const LANES: usize> LaneGenerator<LANES, 4, 4> for Xoshiro256StarStar<LANES> {
+    #[inline(always)]
+    fn vectors(&self) -> [Simd<u64, LANES>; 4] {
+        [self.s0, self.s1, self.s2, self.s3]
+    }
+
+    #[inline(always)]
+    fn from_vectors(vectors: [Simd<u64, LANES>; 4]) -> Self {
+        let [s0, s1, s2, s3] = vectors;
+
+        Self { s0, s1, s2, s3 }
+    }
+
+    /// Reads the state vectors straight from the seed.
+    #[inline(always)]
+    fn from_seed_vectors(seed: [Simd<u64, LANES>; 4]) -> Self {
+        Self::from_vectors(seed)
+    }
+
+    #[inline(always)]
+    fn next_vector(&mut self) -> Simd<u64, LANES> {
+        // `s1 * 5` and `* 9` as shift-add, avoiding 64-bit multiplies
+        let s1_times_5 = (self.s1 << 2) + self.s1;
+        let rotated = rotate_left(s1_times_5, 7);
+        let result = (rotated << 3) + rotated;
+
+        let t = self.s1 << Simd::splat(17);
+
+        self.s2 ^= self.s0;
+        self.s3 ^= self.s1;
+        self.s1 ^= self.s2;
+        self.s0 ^= self.s3;
+
+        self.s2 ^= t;
+
+        self.s3 = rotate_left(self.s3, 45);
+
+        result
+    }
+}
+
+impl_lanes!(
+    Xoshiro256StarStar,
+    scalar = crate::scalar::Xoshiro256StarStar,
+    fields = 4,
+    seed_vectors = 4,
+    widths = [
+        Xoshiro256StarStarX2(Xoshiro256StarStarX2Seed),
+        Xoshiro256StarStarX4(Xoshiro256StarStarX4Seed),
+        Xoshiro256StarStarX8(Xoshiro256StarStarX8Seed),
+        Xoshiro256StarStarX16(Xoshiro256StarStarX16Seed),
+        Xoshiro256StarStarX32(Xoshiro256StarStarX32Seed),
+    ],
+);
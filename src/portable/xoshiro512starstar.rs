@@ -0,0 +1,168 @@
+use core::simd::Simd;
+
+use super::lanes::{LaneGenerator, impl_lanes};
+use super::rotate_left;
+
+const JUMP: [u64; 8] = [
+    0x33ed89b6e7a353f9,
+    0x760083d7955323be,
+    0x2837f2fbb5f22fae,
+    0x4b8c5674d309511c,
+    0xb11ac47a7ba28c25,
+    0xf1be7667092bcc1c,
+    0x53851efdb6df0aaf,
+    0x1ebbc8b23eaf25db,
+];
+const LONG_JUMP: [u64; 8] = [
+    0x11467fef8f921d28,
+    0xa2a819f2e79c8ea8,
+    0xa8299fc284b3959a,
+    0xb4d347340ca63ee1,
+    0x1cb0940bedbff6ce,
+    0xd956c5c4fa1f8e17,
+    0x915e38fd4eda93bc,
+    0x5b3ccdfa5d7daca5,
+];
+
+/// Xoshiro512** over `LANES` lanes, 8 state vectors for a 2^512 - 1 period per lane.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Xoshiro512StarStar<const LANES: usize> {
+    s: [Simd<u64, LANES>; 8],
+}
+
+impl<const LANES: usize> Xoshiro512StarStar<LANES> {
+    /// Advances every lane by 2^256 steps, the same as `jump` on the scalar generator.
+    pub fn jump(&mut self) {
+        self.jump_by(JUMP);
+    }
+
+    /// Advances every lane by 2^384 steps, the same as `long_jump` on the scalar generator.
+    pub fn long_jump(&mut self) {
+        self.jump_by(LONG_JUMP);
+    }
+
+    fn jump_by(&mut self, polynomial: [u64; 8]) {
+        let mut s = [Simd::splat(0); 8];
+
+        for word in polynomial {
+            for bit in 0..64 {
+                if word & (1 << bit) != 0 {
+                    for (acc, word) in s.iter_mut().zip(self.vectors()) {
+                        *acc ^= word;
+                    }
+                }
+                self.next_vector();
+            }
+        }
+
+        *self = Self::from_vectors(s);
+    }
+}
+
+impl<const LANES: usize> LaneGenerator<LANES, 8, 8> for Xoshiro512StarStar<LANES> {
+    #[inline(always)]
+    fn vectors(&self) -> [Simd<u64, LANES>; 8] {
+        self.s
+    }
+
+    #[inline(always)]
+    fn from_vectors(vectors: [Simd<u64, LANES>; 8]) -> Self {
+        Self { s: vectors }
+    }
+
+    /// Reads the state vectors straight from the seed.
+    #[inline(always)]
+    fn from_seed_vectors(seed: [Simd<u64, LANES>; 8]) -> Self {
+        Self::from_vectors(seed)
+    }
+
+    #[inline(always)]
+    fn next_vector(&mut self) -> Simd<u64, LANES> {
+        let s = &mut self.s;
+        // `s[1] * 5` and `* 9` as shift-add, avoiding 64-bit multiplies
+        let s1_times_5 = (s[1] << 2) + s[1];
+        let rotated = rotate_left(s1_times_5, 7);
+        let result = (rotated << 3) + rotated;
+
+        let t = s[1] << Simd::splat(11);
+
+        s[2] ^= s[0];
+        s[5] ^= s[1];
+        s[1] ^= s[2];
+        s[7] ^= s[3];
+        s[3] ^= s[4];
+        s[4] ^= s[5];
+        s[0] ^= s[6];
+        s[6] ^= s[7];
+
+        s[6] ^= t;
+
+        s[7] = rotate_left(s[7], 21);
+
+        result
+    }
+}
+
+impl_lanes!(
+    Xoshiro512StarStar,
+    scalar = crate::scalar::Xoshiro512StarStar,
+    fields = 8,
+    seed_vectors = 8,
+    widths = [
+        Xoshiro512StarStarX2(Xoshiro512StarStarX2Seed),
+        Xoshiro512StarStarX4(Xoshiro512StarStarX4Seed),
+        Xoshiro512StarStarX8(Xoshiro512StarStarX8Seed),
+        Xoshiro512StarStarX16(Xoshiro512StarStarX16Seed),
+        Xoshiro512StarStarX32(Xoshiro512StarStarX32Seed),
+    ],
+);
+
+#[cfg(test)]
+mod tests {
+    use rand_core::{RngCore, SeedableRng};
+
+    use super::Xoshiro512StarStar;
+    use crate::SimdRng;
+
+    fn reference_lanes<const LANES: usize>(
+        rng: &Xoshiro512StarStar<LANES>,
+    ) -> [rand_xoshiro::Xoshiro512StarStar; LANES] {
+        core::array::from_fn(|lane| {
+            let mut seed = [0u8; 64];
+            for (chunk, word) in seed.chunks_exact_mut(8).zip(rng.lane(lane).state()) {
+                chunk.copy_from_slice(&word.to_le_bytes());
+            }
+            rand_xoshiro::Xoshiro512StarStar::from_seed(rand_xoshiro::Seed512(seed))
+        })
+    }
+
+    fn assert_jumps_match_scalar_reference<const LANES: usize>() {
+        let mut rng = Xoshiro512StarStar::<LANES>::from_lanes(core::array::from_fn(|lane| {
+            crate::scalar::Xoshiro512StarStar::from_state(core::array::from_fn(|word| (word * LANES + lane) as u64 + 1))
+        }));
+        let mut reference = reference_lanes(&rng);
+
+        rng.jump();
+        reference.iter_mut().for_each(rand_xoshiro::Xoshiro512StarStar::jump);
+        assert_eq!(
+            rng.next_u64_vec().to_array(),
+            reference.each_mut().map(RngCore::next_u64)
+        );
+
+        rng.long_jump();
+        reference
+            .iter_mut()
+            .for_each(rand_xoshiro::Xoshiro512StarStar::long_jump);
+        assert_eq!(
+            rng.next_u64_vec().to_array(),
+            reference.each_mut().map(RngCore::next_u64)
+        );
+    }
+
+    #[test]
+    fn jumps_match_scalar_reference() {
+        assert_jumps_match_scalar_reference::<4>();
+        assert_jumps_match_scalar_reference::<8>();
+        assert_jumps_match_scalar_reference::<16>();
+    }
+}
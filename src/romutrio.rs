@@ -0,0 +1,2 @@
+/// Multiplier of the `x` word, 15241094284759029579 in the Romu paper.
+pub const MULTIPLIER: u64 = 0xd3833e804f4c574b;
@@ -0,0 +1,75 @@
+use rand_core::{RngCore, impls};
+
+use crate::biski64::advance_state;
+
+/// Scalar Biski64, producing the same output as `biski64::Biski64Rng`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Biski64 {
+    fast_loop: u64,
+    mix: u64,
+    loop_mix: u64,
+}
+
+impl Biski64 {
+    /// Returns the generator state as the `fast_loop`, `mix` and `loop_mix` words.
+    #[must_use]
+    pub const fn state(&self) -> [u64; 3] {
+        [self.fast_loop, self.mix, self.loop_mix]
+    }
+
+    /// Restores a generator from a snapshot taken with [`Biski64::state`].
+    #[must_use]
+    pub const fn from_state(state: [u64; 3]) -> Self {
+        let [fast_loop, mix, loop_mix] = state;
+
+        Self {
+            fast_loop,
+            mix,
+            loop_mix,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+crate::serde::impl_state_serde!(Biski64, 3);
+
+impl RngCore for Biski64 {
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let output = self.mix.wrapping_add(self.loop_mix);
+        advance_state(&mut self.fast_loop, &mut self.mix, &mut self.loop_mix);
+        output
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        impls::fill_bytes_via_next(self, dst);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand_core::RngCore;
+
+    use super::Biski64;
+    use crate::biski64::seed_state;
+
+    #[test]
+    fn reference() {
+        let mut rng = Biski64::from_state(seed_state(42));
+        let mut reference = biski64::Biski64Rng::from_seed_for_stream(42, 0, 1);
+
+        for _ in 0..1024 {
+            assert_eq!(rng.next_u64(), reference.next_u64());
+        }
+        assert_eq!(rng.next_u32(), reference.next_u32());
+
+        let mut bytes = [0u8; 37];
+        let mut reference_bytes = [0u8; 37];
+        rng.fill_bytes(&mut bytes);
+        reference.fill_bytes(&mut reference_bytes);
+        assert_eq!(bytes, reference_bytes);
+    }
+}
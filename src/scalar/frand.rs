@@ -0,0 +1,65 @@
+use rand_core::{RngCore, impls};
+
+const INCREMENT: u64 = 12964901029718341801;
+const MUL_XOR: u64 = 149988720821803190;
+const SHIFT: u32 = 32;
+
+/// Scalar Frand, producing the same `u64` output as `frand::Rand`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Frand {
+    seed: u64,
+}
+
+impl Frand {
+    /// Returns the generator state as its counter word.
+    #[must_use]
+    pub const fn state(&self) -> [u64; 1] {
+        [self.seed]
+    }
+
+    /// Restores a generator from a snapshot taken with [`Frand::state`].
+    #[must_use]
+    pub const fn from_state(state: [u64; 1]) -> Self {
+        let [seed] = state;
+
+        Self { seed }
+    }
+}
+
+#[cfg(feature = "serde")]
+crate::serde::impl_state_serde!(Frand, 1);
+
+impl RngCore for Frand {
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let value = self.seed.wrapping_add(INCREMENT);
+        self.seed = value;
+        let value = value.wrapping_mul(MUL_XOR ^ value);
+        value ^ (value >> SHIFT)
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        impls::fill_bytes_via_next(self, dst);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand_core::RngCore;
+
+    use super::Frand;
+    use crate::frand::test_support::upstream_state;
+
+    #[test]
+    fn reference() {
+        let mut rng = Frand::from_state([upstream_state(42)]);
+        let mut reference = ::frand::Rand::with_seed(42);
+
+        for _ in 0..1024 {
+            assert_eq!(rng.next_u64(), reference.r#gen::<u64>());
+        }
+    }
+}
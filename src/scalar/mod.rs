@@ -0,0 +1,44 @@
+//! Scalar (single lane) versions of the vectorized generators.
+//!
+//! These are what `lane(i)`/`into_lanes()` on the X4/X8 generators hand out, so that a single lane can be passed
+//! to scalar code paths and continue exactly where the vector generator left off.
+//! The output of each generator is bit-identical to its reference implementation
+//! (`rand_xoshiro`, `rand_pcg`, `biski64` and `frand` respectively).
+
+pub use biski64::*;
+pub use frand::*;
+pub use jsf64::*;
+pub use mt19937_64::*;
+pub use pcg64dxsm::*;
+pub use philox2x64::*;
+pub use philox4x32::*;
+pub use romutrio::*;
+pub use sfc64::*;
+pub use splitmix64::*;
+pub use wyrand::*;
+pub use xoroshiro128plus::*;
+pub use xoroshiro128plusplus::*;
+pub use xoshiro256plus::*;
+pub use xoshiro256plusplus::*;
+pub use xoshiro256starstar::*;
+pub use xoshiro512plusplus::*;
+pub use xoshiro512starstar::*;
+
+mod biski64;
+mod frand;
+mod jsf64;
+mod mt19937_64;
+mod pcg64dxsm;
+mod philox2x64;
+mod philox4x32;
+mod romutrio;
+mod sfc64;
+mod splitmix64;
+mod wyrand;
+mod xoroshiro128plus;
+mod xoroshiro128plusplus;
+mod xoshiro256plus;
+mod xoshiro256plusplus;
+mod xoshiro256starstar;
+mod xoshiro512plusplus;
+mod xoshiro512starstar;
@@ -0,0 +1,133 @@
+use core::fmt;
+
+use rand_core::{RngCore, SeedableRng, impls};
+
+use crate::mt19937_64::{Buffer, DEFAULT_SEED, STATE_WORDS, temper, twist_scalar};
+
+/// Scalar MT19937-64, producing the same output as the reference `genrand64_int64` and `std::mt19937_64`.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Mt19937_64 {
+    buffer: Buffer,
+}
+
+impl Mt19937_64 {
+    /// Seeds the generator like `init_genrand64(seed)` and `std::mt19937_64(seed)`.
+    #[must_use]
+    pub fn new(seed: u64) -> Self {
+        Self {
+            buffer: Buffer::new(seed),
+        }
+    }
+
+    /// Seeds the generator like `init_by_array64(key)`.
+    ///
+    /// # Panics
+    ///
+    /// If `key` is empty.
+    #[must_use]
+    pub fn from_key(key: &[u64]) -> Self {
+        assert!(!key.is_empty(), "The MT19937-64 key must not be empty");
+
+        Self {
+            buffer: Buffer::from_key(key),
+        }
+    }
+
+    /// Returns the generator state as the 312 words of the state array followed by the index of the next word,
+    /// the `mt` and `mti` of the reference implementation.
+    #[must_use]
+    pub fn state(&self) -> [u64; STATE_WORDS] {
+        self.buffer.state()
+    }
+
+    /// Restores a generator from a snapshot taken with [`Mt19937_64::state`].
+    ///
+    /// # Panics
+    ///
+    /// If the index in the snapshot is larger than 312.
+    #[must_use]
+    pub fn from_state(state: [u64; STATE_WORDS]) -> Self {
+        Self {
+            buffer: Buffer::from_state(&state),
+        }
+    }
+}
+
+impl Default for Mt19937_64 {
+    /// Seeds the generator with 5489, like a default constructed `std::mt19937_64`.
+    fn default() -> Self {
+        Self::new(DEFAULT_SEED)
+    }
+}
+
+impl fmt::Debug for Mt19937_64 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Mt19937_64")
+            .field("index", &self.buffer.index())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "serde")]
+crate::mt19937_64::impl_state_serde!(Mt19937_64);
+
+impl SeedableRng for Mt19937_64 {
+    type Seed = [u8; 8];
+
+    /// Seeds the generator with the little-endian `u64` in `seed`, see [`Mt19937_64::new`].
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self::new(u64::from_le_bytes(seed))
+    }
+
+    /// Seeds the generator with `state` directly, see [`Mt19937_64::new`].
+    fn seed_from_u64(state: u64) -> Self {
+        Self::new(state)
+    }
+}
+
+impl RngCore for Mt19937_64 {
+    // The low half of the output, as casting the output of `std::mt19937_64` does
+    #[allow(clippy::cast_possible_truncation)]
+    fn next_u32(&mut self) -> u32 {
+        self.next_u64() as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let [word] = self.buffer.next_words::<1>(twist_scalar);
+        temper(word)
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        impls::fill_bytes_via_next(self, dst);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand_core::RngCore;
+
+    use super::Mt19937_64;
+
+    #[test]
+    fn reference() {
+        // First outputs of the test program of the reference `mt19937-64.c`
+        let mut rng = Mt19937_64::from_key(&[0x12345, 0x23456, 0x34567, 0x45678]);
+
+        assert_eq!(rng.next_u64(), 7266447313870364031);
+        assert_eq!(rng.next_u64(), 4946485549665804864);
+        assert_eq!(rng.next_u64(), 16945909448695747420);
+        assert_eq!(rng.next_u64(), 16394063075524226720);
+        assert_eq!(rng.next_u64(), 4873882236456199058);
+    }
+
+    #[test]
+    fn default_seed() {
+        // The C++ standard requires this to be the 10000th output of a default constructed `std::mt19937_64`
+        let mut rng = Mt19937_64::default();
+        for _ in 0..9999 {
+            rng.next_u64();
+        }
+
+        assert_eq!(rng.next_u64(), 9981545732273789042);
+    }
+}
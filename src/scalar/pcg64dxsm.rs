@@ -0,0 +1,100 @@
+use rand_core::{RngCore, impls};
+
+use crate::pcg64::{from_words, initial_state, output_dxsm, step, to_words};
+
+/// Scalar PCG64-DXSM, producing the same output as `rand_pcg::Pcg64Dxsm`, and `PCG64DXSM` in `numpy.random`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Pcg64Dxsm {
+    state: u128,
+    increment: u128,
+}
+
+impl Pcg64Dxsm {
+    /// Creates a generator on stream `stream`, the same as `rand_pcg::Pcg64Dxsm::new`.
+    #[must_use]
+    pub const fn new(state: u128, stream: u128) -> Self {
+        // The increment must be odd, hence we discard one bit
+        let increment = (stream << 1) | 1;
+
+        Self {
+            state: initial_state(state, increment),
+            increment,
+        }
+    }
+
+    /// Returns the generator state as the `state_lo`, `state_hi`, `increment_lo` and `increment_hi` words.
+    #[must_use]
+    pub const fn state(&self) -> [u64; 4] {
+        to_words(self.state, self.increment)
+    }
+
+    /// Restores a generator from a snapshot taken with [`Pcg64Dxsm::state`].
+    #[must_use]
+    pub const fn from_state(state: [u64; 4]) -> Self {
+        let (state, increment) = from_words(state);
+
+        Self { state, increment }
+    }
+}
+
+#[cfg(feature = "serde")]
+crate::serde::impl_state_serde!(Pcg64Dxsm, 4);
+
+impl RngCore for Pcg64Dxsm {
+    // Use the lower bits, matching `rand_pcg`.
+    #[allow(clippy::cast_possible_truncation)]
+    fn next_u32(&mut self) -> u32 {
+        self.next_u64() as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let output = output_dxsm(self.state);
+        self.state = step(self.state, self.increment);
+        output
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        impls::fill_bytes_via_next(self, dst);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand_core::{RngCore, SeedableRng};
+
+    use super::Pcg64Dxsm;
+
+    #[test]
+    fn new_matches_reference() {
+        let state = 0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210;
+        let stream = 0xCAFE_F00D_D15E_A5E5_0BAD_5EED_DEAD_BEEF;
+        let mut rng = Pcg64Dxsm::new(state, stream);
+        let mut reference = rand_pcg::Pcg64Dxsm::new(state, stream);
+
+        for _ in 0..1024 {
+            assert_eq!(rng.next_u64(), reference.next_u64());
+        }
+        assert_eq!(rng.next_u32(), reference.next_u32());
+
+        let mut bytes = [0u8; 37];
+        let mut reference_bytes = [0u8; 37];
+        rng.fill_bytes(&mut bytes);
+        reference.fill_bytes(&mut reference_bytes);
+        assert_eq!(bytes, reference_bytes);
+    }
+
+    #[test]
+    fn from_state_matches_reference_seed() {
+        let words: [u64; 4] = core::array::from_fn(|index| index as u64 + 1);
+        let mut seed = [0u8; 32];
+        for (chunk, word) in seed.chunks_exact_mut(8).zip(words) {
+            chunk.copy_from_slice(&word.to_le_bytes());
+        }
+        let mut reference = rand_pcg::Pcg64Dxsm::from_seed(seed);
+        let mut rng = Pcg64Dxsm::from_state(crate::pcg64::seed_state::<1, 4>(&seed));
+
+        for _ in 0..1024 {
+            assert_eq!(rng.next_u64(), reference.next_u64());
+        }
+    }
+}
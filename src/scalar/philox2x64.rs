@@ -0,0 +1,147 @@
+use rand_core::{RngCore, impls};
+
+use crate::philox::{counter2x64_from_u128, counter2x64_to_u128, philox2x64};
+
+/// Scalar Philox2x64-10 stream, the output of block `counter` followed by block `counter + 1` and so on,
+/// block words `[x0, x1]` are output in order.
+///
+/// This is the same stream the `Philox2x64X4`/`Philox2x64X8` generators produce, whatever the vector width.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Philox2x64 {
+    key: u64,
+    counter: u128,
+    block: [u64; 2],
+    high: bool,
+}
+
+impl Philox2x64 {
+    /// Returns the Philox2x64-10 block for `key` and `counter`, the same as Random123's `philox2x64(ctr, key)`.
+    #[must_use]
+    pub const fn block(key: u64, counter: [u64; 2]) -> [u64; 2] {
+        philox2x64(key, counter)
+    }
+
+    /// Creates a stream starting at the first word of block `counter`.
+    #[must_use]
+    pub const fn new(key: u64, counter: [u64; 2]) -> Self {
+        let counter = counter2x64_to_u128(counter);
+
+        Self {
+            key,
+            counter,
+            block: block_words(key, counter),
+            high: false,
+        }
+    }
+
+    /// Returns the key, `key`.
+    #[must_use]
+    pub const fn key(&self) -> u64 {
+        self.key
+    }
+
+    /// Returns the counter of the block the next output is taken from, `[c0, c1]`.
+    #[must_use]
+    pub const fn counter(&self) -> [u64; 2] {
+        counter2x64_from_u128(self.counter)
+    }
+
+    /// Moves the stream to the first word of block `counter`.
+    pub const fn set_counter(&mut self, counter: [u64; 2]) {
+        *self = Self::new(self.key(), counter);
+    }
+
+    /// Returns the generator state as the key word, the low and high counter words and the position within the block.
+    #[must_use]
+    pub const fn state(&self) -> [u64; 4] {
+        let [counter_lo, counter_hi] = counter2x64_from_u128(self.counter);
+
+        [self.key, counter_lo, counter_hi, self.high as u64]
+    }
+
+    /// Restores a generator from a snapshot taken with [`Philox2x64::state`].
+    #[must_use]
+    pub const fn from_state(state: [u64; 4]) -> Self {
+        let [key, counter_lo, counter_hi, high] = state;
+        let counter = counter2x64_to_u128([counter_lo, counter_hi]);
+
+        Self {
+            key,
+            counter,
+            block: block_words(key, counter),
+            high: high != 0,
+        }
+    }
+}
+
+const fn block_words(key: u64, counter: u128) -> [u64; 2] {
+    philox2x64(key, counter2x64_from_u128(counter))
+}
+
+#[cfg(feature = "serde")]
+crate::serde::impl_state_serde!(Philox2x64, 4);
+
+impl RngCore for Philox2x64 {
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        if self.high {
+            let output = self.block[1];
+            self.counter = self.counter.wrapping_add(1);
+            self.block = block_words(self.key, self.counter);
+            self.high = false;
+            output
+        } else {
+            self.high = true;
+            self.block[0]
+        }
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        impls::fill_bytes_via_next(self, dst);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand_core::RngCore;
+
+    use super::Philox2x64;
+
+    const KEY: u64 = 0xa4093822299f31d0;
+    const COUNTER: [u64; 2] = [0x243f6a8885a308d3, 0x13198a2e03707344];
+
+    #[test]
+    fn stream_is_consecutive_blocks() {
+        let mut rng = Philox2x64::new(KEY, COUNTER);
+
+        for offset in 0..64 {
+            let [x0, x1] = Philox2x64::block(KEY, [COUNTER[0] + offset, COUNTER[1]]);
+            assert_eq!(rng.next_u64(), x0);
+            assert_eq!(rng.next_u64(), x1);
+        }
+    }
+
+    #[test]
+    fn counter_carries_into_next_word() {
+        let mut rng = Philox2x64::new(KEY, [u64::MAX, 0]);
+        rng.next_u64();
+        rng.next_u64();
+
+        assert_eq!(rng.counter(), [0, 1]);
+    }
+
+    #[test]
+    fn state_roundtrip_within_block() {
+        let mut rng = Philox2x64::new(KEY, COUNTER);
+        rng.next_u64();
+        let mut restored = Philox2x64::from_state(rng.state());
+
+        assert_eq!(restored, rng);
+        for _ in 0..5 {
+            assert_eq!(restored.next_u64(), rng.next_u64());
+        }
+    }
+}
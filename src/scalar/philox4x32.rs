@@ -0,0 +1,151 @@
+use rand_core::{RngCore, impls};
+
+use crate::philox::{
+    block4x32_to_words, counter2x64_from_u128, counter2x64_to_u128, counter4x32_from_u128, counter4x32_to_u128,
+    key4x32_from_word, key4x32_to_word, philox4x32,
+};
+
+/// Scalar Philox4x32-10 stream, the output of block `counter` followed by block `counter + 1` and so on,
+/// block words `[x0, x1, x2, x3]` are output as the two words `x0 | x1 << 32` and `x2 | x3 << 32`.
+///
+/// This is the same stream the `Philox4x32X4`/`Philox4x32X8` generators produce, whatever the vector width.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Philox4x32 {
+    key: u64,
+    counter: u128,
+    block: [u64; 2],
+    high: bool,
+}
+
+impl Philox4x32 {
+    /// Returns the Philox4x32-10 block for `key` and `counter`, the same as Random123's `philox4x32(ctr, key)`.
+    #[must_use]
+    pub const fn block(key: [u32; 2], counter: [u32; 4]) -> [u32; 4] {
+        philox4x32(key, counter)
+    }
+
+    /// Creates a stream starting at the first word of block `counter`.
+    #[must_use]
+    pub const fn new(key: [u32; 2], counter: [u32; 4]) -> Self {
+        let key = key4x32_to_word(key);
+        let counter = counter4x32_to_u128(counter);
+
+        Self {
+            key,
+            counter,
+            block: block_words(key, counter),
+            high: false,
+        }
+    }
+
+    /// Returns the key, `[k0, k1]`.
+    #[must_use]
+    pub const fn key(&self) -> [u32; 2] {
+        key4x32_from_word(self.key)
+    }
+
+    /// Returns the counter of the block the next output is taken from, `[c0, c1, c2, c3]`.
+    #[must_use]
+    pub const fn counter(&self) -> [u32; 4] {
+        counter4x32_from_u128(self.counter)
+    }
+
+    /// Moves the stream to the first word of block `counter`.
+    pub const fn set_counter(&mut self, counter: [u32; 4]) {
+        *self = Self::new(self.key(), counter);
+    }
+
+    /// Returns the generator state as the key word, the low and high counter words and the position within the block.
+    #[must_use]
+    pub const fn state(&self) -> [u64; 4] {
+        let [counter_lo, counter_hi] = counter2x64_from_u128(self.counter);
+
+        [self.key, counter_lo, counter_hi, self.high as u64]
+    }
+
+    /// Restores a generator from a snapshot taken with [`Philox4x32::state`].
+    #[must_use]
+    pub const fn from_state(state: [u64; 4]) -> Self {
+        let [key, counter_lo, counter_hi, high] = state;
+        let counter = counter2x64_to_u128([counter_lo, counter_hi]);
+
+        Self {
+            key,
+            counter,
+            block: block_words(key, counter),
+            high: high != 0,
+        }
+    }
+}
+
+const fn block_words(key: u64, counter: u128) -> [u64; 2] {
+    block4x32_to_words(philox4x32(key4x32_from_word(key), counter4x32_from_u128(counter)))
+}
+
+#[cfg(feature = "serde")]
+crate::serde::impl_state_serde!(Philox4x32, 4);
+
+impl RngCore for Philox4x32 {
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        if self.high {
+            let output = self.block[1];
+            self.counter = self.counter.wrapping_add(1);
+            self.block = block_words(self.key, self.counter);
+            self.high = false;
+            output
+        } else {
+            self.high = true;
+            self.block[0]
+        }
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        impls::fill_bytes_via_next(self, dst);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand_core::RngCore;
+
+    use super::Philox4x32;
+
+    const KEY: [u32; 2] = [0xa4093822, 0x299f31d0];
+    const COUNTER: [u32; 4] = [0x243f6a88, 0x85a308d3, 0x13198a2e, 0x03707344];
+
+    #[test]
+    fn stream_is_consecutive_blocks() {
+        let mut rng = Philox4x32::new(KEY, COUNTER);
+
+        for offset in 0..64 {
+            let [x0, x1, x2, x3] = Philox4x32::block(KEY, [COUNTER[0] + offset, COUNTER[1], COUNTER[2], COUNTER[3]]);
+            assert_eq!(rng.next_u64(), u64::from(x0) | u64::from(x1) << 32);
+            assert_eq!(rng.next_u64(), u64::from(x2) | u64::from(x3) << 32);
+        }
+    }
+
+    #[test]
+    fn counter_carries_into_next_word() {
+        let mut rng = Philox4x32::new(KEY, [u32::MAX, u32::MAX, u32::MAX, 0]);
+        rng.next_u64();
+        rng.next_u64();
+
+        assert_eq!(rng.counter(), [0, 0, 0, 1]);
+    }
+
+    #[test]
+    fn state_roundtrip_within_block() {
+        let mut rng = Philox4x32::new(KEY, COUNTER);
+        rng.next_u64();
+        let mut restored = Philox4x32::from_state(rng.state());
+
+        assert_eq!(restored, rng);
+        for _ in 0..5 {
+            assert_eq!(restored.next_u64(), rng.next_u64());
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use rand_core::{RngCore, SeedableRng, impls};
+
+use crate::romutrio::MULTIPLIER;
+use crate::splitmix64::expand_seed;
+
+/// Scalar RomuTrio, the three word generator from Mark Overton's Romu family.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RomuTrio {
+    s: [u64; 3],
+}
+
+impl RomuTrio {
+    /// Returns the generator state as the `x`, `y` and `z` words.
+    #[must_use]
+    pub const fn state(&self) -> [u64; 3] {
+        self.s
+    }
+
+    /// Restores a generator from a snapshot taken with [`RomuTrio::state`].
+    #[must_use]
+    pub const fn from_state(state: [u64; 3]) -> Self {
+        Self { s: state }
+    }
+}
+
+#[cfg(feature = "serde")]
+crate::serde::impl_state_serde!(RomuTrio, 3);
+
+impl SeedableRng for RomuTrio {
+    type Seed = [u8; 24];
+
+    /// Reads the `x`, `y` and `z` words from `seed` as little-endian.
+    fn from_seed(seed: Self::Seed) -> Self {
+        let (chunks, _) = seed.as_chunks::<8>();
+
+        Self::from_state(core::array::from_fn(|index| u64::from_le_bytes(chunks[index])))
+    }
+
+    /// Expands `state` with SplitMix64, which gives lane 0 of every vector RomuTrio seeded with the same `state`.
+    fn seed_from_u64(state: u64) -> Self {
+        Self::from_seed(expand_seed::<1, 24>(state))
+    }
+}
+
+impl RngCore for RomuTrio {
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let [x, y, z] = self.s;
+
+        self.s = [
+            z.wrapping_mul(MULTIPLIER),
+            y.wrapping_sub(x).rotate_left(12),
+            z.wrapping_sub(y).rotate_left(44),
+        ];
+
+        x
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        impls::fill_bytes_via_next(self, dst);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand_core::{RngCore, SeedableRng};
+
+    use super::RomuTrio;
+
+    #[test]
+    fn reference() {
+        // `romuTrio_random` returns the previous `x`, worked through by hand from (1, 2, 3)
+        let mut rng = RomuTrio::from_state([1, 2, 3]);
+
+        assert_eq!(rng.next_u64(), 1);
+        assert_eq!(rng.state(), [0x7a89bb80ede505e1, 1 << 12, 1 << 44]);
+        assert_eq!(rng.next_u64(), 0x7a89bb80ede505e1);
+        assert_eq!(rng.next_u64(), 0xc574b00000000000);
+    }
+
+    #[test]
+    fn seed_from_u64_reads_splitmix64() {
+        let mut stream = rand_xoshiro::SplitMix64::seed_from_u64(42);
+        let mut lane = rand_xoshiro::SplitMix64::seed_from_u64(stream.next_u64());
+
+        assert_eq!(
+            RomuTrio::seed_from_u64(42).state(),
+            core::array::from_fn(|_| lane.next_u64())
+        );
+    }
+}
@@ -0,0 +1,69 @@
+use rand_core::{RngCore, impls};
+
+use crate::splitmix64::{GAMMA, next};
+
+/// Scalar SplitMix64, producing the same output as `rand_xoshiro::SplitMix64`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SplitMix64 {
+    x: u64,
+}
+
+impl SplitMix64 {
+    /// Returns the generator state, the single `x` word.
+    #[must_use]
+    pub const fn state(&self) -> [u64; 1] {
+        [self.x]
+    }
+
+    /// Restores a generator from a snapshot taken with [`SplitMix64::state`].
+    #[must_use]
+    pub const fn from_state(state: [u64; 1]) -> Self {
+        Self { x: state[0] }
+    }
+}
+
+#[cfg(feature = "serde")]
+crate::serde::impl_state_serde!(SplitMix64, 1);
+
+impl RngCore for SplitMix64 {
+    fn next_u32(&mut self) -> u32 {
+        // `rand_xoshiro` uses the cheaper "Mix4" finalizer from `dsiutils` for 32-bit output
+        self.x = self.x.wrapping_add(GAMMA);
+        let z = self.x;
+        let z = (z ^ (z >> 33)).wrapping_mul(0x62A9D9ED799705F5);
+        let z = (z ^ (z >> 28)).wrapping_mul(0xCB24D0A5C88C35B3);
+        (z >> 32) as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        next(&mut self.x)
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        impls::fill_bytes_via_next(self, dst);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand_core::{RngCore, SeedableRng};
+
+    use super::SplitMix64;
+
+    #[test]
+    fn reference() {
+        let mut rng = SplitMix64::from_state([1477776061723855037]);
+        let mut reference = rand_xoshiro::SplitMix64::seed_from_u64(1477776061723855037);
+
+        for _ in 0..1024 {
+            assert_eq!(rng.next_u64(), reference.next_u64());
+        }
+        assert_eq!(rng.next_u32(), reference.next_u32());
+
+        let mut bytes = [0u8; 37];
+        let mut reference_bytes = [0u8; 37];
+        rng.fill_bytes(&mut bytes);
+        reference.fill_bytes(&mut reference_bytes);
+        assert_eq!(bytes, reference_bytes);
+    }
+}
@@ -0,0 +1,62 @@
+use rand_core::{RngCore, impls};
+
+use crate::wyrand::{INCREMENT, XOR};
+
+/// Scalar WyRand, the `wyrand` generator from wyhash that `fastrand` and `nanorand` build on.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WyRand {
+    s: u64,
+}
+
+impl WyRand {
+    /// Returns the generator state, the single `s` word.
+    #[must_use]
+    pub const fn state(&self) -> [u64; 1] {
+        [self.s]
+    }
+
+    /// Restores a generator from a snapshot taken with [`WyRand::state`].
+    #[must_use]
+    pub const fn from_state(state: [u64; 1]) -> Self {
+        Self { s: state[0] }
+    }
+}
+
+#[cfg(feature = "serde")]
+crate::serde::impl_state_serde!(WyRand, 1);
+
+impl RngCore for WyRand {
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    // Folding the 128-bit product into its halves is the point
+    #[allow(clippy::cast_possible_truncation)]
+    fn next_u64(&mut self) -> u64 {
+        self.s = self.s.wrapping_add(INCREMENT);
+
+        let t = u128::from(self.s) * u128::from(self.s ^ XOR);
+        (t >> 64) as u64 ^ t as u64
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        impls::fill_bytes_via_next(self, dst);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand_core::RngCore;
+
+    use super::WyRand;
+
+    #[test]
+    fn reference() {
+        // `wyrand` from a zero seed: s += wyp0, then the halves of s * (s ^ wyp1) xored together
+        let mut rng = WyRand::from_state([0]);
+
+        assert_eq!(rng.next_u64(), 0x111cb3a78f59a58e);
+        assert_eq!(rng.next_u64(), 0xceabd938ff4e856d);
+        assert_eq!(rng.state(), [0xa0761d6478bd642f_u64.wrapping_mul(2)]);
+    }
+}
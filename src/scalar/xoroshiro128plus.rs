@@ -0,0 +1,126 @@
+use rand_core::{RngCore, impls};
+
+const JUMP: [u64; 2] = [0xdf900294d8f554a5, 0x170865df4b3201fc];
+const LONG_JUMP: [u64; 2] = [0xd2a98b26625eee7b, 0xdddf9b1090aa7ac1];
+
+/// Scalar Xoroshiro128+, producing the same output as `rand_xoshiro::Xoroshiro128Plus`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Xoroshiro128Plus {
+    s0: u64,
+    s1: u64,
+}
+
+impl Xoroshiro128Plus {
+    /// Returns the generator state as the `s0` and `s1` words.
+    #[must_use]
+    pub const fn state(&self) -> [u64; 2] {
+        [self.s0, self.s1]
+    }
+
+    /// Restores a generator from a snapshot taken with [`Xoroshiro128Plus::state`].
+    #[must_use]
+    pub const fn from_state(state: [u64; 2]) -> Self {
+        let [s0, s1] = state;
+
+        Self { s0, s1 }
+    }
+
+    /// Advances the generator by 2^64 steps, see `rand_xoshiro::Xoroshiro128Plus::jump`.
+    pub fn jump(&mut self) {
+        self.jump_by(JUMP);
+    }
+
+    /// Advances the generator by 2^96 steps, see `rand_xoshiro::Xoroshiro128Plus::long_jump`.
+    pub fn long_jump(&mut self) {
+        self.jump_by(LONG_JUMP);
+    }
+
+    fn jump_by(&mut self, polynomial: [u64; 2]) {
+        let mut s0 = 0;
+        let mut s1 = 0;
+
+        for word in polynomial {
+            for bit in 0..64 {
+                if word & (1 << bit) != 0 {
+                    s0 ^= self.s0;
+                    s1 ^= self.s1;
+                }
+                self.next_u64();
+            }
+        }
+
+        self.s0 = s0;
+        self.s1 = s1;
+    }
+}
+
+#[cfg(feature = "serde")]
+crate::serde::impl_state_serde!(Xoroshiro128Plus, 2);
+
+impl RngCore for Xoroshiro128Plus {
+    fn next_u32(&mut self) -> u32 {
+        // The two lowest bits have linear dependencies, so use the upper bits as `rand_xoshiro` does.
+        (self.next_u64() >> 32) as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let result = self.s0.wrapping_add(self.s1);
+
+        self.s1 ^= self.s0;
+        self.s0 = self.s0.rotate_left(24) ^ self.s1 ^ (self.s1 << 16);
+        self.s1 = self.s1.rotate_left(37);
+
+        result
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        impls::fill_bytes_via_next(self, dst);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand_core::{RngCore, SeedableRng};
+
+    use super::Xoroshiro128Plus;
+
+    fn reference(state: [u64; 2]) -> rand_xoshiro::Xoroshiro128Plus {
+        let mut seed = [0u8; 16];
+        for (chunk, word) in seed.chunks_exact_mut(8).zip(state) {
+            chunk.copy_from_slice(&word.to_le_bytes());
+        }
+
+        rand_xoshiro::Xoroshiro128Plus::from_seed(seed)
+    }
+
+    #[test]
+    fn matches_reference() {
+        let mut rng = Xoroshiro128Plus::from_state([1, 2]);
+        let mut reference = reference([1, 2]);
+
+        for _ in 0..1024 {
+            assert_eq!(rng.next_u64(), reference.next_u64());
+        }
+        assert_eq!(rng.next_u32(), reference.next_u32());
+
+        let mut bytes = [0u8; 37];
+        let mut reference_bytes = [0u8; 37];
+        rng.fill_bytes(&mut bytes);
+        reference.fill_bytes(&mut reference_bytes);
+        assert_eq!(bytes, reference_bytes);
+    }
+
+    #[test]
+    fn jumps_match_reference() {
+        let mut rng = Xoroshiro128Plus::from_state([1, 2]);
+        let mut reference = reference([1, 2]);
+
+        rng.jump();
+        reference.jump();
+        assert_eq!(rng.next_u64(), reference.next_u64());
+
+        rng.long_jump();
+        reference.long_jump();
+        assert_eq!(rng.next_u64(), reference.next_u64());
+    }
+}
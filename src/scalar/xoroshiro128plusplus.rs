@@ -0,0 +1,127 @@
+use rand_core::{RngCore, impls};
+
+const JUMP: [u64; 2] = [0x2bd7a6a6e99c2ddc, 0x0992ccaf6a6fca05];
+const LONG_JUMP: [u64; 2] = [0x360fd5f2cf8d5d99, 0x9c6e6877736c46e3];
+
+/// Scalar Xoroshiro128++, producing the same output as `rand_xoshiro::Xoroshiro128PlusPlus`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Xoroshiro128PlusPlus {
+    s0: u64,
+    s1: u64,
+}
+
+impl Xoroshiro128PlusPlus {
+    /// Returns the generator state as the `s0` and `s1` words.
+    #[must_use]
+    pub const fn state(&self) -> [u64; 2] {
+        [self.s0, self.s1]
+    }
+
+    /// Restores a generator from a snapshot taken with [`Xoroshiro128PlusPlus::state`].
+    #[must_use]
+    pub const fn from_state(state: [u64; 2]) -> Self {
+        let [s0, s1] = state;
+
+        Self { s0, s1 }
+    }
+
+    /// Advances the generator by 2^64 steps, see `rand_xoshiro::Xoroshiro128PlusPlus::jump`.
+    pub fn jump(&mut self) {
+        self.jump_by(JUMP);
+    }
+
+    /// Advances the generator by 2^96 steps, see `rand_xoshiro::Xoroshiro128PlusPlus::long_jump`.
+    pub fn long_jump(&mut self) {
+        self.jump_by(LONG_JUMP);
+    }
+
+    fn jump_by(&mut self, polynomial: [u64; 2]) {
+        let mut s0 = 0;
+        let mut s1 = 0;
+
+        for word in polynomial {
+            for bit in 0..64 {
+                if word & (1 << bit) != 0 {
+                    s0 ^= self.s0;
+                    s1 ^= self.s1;
+                }
+                self.next_u64();
+            }
+        }
+
+        self.s0 = s0;
+        self.s1 = s1;
+    }
+}
+
+#[cfg(feature = "serde")]
+crate::serde::impl_state_serde!(Xoroshiro128PlusPlus, 2);
+
+impl RngCore for Xoroshiro128PlusPlus {
+    #[allow(clippy::cast_possible_truncation)]
+    fn next_u32(&mut self) -> u32 {
+        // Take the low bits, matching `rand_xoshiro`.
+        self.next_u64() as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let result = self.s0.wrapping_add(self.s1).rotate_left(17).wrapping_add(self.s0);
+
+        self.s1 ^= self.s0;
+        self.s0 = self.s0.rotate_left(49) ^ self.s1 ^ (self.s1 << 21);
+        self.s1 = self.s1.rotate_left(28);
+
+        result
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        impls::fill_bytes_via_next(self, dst);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand_core::{RngCore, SeedableRng};
+
+    use super::Xoroshiro128PlusPlus;
+
+    fn reference(state: [u64; 2]) -> rand_xoshiro::Xoroshiro128PlusPlus {
+        let mut seed = [0u8; 16];
+        for (chunk, word) in seed.chunks_exact_mut(8).zip(state) {
+            chunk.copy_from_slice(&word.to_le_bytes());
+        }
+
+        rand_xoshiro::Xoroshiro128PlusPlus::from_seed(seed)
+    }
+
+    #[test]
+    fn matches_reference() {
+        let mut rng = Xoroshiro128PlusPlus::from_state([1, 2]);
+        let mut reference = reference([1, 2]);
+
+        for _ in 0..1024 {
+            assert_eq!(rng.next_u64(), reference.next_u64());
+        }
+        assert_eq!(rng.next_u32(), reference.next_u32());
+
+        let mut bytes = [0u8; 37];
+        let mut reference_bytes = [0u8; 37];
+        rng.fill_bytes(&mut bytes);
+        reference.fill_bytes(&mut reference_bytes);
+        assert_eq!(bytes, reference_bytes);
+    }
+
+    #[test]
+    fn jumps_match_reference() {
+        let mut rng = Xoroshiro128PlusPlus::from_state([1, 2]);
+        let mut reference = reference([1, 2]);
+
+        rng.jump();
+        reference.jump();
+        assert_eq!(rng.next_u64(), reference.next_u64());
+
+        rng.long_jump();
+        reference.long_jump();
+        assert_eq!(rng.next_u64(), reference.next_u64());
+    }
+}
@@ -0,0 +1,83 @@
+use rand_core::{RngCore, impls};
+
+/// Scalar Xoshiro256++, producing the same output as `rand_xoshiro::Xoshiro256PlusPlus`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Xoshiro256PlusPlus {
+    s: [u64; 4],
+}
+
+impl Xoshiro256PlusPlus {
+    /// Returns the generator state as the `s0`..`s3` words.
+    #[must_use]
+    pub const fn state(&self) -> [u64; 4] {
+        self.s
+    }
+
+    /// Restores a generator from a snapshot taken with [`Xoshiro256PlusPlus::state`].
+    #[must_use]
+    pub const fn from_state(state: [u64; 4]) -> Self {
+        Self { s: state }
+    }
+}
+
+#[cfg(feature = "serde")]
+crate::serde::impl_state_serde!(Xoshiro256PlusPlus, 4);
+
+impl RngCore for Xoshiro256PlusPlus {
+    fn next_u32(&mut self) -> u32 {
+        // The lowest bits have linear dependencies, so use the upper bits as `rand_xoshiro` does.
+        (self.next_u64() >> 32) as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let [s0, s1, s2, s3] = &mut self.s;
+        let result = s0.wrapping_add(*s3).rotate_left(23).wrapping_add(*s0);
+
+        let t = *s1 << 17;
+
+        *s2 ^= *s0;
+        *s3 ^= *s1;
+        *s1 ^= *s2;
+        *s0 ^= *s3;
+
+        *s2 ^= t;
+
+        *s3 = s3.rotate_left(45);
+
+        result
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        impls::fill_bytes_via_next(self, dst);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand_core::{RngCore, SeedableRng};
+
+    use super::Xoshiro256PlusPlus;
+
+    #[test]
+    fn reference() {
+        let state = [1, 2, 3, 4];
+        let mut rng = Xoshiro256PlusPlus::from_state(state);
+
+        let mut seed = [0u8; 32];
+        for (chunk, word) in seed.chunks_exact_mut(8).zip(state) {
+            chunk.copy_from_slice(&word.to_le_bytes());
+        }
+        let mut reference = rand_xoshiro::Xoshiro256PlusPlus::from_seed(seed);
+
+        for _ in 0..1024 {
+            assert_eq!(rng.next_u64(), reference.next_u64());
+        }
+        assert_eq!(rng.next_u32(), reference.next_u32());
+
+        let mut bytes = [0u8; 37];
+        let mut reference_bytes = [0u8; 37];
+        rng.fill_bytes(&mut bytes);
+        reference.fill_bytes(&mut reference_bytes);
+        assert_eq!(bytes, reference_bytes);
+    }
+}
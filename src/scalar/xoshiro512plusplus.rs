@@ -0,0 +1,154 @@
+use rand_core::{RngCore, impls};
+
+const JUMP: [u64; 8] = [
+    0x33ed89b6e7a353f9,
+    0x760083d7955323be,
+    0x2837f2fbb5f22fae,
+    0x4b8c5674d309511c,
+    0xb11ac47a7ba28c25,
+    0xf1be7667092bcc1c,
+    0x53851efdb6df0aaf,
+    0x1ebbc8b23eaf25db,
+];
+const LONG_JUMP: [u64; 8] = [
+    0x11467fef8f921d28,
+    0xa2a819f2e79c8ea8,
+    0xa8299fc284b3959a,
+    0xb4d347340ca63ee1,
+    0x1cb0940bedbff6ce,
+    0xd956c5c4fa1f8e17,
+    0x915e38fd4eda93bc,
+    0x5b3ccdfa5d7daca5,
+];
+
+/// Scalar Xoshiro512++, producing the same output as `rand_xoshiro::Xoshiro512PlusPlus`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Xoshiro512PlusPlus {
+    s: [u64; 8],
+}
+
+impl Xoshiro512PlusPlus {
+    /// Returns the generator state as the `s[0]`..`s[7]` words.
+    #[must_use]
+    pub const fn state(&self) -> [u64; 8] {
+        self.s
+    }
+
+    /// Restores a generator from a snapshot taken with [`Xoshiro512PlusPlus::state`].
+    #[must_use]
+    pub const fn from_state(state: [u64; 8]) -> Self {
+        Self { s: state }
+    }
+
+    /// Advances the generator by 2^256 steps, see `rand_xoshiro::Xoshiro512PlusPlus::jump`.
+    pub fn jump(&mut self) {
+        self.jump_by(JUMP);
+    }
+
+    /// Advances the generator by 2^384 steps, see `rand_xoshiro::Xoshiro512PlusPlus::long_jump`.
+    pub fn long_jump(&mut self) {
+        self.jump_by(LONG_JUMP);
+    }
+
+    fn jump_by(&mut self, polynomial: [u64; 8]) {
+        let mut s = [0; 8];
+
+        for word in polynomial {
+            for bit in 0..64 {
+                if word & (1 << bit) != 0 {
+                    for (acc, word) in s.iter_mut().zip(self.s) {
+                        *acc ^= word;
+                    }
+                }
+                self.next_u64();
+            }
+        }
+
+        self.s = s;
+    }
+}
+
+#[cfg(feature = "serde")]
+crate::serde::impl_state_serde!(Xoshiro512PlusPlus, 8);
+
+impl RngCore for Xoshiro512PlusPlus {
+    fn next_u32(&mut self) -> u32 {
+        // Use the upper bits, matching `rand_xoshiro`.
+        (self.next_u64() >> 32) as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let s = &mut self.s;
+        let result = s[2].wrapping_add(s[0]).rotate_left(17).wrapping_add(s[2]);
+
+        let t = s[1] << 11;
+
+        s[2] ^= s[0];
+        s[5] ^= s[1];
+        s[1] ^= s[2];
+        s[7] ^= s[3];
+        s[3] ^= s[4];
+        s[4] ^= s[5];
+        s[0] ^= s[6];
+        s[6] ^= s[7];
+
+        s[6] ^= t;
+
+        s[7] = s[7].rotate_left(21);
+
+        result
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        impls::fill_bytes_via_next(self, dst);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand_core::{RngCore, SeedableRng};
+
+    use super::Xoshiro512PlusPlus;
+
+    fn reference(state: [u64; 8]) -> rand_xoshiro::Xoshiro512PlusPlus {
+        let mut seed = [0u8; 64];
+        for (chunk, word) in seed.chunks_exact_mut(8).zip(state) {
+            chunk.copy_from_slice(&word.to_le_bytes());
+        }
+
+        rand_xoshiro::Xoshiro512PlusPlus::from_seed(rand_xoshiro::Seed512(seed))
+    }
+
+    #[test]
+    fn matches_reference() {
+        let state = core::array::from_fn(|index| index as u64 + 1);
+        let mut rng = Xoshiro512PlusPlus::from_state(state);
+        let mut reference = reference(state);
+
+        for _ in 0..1024 {
+            assert_eq!(rng.next_u64(), reference.next_u64());
+        }
+        assert_eq!(rng.next_u32(), reference.next_u32());
+
+        let mut bytes = [0u8; 37];
+        let mut reference_bytes = [0u8; 37];
+        rng.fill_bytes(&mut bytes);
+        reference.fill_bytes(&mut reference_bytes);
+        assert_eq!(bytes, reference_bytes);
+    }
+
+    #[test]
+    fn jumps_match_reference() {
+        let state = core::array::from_fn(|index| index as u64 + 1);
+        let mut rng = Xoshiro512PlusPlus::from_state(state);
+        let mut reference = reference(state);
+
+        rng.jump();
+        reference.jump();
+        assert_eq!(rng.next_u64(), reference.next_u64());
+
+        rng.long_jump();
+        reference.long_jump();
+        assert_eq!(rng.next_u64(), reference.next_u64());
+    }
+}
@@ -0,0 +1,61 @@
+use core::{fmt, marker::PhantomData};
+
+use serde::{
+    de::{Error, SeqAccess, Visitor},
+    ser::SerializeTuple,
+};
+
+// serde only implements arrays up to 32 elements, generator state can be larger so it goes through tuples
+pub fn serialize_state<S: serde::Serializer, const WORDS: usize>(
+    state: &[u64; WORDS],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut tuple = serializer.serialize_tuple(WORDS)?;
+    for word in state {
+        tuple.serialize_element(word)?;
+    }
+    tuple.end()
+}
+
+pub fn deserialize_state<'de, D: serde::Deserializer<'de>, const WORDS: usize>(
+    deserializer: D,
+) -> Result<[u64; WORDS], D::Error> {
+    struct StateVisitor<const WORDS: usize>(PhantomData<[u64; WORDS]>);
+
+    impl<'de, const WORDS: usize> Visitor<'de> for StateVisitor<WORDS> {
+        type Value = [u64; WORDS];
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            write!(formatter, "an array of {WORDS} u64 state words")
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            let mut state = [0; WORDS];
+            for (index, word) in state.iter_mut().enumerate() {
+                *word = seq.next_element()?.ok_or_else(|| Error::invalid_length(index, &self))?;
+            }
+            Ok(state)
+        }
+    }
+
+    deserializer.deserialize_tuple(WORDS, StateVisitor::<WORDS>(PhantomData))
+}
+
+/// Implements `Serialize`/`Deserialize` through the generator's `state`/`from_state` pair.
+macro_rules! impl_state_serde {
+    ($rng:ty, $words:expr) => {
+        impl serde::Serialize for $rng {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                crate::serde::serialize_state::<S, { $words }>(&self.state(), serializer)
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $rng {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                crate::serde::deserialize_state::<D, { $words }>(deserializer).map(Self::from_state)
+            }
+        }
+    };
+}
+
+pub(crate) use impl_state_serde;
@@ -0,0 +1,14 @@
+/// Outputs discarded after seeding, as PractRand's `sfc64::seed` does.
+pub const WARMUP_ROUNDS: usize = 12;
+
+/// Counter scalar stream `stream` starts from, stream 0 starts at 1 like PractRand's `sfc64::seed`.
+#[must_use]
+pub const fn stream_counter(stream: u64) -> u64 {
+    stream.wrapping_add(1)
+}
+
+/// Counters the lanes of vector stream `stream` start from, lane `i` runs scalar stream `stream * LANES + i`.
+#[must_use]
+pub fn lane_counters<const LANES: usize>(stream: u64) -> [u64; LANES] {
+    core::array::from_fn(|lane| stream_counter(stream.wrapping_mul(LANES as u64).wrapping_add(lane as u64)))
+}
@@ -0,0 +1,64 @@
+use core::fmt::Debug;
+
+/// A generator producing vectors of `LANES` random numbers, whatever the backend.
+///
+/// Every vectorized generator implements it next to its backend specific trait ([`SimdRandX4`], [`SimdRandX8`],
+/// `specific::avx2::SimdRand` or `specific::avx512::SimdRand`), so a kernel can be written once and run with any of
+/// them. The vector types are `core::simd` vectors for the `portable` generators and the aligned array wrappers of
+/// the `specific` backends, all of which convert from and into arrays (and the raw intrinsic types) with `From`.
+///
+/// ```rust
+/// use simd_rand::SimdRng;
+///
+/// /// Estimates pi from the share of random points in the unit square that fall inside the quarter circle.
+/// fn estimate_pi<R: SimdRng>(rng: &mut R, rounds: usize) -> f64 {
+///     let mut inside = 0;
+///     for _ in 0..rounds {
+///         let (x, y) = (rng.next_f64_vec(), rng.next_f64_vec());
+///         inside += x.as_ref().iter().zip(y.as_ref()).filter(|&(x, y)| x * x + y * y <= 1.0).count();
+///     }
+///
+///     4.0 * inside as f64 / (rounds * R::LANES) as f64
+/// }
+/// # #[cfg(feature = "portable")]
+/// # {
+/// # use rand_core::SeedableRng;
+/// # let pi = estimate_pi(&mut simd_rand::portable::Xoshiro256PlusX8::seed_from_u64(42), 100_000);
+/// # assert!((pi - core::f64::consts::PI).abs() < 0.01);
+/// # }
+/// ```
+///
+/// [`SimdRandX4`]: crate::portable::SimdRandX4
+/// [`SimdRandX8`]: crate::portable::SimdRandX8
+pub trait SimdRng {
+    /// Number of 64-bit lanes in the vectors.
+    const LANES: usize;
+
+    /// Vector of `LANES` random `u64`s.
+    type U64Vec: Copy + Default + Debug + AsRef<[u64]> + AsMut<[u64]>;
+    /// Vector of `LANES` random `f64`s in `[0, 1)`.
+    type F64Vec: Copy + Default + Debug + AsRef<[f64]> + AsMut<[f64]>;
+
+    fn next_u64_vec(&mut self) -> Self::U64Vec;
+
+    fn next_f64_vec(&mut self) -> Self::F64Vec;
+}
+
+#[cfg(all(
+    test,
+    any(
+        feature = "portable",
+        all(feature = "specific", target_arch = "x86_64", target_feature = "avx2")
+    )
+))]
+pub mod test_support {
+    /// Words at the edges of the `f64` conversion: both ends of `[0, 1)`, and either side of the top bit, which the
+    /// conversions without a `u64` to `f64` instruction handle apart.
+    pub const F64_EDGES: [u64; 6] = [0, 1 << 11, (1 << 63) - 1, 1 << 63, (1 << 63) | (1 << 11), u64::MAX];
+
+    /// The bits of (x >> 11) * 2^-53, which every backend matches.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn reference_f64_bits(x: u64) -> u64 {
+        ((x >> 11) as f64 * (1.0 / (1u64 << 53) as f64)).to_bits()
+    }
+}
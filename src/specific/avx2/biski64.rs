@@ -1,14 +1,16 @@
 use core::{
     arch::x86_64::*,
-    mem,
+    fmt, mem,
     ops::{Deref, DerefMut},
 };
 
 use rand_core::{RngCore, SeedableRng, TryRngCore};
 
 use crate::biski64::{FAST_LOOP_INCREMENT, seed_from_bytes, seed_state, seed_stream_states};
+use crate::scalar::Biski64;
+use crate::state::{lane_state, lanes_to_state};
 
-use super::{rotate_left, simdrand::*};
+use super::{lanes, rotate_left, simdrand::*, state_to_vectors, vectors_to_state};
 
 #[derive(Clone, Default)]
 pub struct Biski64X4Seed([u8; 32]);
@@ -61,6 +63,7 @@ impl AsMut<[u8]> for Biski64X4Seed {
     }
 }
 
+#[derive(Clone)]
 #[repr(align(32))]
 pub struct Biski64X4 {
     fast_loop: __m256i,
@@ -68,6 +71,87 @@ pub struct Biski64X4 {
     loop_mix: __m256i,
 }
 
+impl Biski64X4 {
+    /// Returns the generator state as `fast_loop`, `mix` and `loop_mix` lane words, one vector after the other.
+    ///
+    /// Pass it to [`Biski64X4::from_state`] to resume the exact same stream later.
+    #[must_use]
+    pub fn state(&self) -> [u64; 12] {
+        vectors_to_state([self.fast_loop, self.mix, self.loop_mix])
+    }
+
+    /// Restores a generator from a snapshot taken with [`Biski64X4::state`].
+    #[must_use]
+    pub fn from_state(state: [u64; 12]) -> Self {
+        let [fast_loop, mix, loop_mix] = state_to_vectors(&state);
+
+        Self {
+            fast_loop,
+            mix,
+            loop_mix,
+        }
+    }
+
+    /// Returns a scalar generator positioned at the same point in the stream of lane `lane`.
+    ///
+    /// # Panics
+    ///
+    /// If `lane` is not less than 4.
+    #[must_use]
+    pub fn lane(&self, lane: usize) -> Biski64 {
+        Biski64::from_state(lane_state::<4, 12, 3>(&self.state(), lane))
+    }
+
+    /// Splits the generator into one scalar generator per lane, each continuing its lane's stream.
+    #[must_use]
+    pub fn into_lanes(self) -> [Biski64; 4] {
+        let state = self.state();
+
+        core::array::from_fn(|lane| Biski64::from_state(lane_state::<4, 12, 3>(&state, lane)))
+    }
+
+    /// Assembles a generator from scalar generators, lane `i` continues the stream of `lanes[i]`.
+    #[must_use]
+    pub fn from_lanes(lanes: [Biski64; 4]) -> Self {
+        Self::from_state(lanes_to_state::<4, 12, 3>(&lanes.map(|lane| lane.state())))
+    }
+}
+
+impl fmt::Debug for Biski64X4 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Biski64X4")
+            .field("fast_loop", &lanes(self.fast_loop))
+            .field("mix", &lanes(self.mix))
+            .field("loop_mix", &lanes(self.loop_mix))
+            .finish()
+    }
+}
+
+impl PartialEq for Biski64X4 {
+    fn eq(&self, other: &Self) -> bool {
+        self.state() == other.state()
+    }
+}
+
+impl Eq for Biski64X4 {}
+
+#[cfg(feature = "serde")]
+crate::serde::impl_state_serde!(Biski64X4, 12);
+
+#[cfg(feature = "portable")]
+impl From<crate::portable::Biski64X4> for Biski64X4 {
+    fn from(rng: crate::portable::Biski64X4) -> Self {
+        Self::from_state(rng.state())
+    }
+}
+
+#[cfg(feature = "portable")]
+impl From<Biski64X4> for crate::portable::Biski64X4 {
+    fn from(rng: Biski64X4) -> Self {
+        Self::from_state(rng.state())
+    }
+}
+
 impl SeedableRng for Biski64X4 {
     type Seed = Biski64X4Seed;
 
@@ -133,6 +217,8 @@ impl SimdRand for Biski64X4 {
     }
 }
 
+super::simdrand::impl_simd_rng!(Biski64X4);
+
 #[inline(always)]
 fn pack_u64x4(values: [u64; 4]) -> __m256i {
     unsafe {
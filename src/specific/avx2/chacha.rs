@@ -0,0 +1,287 @@
+use core::{arch::x86_64::*, fmt};
+
+use rand_core::{CryptoRng, RngCore, SeedableRng};
+
+use super::simdrand::*;
+use crate::chacha::{BLOCK_WORDS, BlockBuffer, counter_lanes, input_words};
+
+// One block per 32-bit lane
+const BLOCKS: usize = 8;
+const BUFFER_WORDS: usize = BLOCKS * BLOCK_WORDS;
+
+/// ChaCha with `ROUNDS` rounds, computing 8 blocks per refill of its output buffer.
+///
+/// The output is the same as `rand_chacha`'s generators for the same seed, stream and word position,
+/// through [`SimdRand`] as well as [`RngCore`], so vectors of 4 words continue the stream of [`RngCore::next_u64`].
+#[derive(Clone, PartialEq, Eq)]
+pub struct ChaChaX4<const ROUNDS: usize> {
+    buffer: BlockBuffer<BUFFER_WORDS>,
+}
+
+/// ChaCha with 8 rounds, the stream of `rand_chacha::ChaCha8Rng`.
+pub type ChaCha8X4 = ChaChaX4<8>;
+/// ChaCha with 12 rounds, the stream of `rand_chacha::ChaCha12Rng`.
+pub type ChaCha12X4 = ChaChaX4<12>;
+/// ChaCha with 20 rounds, the stream of `rand_chacha::ChaCha20Rng`.
+pub type ChaCha20X4 = ChaChaX4<20>;
+
+impl<const ROUNDS: usize> ChaChaX4<ROUNDS> {
+    /// Returns the seed the generator was created from, its key.
+    #[must_use]
+    pub fn seed(&self) -> [u8; 32] {
+        self.buffer.seed()
+    }
+
+    /// Returns the stream, the 64-bit nonce.
+    #[must_use]
+    pub const fn stream(&self) -> u64 {
+        self.buffer.stream()
+    }
+
+    /// Switches to `stream`, continuing at the same word position.
+    pub fn set_stream(&mut self, stream: u64) {
+        self.buffer.set_stream(stream, blocks::<ROUNDS>);
+    }
+
+    /// Returns the position of the next 32-bit word in the stream.
+    #[must_use]
+    pub const fn word_pos(&self) -> u128 {
+        self.buffer.word_pos()
+    }
+
+    /// Moves to the 32-bit word at `word_pos`, which wraps at 2^68.
+    pub fn set_word_pos(&mut self, word_pos: u128) {
+        self.buffer.set_word_pos(word_pos, blocks::<ROUNDS>);
+    }
+
+    /// Returns the generator state as the key in four words, followed by the stream and the low and high
+    /// word position words.
+    ///
+    /// The buffered output is not part of the snapshot, [`ChaChaX4::from_state`] regenerates it.
+    #[must_use]
+    pub fn state(&self) -> [u64; 7] {
+        self.buffer.state()
+    }
+
+    /// Restores a generator from a snapshot taken with [`ChaChaX4::state`].
+    #[must_use]
+    pub fn from_state(state: [u64; 7]) -> Self {
+        Self {
+            buffer: BlockBuffer::from_state(state, blocks::<ROUNDS>),
+        }
+    }
+}
+
+impl<const ROUNDS: usize> fmt::Debug for ChaChaX4<ROUNDS> {
+    // The key is left out, it is the secret of a cryptographic generator
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ChaChaX4")
+            .field("rounds", &ROUNDS)
+            .field("stream", &self.stream())
+            .field("word_pos", &self.word_pos())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "serde")]
+crate::serde::impl_state_serde!(ChaCha8X4, 7);
+#[cfg(feature = "serde")]
+crate::serde::impl_state_serde!(ChaCha12X4, 7);
+#[cfg(feature = "serde")]
+crate::serde::impl_state_serde!(ChaCha20X4, 7);
+
+#[cfg(feature = "portable")]
+impl<const ROUNDS: usize> From<crate::portable::ChaChaX4<ROUNDS>> for ChaChaX4<ROUNDS> {
+    fn from(rng: crate::portable::ChaChaX4<ROUNDS>) -> Self {
+        Self::from_state(rng.state())
+    }
+}
+
+#[cfg(feature = "portable")]
+impl<const ROUNDS: usize> From<ChaChaX4<ROUNDS>> for crate::portable::ChaChaX4<ROUNDS> {
+    fn from(rng: ChaChaX4<ROUNDS>) -> Self {
+        Self::from_state(rng.state())
+    }
+}
+
+impl<const ROUNDS: usize> SeedableRng for ChaChaX4<ROUNDS> {
+    type Seed = [u8; 32];
+
+    /// Uses the seed as the key, starting at word 0 of stream 0.
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self {
+            buffer: BlockBuffer::new(seed),
+        }
+    }
+}
+
+impl<const ROUNDS: usize> SimdRand for ChaChaX4<ROUNDS> {
+    #[inline(always)]
+    fn next_m256i(&mut self) -> __m256i {
+        let words = self.buffer.next_words::<8>(blocks::<ROUNDS>);
+
+        // This intrinsic is specifically the unaligned load variant.
+        #[allow(clippy::cast_ptr_alignment)]
+        unsafe {
+            _mm256_loadu_si256(words.as_ptr().cast::<__m256i>())
+        }
+    }
+}
+
+super::simdrand::impl_simd_rng!(impl<const ROUNDS: usize> ChaChaX4<ROUNDS>);
+
+impl<const ROUNDS: usize> RngCore for ChaChaX4<ROUNDS> {
+    #[inline(always)]
+    fn next_u32(&mut self) -> u32 {
+        let [word] = self.buffer.next_words::<1>(blocks::<ROUNDS>);
+        word
+    }
+
+    #[inline(always)]
+    fn next_u64(&mut self) -> u64 {
+        let [lo, hi] = self.buffer.next_words::<2>(blocks::<ROUNDS>);
+        u64::from(lo) | u64::from(hi) << 32
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.buffer.fill_bytes(dest, blocks::<ROUNDS>);
+    }
+}
+
+impl<const ROUNDS: usize> CryptoRng for ChaChaX4<ROUNDS> {}
+
+/// Writes blocks `counter..counter + 8` into `out`, one after the other.
+#[inline(always)]
+fn blocks<const ROUNDS: usize>(key: &[u32; 8], counter: u64, stream: u64, out: &mut [u32; BUFFER_WORDS]) {
+    unsafe {
+        // Vector `i` holds word `i` of every block, lane `j` being block `counter + j`
+        let words = input_words(key, stream);
+        let [counter_lo, counter_hi] = counter_lanes::<BLOCKS>(counter);
+        let mut input: [__m256i; 16] = words.map(|word| _mm256_set1_epi32(word.cast_signed()));
+        // This intrinsic is specifically the unaligned load variant.
+        #[allow(clippy::cast_ptr_alignment)]
+        {
+            input[12] = _mm256_loadu_si256(counter_lo.as_ptr().cast::<__m256i>());
+            input[13] = _mm256_loadu_si256(counter_hi.as_ptr().cast::<__m256i>());
+        }
+
+        let mut x = input;
+        for _ in 0..ROUNDS / 2 {
+            quarter_round(&mut x, 0, 4, 8, 12);
+            quarter_round(&mut x, 1, 5, 9, 13);
+            quarter_round(&mut x, 2, 6, 10, 14);
+            quarter_round(&mut x, 3, 7, 11, 15);
+            quarter_round(&mut x, 0, 5, 10, 15);
+            quarter_round(&mut x, 1, 6, 11, 12);
+            quarter_round(&mut x, 2, 7, 8, 13);
+            quarter_round(&mut x, 3, 4, 9, 14);
+        }
+        for (x, input) in x.iter_mut().zip(input) {
+            *x = _mm256_add_epi32(*x, input);
+        }
+
+        let [x0, x1, x2, x3, x4, x5, x6, x7, x8, x9, x10, x11, x12, x13, x14, x15] = x;
+        let low = transpose([x0, x1, x2, x3, x4, x5, x6, x7]);
+        let high = transpose([x8, x9, x10, x11, x12, x13, x14, x15]);
+        for (block, (low, high)) in out.chunks_exact_mut(BLOCK_WORDS).zip(low.into_iter().zip(high)) {
+            // This intrinsic is specifically the unaligned store variant.
+            #[allow(clippy::cast_ptr_alignment)]
+            {
+                _mm256_storeu_si256(block.as_mut_ptr().cast::<__m256i>(), low);
+                _mm256_storeu_si256(block[8..].as_mut_ptr().cast::<__m256i>(), high);
+            }
+        }
+    }
+}
+
+#[inline(always)]
+// Word names follow the ChaCha specification
+#[allow(clippy::many_single_char_names)]
+fn quarter_round(x: &mut [__m256i; 16], a: usize, b: usize, c: usize, d: usize) {
+    unsafe {
+        x[a] = _mm256_add_epi32(x[a], x[b]);
+        x[d] = rotate_left::<16>(_mm256_xor_si256(x[d], x[a]));
+        x[c] = _mm256_add_epi32(x[c], x[d]);
+        x[b] = rotate_left::<12>(_mm256_xor_si256(x[b], x[c]));
+        x[a] = _mm256_add_epi32(x[a], x[b]);
+        x[d] = rotate_left::<8>(_mm256_xor_si256(x[d], x[a]));
+        x[c] = _mm256_add_epi32(x[c], x[d]);
+        x[b] = rotate_left::<7>(_mm256_xor_si256(x[b], x[c]));
+    }
+}
+
+#[inline(always)]
+#[cfg(all(target_arch = "x86_64", target_feature = "avx512f", target_feature = "avx512vl"))]
+fn rotate_left<const K: i32>(x: __m256i) -> __m256i {
+    unsafe { _mm256_rol_epi32::<K>(x) }
+}
+
+#[inline(always)]
+#[cfg(not(all(target_arch = "x86_64", target_feature = "avx512f", target_feature = "avx512vl")))]
+fn rotate_left<const K: i32>(x: __m256i) -> __m256i {
+    unsafe {
+        // Rotations by whole bytes are a single byte shuffle within each word
+        match K {
+            16 => _mm256_shuffle_epi8(
+                x,
+                _mm256_set_epi64x(
+                    0x0D0C0F0E09080B0A,
+                    0x0504070601000302,
+                    0x0D0C0F0E09080B0A,
+                    0x0504070601000302,
+                ),
+            ),
+            8 => _mm256_shuffle_epi8(
+                x,
+                _mm256_set_epi64x(
+                    0x0E0D0C0F0A09080B,
+                    0x0605040702010003,
+                    0x0E0D0C0F0A09080B,
+                    0x0605040702010003,
+                ),
+            ),
+            _ => _mm256_or_si256(
+                _mm256_sll_epi32(x, _mm_cvtsi32_si128(K)),
+                _mm256_srl_epi32(x, _mm_cvtsi32_si128(32 - K)),
+            ),
+        }
+    }
+}
+
+/// Transposes 8 words of 8 blocks into the 8 words of each block.
+#[inline(always)]
+fn transpose(rows: [__m256i; 8]) -> [__m256i; 8] {
+    unsafe {
+        let [r0, r1, r2, r3, r4, r5, r6, r7] = rows;
+
+        // Word pairs, then word quads of blocks `j` and `j + 4` in the low and high halves
+        let t0 = _mm256_unpacklo_epi32(r0, r1);
+        let t1 = _mm256_unpackhi_epi32(r0, r1);
+        let t2 = _mm256_unpacklo_epi32(r2, r3);
+        let t3 = _mm256_unpackhi_epi32(r2, r3);
+        let t4 = _mm256_unpacklo_epi32(r4, r5);
+        let t5 = _mm256_unpackhi_epi32(r4, r5);
+        let t6 = _mm256_unpacklo_epi32(r6, r7);
+        let t7 = _mm256_unpackhi_epi32(r6, r7);
+
+        let u0 = _mm256_unpacklo_epi64(t0, t2);
+        let u1 = _mm256_unpackhi_epi64(t0, t2);
+        let u2 = _mm256_unpacklo_epi64(t1, t3);
+        let u3 = _mm256_unpackhi_epi64(t1, t3);
+        let u4 = _mm256_unpacklo_epi64(t4, t6);
+        let u5 = _mm256_unpackhi_epi64(t4, t6);
+        let u6 = _mm256_unpacklo_epi64(t5, t7);
+        let u7 = _mm256_unpackhi_epi64(t5, t7);
+
+        [
+            _mm256_permute2x128_si256::<0x20>(u0, u4),
+            _mm256_permute2x128_si256::<0x20>(u1, u5),
+            _mm256_permute2x128_si256::<0x20>(u2, u6),
+            _mm256_permute2x128_si256::<0x20>(u3, u7),
+            _mm256_permute2x128_si256::<0x31>(u0, u4),
+            _mm256_permute2x128_si256::<0x31>(u1, u5),
+            _mm256_permute2x128_si256::<0x31>(u2, u6),
+            _mm256_permute2x128_si256::<0x31>(u3, u7),
+        ]
+    }
+}
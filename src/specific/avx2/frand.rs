@@ -1,13 +1,15 @@
 use core::{
     arch::x86_64::*,
-    mem,
+    fmt, mem,
     ops::{Deref, DerefMut},
 };
 
 use rand_core::SeedableRng;
 
-use crate::frand::{hash_seed_bytes, repeated_seed_bytes};
-use crate::specific::avx2::read_u64_into_vec;
+use crate::scalar::Frand;
+use crate::specific::avx2::{lanes, read_u64_into_vec, state_to_vectors, vectors_to_state};
+use crate::splitmix64::expand_seed;
+use crate::state::{lane_state, lanes_to_state};
 
 use super::simdrand::*;
 
@@ -61,11 +63,85 @@ impl AsMut<[u8]> for FrandX4Seed {
     }
 }
 
+#[derive(Clone)]
 #[repr(align(32))]
 pub struct FrandX4 {
     seed: __m256i,
 }
 
+impl FrandX4 {
+    /// Returns the generator state as one counter word per lane.
+    ///
+    /// Pass it to [`FrandX4::from_state`] to resume the exact same stream later.
+    #[must_use]
+    pub fn state(&self) -> [u64; 4] {
+        vectors_to_state([self.seed])
+    }
+
+    /// Restores a generator from a snapshot taken with [`FrandX4::state`].
+    #[must_use]
+    pub fn from_state(state: [u64; 4]) -> Self {
+        let [seed] = state_to_vectors(&state);
+
+        Self { seed }
+    }
+
+    /// Returns a scalar generator positioned at the same point in the stream of lane `lane`.
+    ///
+    /// # Panics
+    ///
+    /// If `lane` is not less than 4.
+    #[must_use]
+    pub fn lane(&self, lane: usize) -> Frand {
+        Frand::from_state(lane_state::<4, 4, 1>(&self.state(), lane))
+    }
+
+    /// Splits the generator into one scalar generator per lane, each continuing its lane's stream.
+    #[must_use]
+    pub fn into_lanes(self) -> [Frand; 4] {
+        let state = self.state();
+
+        core::array::from_fn(|lane| Frand::from_state(lane_state::<4, 4, 1>(&state, lane)))
+    }
+
+    /// Assembles a generator from scalar generators, lane `i` continues the stream of `lanes[i]`.
+    #[must_use]
+    pub fn from_lanes(lanes: [Frand; 4]) -> Self {
+        Self::from_state(lanes_to_state::<4, 4, 1>(&lanes.map(|lane| lane.state())))
+    }
+}
+
+impl fmt::Debug for FrandX4 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FrandX4").field("seed", &lanes(self.seed)).finish()
+    }
+}
+
+impl PartialEq for FrandX4 {
+    fn eq(&self, other: &Self) -> bool {
+        self.state() == other.state()
+    }
+}
+
+impl Eq for FrandX4 {}
+
+#[cfg(feature = "serde")]
+crate::serde::impl_state_serde!(FrandX4, 4);
+
+#[cfg(feature = "portable")]
+impl From<crate::portable::FrandX4> for FrandX4 {
+    fn from(rng: crate::portable::FrandX4) -> Self {
+        Self::from_state(rng.state())
+    }
+}
+
+#[cfg(feature = "portable")]
+impl From<FrandX4> for crate::portable::FrandX4 {
+    fn from(rng: FrandX4) -> Self {
+        Self::from_state(rng.state())
+    }
+}
+
 impl SeedableRng for FrandX4 {
     type Seed = FrandX4Seed;
 
@@ -74,14 +150,13 @@ impl SeedableRng for FrandX4 {
         const LEN: usize = 4;
         assert!(seed.len() == SIZE * LEN);
 
-        let seed = hash_seed_bytes::<32>(&seed[..]);
-        let s = read_u64_into_vec(&seed);
+        let s = read_u64_into_vec(&seed[..]);
 
         Self { seed: s }
     }
 
     fn seed_from_u64(seed: u64) -> Self {
-        Self::from_seed(Self::Seed::from(repeated_seed_bytes::<32>(seed)))
+        Self::from_seed(Self::Seed::from(expand_seed::<4, 32>(seed)))
     }
 }
 
@@ -130,15 +205,17 @@ impl SimdRand for FrandX4 {
     }
 }
 
+super::simdrand::impl_simd_rng!(FrandX4);
+
 #[cfg(test)]
 mod tests {
     use rand_core::SeedableRng;
 
     use super::{FrandX4, SimdRand};
-    use crate::frand::test_support::assert_seed_from_u64_matches_upstream;
+    use crate::frand::test_support::assert_seed_from_u64_matches_splitmix64;
 
     #[test]
-    fn seed_from_u64_matches_upstream() {
-        assert_seed_from_u64_matches_upstream::<4, _>(42, FrandX4::seed_from_u64(42), |rng| *rng.next_u64x4());
+    fn seed_from_u64_matches_splitmix64() {
+        assert_seed_from_u64_matches_splitmix64::<4, _>(42, FrandX4::seed_from_u64(42), |rng| *rng.next_u64x4());
     }
 }
@@ -0,0 +1,202 @@
+use core::{
+    arch::x86_64::*,
+    fmt,
+    ops::{Deref, DerefMut},
+};
+
+use rand_core::SeedableRng;
+
+use crate::jsf64::{SEED_A, WARMUP_ROUNDS};
+use crate::scalar::Jsf64;
+use crate::specific::avx2::{lanes, read_u64_into_vec, state_to_vectors, vectors_to_state};
+use crate::splitmix64::expand_seed;
+use crate::state::{lane_state, lanes_to_state};
+
+use super::{rotate_left, simdrand::*};
+
+#[derive(Clone, Default)]
+pub struct Jsf64X4Seed([u8; 32]);
+
+impl Jsf64X4Seed {
+    #[must_use]
+    pub const fn new(seed: [u8; 32]) -> Self {
+        Self(seed)
+    }
+}
+
+impl From<[u8; 32]> for Jsf64X4Seed {
+    fn from(val: [u8; 32]) -> Self {
+        Self::new(val)
+    }
+}
+
+impl From<&[u8]> for Jsf64X4Seed {
+    fn from(val: &[u8]) -> Self {
+        assert_eq!(val.len(), 32);
+        let mut seed = [0u8; 32];
+        seed.copy_from_slice(val);
+        Self::new(seed)
+    }
+}
+
+impl Deref for Jsf64X4Seed {
+    type Target = [u8; 32];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl DerefMut for Jsf64X4Seed {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl AsRef<[u8]> for Jsf64X4Seed {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsMut<[u8]> for Jsf64X4Seed {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+/// JSF64 with 4 independent lanes, each the stream of [`Jsf64`] from its own state.
+///
+/// The step is only additions, subtractions, rotations and a xor, all of which map directly to vector instructions.
+///
+/// Unlike SFC64, JSF64 has no counter to give the lanes separate streams, they are apart only by their seed words.
+/// Lanes seeded with the same word run the same stream, `seed_from_u64` gives every lane a different one.
+#[derive(Clone)]
+#[repr(align(32))]
+pub struct Jsf64X4 {
+    a: __m256i,
+    b: __m256i,
+    c: __m256i,
+    d: __m256i,
+}
+
+impl Jsf64X4 {
+    /// Returns the generator state as `a`, `b`, `c` and `d` lane words.
+    ///
+    /// Pass it to [`Jsf64X4::from_state`] to resume the exact same stream later.
+    #[must_use]
+    pub fn state(&self) -> [u64; 16] {
+        vectors_to_state([self.a, self.b, self.c, self.d])
+    }
+
+    /// Restores a generator from a snapshot taken with [`Jsf64X4::state`].
+    #[must_use]
+    pub fn from_state(state: [u64; 16]) -> Self {
+        let [a, b, c, d] = state_to_vectors(&state);
+
+        Self { a, b, c, d }
+    }
+
+    /// Returns a scalar generator positioned at the same point in the stream of lane `lane`.
+    ///
+    /// # Panics
+    ///
+    /// If `lane` is not less than 4.
+    #[must_use]
+    pub fn lane(&self, lane: usize) -> Jsf64 {
+        Jsf64::from_state(lane_state::<4, 16, 4>(&self.state(), lane))
+    }
+
+    /// Splits the generator into one scalar generator per lane, each continuing its lane's stream.
+    #[must_use]
+    pub fn into_lanes(self) -> [Jsf64; 4] {
+        let state = self.state();
+
+        core::array::from_fn(|lane| Jsf64::from_state(lane_state::<4, 16, 4>(&state, lane)))
+    }
+
+    /// Assembles a generator from scalar generators, lane `i` continues the stream of `lanes[i]`.
+    #[must_use]
+    pub fn from_lanes(lanes: [Jsf64; 4]) -> Self {
+        Self::from_state(lanes_to_state::<4, 16, 4>(&lanes.map(|lane| lane.state())))
+    }
+}
+
+impl fmt::Debug for Jsf64X4 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Jsf64X4")
+            .field("a", &lanes(self.a))
+            .field("b", &lanes(self.b))
+            .field("c", &lanes(self.c))
+            .field("d", &lanes(self.d))
+            .finish()
+    }
+}
+
+impl PartialEq for Jsf64X4 {
+    fn eq(&self, other: &Self) -> bool {
+        self.state() == other.state()
+    }
+}
+
+impl Eq for Jsf64X4 {}
+
+#[cfg(feature = "serde")]
+crate::serde::impl_state_serde!(Jsf64X4, 16);
+
+#[cfg(feature = "portable")]
+impl From<crate::portable::Jsf64X4> for Jsf64X4 {
+    fn from(rng: crate::portable::Jsf64X4) -> Self {
+        Self::from_state(rng.state())
+    }
+}
+
+#[cfg(feature = "portable")]
+impl From<Jsf64X4> for crate::portable::Jsf64X4 {
+    fn from(rng: Jsf64X4) -> Self {
+        Self::from_state(rng.state())
+    }
+}
+
+impl SeedableRng for Jsf64X4 {
+    type Seed = Jsf64X4Seed;
+
+    /// Seeds lane `i` with word `i` of the seed like Bob Jenkins' `raninit`, the same as [`Jsf64::new`].
+    fn from_seed(seed: Self::Seed) -> Self {
+        let seed = read_u64_into_vec(&seed[..]);
+        let mut rng = unsafe {
+            Self {
+                a: _mm256_set1_epi64x(SEED_A.cast_signed()),
+                b: seed,
+                c: seed,
+                d: seed,
+            }
+        };
+        for _ in 0..WARMUP_ROUNDS {
+            rng.next_m256i();
+        }
+
+        rng
+    }
+
+    /// Fills the seed with the output of [`SplitMix64X4`](super::SplitMix64X4) seeded with `seed`.
+    fn seed_from_u64(seed: u64) -> Self {
+        Self::from_seed(Jsf64X4Seed::new(expand_seed::<4, 32>(seed)))
+    }
+}
+
+impl SimdRand for Jsf64X4 {
+    #[inline(always)]
+    fn next_m256i(&mut self) -> __m256i {
+        unsafe {
+            let e = _mm256_sub_epi64(self.a, rotate_left::<7>(self.b));
+            self.a = _mm256_xor_si256(self.b, rotate_left::<13>(self.c));
+            self.b = _mm256_add_epi64(self.c, rotate_left::<37>(self.d));
+            self.c = _mm256_add_epi64(self.d, e);
+            self.d = _mm256_add_epi64(e, self.a);
+
+            self.d
+        }
+    }
+}
+
+super::simdrand::impl_simd_rng!(Jsf64X4);
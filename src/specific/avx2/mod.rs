@@ -1,20 +1,46 @@
 use core::arch::x86_64::*;
 
 pub use biski64::*;
+pub use chacha::*;
 pub use frand::*;
+pub use jsf64::*;
+pub use mt19937_64::*;
+pub use pcg64dxsm::*;
+pub use philox2x64::*;
+pub use philox4x32::*;
+pub use romutrio::*;
+pub use sfc64::*;
 pub use shishua::*;
 pub use simdrand::*;
+pub use splitmix64::*;
 pub use vecs::*;
+pub use wyrand::*;
 pub use xoshiro256plus::*;
 pub use xoshiro256plusplus::*;
+pub use xoshiro256starstar::*;
+pub use xoshiro512plusplus::*;
+pub use xoshiro512starstar::*;
 
 mod biski64;
+mod chacha;
 mod frand;
+mod jsf64;
+mod mt19937_64;
+mod pcg64dxsm;
+mod philox2x64;
+mod philox4x32;
+mod romutrio;
+mod sfc64;
 mod shishua;
 mod simdrand;
+mod splitmix64;
 mod vecs;
+mod wyrand;
 mod xoshiro256plus;
 mod xoshiro256plusplus;
+mod xoshiro256starstar;
+mod xoshiro512plusplus;
+mod xoshiro512starstar;
 #[cfg(test)]
 pub(crate) use shishua::test_vectors as shishua_test_vectors;
 
@@ -29,6 +55,36 @@ fn read_u64_into_vec(src: &[u8]) -> __m256i {
     }
 }
 
+#[inline(always)]
+fn vectors_to_state<const VECTORS: usize, const WORDS: usize>(vectors: [__m256i; VECTORS]) -> [u64; WORDS] {
+    assert_eq!(4 * VECTORS, WORDS);
+
+    let mut state = [0; WORDS];
+    for (words, vector) in state.chunks_exact_mut(4).zip(vectors) {
+        // This intrinsic is specifically the unaligned store variant.
+        #[allow(clippy::cast_ptr_alignment)]
+        unsafe {
+            _mm256_storeu_si256(words.as_mut_ptr().cast::<__m256i>(), vector);
+        }
+    }
+
+    state
+}
+
+#[inline(always)]
+fn lanes(vector: __m256i) -> [u64; 4] {
+    vectors_to_state([vector])
+}
+
+#[inline(always)]
+fn state_to_vectors<const VECTORS: usize, const WORDS: usize>(state: &[u64; WORDS]) -> [__m256i; VECTORS] {
+    assert_eq!(4 * VECTORS, WORDS);
+
+    // This intrinsic is specifically the unaligned load variant.
+    #[allow(clippy::cast_ptr_alignment)]
+    core::array::from_fn(|index| unsafe { _mm256_loadu_si256(state[(index * 4)..].as_ptr().cast::<__m256i>()) })
+}
+
 #[inline(always)]
 #[cfg(all(target_arch = "x86_64", target_feature = "avx512f", target_feature = "avx512vl"))]
 fn rotate_left<const K: i32>(x: __m256i) -> __m256i {
@@ -47,6 +103,49 @@ fn rotate_left<const K: i32>(x: __m256i) -> __m256i {
     }
 }
 
+#[inline(always)]
+#[cfg(all(target_arch = "x86_64", target_feature = "avx512dq", target_feature = "avx512vl"))]
+fn mul_lo(a: __m256i, b: __m256i) -> __m256i {
+    unsafe { _mm256_mullo_epi64(a, b) }
+}
+
+#[inline(always)]
+#[cfg(not(all(target_arch = "x86_64", target_feature = "avx512dq", target_feature = "avx512vl")))]
+fn mul_lo(a: __m256i, b: __m256i) -> __m256i {
+    // Low 64 bits of the product from `vpmuludq`: a_lo * b_lo + ((a_lo * b_hi + a_hi * b_lo) << 32)
+    unsafe {
+        let cross = _mm256_add_epi64(
+            _mm256_mul_epu32(a, _mm256_srli_epi64::<32>(b)),
+            _mm256_mul_epu32(_mm256_srli_epi64::<32>(a), b),
+        );
+        _mm256_add_epi64(_mm256_mul_epu32(a, b), _mm256_slli_epi64::<32>(cross))
+    }
+}
+
+#[inline(always)]
+fn mul_hi(a: __m256i, b: __m256i) -> __m256i {
+    // High 64 bits of the 128-bit product, composed from `vpmuludq` 32x32 -> 64-bit partial products
+    unsafe {
+        let mask = _mm256_set1_epi64x(0xFFFF_FFFF);
+        let a_hi = _mm256_srli_epi64::<32>(a);
+        let b_hi = _mm256_srli_epi64::<32>(b);
+
+        let lo_lo = _mm256_mul_epu32(a, b);
+        let lo_hi = _mm256_mul_epu32(a, b_hi);
+        let hi_lo = _mm256_mul_epu32(a_hi, b);
+        let hi_hi = _mm256_mul_epu32(a_hi, b_hi);
+
+        let middle = _mm256_add_epi64(
+            _mm256_add_epi64(_mm256_srli_epi64::<32>(lo_lo), _mm256_and_si256(lo_hi, mask)),
+            _mm256_and_si256(hi_lo, mask),
+        );
+        _mm256_add_epi64(
+            _mm256_add_epi64(hi_hi, _mm256_srli_epi64::<32>(lo_hi)),
+            _mm256_add_epi64(_mm256_srli_epi64::<32>(hi_lo), _mm256_srli_epi64::<32>(middle)),
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use core::arch::x86_64::{__m256i, _mm256_store_si256};
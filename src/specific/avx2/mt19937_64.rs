@@ -0,0 +1,197 @@
+use core::{arch::x86_64::*, fmt};
+
+use rand_core::{RngCore, SeedableRng, impls};
+
+use super::simdrand::*;
+use crate::mt19937_64::{
+    Buffer, DEFAULT_SEED, LOWER_MASK, MATRIX_A, NN, STATE_WORDS, UPPER_MASK, temper as temper_word,
+};
+
+/// MT19937-64 twisting its state 4 words at a time, handing out 4 consecutive outputs per [`SimdRand`] call.
+///
+/// The output is the same as the reference `genrand64_int64` and `std::mt19937_64` for the same seed,
+/// through [`SimdRand`] as well as [`RngCore`], so vectors of 4 words continue the stream of [`RngCore::next_u64`].
+#[derive(Clone, PartialEq, Eq)]
+pub struct Mt19937_64X4 {
+    buffer: Buffer,
+}
+
+impl Mt19937_64X4 {
+    /// Seeds the generator like `init_genrand64(seed)` and `std::mt19937_64(seed)`.
+    #[must_use]
+    pub fn new(seed: u64) -> Self {
+        Self {
+            buffer: Buffer::new(seed),
+        }
+    }
+
+    /// Seeds the generator like `init_by_array64(key)`.
+    ///
+    /// # Panics
+    ///
+    /// If `key` is empty.
+    #[must_use]
+    pub fn from_key(key: &[u64]) -> Self {
+        assert!(!key.is_empty(), "The MT19937-64 key must not be empty");
+
+        Self {
+            buffer: Buffer::from_key(key),
+        }
+    }
+
+    /// Returns the generator state as the 312 words of the state array followed by the index of the next word,
+    /// the `mt` and `mti` of the reference implementation.
+    #[must_use]
+    pub fn state(&self) -> [u64; STATE_WORDS] {
+        self.buffer.state()
+    }
+
+    /// Restores a generator from a snapshot taken with [`Mt19937_64X4::state`].
+    ///
+    /// # Panics
+    ///
+    /// If the index in the snapshot is larger than 312.
+    #[must_use]
+    pub fn from_state(state: [u64; STATE_WORDS]) -> Self {
+        Self {
+            buffer: Buffer::from_state(&state),
+        }
+    }
+}
+
+impl Default for Mt19937_64X4 {
+    /// Seeds the generator with 5489, like a default constructed `std::mt19937_64`.
+    fn default() -> Self {
+        Self::new(DEFAULT_SEED)
+    }
+}
+
+impl fmt::Debug for Mt19937_64X4 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Mt19937_64X4")
+            .field("index", &self.buffer.index())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "serde")]
+crate::mt19937_64::impl_state_serde!(Mt19937_64X4);
+
+impl From<crate::scalar::Mt19937_64> for Mt19937_64X4 {
+    fn from(rng: crate::scalar::Mt19937_64) -> Self {
+        Self::from_state(rng.state())
+    }
+}
+
+impl From<Mt19937_64X4> for crate::scalar::Mt19937_64 {
+    fn from(rng: Mt19937_64X4) -> Self {
+        Self::from_state(rng.state())
+    }
+}
+
+#[cfg(feature = "portable")]
+impl From<crate::portable::Mt19937_64X4> for Mt19937_64X4 {
+    fn from(rng: crate::portable::Mt19937_64X4) -> Self {
+        Self::from_state(rng.state())
+    }
+}
+
+#[cfg(feature = "portable")]
+impl From<Mt19937_64X4> for crate::portable::Mt19937_64X4 {
+    fn from(rng: Mt19937_64X4) -> Self {
+        Self::from_state(rng.state())
+    }
+}
+
+impl SeedableRng for Mt19937_64X4 {
+    type Seed = [u8; 8];
+
+    /// Seeds the generator with the little-endian `u64` in `seed`, see [`Mt19937_64X4::new`].
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self::new(u64::from_le_bytes(seed))
+    }
+
+    /// Seeds the generator with `state` directly, see [`Mt19937_64X4::new`].
+    fn seed_from_u64(state: u64) -> Self {
+        Self::new(state)
+    }
+}
+
+impl SimdRand for Mt19937_64X4 {
+    #[inline(always)]
+    fn next_m256i(&mut self) -> __m256i {
+        let words = self.buffer.next_words::<4>(twist);
+
+        // This intrinsic is specifically the unaligned load variant.
+        #[allow(clippy::cast_ptr_alignment)]
+        unsafe {
+            temper(_mm256_loadu_si256(words.as_ptr().cast::<__m256i>()))
+        }
+    }
+}
+
+super::simdrand::impl_simd_rng!(Mt19937_64X4);
+
+impl RngCore for Mt19937_64X4 {
+    // The low half of the output, as casting the output of `std::mt19937_64` does
+    #[allow(clippy::cast_possible_truncation)]
+    #[inline(always)]
+    fn next_u32(&mut self) -> u32 {
+        self.next_u64() as u32
+    }
+
+    #[inline(always)]
+    fn next_u64(&mut self) -> u64 {
+        let [word] = self.buffer.next_words::<1>(twist);
+        temper_word(word)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        impls::fill_bytes_via_next(self, dest);
+    }
+}
+
+fn twist(mt: &mut [u64; NN]) {
+    crate::mt19937_64::twist::<4>(mt, |mt, i, source| {
+        // This intrinsic is specifically the unaligned load/store variant.
+        #[allow(clippy::cast_ptr_alignment)]
+        unsafe {
+            let current = _mm256_loadu_si256(mt[i..i + 4].as_ptr().cast::<__m256i>());
+            let next = _mm256_loadu_si256(mt[i + 1..i + 5].as_ptr().cast::<__m256i>());
+            let source = _mm256_loadu_si256(mt[source..source + 4].as_ptr().cast::<__m256i>());
+
+            let y = _mm256_or_si256(
+                _mm256_and_si256(current, _mm256_set1_epi64x(UPPER_MASK.cast_signed())),
+                _mm256_and_si256(next, _mm256_set1_epi64x(LOWER_MASK.cast_signed())),
+            );
+            // All ones where the low bit of `y` is set
+            let odd = _mm256_sub_epi64(_mm256_setzero_si256(), _mm256_and_si256(y, _mm256_set1_epi64x(1)));
+            let mag = _mm256_and_si256(odd, _mm256_set1_epi64x(MATRIX_A.cast_signed()));
+
+            let x = _mm256_xor_si256(_mm256_xor_si256(source, _mm256_srli_epi64::<1>(y)), mag);
+            _mm256_storeu_si256(mt[i..i + 4].as_mut_ptr().cast::<__m256i>(), x);
+        }
+    });
+}
+
+#[inline(always)]
+fn temper(x: __m256i) -> __m256i {
+    unsafe {
+        let x = _mm256_xor_si256(
+            x,
+            _mm256_and_si256(_mm256_srli_epi64::<29>(x), _mm256_set1_epi64x(0x5555555555555555)),
+        );
+        let x = _mm256_xor_si256(
+            x,
+            _mm256_and_si256(_mm256_slli_epi64::<17>(x), _mm256_set1_epi64x(0x71d67fffeda60000)),
+        );
+        let x = _mm256_xor_si256(
+            x,
+            _mm256_and_si256(
+                _mm256_slli_epi64::<37>(x),
+                _mm256_set1_epi64x(0xfff7eee000000000u64.cast_signed()),
+            ),
+        );
+        _mm256_xor_si256(x, _mm256_srli_epi64::<43>(x))
+    }
+}
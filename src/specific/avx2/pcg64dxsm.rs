@@ -0,0 +1,241 @@
+use core::{
+    arch::x86_64::*,
+    fmt,
+    ops::{Deref, DerefMut},
+};
+
+use rand_core::SeedableRng;
+
+use crate::pcg64::{MULTIPLIER, seed_state, stream_state};
+use crate::scalar::Pcg64Dxsm;
+use crate::splitmix64::expand_seed;
+use crate::state::{lane_state, lanes_to_state};
+
+use super::{lanes, mul_hi, mul_lo, simdrand::*, state_to_vectors, vectors_to_state};
+
+#[derive(Clone)]
+pub struct Pcg64DxsmX4Seed([u8; 128]);
+
+impl Pcg64DxsmX4Seed {
+    #[must_use]
+    pub const fn new(seed: [u8; 128]) -> Self {
+        Self(seed)
+    }
+}
+
+impl From<[u8; 128]> for Pcg64DxsmX4Seed {
+    fn from(val: [u8; 128]) -> Self {
+        Self::new(val)
+    }
+}
+
+impl From<&[u8]> for Pcg64DxsmX4Seed {
+    fn from(val: &[u8]) -> Self {
+        assert_eq!(val.len(), 128);
+        let mut seed = [0u8; 128];
+        seed.copy_from_slice(val);
+        Self::new(seed)
+    }
+}
+
+impl Deref for Pcg64DxsmX4Seed {
+    type Target = [u8; 128];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl DerefMut for Pcg64DxsmX4Seed {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl Default for Pcg64DxsmX4Seed {
+    fn default() -> Self {
+        Self([0; 128])
+    }
+}
+
+impl AsRef<[u8]> for Pcg64DxsmX4Seed {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsMut<[u8]> for Pcg64DxsmX4Seed {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+/// PCG64-DXSM over 4 lanes, the 128-bit LCG state of every lane is held as a low and a high word vector.
+///
+/// Every lane has its own stream increment, see [`Pcg64DxsmX4::new`].
+#[derive(Clone)]
+#[repr(align(32))]
+pub struct Pcg64DxsmX4 {
+    state_lo: __m256i,
+    state_hi: __m256i,
+    increment_lo: __m256i,
+    increment_hi: __m256i,
+}
+
+impl Pcg64DxsmX4 {
+    /// Creates a generator where lane `i` is on stream `streams[i]`, the same as `rand_pcg::Pcg64Dxsm::new(states[i], streams[i])`.
+    #[must_use]
+    pub fn new(states: [u128; 4], streams: [u128; 4]) -> Self {
+        Self::from_state(stream_state::<4, 16>(states, streams))
+    }
+
+    /// Returns the generator state as `state_lo`, `state_hi`, `increment_lo` and `increment_hi` lane words,
+    /// one vector after the other.
+    ///
+    /// Pass it to [`Pcg64DxsmX4::from_state`] to resume the exact same stream later.
+    #[must_use]
+    pub fn state(&self) -> [u64; 16] {
+        vectors_to_state([self.state_lo, self.state_hi, self.increment_lo, self.increment_hi])
+    }
+
+    /// Restores a generator from a snapshot taken with [`Pcg64DxsmX4::state`].
+    #[must_use]
+    pub fn from_state(state: [u64; 16]) -> Self {
+        let [state_lo, state_hi, increment_lo, increment_hi] = state_to_vectors(&state);
+
+        Self {
+            state_lo,
+            state_hi,
+            increment_lo,
+            increment_hi,
+        }
+    }
+
+    /// Returns a scalar generator positioned at the same point in the stream of lane `lane`.
+    ///
+    /// # Panics
+    ///
+    /// If `lane` is not less than 4.
+    #[must_use]
+    pub fn lane(&self, lane: usize) -> Pcg64Dxsm {
+        Pcg64Dxsm::from_state(lane_state::<4, 16, 4>(&self.state(), lane))
+    }
+
+    /// Splits the generator into one scalar generator per lane, each continuing its lane's stream.
+    #[must_use]
+    pub fn into_lanes(self) -> [Pcg64Dxsm; 4] {
+        let state = self.state();
+
+        core::array::from_fn(|lane| Pcg64Dxsm::from_state(lane_state::<4, 16, 4>(&state, lane)))
+    }
+
+    /// Assembles a generator from scalar generators, lane `i` continues the stream of `lanes[i]`.
+    #[must_use]
+    pub fn from_lanes(lanes: [Pcg64Dxsm; 4]) -> Self {
+        Self::from_state(lanes_to_state::<4, 16, 4>(&lanes.map(|lane| lane.state())))
+    }
+}
+
+impl fmt::Debug for Pcg64DxsmX4 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Pcg64DxsmX4")
+            .field("state_lo", &lanes(self.state_lo))
+            .field("state_hi", &lanes(self.state_hi))
+            .field("increment_lo", &lanes(self.increment_lo))
+            .field("increment_hi", &lanes(self.increment_hi))
+            .finish()
+    }
+}
+
+impl PartialEq for Pcg64DxsmX4 {
+    fn eq(&self, other: &Self) -> bool {
+        self.state() == other.state()
+    }
+}
+
+impl Eq for Pcg64DxsmX4 {}
+
+#[cfg(feature = "serde")]
+crate::serde::impl_state_serde!(Pcg64DxsmX4, 16);
+
+#[cfg(feature = "portable")]
+impl From<crate::portable::Pcg64DxsmX4> for Pcg64DxsmX4 {
+    fn from(rng: crate::portable::Pcg64DxsmX4) -> Self {
+        Self::from_state(rng.state())
+    }
+}
+
+#[cfg(feature = "portable")]
+impl From<Pcg64DxsmX4> for crate::portable::Pcg64DxsmX4 {
+    fn from(rng: Pcg64DxsmX4) -> Self {
+        Self::from_state(rng.state())
+    }
+}
+
+impl SeedableRng for Pcg64DxsmX4 {
+    type Seed = Pcg64DxsmX4Seed;
+
+    /// Lane `i` is seeded the same as `rand_pcg::Pcg64Dxsm::from_seed`, reading the seed as `state_lo`, `state_hi`,
+    /// `increment_lo` and `increment_hi` vectors.
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self::from_state(seed_state::<4, 16>(&seed[..]))
+    }
+
+    /// Fills the seed with the output of [`SplitMix64X4`](super::SplitMix64X4) seeded with `seed`.
+    fn seed_from_u64(seed: u64) -> Self {
+        Self::from_seed(Pcg64DxsmX4Seed::new(expand_seed::<4, 128>(seed)))
+    }
+}
+
+impl SimdRand for Pcg64DxsmX4 {
+    #[inline(always)]
+    fn next_m256i(&mut self) -> __m256i {
+        unsafe {
+            let multiplier = _mm256_set1_epi64x(MULTIPLIER.cast_signed());
+
+            // DXSM output of the current state
+            let lo = _mm256_or_si256(self.state_lo, _mm256_set1_epi64x(1));
+            let mut hi = self.state_hi;
+            hi = _mm256_xor_si256(hi, _mm256_srli_epi64::<32>(hi));
+            hi = mul_lo(hi, multiplier);
+            hi = _mm256_xor_si256(hi, _mm256_srli_epi64::<48>(hi));
+            let vector = mul_lo(hi, lo);
+
+            // 128-bit LCG step: state * MULTIPLIER + increment, carrying from the low into the high word
+            let product_lo = mul_lo(self.state_lo, multiplier);
+            let product_hi = _mm256_add_epi64(mul_hi(self.state_lo, multiplier), mul_lo(self.state_hi, multiplier));
+            let state_lo = _mm256_add_epi64(product_lo, self.increment_lo);
+            // AVX2 only has a signed compare, flipping the sign bit makes it unsigned
+            let sign = _mm256_set1_epi64x(i64::MIN);
+            let carry = _mm256_cmpgt_epi64(_mm256_xor_si256(product_lo, sign), _mm256_xor_si256(state_lo, sign));
+
+            self.state_lo = state_lo;
+            // The carry mask is all ones (-1) where the low word wrapped
+            self.state_hi = _mm256_sub_epi64(_mm256_add_epi64(product_hi, self.increment_hi), carry);
+
+            vector
+        }
+    }
+}
+
+super::simdrand::impl_simd_rng!(Pcg64DxsmX4);
+
+#[cfg(test)]
+mod tests {
+    use rand_core::RngCore;
+
+    use super::{Pcg64DxsmX4, SimdRand};
+
+    #[test]
+    fn new_matches_scalar_reference() {
+        let states: [u128; 4] =
+            core::array::from_fn(|lane| 0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210 * (lane as u128 + 1));
+        let streams: [u128; 4] = core::array::from_fn(|lane| 0xCAFE_F00D_D15E_A5E5 ^ lane as u128);
+        let mut rng = Pcg64DxsmX4::new(states, streams);
+        let mut reference: [rand_pcg::Pcg64Dxsm; 4] =
+            core::array::from_fn(|lane| rand_pcg::Pcg64Dxsm::new(states[lane], streams[lane]));
+
+        for _ in 0..1024 {
+            assert_eq!(*rng.next_u64x4(), reference.each_mut().map(RngCore::next_u64));
+        }
+    }
+}
@@ -0,0 +1,208 @@
+use core::{
+    arch::x86_64::*,
+    ops::{Deref, DerefMut},
+};
+
+use rand_core::SeedableRng;
+
+use super::{mul_hi, mul_lo, simdrand::*, state_to_vectors};
+use crate::philox::{
+    PHILOX_M2X64, PHILOX_W64, ROUNDS, counter_words, counter2x64_from_u128, counter2x64_to_u128, seed_state,
+    words_to_blocks2x64,
+};
+
+#[derive(Clone, Default)]
+pub struct Philox2x64X4Seed([u8; 24]);
+
+impl Philox2x64X4Seed {
+    #[must_use]
+    pub const fn new(seed: [u8; 24]) -> Self {
+        Self(seed)
+    }
+}
+
+impl From<[u8; 24]> for Philox2x64X4Seed {
+    fn from(val: [u8; 24]) -> Self {
+        Self::new(val)
+    }
+}
+
+impl From<&[u8]> for Philox2x64X4Seed {
+    fn from(val: &[u8]) -> Self {
+        assert_eq!(val.len(), 24);
+        let mut seed = [0u8; 24];
+        seed.copy_from_slice(val);
+        Self::new(seed)
+    }
+}
+
+impl Deref for Philox2x64X4Seed {
+    type Target = [u8; 24];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl DerefMut for Philox2x64X4Seed {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl AsRef<[u8]> for Philox2x64X4Seed {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsMut<[u8]> for Philox2x64X4Seed {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+/// Philox2x64-10 producing 4 words per call from blocks `counter..counter + 2` of a single key.
+///
+/// Since the blocks are consecutive, the output is the stream of [`crate::scalar::Philox2x64`] whatever the backend or
+/// vector width, and the counter gives random access into it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Philox2x64X4 {
+    key: u64,
+    counter: u128,
+}
+
+impl Philox2x64X4 {
+    /// Creates a generator starting at block `counter`, the same stream as [`crate::scalar::Philox2x64::new`].
+    #[must_use]
+    pub const fn new(key: u64, counter: [u64; 2]) -> Self {
+        Self {
+            key,
+            counter: counter2x64_to_u128(counter),
+        }
+    }
+
+    /// Returns blocks `counter..counter + 2` for `key`, evaluated together in one vector pass.
+    #[must_use]
+    pub fn blocks(key: u64, counter: [u64; 2]) -> [[u64; 2]; 2] {
+        words_to_blocks2x64(*Self::new(key, counter).next_u64x4())
+    }
+
+    /// Returns the key.
+    #[must_use]
+    pub const fn key(&self) -> u64 {
+        self.key
+    }
+
+    /// Returns the counter of the block the next output starts at.
+    #[must_use]
+    pub const fn counter(&self) -> [u64; 2] {
+        counter2x64_from_u128(self.counter)
+    }
+
+    /// Moves the stream to block `counter`, every block can be reached directly.
+    pub const fn set_counter(&mut self, counter: [u64; 2]) {
+        self.counter = counter2x64_to_u128(counter);
+    }
+
+    /// Returns the generator state as the key word followed by the low and high counter words.
+    ///
+    /// Pass it to [`Philox2x64X4::from_state`] to resume the exact same stream later.
+    #[must_use]
+    pub const fn state(&self) -> [u64; 3] {
+        let [counter_lo, counter_hi] = counter2x64_from_u128(self.counter);
+
+        [self.key, counter_lo, counter_hi]
+    }
+
+    /// Restores a generator from a snapshot taken with [`Philox2x64X4::state`].
+    #[must_use]
+    pub const fn from_state(state: [u64; 3]) -> Self {
+        let [key, counter_lo, counter_hi] = state;
+
+        Self {
+            key,
+            counter: counter2x64_to_u128([counter_lo, counter_hi]),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+crate::serde::impl_state_serde!(Philox2x64X4, 3);
+
+#[cfg(feature = "portable")]
+impl From<crate::portable::Philox2x64X4> for Philox2x64X4 {
+    fn from(rng: crate::portable::Philox2x64X4) -> Self {
+        Self::from_state(rng.state())
+    }
+}
+
+#[cfg(feature = "portable")]
+impl From<Philox2x64X4> for crate::portable::Philox2x64X4 {
+    fn from(rng: Philox2x64X4) -> Self {
+        Self::from_state(rng.state())
+    }
+}
+
+impl SeedableRng for Philox2x64X4 {
+    type Seed = Philox2x64X4Seed;
+
+    /// Reads the key word followed by the low and high counter words.
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self::from_state(seed_state(&seed[..]))
+    }
+}
+
+impl SimdRand for Philox2x64X4 {
+    #[inline(always)]
+    fn next_m256i(&mut self) -> __m256i {
+        unsafe {
+            // Lane pairs hold the `x0` and `x1` words of one block
+            let [mut x] = state_to_vectors(&counter_words::<2, 4>(self.counter));
+            self.counter = self.counter.wrapping_add(2);
+
+            let multiplier = _mm256_set1_epi64x(PHILOX_M2X64.cast_signed());
+            let bump = _mm256_set1_epi64x(PHILOX_W64.cast_signed());
+            let mut key = _mm256_set1_epi64x(self.key.cast_signed());
+            for round in 0..ROUNDS {
+                if round > 0 {
+                    key = _mm256_add_epi64(key, bump);
+                }
+
+                let lo = mul_lo(x, multiplier);
+                let hi = mul_hi(x, multiplier);
+                // [hi(x0) ^ x1 ^ key, lo(x0)] for every block
+                x = _mm256_unpacklo_epi64(
+                    _mm256_xor_si256(_mm256_xor_si256(hi, _mm256_shuffle_epi32::<0b01_00_11_10>(x)), key),
+                    lo,
+                );
+            }
+
+            x
+        }
+    }
+}
+
+super::simdrand::impl_simd_rng!(Philox2x64X4);
+
+#[cfg(test)]
+mod tests {
+    use rand_core::RngCore;
+
+    use super::{Philox2x64X4, SimdRand};
+    use crate::scalar::Philox2x64;
+
+    #[test]
+    fn matches_scalar_stream() {
+        // Starts a few blocks before the low counter word wraps
+        let key = 0xa4093822299f31d0;
+        let counter = [u64::MAX - 2, 0x13198a2e03707344];
+        let mut rng = Philox2x64X4::new(key, counter);
+        let mut reference = Philox2x64::new(key, counter);
+
+        for _ in 0..64 {
+            let expected: [u64; 4] = core::array::from_fn(|_| reference.next_u64());
+            assert_eq!(*rng.next_u64x4(), expected);
+        }
+        assert_eq!(rng.counter(), reference.counter());
+    }
+}
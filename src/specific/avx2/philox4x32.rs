@@ -0,0 +1,239 @@
+use core::{
+    arch::x86_64::*,
+    ops::{Deref, DerefMut},
+};
+
+use rand_core::SeedableRng;
+
+use super::{simdrand::*, state_to_vectors};
+use crate::philox::{
+    PHILOX_M4X32_0, PHILOX_M4X32_1, PHILOX_W32_0, PHILOX_W32_1, ROUNDS, counter_words, counter2x64_from_u128,
+    counter2x64_to_u128, counter4x32_from_u128, counter4x32_to_u128, key4x32_from_word, key4x32_to_word, seed_state,
+    words_to_blocks4x32,
+};
+
+#[derive(Clone, Default)]
+pub struct Philox4x32X4Seed([u8; 24]);
+
+impl Philox4x32X4Seed {
+    #[must_use]
+    pub const fn new(seed: [u8; 24]) -> Self {
+        Self(seed)
+    }
+}
+
+impl From<[u8; 24]> for Philox4x32X4Seed {
+    fn from(val: [u8; 24]) -> Self {
+        Self::new(val)
+    }
+}
+
+impl From<&[u8]> for Philox4x32X4Seed {
+    fn from(val: &[u8]) -> Self {
+        assert_eq!(val.len(), 24);
+        let mut seed = [0u8; 24];
+        seed.copy_from_slice(val);
+        Self::new(seed)
+    }
+}
+
+impl Deref for Philox4x32X4Seed {
+    type Target = [u8; 24];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl DerefMut for Philox4x32X4Seed {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl AsRef<[u8]> for Philox4x32X4Seed {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsMut<[u8]> for Philox4x32X4Seed {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+/// Philox4x32-10 producing 4 words per call from blocks `counter..counter + 2` of a single key.
+///
+/// Since the blocks are consecutive, the output is the stream of [`crate::scalar::Philox4x32`] whatever the backend or
+/// vector width, and the counter gives random access into it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Philox4x32X4 {
+    key: u64,
+    counter: u128,
+}
+
+impl Philox4x32X4 {
+    /// Creates a generator starting at block `counter`, the same stream as [`crate::scalar::Philox4x32::new`].
+    #[must_use]
+    pub const fn new(key: [u32; 2], counter: [u32; 4]) -> Self {
+        Self {
+            key: key4x32_to_word(key),
+            counter: counter4x32_to_u128(counter),
+        }
+    }
+
+    /// Returns blocks `counter..counter + 2` for `key`, evaluated together in one vector pass.
+    #[must_use]
+    pub fn blocks(key: [u32; 2], counter: [u32; 4]) -> [[u32; 4]; 2] {
+        words_to_blocks4x32(*Self::new(key, counter).next_u64x4())
+    }
+
+    /// Returns the key.
+    #[must_use]
+    pub const fn key(&self) -> [u32; 2] {
+        key4x32_from_word(self.key)
+    }
+
+    /// Returns the counter of the block the next output starts at.
+    #[must_use]
+    pub const fn counter(&self) -> [u32; 4] {
+        counter4x32_from_u128(self.counter)
+    }
+
+    /// Moves the stream to block `counter`, every block can be reached directly.
+    pub const fn set_counter(&mut self, counter: [u32; 4]) {
+        self.counter = counter4x32_to_u128(counter);
+    }
+
+    /// Returns the generator state as the key word followed by the low and high counter words.
+    ///
+    /// Pass it to [`Philox4x32X4::from_state`] to resume the exact same stream later.
+    #[must_use]
+    pub const fn state(&self) -> [u64; 3] {
+        let [counter_lo, counter_hi] = counter2x64_from_u128(self.counter);
+
+        [self.key, counter_lo, counter_hi]
+    }
+
+    /// Restores a generator from a snapshot taken with [`Philox4x32X4::state`].
+    #[must_use]
+    pub const fn from_state(state: [u64; 3]) -> Self {
+        let [key, counter_lo, counter_hi] = state;
+
+        Self {
+            key,
+            counter: counter2x64_to_u128([counter_lo, counter_hi]),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+crate::serde::impl_state_serde!(Philox4x32X4, 3);
+
+#[cfg(feature = "portable")]
+impl From<crate::portable::Philox4x32X4> for Philox4x32X4 {
+    fn from(rng: crate::portable::Philox4x32X4) -> Self {
+        Self::from_state(rng.state())
+    }
+}
+
+#[cfg(feature = "portable")]
+impl From<Philox4x32X4> for crate::portable::Philox4x32X4 {
+    fn from(rng: Philox4x32X4) -> Self {
+        Self::from_state(rng.state())
+    }
+}
+
+impl SeedableRng for Philox4x32X4 {
+    type Seed = Philox4x32X4Seed;
+
+    /// Reads the key word followed by the low and high counter words.
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self::from_state(seed_state(&seed[..]))
+    }
+}
+
+impl SimdRand for Philox4x32X4 {
+    #[inline(always)]
+    fn next_m256i(&mut self) -> __m256i {
+        unsafe {
+            // Lane pairs hold the `x0 | x1 << 32` and `x2 | x3 << 32` words of one block
+            let [mut x] = state_to_vectors(&counter_words::<2, 4>(self.counter));
+            self.counter = self.counter.wrapping_add(2);
+
+            let [k0, k1] = key4x32_from_word(self.key);
+            let multipliers = _mm256_setr_epi32(
+                PHILOX_M4X32_0.cast_signed(),
+                0,
+                PHILOX_M4X32_1.cast_signed(),
+                0,
+                PHILOX_M4X32_0.cast_signed(),
+                0,
+                PHILOX_M4X32_1.cast_signed(),
+                0,
+            );
+            let bumps = _mm256_setr_epi32(
+                PHILOX_W32_0.cast_signed(),
+                0,
+                PHILOX_W32_1.cast_signed(),
+                0,
+                PHILOX_W32_0.cast_signed(),
+                0,
+                PHILOX_W32_1.cast_signed(),
+                0,
+            );
+            let mut key = _mm256_setr_epi32(
+                k0.cast_signed(),
+                0,
+                k1.cast_signed(),
+                0,
+                k0.cast_signed(),
+                0,
+                k1.cast_signed(),
+                0,
+            );
+            for round in 0..ROUNDS {
+                if round > 0 {
+                    key = _mm256_add_epi32(key, bumps);
+                }
+
+                // `x0 * M0` and `x2 * M1`, the 32-bit words reversed into [hi1, lo1, hi0, lo0]
+                let product = _mm256_mul_epu32(x, multipliers);
+                x = _mm256_xor_si256(
+                    _mm256_xor_si256(
+                        _mm256_shuffle_epi32::<0b00_01_10_11>(product),
+                        _mm256_srli_epi64::<32>(x),
+                    ),
+                    key,
+                );
+            }
+
+            x
+        }
+    }
+}
+
+super::simdrand::impl_simd_rng!(Philox4x32X4);
+
+#[cfg(test)]
+mod tests {
+    use rand_core::RngCore;
+
+    use super::{Philox4x32X4, SimdRand};
+    use crate::scalar::Philox4x32;
+
+    #[test]
+    fn matches_scalar_stream() {
+        // Starts a few blocks before the low counter word wraps
+        let key = [0xa4093822, 0x299f31d0];
+        let counter = [u32::MAX - 2, u32::MAX, u32::MAX, 0x03707344];
+        let mut rng = Philox4x32X4::new(key, counter);
+        let mut reference = Philox4x32::new(key, counter);
+
+        for _ in 0..64 {
+            let expected: [u64; 4] = core::array::from_fn(|_| reference.next_u64());
+            assert_eq!(*rng.next_u64x4(), expected);
+        }
+        assert_eq!(rng.counter(), reference.counter());
+    }
+}
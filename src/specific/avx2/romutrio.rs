@@ -0,0 +1,197 @@
+use core::{
+    arch::x86_64::*,
+    fmt, mem,
+    ops::{Deref, DerefMut},
+};
+
+use rand_core::SeedableRng;
+
+use crate::romutrio::MULTIPLIER;
+use crate::scalar::RomuTrio;
+use crate::specific::avx2::{lanes, read_u64_into_vec, state_to_vectors, vectors_to_state};
+use crate::splitmix64::expand_seed;
+use crate::state::{lane_state, lanes_to_state};
+
+use super::{mul_lo, rotate_left, simdrand::*};
+
+#[derive(Clone)]
+pub struct RomuTrioX4Seed([u8; 96]);
+
+impl RomuTrioX4Seed {
+    #[must_use]
+    pub const fn new(seed: [u8; 96]) -> Self {
+        Self(seed)
+    }
+}
+
+impl From<[u8; 96]> for RomuTrioX4Seed {
+    fn from(val: [u8; 96]) -> Self {
+        Self::new(val)
+    }
+}
+
+impl From<&[u8]> for RomuTrioX4Seed {
+    fn from(val: &[u8]) -> Self {
+        assert_eq!(val.len(), 96);
+        let mut seed = [0u8; 96];
+        seed.copy_from_slice(val);
+        Self::new(seed)
+    }
+}
+
+impl Deref for RomuTrioX4Seed {
+    type Target = [u8; 96];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl DerefMut for RomuTrioX4Seed {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl Default for RomuTrioX4Seed {
+    fn default() -> Self {
+        Self([0; 96])
+    }
+}
+
+impl AsRef<[u8]> for RomuTrioX4Seed {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsMut<[u8]> for RomuTrioX4Seed {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+/// RomuTrio with 4 independent lanes, each the stream of [`RomuTrio`] from its own state.
+///
+/// The step is a multiply, two subtractions and two rotations, all of which map directly to vector instructions.
+#[derive(Clone)]
+#[repr(align(32))]
+pub struct RomuTrioX4 {
+    x: __m256i,
+    y: __m256i,
+    z: __m256i,
+}
+
+impl RomuTrioX4 {
+    /// Returns the generator state as `x`, `y` and `z` lane words, in the same layout `from_seed` reads.
+    ///
+    /// Pass it to [`RomuTrioX4::from_state`] to resume the exact same stream later.
+    #[must_use]
+    pub fn state(&self) -> [u64; 12] {
+        vectors_to_state([self.x, self.y, self.z])
+    }
+
+    /// Restores a generator from a snapshot taken with [`RomuTrioX4::state`].
+    #[must_use]
+    pub fn from_state(state: [u64; 12]) -> Self {
+        let [x, y, z] = state_to_vectors(&state);
+
+        Self { x, y, z }
+    }
+
+    /// Returns a scalar generator positioned at the same point in the stream of lane `lane`.
+    ///
+    /// # Panics
+    ///
+    /// If `lane` is not less than 4.
+    #[must_use]
+    pub fn lane(&self, lane: usize) -> RomuTrio {
+        RomuTrio::from_state(lane_state::<4, 12, 3>(&self.state(), lane))
+    }
+
+    /// Splits the generator into one scalar generator per lane, each continuing its lane's stream.
+    #[must_use]
+    pub fn into_lanes(self) -> [RomuTrio; 4] {
+        let state = self.state();
+
+        core::array::from_fn(|lane| RomuTrio::from_state(lane_state::<4, 12, 3>(&state, lane)))
+    }
+
+    /// Assembles a generator from scalar generators, lane `i` continues the stream of `lanes[i]`.
+    #[must_use]
+    pub fn from_lanes(lanes: [RomuTrio; 4]) -> Self {
+        Self::from_state(lanes_to_state::<4, 12, 3>(&lanes.map(|lane| lane.state())))
+    }
+}
+
+impl fmt::Debug for RomuTrioX4 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RomuTrioX4")
+            .field("x", &lanes(self.x))
+            .field("y", &lanes(self.y))
+            .field("z", &lanes(self.z))
+            .finish()
+    }
+}
+
+impl PartialEq for RomuTrioX4 {
+    fn eq(&self, other: &Self) -> bool {
+        self.state() == other.state()
+    }
+}
+
+impl Eq for RomuTrioX4 {}
+
+#[cfg(feature = "serde")]
+crate::serde::impl_state_serde!(RomuTrioX4, 12);
+
+#[cfg(feature = "portable")]
+impl From<crate::portable::RomuTrioX4> for RomuTrioX4 {
+    fn from(rng: crate::portable::RomuTrioX4) -> Self {
+        Self::from_state(rng.state())
+    }
+}
+
+#[cfg(feature = "portable")]
+impl From<RomuTrioX4> for crate::portable::RomuTrioX4 {
+    fn from(rng: RomuTrioX4) -> Self {
+        Self::from_state(rng.state())
+    }
+}
+
+impl SeedableRng for RomuTrioX4 {
+    type Seed = RomuTrioX4Seed;
+
+    /// Reads the `x`, `y` and `z` lane words, a lane whose words are all zero only outputs zeros.
+    #[allow(clippy::identity_op, clippy::erasing_op)]
+    fn from_seed(seed: Self::Seed) -> Self {
+        const VECSIZE: usize = mem::size_of::<u64>() * 4;
+
+        let x = read_u64_into_vec(&seed[(VECSIZE * 0)..(VECSIZE * 1)]);
+        let y = read_u64_into_vec(&seed[(VECSIZE * 1)..(VECSIZE * 2)]);
+        let z = read_u64_into_vec(&seed[(VECSIZE * 2)..(VECSIZE * 3)]);
+
+        Self { x, y, z }
+    }
+
+    /// Fills the seed with the output of [`SplitMix64X4`](super::SplitMix64X4) seeded with `seed`.
+    fn seed_from_u64(seed: u64) -> Self {
+        Self::from_seed(RomuTrioX4Seed::new(expand_seed::<4, 96>(seed)))
+    }
+}
+
+impl SimdRand for RomuTrioX4 {
+    #[inline(always)]
+    fn next_m256i(&mut self) -> __m256i {
+        unsafe {
+            let (x, y, z) = (self.x, self.y, self.z);
+
+            self.x = mul_lo(z, _mm256_set1_epi64x(MULTIPLIER.cast_signed()));
+            self.y = rotate_left::<12>(_mm256_sub_epi64(y, x));
+            self.z = rotate_left::<44>(_mm256_sub_epi64(z, y));
+
+            x
+        }
+    }
+}
+
+super::simdrand::impl_simd_rng!(RomuTrioX4);
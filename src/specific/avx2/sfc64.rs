@@ -0,0 +1,235 @@
+use core::{
+    arch::x86_64::*,
+    fmt,
+    ops::{Deref, DerefMut},
+};
+
+use rand_core::SeedableRng;
+
+use crate::scalar::Sfc64;
+use crate::sfc64::{WARMUP_ROUNDS, lane_counters};
+use crate::specific::avx2::{lanes, read_u64_into_vec, state_to_vectors, vectors_to_state};
+use crate::splitmix64::expand_seed;
+use crate::state::{lane_state, lanes_to_state};
+
+use super::{rotate_left, simdrand::*};
+
+#[derive(Clone, Default)]
+pub struct Sfc64X4Seed([u8; 32]);
+
+impl Sfc64X4Seed {
+    #[must_use]
+    pub const fn new(seed: [u8; 32]) -> Self {
+        Self(seed)
+    }
+}
+
+impl From<[u8; 32]> for Sfc64X4Seed {
+    fn from(val: [u8; 32]) -> Self {
+        Self::new(val)
+    }
+}
+
+impl From<&[u8]> for Sfc64X4Seed {
+    fn from(val: &[u8]) -> Self {
+        assert_eq!(val.len(), 32);
+        let mut seed = [0u8; 32];
+        seed.copy_from_slice(val);
+        Self::new(seed)
+    }
+}
+
+impl Deref for Sfc64X4Seed {
+    type Target = [u8; 32];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl DerefMut for Sfc64X4Seed {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl AsRef<[u8]> for Sfc64X4Seed {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsMut<[u8]> for Sfc64X4Seed {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+/// SFC64 with 4 independent lanes, each the stream of [`Sfc64`] from its own state.
+///
+/// Every lane has its own `counter` word, which guarantees a period of at least 2^64 per lane whatever the other
+/// words are. The counters start apart, lane `i` is stream `i` of [`Sfc64::with_stream`], so lanes seeded with the
+/// same word still run separate streams.
+/// The step is only additions, shifts, a rotation and a xor, all of which map directly to vector instructions.
+#[derive(Clone)]
+#[repr(align(32))]
+pub struct Sfc64X4 {
+    a: __m256i,
+    b: __m256i,
+    c: __m256i,
+    counter: __m256i,
+}
+
+impl Sfc64X4 {
+    /// Seeds lane `i` like [`Sfc64::with_stream`] with `seeds[i]` and stream `stream * 4 + i`.
+    ///
+    /// `from_seed` is stream 0, other streams keep the lanes of generators seeded with the same words apart.
+    #[must_use]
+    pub fn with_stream(seeds: [u64; 4], stream: u64) -> Self {
+        let [seed] = state_to_vectors(&seeds);
+        let [counter] = state_to_vectors(&lane_counters::<4>(stream));
+
+        Self::seeded(seed, counter)
+    }
+
+    fn seeded(seed: __m256i, counter: __m256i) -> Self {
+        let mut rng = Self {
+            a: seed,
+            b: seed,
+            c: seed,
+            counter,
+        };
+        for _ in 0..WARMUP_ROUNDS {
+            rng.next_m256i();
+        }
+
+        rng
+    }
+
+    /// Returns the generator state as `a`, `b`, `c` and `counter` lane words.
+    ///
+    /// Pass it to [`Sfc64X4::from_state`] to resume the exact same stream later.
+    #[must_use]
+    pub fn state(&self) -> [u64; 16] {
+        vectors_to_state([self.a, self.b, self.c, self.counter])
+    }
+
+    /// Restores a generator from a snapshot taken with [`Sfc64X4::state`].
+    #[must_use]
+    pub fn from_state(state: [u64; 16]) -> Self {
+        let [a, b, c, counter] = state_to_vectors(&state);
+
+        Self { a, b, c, counter }
+    }
+
+    /// Returns a scalar generator positioned at the same point in the stream of lane `lane`.
+    ///
+    /// # Panics
+    ///
+    /// If `lane` is not less than 4.
+    #[must_use]
+    pub fn lane(&self, lane: usize) -> Sfc64 {
+        Sfc64::from_state(lane_state::<4, 16, 4>(&self.state(), lane))
+    }
+
+    /// Splits the generator into one scalar generator per lane, each continuing its lane's stream.
+    #[must_use]
+    pub fn into_lanes(self) -> [Sfc64; 4] {
+        let state = self.state();
+
+        core::array::from_fn(|lane| Sfc64::from_state(lane_state::<4, 16, 4>(&state, lane)))
+    }
+
+    /// Assembles a generator from scalar generators, lane `i` continues the stream of `lanes[i]`.
+    #[must_use]
+    pub fn from_lanes(lanes: [Sfc64; 4]) -> Self {
+        Self::from_state(lanes_to_state::<4, 16, 4>(&lanes.map(|lane| lane.state())))
+    }
+}
+
+impl fmt::Debug for Sfc64X4 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Sfc64X4")
+            .field("a", &lanes(self.a))
+            .field("b", &lanes(self.b))
+            .field("c", &lanes(self.c))
+            .field("counter", &lanes(self.counter))
+            .finish()
+    }
+}
+
+impl PartialEq for Sfc64X4 {
+    fn eq(&self, other: &Self) -> bool {
+        self.state() == other.state()
+    }
+}
+
+impl Eq for Sfc64X4 {}
+
+#[cfg(feature = "serde")]
+crate::serde::impl_state_serde!(Sfc64X4, 16);
+
+#[cfg(feature = "portable")]
+impl From<crate::portable::Sfc64X4> for Sfc64X4 {
+    fn from(rng: crate::portable::Sfc64X4) -> Self {
+        Self::from_state(rng.state())
+    }
+}
+
+#[cfg(feature = "portable")]
+impl From<Sfc64X4> for crate::portable::Sfc64X4 {
+    fn from(rng: Sfc64X4) -> Self {
+        Self::from_state(rng.state())
+    }
+}
+
+impl SeedableRng for Sfc64X4 {
+    type Seed = Sfc64X4Seed;
+
+    /// Seeds lane `i` with word `i` of the seed, stream 0 of [`Sfc64X4::with_stream`].
+    fn from_seed(seed: Self::Seed) -> Self {
+        let [counter] = state_to_vectors(&lane_counters::<4>(0));
+
+        Self::seeded(read_u64_into_vec(&seed[..]), counter)
+    }
+
+    /// Fills the seed with the output of [`SplitMix64X4`](super::SplitMix64X4) seeded with `seed`.
+    fn seed_from_u64(seed: u64) -> Self {
+        Self::from_seed(Sfc64X4Seed::new(expand_seed::<4, 32>(seed)))
+    }
+}
+
+impl SimdRand for Sfc64X4 {
+    #[inline(always)]
+    fn next_m256i(&mut self) -> __m256i {
+        unsafe {
+            let tmp = _mm256_add_epi64(_mm256_add_epi64(self.a, self.b), self.counter);
+            self.counter = _mm256_add_epi64(self.counter, _mm256_set1_epi64x(1));
+            self.a = _mm256_xor_si256(self.b, _mm256_srli_epi64::<11>(self.b));
+            self.b = _mm256_add_epi64(self.c, _mm256_slli_epi64::<3>(self.c));
+            self.c = _mm256_add_epi64(rotate_left::<24>(self.c), tmp);
+
+            tmp
+        }
+    }
+}
+
+super::simdrand::impl_simd_rng!(Sfc64X4);
+
+#[cfg(test)]
+mod tests {
+    use rand_core::RngCore;
+
+    use super::{Sfc64, Sfc64X4, SimdRand};
+
+    #[test]
+    fn lanes_with_the_same_seed_diverge() {
+        let mut rng = Sfc64X4::with_stream([0x0123456789abcdef; 4], 3);
+        let mut lanes: [_; 4] = core::array::from_fn(|lane| Sfc64::with_stream(0x0123456789abcdef, 12 + lane as u64));
+
+        for _ in 0..64 {
+            let vector = *rng.next_u64x4();
+            assert_eq!(vector, lanes.each_mut().map(RngCore::next_u64));
+            assert!((1..4).all(|lane| vector[lane] != vector[0]), "{vector:?}");
+        }
+    }
+}
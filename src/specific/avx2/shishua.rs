@@ -1,5 +1,5 @@
 use core::ptr::NonNull;
-use core::{arch::x86_64::*, mem::size_of};
+use core::{arch::x86_64::*, fmt, mem::size_of};
 
 use alloc::alloc;
 use core::alloc::{Layout, LayoutError};
@@ -7,7 +7,7 @@ use core::mem;
 use rand_core::le::read_u64_into;
 use rand_core::{RngCore, SeedableRng};
 
-use super::simdrand::*;
+use super::{lanes, simdrand::*, state_to_vectors, vectors_to_state};
 
 pub const DEFAULT_BUFFER_SIZE: usize = 1024 * 32;
 
@@ -36,6 +36,8 @@ pub struct Shishua<const BUFFER_SIZE: usize = DEFAULT_BUFFER_SIZE> {
 }
 
 const BUFFERED_STATE_ALIGNMENT: usize = 128;
+/// Bytes produced per iteration of the generator core (4 x `__m256i`).
+const BLOCK_SIZE: usize = 128;
 const fn get_buffered_state_layout_unchecked<const BUFFER_SIZE: usize>() -> Layout {
     unsafe { Layout::from_size_align_unchecked(size_of::<BufferedState<BUFFER_SIZE>>(), BUFFERED_STATE_ALIGNMENT) }
 }
@@ -63,6 +65,99 @@ impl<const BUFFER_SIZE: usize> Shishua<BUFFER_SIZE> {
         };
     }
 
+    /// Fills `dest` by generating straight into it, bypassing the internal buffer.
+    ///
+    /// Bytes that are still buffered are handed out first so the stream stays continuous,
+    /// then whole 128-byte blocks are written directly into `dest` without an intermediate copy.
+    /// A trailing partial block is served by rebuffering, leaving the rest of that block buffered
+    /// for subsequent calls. [`RngCore::fill_bytes`] takes this path whenever a request
+    /// doesn't fit in what is currently buffered, so `dest` may be of any length.
+    #[inline(always)]
+    pub fn fill_bytes_unbuffered(&mut self, dest: &mut [u8]) {
+        let state = unsafe { self.state.as_mut() };
+        state.fill_unbuffered(dest);
+    }
+
+    /// Returns the generator state as 37 words: the `state`, `output` and `counter` lane words of the
+    /// core the current buffer was generated from, followed by the buffer index.
+    ///
+    /// The buffer itself is not part of the snapshot, [`Shishua::from_state`] regenerates it
+    /// and resumes at the same index, so the restored generator continues the exact same stream.
+    #[must_use]
+    pub fn state(&self) -> [u64; 37] {
+        let (origin, buffer_index) = self.snapshot();
+
+        let mut words = [0; 37];
+        words[..36].copy_from_slice(&origin.to_words());
+        words[36] = buffer_index as u64;
+        words
+    }
+
+    /// The core the current buffer was generated from and the index into the buffer, what [`Shishua::state`],
+    /// `Debug` and `PartialEq` see of the generator.
+    const fn snapshot(&self) -> (&RawState, usize) {
+        let state = unsafe { self.state.as_ref() };
+
+        // A spent buffer is equivalent to a fresh one generated from the current core state
+        if state.buffer_index == BUFFER_SIZE {
+            (&state.state, 0)
+        } else {
+            (&state.buffer_origin, state.buffer_index)
+        }
+    }
+
+    /// Restores a generator from a snapshot taken with [`Shishua::state`].
+    ///
+    /// # Panics
+    ///
+    /// If the buffer index in the snapshot is larger than `BUFFER_SIZE`.
+    #[must_use]
+    pub fn from_state(state: [u64; 37]) -> Self {
+        let buffer_index = usize::try_from(state[36]).unwrap_or(usize::MAX);
+        assert!(
+            buffer_index <= BUFFER_SIZE,
+            "The Shishua buffer index must be <= the buffer size"
+        );
+
+        let mut words = [0; 36];
+        words.copy_from_slice(&state[..36]);
+
+        Self::allocate(|buffered_state| {
+            buffered_state.state = RawState::from_words(&words);
+            buffered_state.rebuffer();
+            buffered_state.buffer_index = buffer_index;
+        })
+    }
+
+    fn allocate(init: impl FnOnce(&mut BufferedState<BUFFER_SIZE>)) -> Self {
+        assert!(
+            get_buffered_state_layout::<BUFFER_SIZE>().is_ok(),
+            "couldnt construct memory layout of Shishua buffered state"
+        );
+        assert!(
+            BUFFER_SIZE.is_power_of_two(),
+            "The Shishua buffer size must be a power of 2"
+        );
+        assert!(
+            BUFFER_SIZE >= 256,
+            "The Shishua buffer size must be >= 256 (and power of 2)"
+        );
+
+        let ptr = unsafe {
+            let ptr = alloc::alloc(Self::LAYOUT).cast::<BufferedState<BUFFER_SIZE>>();
+
+            if ptr.is_null() {
+                alloc::handle_alloc_error(Self::LAYOUT);
+            }
+
+            init(ptr.as_mut().unwrap_unchecked());
+
+            NonNull::new_unchecked(ptr)
+        };
+
+        Self { state: ptr }
+    }
+
     #[inline(always)]
     #[must_use]
     pub const fn buffer_index(&self) -> usize {
@@ -91,6 +186,60 @@ impl<const BUFFER_SIZE: usize> Shishua<BUFFER_SIZE> {
     }
 }
 
+impl<const BUFFER_SIZE: usize> Clone for Shishua<BUFFER_SIZE> {
+    /// Deep copies the heap allocated state, including the buffered bytes.
+    fn clone(&self) -> Self {
+        let source = self.state.as_ptr();
+
+        Self::allocate(|buffered_state| unsafe {
+            core::ptr::copy_nonoverlapping(source, buffered_state, 1);
+        })
+    }
+}
+
+impl<const BUFFER_SIZE: usize> fmt::Debug for Shishua<BUFFER_SIZE> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (origin, buffer_index) = self.snapshot();
+
+        f.debug_struct("Shishua")
+            .field("state", &origin.state.map(lanes))
+            .field("output", &origin.output.map(lanes))
+            .field("counter", &lanes(origin.counter))
+            .field("buffer_index", &buffer_index)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<const BUFFER_SIZE: usize> PartialEq for Shishua<BUFFER_SIZE> {
+    /// Generators are equal when they will produce the same stream from here on.
+    fn eq(&self, other: &Self) -> bool {
+        self.state() == other.state()
+    }
+}
+
+impl<const BUFFER_SIZE: usize> Eq for Shishua<BUFFER_SIZE> {}
+
+#[cfg(feature = "serde")]
+impl<const BUFFER_SIZE: usize> serde::Serialize for Shishua<BUFFER_SIZE> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::serde::serialize_state(&self.state(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, const BUFFER_SIZE: usize> serde::Deserialize<'de> for Shishua<BUFFER_SIZE> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let state = crate::serde::deserialize_state(deserializer)?;
+
+        let buffer_index = usize::try_from(state[36]).unwrap_or(usize::MAX);
+        if buffer_index > BUFFER_SIZE {
+            return Err(serde::de::Error::custom("Shishua buffer index exceeds the buffer size"));
+        }
+
+        Ok(Self::from_state(state))
+    }
+}
+
 impl<const BUFFER_SIZE: usize> SimdRand for Shishua<BUFFER_SIZE> {
     #[inline(always)]
     fn next_m256i(&mut self) -> __m256i {
@@ -113,42 +262,21 @@ impl<const BUFFER_SIZE: usize> SimdRand for Shishua<BUFFER_SIZE> {
     }
 }
 
+super::simdrand::impl_simd_rng!(impl<const BUFFER_SIZE: usize> Shishua<BUFFER_SIZE>);
+
 impl<const BUFFER_SIZE: usize> SeedableRng for Shishua<BUFFER_SIZE> {
     type Seed = [u8; 32];
 
     fn from_seed(seed: Self::Seed) -> Self {
-        assert!(
-            get_buffered_state_layout::<BUFFER_SIZE>().is_ok(),
-            "couldnt construct memory layout of Shishua buffered state"
-        );
-        assert!(
-            BUFFER_SIZE.is_power_of_two(),
-            "The Shishua buffer size must be a power of 2"
-        );
-        assert!(
-            BUFFER_SIZE >= 256,
-            "The Shishua buffer size must be >= 256 (and power of 2)"
-        );
-
-        let ptr = unsafe {
-            let ptr = alloc::alloc(Self::LAYOUT).cast::<BufferedState<BUFFER_SIZE>>();
+        let mut iseed = [0; 4];
+        read_u64_into(&seed[..], iseed.as_mut_slice());
 
-            if ptr.is_null() {
-                alloc::handle_alloc_error(Self::LAYOUT);
+        Self::allocate(|buffered_state| {
+            unsafe {
+                buffered_state.state.prng_init(&iseed);
             }
-
-            let buffered_state = ptr.as_mut().unwrap_unchecked();
-
-            let mut iseed = [0; 4];
-            read_u64_into(&seed[..], iseed.as_mut_slice());
-
-            buffered_state.state.prng_init(&iseed);
             buffered_state.rebuffer();
-
-            NonNull::new_unchecked(ptr)
-        };
-
-        Self { state: ptr }
+        })
     }
 }
 
@@ -185,7 +313,10 @@ impl<const BUFFER_SIZE: usize> RngCore for Shishua<BUFFER_SIZE> {
         unsafe {
             let state = self.state.as_mut();
 
-            state.ensure_buffered(size);
+            if BUFFER_SIZE - state.buffer_index < size {
+                state.fill_unbuffered(dest);
+                return;
+            }
 
             let src = state
                 .buffer
@@ -200,6 +331,8 @@ impl<const BUFFER_SIZE: usize> RngCore for Shishua<BUFFER_SIZE> {
 
 struct BufferedState<const BUFFER_SIZE: usize> {
     state: RawState,
+    // Core state the current buffer was generated from, lets `Shishua::state` snapshot without the buffer
+    buffer_origin: RawState,
     buffer: BufferedStateBuffer<BUFFER_SIZE>,
     buffer_index: usize,
 }
@@ -215,9 +348,33 @@ impl<const BUFFER_SIZE: usize> BufferedState<BUFFER_SIZE> {
         }
     }
 
+    #[inline(never)] // Large requests are the rare case, keep the buffered hotpath small
+    fn fill_unbuffered(&mut self, dest: &mut [u8]) {
+        // Drain what is left of the buffer first. `BUFFER_SIZE` is a multiple of the block size,
+        // so once the buffer is spent we are positioned on a block boundary of the stream.
+        let buffered = (BUFFER_SIZE - self.buffer_index).min(dest.len());
+        let (head, rest) = dest.split_at_mut(buffered);
+        head.copy_from_slice(&self.buffer.0[self.buffer_index..self.buffer_index + buffered]);
+        self.buffer_index += buffered;
+
+        let (blocks, tail) = rest.split_at_mut(rest.len() - rest.len() % BLOCK_SIZE);
+        if !blocks.is_empty() {
+            unsafe {
+                self.state.prng_gen(blocks);
+            }
+        }
+
+        if !tail.is_empty() {
+            self.rebuffer();
+            tail.copy_from_slice(&self.buffer.0[..tail.len()]);
+            self.buffer_index = tail.len();
+        }
+    }
+
     #[cold] // This attribute seems to make LLVM organize jumps/branches better
     #[inline(never)] // This should not be inlined, as entering the branch above is the rare case
     fn rebuffer(&mut self) {
+        self.buffer_origin = self.state;
         unsafe {
             self.state.prng_gen(&mut self.buffer.0[..]);
         }
@@ -225,6 +382,7 @@ impl<const BUFFER_SIZE: usize> BufferedState<BUFFER_SIZE> {
     }
 }
 
+#[derive(Clone, Copy)]
 struct RawState {
     state: [__m256i; 4],
     output: [__m256i; 4],
@@ -232,6 +390,21 @@ struct RawState {
 }
 
 impl RawState {
+    fn to_words(self) -> [u64; 36] {
+        let [s0, s1, s2, s3] = self.state;
+        let [o0, o1, o2, o3] = self.output;
+        vectors_to_state([s0, s1, s2, s3, o0, o1, o2, o3, self.counter])
+    }
+
+    fn from_words(words: &[u64; 36]) -> Self {
+        let [s0, s1, s2, s3, o0, o1, o2, o3, counter] = state_to_vectors(words);
+        Self {
+            state: [s0, s1, s2, s3],
+            output: [o0, o1, o2, o3],
+            counter,
+        }
+    }
+
     // Wrapping u64→i64 is intentional; SHISHUA uses signed arithmetic on seed values
     #[allow(clippy::cast_possible_wrap)]
     unsafe fn prng_init(&mut self, seed: &[u64; 4]) {
@@ -277,10 +450,10 @@ impl RawState {
         }
     }
 
-    // Buffer is allocated with __m256i alignment; u8 pointer cast is safe
+    // Stores are unaligned, so `buf` may be any caller slice of whole blocks
     #[allow(clippy::cast_ptr_alignment)]
     unsafe fn prng_gen(&mut self, buf: &mut [u8]) {
-        assert!(buf.len().is_multiple_of(128));
+        assert!(buf.len().is_multiple_of(BLOCK_SIZE));
 
         // SAFETY: caller guarantees AVX2 is available and `buf` is writable.
         unsafe {
@@ -308,7 +481,7 @@ impl RawState {
             let increment = _mm256_set_epi64x(1, 3, 5, 7);
 
             let buf_ptr = buf.as_mut_ptr();
-            for i in (0..buf.len()).step_by(128) {
+            for i in (0..buf.len()).step_by(BLOCK_SIZE) {
                 _mm256_storeu_si256(buf_ptr.add(i).cast::<__m256i>(), o0);
                 _mm256_storeu_si256(buf_ptr.add(i + 32).cast::<__m256i>(), o1);
                 _mm256_storeu_si256(buf_ptr.add(i + 64).cast::<__m256i>(), o2);
@@ -475,6 +648,15 @@ mod tests {
         assert!(buf_alignment % 32 == 0);
     }
 
+    #[test]
+    fn clone_allocates_separate_state() {
+        let rng = super::Shishua::<256>::from_seed([0; 32]);
+        let fork = rng.clone();
+
+        assert_ne!(rng.state.as_ptr(), fork.state.as_ptr());
+        assert_eq!(rng.buffer_index(), fork.buffer_index());
+    }
+
     #[test]
     fn reference_zero() {
         unsafe {
@@ -1,73 +1,122 @@
-use core::{arch::x86_64::*, mem::transmute};
+use core::arch::x86_64::*;
 
 use super::vecs::*;
 
 pub trait SimdRand {
     fn next_m256i(&mut self) -> __m256i;
 
-    #[allow(clippy::items_after_statements)]
+    /// Vigna's recommended conversion: (x >> 11) * 2^-53 (<https://prng.di.unimi.it/>).
     #[inline(always)]
     fn next_m256d(&mut self) -> __m256d {
-        unsafe {
-            let v = self.next_m256i();
-
-            let lhs = m256i_to_m256d(_mm256_srli_epi64::<11>(v));
-
-            // PERF: This is precomputed based on the constants from the formula above
-            // I found no other efficient (and succint) constant way of representing the RHS.
-            // setzero and constants used in shifts like 11 above are automatically constant folded.
-            // Writing out the actual formula ended up not being constant folded by the compiler.
-            const RHS_FACTOR: [f64; 4] = [1.1102230246251565E-16; 4];
-            const RHS: __m256d = unsafe { transmute::<[f64; 4], __m256d>(RHS_FACTOR) };
-
-            _mm256_mul_pd(lhs, RHS)
-        }
+        unsafe { m256i_to_m256d(self.next_m256i()) }
     }
 
     #[inline(always)]
     fn next_u64x4(&mut self) -> U64x4 {
-        unsafe {
-            let v = self.next_m256i();
-            let mut vector = U64x4::default();
-            _mm256_store_si256(core::ptr::from_mut(&mut vector).cast::<__m256i>(), v);
-            vector
-        }
+        self.next_m256i().into()
     }
 
     #[inline(always)]
     fn next_f64x4(&mut self) -> F64x4 {
-        unsafe {
-            let v = self.next_m256d();
-            let mut vector = F64x4::default();
-            _mm256_store_pd(core::ptr::from_mut(&mut vector).cast::<f64>(), v);
-            vector
-        }
+        self.next_m256d().into()
     }
 }
 
+#[allow(clippy::items_after_statements)]
 #[inline(always)]
 #[cfg(all(target_arch = "x86_64", target_feature = "avx512dq", target_feature = "avx512vl"))]
 unsafe fn m256i_to_m256d(v: __m256i) -> __m256d {
-    unsafe { _mm256_cvtepu64_pd(v) }
+    unsafe {
+        let lhs = _mm256_cvtepu64_pd(_mm256_srli_epi64::<11>(v));
+
+        // PERF: This is precomputed based on the constants from the formula above
+        // I found no other efficient (and succint) constant way of representing the RHS.
+        // setzero and constants used in shifts like 11 above are automatically constant folded.
+        // Writing out the actual formula ended up not being constant folded by the compiler.
+        const RHS_FACTOR: [f64; 4] = [1.1102230246251565E-16; 4];
+        const RHS: __m256d = unsafe { core::mem::transmute::<[f64; 4], __m256d>(RHS_FACTOR) };
+
+        _mm256_mul_pd(lhs, RHS)
+    }
 }
 
+#[inline(always)]
 #[cfg(not(all(target_arch = "x86_64", target_feature = "avx512dq", target_feature = "avx512vl")))]
 unsafe fn m256i_to_m256d(v: __m256i) -> __m256d {
-    // No direct conv intrinsic in AVX2, this "hack" is from
-    // https://stackoverflow.com/questions/41144668/how-to-efficiently-perform-double-int64-conversions-with-sse-avx
-    // Unfortunately not faster than just loading and operating on the scalars
+    // AVX2 has no u64 -> f64 conversion, but the 53 bits we keep need none. The float is built from the bits like
+    // `multiply_from_bits` in `portable::simdrand`, which explains why the result is exact.
     // SAFETY: relies on AVX2 being enabled for the build.
     unsafe {
-        let magic_i_lo = _mm256_set1_epi64x(0x4330000000000000);
-        let magic_i_hi32 = _mm256_set1_epi64x(0x4530000000000000);
-        let magic_i_all = _mm256_set1_epi64x(0x4530000000100000);
-        let magic_d_all = _mm256_castsi256_pd(magic_i_all);
-
-        let v_lo = _mm256_blend_epi32(magic_i_lo, v, 0b01010101);
-        let v_hi = _mm256_srli_epi64::<32>(v);
-        let v_hi = _mm256_xor_si256(v_hi, magic_i_hi32);
-        let v_hi_dbl = _mm256_sub_pd(_mm256_castsi256_pd(v_hi), magic_d_all);
-        let result = _mm256_add_pd(v_hi_dbl, _mm256_castsi256_pd(v_lo));
-        result
+        let half = _mm256_set1_epi64x(0x3FE0000000000000);
+        let mantissa = _mm256_and_si256(_mm256_srli_epi64::<11>(v), _mm256_set1_epi64x(0x000FFFFFFFFFFFFF));
+        // Negative as i64 when the top bit is set
+        let top_bit = _mm256_cmpgt_epi64(_mm256_setzero_si256(), v);
+        let subtrahend = _mm256_andnot_si256(top_bit, half);
+
+        _mm256_sub_pd(
+            _mm256_castsi256_pd(_mm256_or_si256(mantissa, half)),
+            _mm256_castsi256_pd(subtrahend),
+        )
+    }
+}
+
+/// Implements [`SimdRng`](crate::SimdRng) for a generator through its [`SimdRand`] implementation.
+macro_rules! impl_simd_rng {
+    (impl<$(const $param:ident: $kind:ty),*> $rng:ty) => {
+        impl<$(const $param: $kind),*> crate::SimdRng for $rng {
+            const LANES: usize = 4;
+
+            type U64Vec = crate::specific::avx2::U64x4;
+            type F64Vec = crate::specific::avx2::F64x4;
+
+            #[inline(always)]
+            fn next_u64_vec(&mut self) -> Self::U64Vec {
+                crate::specific::avx2::SimdRand::next_u64x4(self)
+            }
+
+            #[inline(always)]
+            fn next_f64_vec(&mut self) -> Self::F64Vec {
+                crate::specific::avx2::SimdRand::next_f64x4(self)
+            }
+        }
+    };
+    ($rng:ty) => {
+        crate::specific::avx2::impl_simd_rng!(impl<> $rng);
+    };
+}
+
+pub(crate) use impl_simd_rng;
+
+#[cfg(test)]
+mod tests {
+    use core::arch::x86_64::*;
+
+    use super::{SimdRand, m256i_to_m256d};
+    use crate::simdrng::test_support::{F64_EDGES, reference_f64_bits};
+    use crate::specific::avx2::{F64x4, U64x4};
+
+    struct Words(U64x4);
+
+    impl SimdRand for Words {
+        fn next_m256i(&mut self) -> __m256i {
+            self.0.into()
+        }
+    }
+
+    #[test]
+    fn next_m256d_matches_scalar_conversion() {
+        let mut state = 0x0123456789abcdefu64;
+        let random = (0..4096).map(|_| {
+            let words: [u64; 4] = core::array::from_fn(|_| crate::splitmix64::next(&mut state));
+            words
+        });
+
+        for words in random.chain(F64_EDGES.map(|edge| [edge; 4])) {
+            let expected = words.map(reference_f64_bits);
+
+            let converted = F64x4::from(unsafe { m256i_to_m256d(U64x4::from(words).into()) });
+            assert_eq!(converted.map(f64::to_bits), expected);
+            assert_eq!(Words(words.into()).next_f64x4().map(f64::to_bits), expected);
+        }
     }
 }
@@ -0,0 +1,183 @@
+use core::{
+    arch::x86_64::*,
+    fmt,
+    ops::{Deref, DerefMut},
+};
+
+use rand_core::SeedableRng;
+
+use crate::scalar::SplitMix64;
+use crate::specific::avx2::{lanes, read_u64_into_vec, state_to_vectors, vectors_to_state};
+use crate::splitmix64::{GAMMA, MUL_0, MUL_1, lane_states};
+use crate::state::{lane_state, lanes_to_state};
+
+use super::{mul_lo, simdrand::*};
+
+#[derive(Clone, Default)]
+pub struct SplitMix64X4Seed([u8; 32]);
+
+impl SplitMix64X4Seed {
+    #[must_use]
+    pub const fn new(seed: [u8; 32]) -> Self {
+        Self(seed)
+    }
+}
+
+impl From<[u8; 32]> for SplitMix64X4Seed {
+    fn from(val: [u8; 32]) -> Self {
+        Self::new(val)
+    }
+}
+
+impl From<&[u8]> for SplitMix64X4Seed {
+    fn from(val: &[u8]) -> Self {
+        assert_eq!(val.len(), 32);
+        let mut seed = [0u8; 32];
+        seed.copy_from_slice(val);
+        Self::new(seed)
+    }
+}
+
+impl Deref for SplitMix64X4Seed {
+    type Target = [u8; 32];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl DerefMut for SplitMix64X4Seed {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl AsRef<[u8]> for SplitMix64X4Seed {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsMut<[u8]> for SplitMix64X4Seed {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+/// SplitMix64 with 4 independent lanes, each the stream of [`SplitMix64`] from its own state.
+///
+/// Seeded with [`SeedableRng::seed_from_u64`], the lanes start at the first 4 outputs of the scalar stream of the
+/// seed, and the output vectors are what the other generators' `seed_from_u64` fill their seeds with.
+#[derive(Clone)]
+#[repr(align(32))]
+pub struct SplitMix64X4 {
+    x: __m256i,
+}
+
+impl SplitMix64X4 {
+    /// Returns the generator state as the `x` word of every lane, in the same layout `from_seed` reads.
+    ///
+    /// Pass it to [`SplitMix64X4::from_state`] to resume the exact same stream later.
+    #[must_use]
+    pub fn state(&self) -> [u64; 4] {
+        vectors_to_state([self.x])
+    }
+
+    /// Restores a generator from a snapshot taken with [`SplitMix64X4::state`].
+    #[must_use]
+    pub fn from_state(state: [u64; 4]) -> Self {
+        let [x] = state_to_vectors(&state);
+
+        Self { x }
+    }
+
+    /// Returns a scalar generator positioned at the same point in the stream of lane `lane`.
+    ///
+    /// # Panics
+    ///
+    /// If `lane` is not less than 4.
+    #[must_use]
+    pub fn lane(&self, lane: usize) -> SplitMix64 {
+        SplitMix64::from_state(lane_state::<4, 4, 1>(&self.state(), lane))
+    }
+
+    /// Splits the generator into one scalar generator per lane, each continuing its lane's stream.
+    #[must_use]
+    pub fn into_lanes(self) -> [SplitMix64; 4] {
+        self.state().map(|x| SplitMix64::from_state([x]))
+    }
+
+    /// Assembles a generator from scalar generators, lane `i` continues the stream of `lanes[i]`.
+    #[must_use]
+    pub fn from_lanes(lanes: [SplitMix64; 4]) -> Self {
+        Self::from_state(lanes_to_state::<4, 4, 1>(&lanes.map(|lane| lane.state())))
+    }
+}
+
+impl fmt::Debug for SplitMix64X4 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SplitMix64X4").field("x", &lanes(self.x)).finish()
+    }
+}
+
+impl PartialEq for SplitMix64X4 {
+    fn eq(&self, other: &Self) -> bool {
+        self.state() == other.state()
+    }
+}
+
+impl Eq for SplitMix64X4 {}
+
+#[cfg(feature = "serde")]
+crate::serde::impl_state_serde!(SplitMix64X4, 4);
+
+#[cfg(feature = "portable")]
+impl From<crate::portable::SplitMix64X4> for SplitMix64X4 {
+    fn from(rng: crate::portable::SplitMix64X4) -> Self {
+        Self::from_state(rng.state())
+    }
+}
+
+#[cfg(feature = "portable")]
+impl From<SplitMix64X4> for crate::portable::SplitMix64X4 {
+    fn from(rng: SplitMix64X4) -> Self {
+        Self::from_state(rng.state())
+    }
+}
+
+impl SeedableRng for SplitMix64X4 {
+    type Seed = SplitMix64X4Seed;
+
+    /// Reads the `x` word of every lane.
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self {
+            x: read_u64_into_vec(&seed[..]),
+        }
+    }
+
+    /// Starts the lanes at the first 4 outputs of the scalar SplitMix64 stream of `seed`.
+    fn seed_from_u64(seed: u64) -> Self {
+        Self::from_state(lane_states(seed))
+    }
+}
+
+impl SimdRand for SplitMix64X4 {
+    #[inline(always)]
+    fn next_m256i(&mut self) -> __m256i {
+        unsafe {
+            self.x = _mm256_add_epi64(self.x, _mm256_set1_epi64x(GAMMA.cast_signed()));
+
+            let z = self.x;
+            let z = mul_lo(
+                _mm256_xor_si256(z, _mm256_srli_epi64::<30>(z)),
+                _mm256_set1_epi64x(MUL_0.cast_signed()),
+            );
+            let z = mul_lo(
+                _mm256_xor_si256(z, _mm256_srli_epi64::<27>(z)),
+                _mm256_set1_epi64x(MUL_1.cast_signed()),
+            );
+            _mm256_xor_si256(z, _mm256_srli_epi64::<31>(z))
+        }
+    }
+}
+
+super::simdrand::impl_simd_rng!(SplitMix64X4);
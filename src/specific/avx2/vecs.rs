@@ -1,10 +1,13 @@
-use core::ops::{Deref, DerefMut};
+use core::{
+    arch::x86_64::*,
+    ops::{Deref, DerefMut},
+};
 
-#[derive(Default, Debug)]
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq)]
 #[repr(align(32))]
 pub struct U64x4([u64; 4]);
 
-#[derive(Default, Debug)]
+#[derive(Clone, Copy, Default, Debug, PartialEq)]
 #[repr(align(32))]
 pub struct F64x4([f64; 4]);
 
@@ -43,6 +46,40 @@ impl From<[u64; 4]> for U64x4 {
     }
 }
 
+impl From<U64x4> for [u64; 4] {
+    fn from(val: U64x4) -> Self {
+        val.0
+    }
+}
+
+impl From<__m256i> for U64x4 {
+    #[inline(always)]
+    fn from(val: __m256i) -> Self {
+        let mut vector = Self::default();
+        unsafe { _mm256_store_si256(core::ptr::from_mut(&mut vector).cast::<__m256i>(), val) };
+        vector
+    }
+}
+
+impl From<U64x4> for __m256i {
+    #[inline(always)]
+    fn from(val: U64x4) -> Self {
+        unsafe { _mm256_load_si256(core::ptr::from_ref(&val).cast::<Self>()) }
+    }
+}
+
+impl AsRef<[u64]> for U64x4 {
+    fn as_ref(&self) -> &[u64] {
+        &self.0
+    }
+}
+
+impl AsMut<[u64]> for U64x4 {
+    fn as_mut(&mut self) -> &mut [u64] {
+        &mut self.0
+    }
+}
+
 impl Deref for F64x4 {
     type Target = [f64; 4];
 
@@ -62,6 +99,40 @@ impl From<[f64; 4]> for F64x4 {
     }
 }
 
+impl From<F64x4> for [f64; 4] {
+    fn from(val: F64x4) -> Self {
+        val.0
+    }
+}
+
+impl From<__m256d> for F64x4 {
+    #[inline(always)]
+    fn from(val: __m256d) -> Self {
+        let mut vector = Self::default();
+        unsafe { _mm256_store_pd(vector.as_mut_ptr(), val) };
+        vector
+    }
+}
+
+impl From<F64x4> for __m256d {
+    #[inline(always)]
+    fn from(val: F64x4) -> Self {
+        unsafe { _mm256_load_pd(val.as_ptr()) }
+    }
+}
+
+impl AsRef<[f64]> for F64x4 {
+    fn as_ref(&self) -> &[f64] {
+        &self.0
+    }
+}
+
+impl AsMut<[f64]> for F64x4 {
+    fn as_mut(&mut self) -> &mut [f64] {
+        &mut self.0
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use core::{
@@ -108,4 +179,20 @@ mod tests {
                 .all(|(actual, expected)| actual.to_bits() == expected.to_bits())
         );
     }
+
+    #[test]
+    fn intrinsic_conversions_round_trip() {
+        let (ints, floats) = unsafe { (_mm256_set_epi64x(4, 3, 2, 1), _mm256_set_pd(4.5, 3.5, 2.5, 1.5)) };
+
+        let u64s = U64x4::from(ints);
+        let f64s = F64x4::from(floats);
+
+        assert_eq!(<[u64; 4]>::from(u64s), [1, 2, 3, 4]);
+        assert_eq!(
+            <[f64; 4]>::from(f64s).map(f64::to_bits),
+            [1.5, 2.5, 3.5, 4.5].map(f64::to_bits)
+        );
+        assert_eq!(U64x4::from(__m256i::from(u64s)), u64s);
+        assert_eq!(F64x4::from(__m256d::from(f64s)), f64s);
+    }
 }
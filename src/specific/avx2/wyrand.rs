@@ -0,0 +1,178 @@
+use core::{
+    arch::x86_64::*,
+    fmt,
+    ops::{Deref, DerefMut},
+};
+
+use rand_core::SeedableRng;
+
+use crate::scalar::WyRand;
+use crate::specific::avx2::{lanes, read_u64_into_vec, state_to_vectors, vectors_to_state};
+use crate::splitmix64::expand_seed;
+use crate::state::{lane_state, lanes_to_state};
+use crate::wyrand::{INCREMENT, XOR};
+
+use super::{mul_hi, mul_lo, simdrand::*};
+
+#[derive(Clone, Default)]
+pub struct WyRandX4Seed([u8; 32]);
+
+impl WyRandX4Seed {
+    #[must_use]
+    pub const fn new(seed: [u8; 32]) -> Self {
+        Self(seed)
+    }
+}
+
+impl From<[u8; 32]> for WyRandX4Seed {
+    fn from(val: [u8; 32]) -> Self {
+        Self::new(val)
+    }
+}
+
+impl From<&[u8]> for WyRandX4Seed {
+    fn from(val: &[u8]) -> Self {
+        assert_eq!(val.len(), 32);
+        let mut seed = [0u8; 32];
+        seed.copy_from_slice(val);
+        Self::new(seed)
+    }
+}
+
+impl Deref for WyRandX4Seed {
+    type Target = [u8; 32];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl DerefMut for WyRandX4Seed {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl AsRef<[u8]> for WyRandX4Seed {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsMut<[u8]> for WyRandX4Seed {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+/// WyRand with 4 independent lanes, each the stream of [`WyRand`] from its own state.
+///
+/// There is no 64x64 -> 128-bit vector multiply, so the high half of the product is composed from 32-bit partial
+/// products.
+#[derive(Clone)]
+#[repr(align(32))]
+pub struct WyRandX4 {
+    s: __m256i,
+}
+
+impl WyRandX4 {
+    /// Returns the generator state as the `s` word of every lane, in the same layout `from_seed` reads.
+    ///
+    /// Pass it to [`WyRandX4::from_state`] to resume the exact same stream later.
+    #[must_use]
+    pub fn state(&self) -> [u64; 4] {
+        vectors_to_state([self.s])
+    }
+
+    /// Restores a generator from a snapshot taken with [`WyRandX4::state`].
+    #[must_use]
+    pub fn from_state(state: [u64; 4]) -> Self {
+        let [s] = state_to_vectors(&state);
+
+        Self { s }
+    }
+
+    /// Returns a scalar generator positioned at the same point in the stream of lane `lane`.
+    ///
+    /// # Panics
+    ///
+    /// If `lane` is not less than 4.
+    #[must_use]
+    pub fn lane(&self, lane: usize) -> WyRand {
+        WyRand::from_state(lane_state::<4, 4, 1>(&self.state(), lane))
+    }
+
+    /// Splits the generator into one scalar generator per lane, each continuing its lane's stream.
+    #[must_use]
+    pub fn into_lanes(self) -> [WyRand; 4] {
+        let state = self.state();
+
+        core::array::from_fn(|lane| WyRand::from_state(lane_state::<4, 4, 1>(&state, lane)))
+    }
+
+    /// Assembles a generator from scalar generators, lane `i` continues the stream of `lanes[i]`.
+    #[must_use]
+    pub fn from_lanes(lanes: [WyRand; 4]) -> Self {
+        Self::from_state(lanes_to_state::<4, 4, 1>(&lanes.map(|lane| lane.state())))
+    }
+}
+
+impl fmt::Debug for WyRandX4 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WyRandX4").field("s", &lanes(self.s)).finish()
+    }
+}
+
+impl PartialEq for WyRandX4 {
+    fn eq(&self, other: &Self) -> bool {
+        self.state() == other.state()
+    }
+}
+
+impl Eq for WyRandX4 {}
+
+#[cfg(feature = "serde")]
+crate::serde::impl_state_serde!(WyRandX4, 4);
+
+#[cfg(feature = "portable")]
+impl From<crate::portable::WyRandX4> for WyRandX4 {
+    fn from(rng: crate::portable::WyRandX4) -> Self {
+        Self::from_state(rng.state())
+    }
+}
+
+#[cfg(feature = "portable")]
+impl From<WyRandX4> for crate::portable::WyRandX4 {
+    fn from(rng: WyRandX4) -> Self {
+        Self::from_state(rng.state())
+    }
+}
+
+impl SeedableRng for WyRandX4 {
+    type Seed = WyRandX4Seed;
+
+    /// Reads the `s` word of every lane.
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self {
+            s: read_u64_into_vec(&seed[..]),
+        }
+    }
+
+    /// Fills the seed with the output of [`SplitMix64X4`](super::SplitMix64X4) seeded with `seed`.
+    fn seed_from_u64(seed: u64) -> Self {
+        Self::from_seed(WyRandX4Seed::new(expand_seed::<4, 32>(seed)))
+    }
+}
+
+impl SimdRand for WyRandX4 {
+    #[inline(always)]
+    fn next_m256i(&mut self) -> __m256i {
+        unsafe {
+            self.s = _mm256_add_epi64(self.s, _mm256_set1_epi64x(INCREMENT.cast_signed()));
+
+            let t = _mm256_xor_si256(self.s, _mm256_set1_epi64x(XOR.cast_signed()));
+            _mm256_xor_si256(mul_hi(self.s, t), mul_lo(self.s, t))
+        }
+    }
+}
+
+super::simdrand::impl_simd_rng!(WyRandX4);
@@ -1,12 +1,15 @@
 use core::{
     arch::x86_64::*,
-    mem,
+    fmt, mem,
     ops::{Deref, DerefMut},
 };
 
 use rand_core::SeedableRng;
 
-use crate::specific::avx2::read_u64_into_vec;
+use crate::scalar::Xoshiro256PlusPlus;
+use crate::specific::avx2::{lanes, read_u64_into_vec, state_to_vectors, vectors_to_state};
+use crate::splitmix64::expand_seed;
+use crate::state::{lane_state, lanes_to_state};
 
 use super::{rotate_left, simdrand::*};
 
@@ -48,6 +51,7 @@ impl DerefMut for Xoshiro256PlusPlusX4Seed {
     }
 }
 
+#[derive(Clone)]
 #[repr(align(32))]
 pub struct Xoshiro256PlusPlusX4 {
     s0: __m256i,
@@ -73,6 +77,84 @@ impl AsMut<[u8]> for Xoshiro256PlusPlusX4Seed {
     }
 }
 
+impl Xoshiro256PlusPlusX4 {
+    /// Returns the generator state as `s0`..`s3` lane words, in the same layout `from_seed` reads.
+    ///
+    /// Pass it to [`Xoshiro256PlusPlusX4::from_state`] to resume the exact same stream later.
+    #[must_use]
+    pub fn state(&self) -> [u64; 16] {
+        vectors_to_state([self.s0, self.s1, self.s2, self.s3])
+    }
+
+    /// Restores a generator from a snapshot taken with [`Xoshiro256PlusPlusX4::state`].
+    #[must_use]
+    pub fn from_state(state: [u64; 16]) -> Self {
+        let [s0, s1, s2, s3] = state_to_vectors(&state);
+
+        Self { s0, s1, s2, s3 }
+    }
+
+    /// Returns a scalar generator positioned at the same point in the stream of lane `lane`.
+    ///
+    /// # Panics
+    ///
+    /// If `lane` is not less than 4.
+    #[must_use]
+    pub fn lane(&self, lane: usize) -> Xoshiro256PlusPlus {
+        Xoshiro256PlusPlus::from_state(lane_state::<4, 16, 4>(&self.state(), lane))
+    }
+
+    /// Splits the generator into one scalar generator per lane, each continuing its lane's stream.
+    #[must_use]
+    pub fn into_lanes(self) -> [Xoshiro256PlusPlus; 4] {
+        let state = self.state();
+
+        core::array::from_fn(|lane| Xoshiro256PlusPlus::from_state(lane_state::<4, 16, 4>(&state, lane)))
+    }
+
+    /// Assembles a generator from scalar generators, lane `i` continues the stream of `lanes[i]`.
+    #[must_use]
+    pub fn from_lanes(lanes: [Xoshiro256PlusPlus; 4]) -> Self {
+        Self::from_state(lanes_to_state::<4, 16, 4>(&lanes.map(|lane| lane.state())))
+    }
+}
+
+impl fmt::Debug for Xoshiro256PlusPlusX4 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Xoshiro256PlusPlusX4")
+            .field("s0", &lanes(self.s0))
+            .field("s1", &lanes(self.s1))
+            .field("s2", &lanes(self.s2))
+            .field("s3", &lanes(self.s3))
+            .finish()
+    }
+}
+
+impl PartialEq for Xoshiro256PlusPlusX4 {
+    fn eq(&self, other: &Self) -> bool {
+        self.state() == other.state()
+    }
+}
+
+impl Eq for Xoshiro256PlusPlusX4 {}
+
+#[cfg(feature = "serde")]
+crate::serde::impl_state_serde!(Xoshiro256PlusPlusX4, 16);
+
+#[cfg(feature = "portable")]
+impl From<crate::portable::Xoshiro256PlusPlusX4> for Xoshiro256PlusPlusX4 {
+    fn from(rng: crate::portable::Xoshiro256PlusPlusX4) -> Self {
+        Self::from_state(rng.state())
+    }
+}
+
+#[cfg(feature = "portable")]
+impl From<Xoshiro256PlusPlusX4> for crate::portable::Xoshiro256PlusPlusX4 {
+    fn from(rng: Xoshiro256PlusPlusX4) -> Self {
+        Self::from_state(rng.state())
+    }
+}
+
 impl SeedableRng for Xoshiro256PlusPlusX4 {
     type Seed = Xoshiro256PlusPlusX4Seed;
 
@@ -89,6 +171,11 @@ impl SeedableRng for Xoshiro256PlusPlusX4 {
 
         Self { s0, s1, s2, s3 }
     }
+
+    /// Fills the seed with the output of [`SplitMix64X4`](super::SplitMix64X4) seeded with `seed`.
+    fn seed_from_u64(seed: u64) -> Self {
+        Self::from_seed(Xoshiro256PlusPlusX4Seed::new(expand_seed::<4, 128>(seed)))
+    }
 }
 
 impl SimdRand for Xoshiro256PlusPlusX4 {
@@ -112,3 +199,5 @@ impl SimdRand for Xoshiro256PlusPlusX4 {
         }
     }
 }
+
+super::simdrand::impl_simd_rng!(Xoshiro256PlusPlusX4);
@@ -0,0 +1,206 @@
+use core::{
+    arch::x86_64::*,
+    fmt, mem,
+    ops::{Deref, DerefMut},
+};
+
+use rand_core::SeedableRng;
+
+use crate::scalar::Xoshiro256StarStar;
+use crate::specific::avx2::{lanes, read_u64_into_vec, state_to_vectors, vectors_to_state};
+use crate::splitmix64::expand_seed;
+use crate::state::{lane_state, lanes_to_state};
+
+use super::{rotate_left, simdrand::*};
+
+#[derive(Clone)]
+pub struct Xoshiro256StarStarX4Seed([u8; 128]);
+
+impl Xoshiro256StarStarX4Seed {
+    #[must_use]
+    pub const fn new(seed: [u8; 128]) -> Self {
+        Self(seed)
+    }
+}
+
+impl From<[u8; 128]> for Xoshiro256StarStarX4Seed {
+    fn from(val: [u8; 128]) -> Self {
+        Self::new(val)
+    }
+}
+
+impl From<&[u8]> for Xoshiro256StarStarX4Seed {
+    fn from(val: &[u8]) -> Self {
+        assert_eq!(val.len(), 128);
+        let mut seed = [0u8; 128];
+        seed.copy_from_slice(val);
+        Self::new(seed)
+    }
+}
+
+impl Deref for Xoshiro256StarStarX4Seed {
+    type Target = [u8; 128];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl DerefMut for Xoshiro256StarStarX4Seed {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[derive(Clone)]
+#[repr(align(32))]
+pub struct Xoshiro256StarStarX4 {
+    s0: __m256i,
+    s1: __m256i,
+    s2: __m256i,
+    s3: __m256i,
+}
+impl Default for Xoshiro256StarStarX4Seed {
+    fn default() -> Self {
+        Self([0; 128])
+    }
+}
+
+impl AsRef<[u8]> for Xoshiro256StarStarX4Seed {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsMut<[u8]> for Xoshiro256StarStarX4Seed {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+impl Xoshiro256StarStarX4 {
+    /// Returns the generator state as `s0`..`s3` lane words, in the same layout `from_seed` reads.
+    ///
+    /// Pass it to [`Xoshiro256StarStarX4::from_state`] to resume the exact same stream later.
+    #[must_use]
+    pub fn state(&self) -> [u64; 16] {
+        vectors_to_state([self.s0, self.s1, self.s2, self.s3])
+    }
+
+    /// Restores a generator from a snapshot taken with [`Xoshiro256StarStarX4::state`].
+    #[must_use]
+    pub fn from_state(state: [u64; 16]) -> Self {
+        let [s0, s1, s2, s3] = state_to_vectors(&state);
+
+        Self { s0, s1, s2, s3 }
+    }
+
+    /// Returns a scalar generator positioned at the same point in the stream of lane `lane`.
+    ///
+    /// # Panics
+    ///
+    /// If `lane` is not less than 4.
+    #[must_use]
+    pub fn lane(&self, lane: usize) -> Xoshiro256StarStar {
+        Xoshiro256StarStar::from_state(lane_state::<4, 16, 4>(&self.state(), lane))
+    }
+
+    /// Splits the generator into one scalar generator per lane, each continuing its lane's stream.
+    #[must_use]
+    pub fn into_lanes(self) -> [Xoshiro256StarStar; 4] {
+        let state = self.state();
+
+        core::array::from_fn(|lane| Xoshiro256StarStar::from_state(lane_state::<4, 16, 4>(&state, lane)))
+    }
+
+    /// Assembles a generator from scalar generators, lane `i` continues the stream of `lanes[i]`.
+    #[must_use]
+    pub fn from_lanes(lanes: [Xoshiro256StarStar; 4]) -> Self {
+        Self::from_state(lanes_to_state::<4, 16, 4>(&lanes.map(|lane| lane.state())))
+    }
+}
+
+impl fmt::Debug for Xoshiro256StarStarX4 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Xoshiro256StarStarX4")
+            .field("s0", &lanes(self.s0))
+            .field("s1", &lanes(self.s1))
+            .field("s2", &lanes(self.s2))
+            .field("s3", &lanes(self.s3))
+            .finish()
+    }
+}
+
+impl PartialEq for Xoshiro256StarStarX4 {
+    fn eq(&self, other: &Self) -> bool {
+        self.state() == other.state()
+    }
+}
+
+impl Eq for Xoshiro256StarStarX4 {}
+
+#[cfg(feature = "serde")]
+crate::serde::impl_state_serde!(Xoshiro256StarStarX4, 16);
+
+#[cfg(feature = "portable")]
+impl From<crate::portable::Xoshiro256StarStarX4> for Xoshiro256StarStarX4 {
+    fn from(rng: crate::portable::Xoshiro256StarStarX4) -> Self {
+        Self::from_state(rng.state())
+    }
+}
+
+#[cfg(feature = "portable")]
+impl From<Xoshiro256StarStarX4> for crate::portable::Xoshiro256StarStarX4 {
+    fn from(rng: Xoshiro256StarStarX4) -> Self {
+        Self::from_state(rng.state())
+    }
+}
+
+impl SeedableRng for Xoshiro256StarStarX4 {
+    type Seed = Xoshiro256StarStarX4Seed;
+
+    #[allow(clippy::identity_op, clippy::erasing_op)]
+    fn from_seed(seed: Self::Seed) -> Self {
+        const SIZE: usize = mem::size_of::<u64>();
+        const LEN: usize = 4;
+        const VECSIZE: usize = SIZE * LEN;
+
+        let s0 = read_u64_into_vec(&seed[(VECSIZE * 0)..(VECSIZE * 1)]);
+        let s1 = read_u64_into_vec(&seed[(VECSIZE * 1)..(VECSIZE * 2)]);
+        let s2 = read_u64_into_vec(&seed[(VECSIZE * 2)..(VECSIZE * 3)]);
+        let s3 = read_u64_into_vec(&seed[(VECSIZE * 3)..(VECSIZE * 4)]);
+
+        Self { s0, s1, s2, s3 }
+    }
+
+    /// Fills the seed with the output of [`SplitMix64X4`](super::SplitMix64X4) seeded with `seed`.
+    fn seed_from_u64(seed: u64) -> Self {
+        Self::from_seed(Xoshiro256StarStarX4Seed::new(expand_seed::<4, 128>(seed)))
+    }
+}
+
+impl SimdRand for Xoshiro256StarStarX4 {
+    #[inline(always)]
+    fn next_m256i(&mut self) -> __m256i {
+        unsafe {
+            // `s1 * 5` and `* 9` as shift-add, AVX2 has no 64-bit multiply
+            let s1_times_5 = _mm256_add_epi64(_mm256_slli_epi64::<2>(self.s1), self.s1);
+            let rotated = rotate_left::<7>(s1_times_5);
+            let vector = _mm256_add_epi64(_mm256_slli_epi64::<3>(rotated), rotated);
+
+            let t = _mm256_slli_epi64::<17>(self.s1);
+
+            self.s2 = _mm256_xor_si256(self.s2, self.s0);
+            self.s3 = _mm256_xor_si256(self.s3, self.s1);
+            self.s1 = _mm256_xor_si256(self.s1, self.s2);
+            self.s0 = _mm256_xor_si256(self.s0, self.s3);
+
+            self.s2 = _mm256_xor_si256(self.s2, t);
+
+            self.s3 = rotate_left::<45>(self.s3);
+
+            vector
+        }
+    }
+}
+
+super::simdrand::impl_simd_rng!(Xoshiro256StarStarX4);
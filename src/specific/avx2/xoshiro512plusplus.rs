@@ -0,0 +1,282 @@
+use core::{
+    arch::x86_64::*,
+    fmt, mem,
+    ops::{Deref, DerefMut},
+};
+
+use rand_core::SeedableRng;
+
+use crate::scalar::Xoshiro512PlusPlus;
+use crate::specific::avx2::{lanes, read_u64_into_vec, state_to_vectors, vectors_to_state};
+use crate::splitmix64::expand_seed;
+use crate::state::{lane_state, lanes_to_state};
+
+use super::{rotate_left, simdrand::*};
+
+const JUMP: [u64; 8] = [
+    0x33ed89b6e7a353f9,
+    0x760083d7955323be,
+    0x2837f2fbb5f22fae,
+    0x4b8c5674d309511c,
+    0xb11ac47a7ba28c25,
+    0xf1be7667092bcc1c,
+    0x53851efdb6df0aaf,
+    0x1ebbc8b23eaf25db,
+];
+const LONG_JUMP: [u64; 8] = [
+    0x11467fef8f921d28,
+    0xa2a819f2e79c8ea8,
+    0xa8299fc284b3959a,
+    0xb4d347340ca63ee1,
+    0x1cb0940bedbff6ce,
+    0xd956c5c4fa1f8e17,
+    0x915e38fd4eda93bc,
+    0x5b3ccdfa5d7daca5,
+];
+
+#[derive(Clone)]
+pub struct Xoshiro512PlusPlusX4Seed([u8; 256]);
+
+impl Xoshiro512PlusPlusX4Seed {
+    #[must_use]
+    pub const fn new(seed: [u8; 256]) -> Self {
+        Self(seed)
+    }
+}
+
+impl From<[u8; 256]> for Xoshiro512PlusPlusX4Seed {
+    fn from(val: [u8; 256]) -> Self {
+        Self::new(val)
+    }
+}
+
+impl From<&[u8]> for Xoshiro512PlusPlusX4Seed {
+    fn from(val: &[u8]) -> Self {
+        assert_eq!(val.len(), 256);
+        let mut seed = [0u8; 256];
+        seed.copy_from_slice(val);
+        Self::new(seed)
+    }
+}
+
+impl Deref for Xoshiro512PlusPlusX4Seed {
+    type Target = [u8; 256];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl DerefMut for Xoshiro512PlusPlusX4Seed {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl Default for Xoshiro512PlusPlusX4Seed {
+    fn default() -> Self {
+        Self([0; 256])
+    }
+}
+
+impl AsRef<[u8]> for Xoshiro512PlusPlusX4Seed {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsMut<[u8]> for Xoshiro512PlusPlusX4Seed {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+/// Xoshiro512++ over 4 lanes, 8 state vectors for a 2^512 - 1 period per lane.
+#[derive(Clone)]
+#[repr(align(32))]
+pub struct Xoshiro512PlusPlusX4 {
+    s: [__m256i; 8],
+}
+
+impl Xoshiro512PlusPlusX4 {
+    /// Returns the generator state as `s[0]`..`s[7]` lane words, in the same layout `from_seed` reads.
+    ///
+    /// Pass it to [`Xoshiro512PlusPlusX4::from_state`] to resume the exact same stream later.
+    #[must_use]
+    pub fn state(&self) -> [u64; 32] {
+        vectors_to_state(self.s)
+    }
+
+    /// Restores a generator from a snapshot taken with [`Xoshiro512PlusPlusX4::state`].
+    #[must_use]
+    pub fn from_state(state: [u64; 32]) -> Self {
+        Self {
+            s: state_to_vectors(&state),
+        }
+    }
+
+    /// Returns a scalar generator positioned at the same point in the stream of lane `lane`.
+    ///
+    /// # Panics
+    ///
+    /// If `lane` is not less than 4.
+    #[must_use]
+    pub fn lane(&self, lane: usize) -> Xoshiro512PlusPlus {
+        Xoshiro512PlusPlus::from_state(lane_state::<4, 32, 8>(&self.state(), lane))
+    }
+
+    /// Splits the generator into one scalar generator per lane, each continuing its lane's stream.
+    #[must_use]
+    pub fn into_lanes(self) -> [Xoshiro512PlusPlus; 4] {
+        let state = self.state();
+
+        core::array::from_fn(|lane| Xoshiro512PlusPlus::from_state(lane_state::<4, 32, 8>(&state, lane)))
+    }
+
+    /// Assembles a generator from scalar generators, lane `i` continues the stream of `lanes[i]`.
+    #[must_use]
+    pub fn from_lanes(lanes: [Xoshiro512PlusPlus; 4]) -> Self {
+        Self::from_state(lanes_to_state::<4, 32, 8>(&lanes.map(|lane| lane.state())))
+    }
+
+    /// Advances every lane by 2^256 steps, the same as `jump` on the scalar generator.
+    pub fn jump(&mut self) {
+        self.jump_by(JUMP);
+    }
+
+    /// Advances every lane by 2^384 steps, the same as `long_jump` on the scalar generator.
+    pub fn long_jump(&mut self) {
+        self.jump_by(LONG_JUMP);
+    }
+
+    fn jump_by(&mut self, polynomial: [u64; 8]) {
+        let mut s = [unsafe { _mm256_setzero_si256() }; 8];
+
+        for word in polynomial {
+            for bit in 0..64 {
+                if word & (1 << bit) != 0 {
+                    for (acc, word) in s.iter_mut().zip(self.s) {
+                        *acc = unsafe { _mm256_xor_si256(*acc, word) };
+                    }
+                }
+                self.next_m256i();
+            }
+        }
+
+        self.s = s;
+    }
+}
+
+impl fmt::Debug for Xoshiro512PlusPlusX4 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Xoshiro512PlusPlusX4")
+            .field("s", &self.s.map(lanes))
+            .finish()
+    }
+}
+
+impl PartialEq for Xoshiro512PlusPlusX4 {
+    fn eq(&self, other: &Self) -> bool {
+        self.state() == other.state()
+    }
+}
+
+impl Eq for Xoshiro512PlusPlusX4 {}
+
+#[cfg(feature = "serde")]
+crate::serde::impl_state_serde!(Xoshiro512PlusPlusX4, 32);
+
+#[cfg(feature = "portable")]
+impl From<crate::portable::Xoshiro512PlusPlusX4> for Xoshiro512PlusPlusX4 {
+    fn from(rng: crate::portable::Xoshiro512PlusPlusX4) -> Self {
+        Self::from_state(rng.state())
+    }
+}
+
+#[cfg(feature = "portable")]
+impl From<Xoshiro512PlusPlusX4> for crate::portable::Xoshiro512PlusPlusX4 {
+    fn from(rng: Xoshiro512PlusPlusX4) -> Self {
+        Self::from_state(rng.state())
+    }
+}
+
+impl SeedableRng for Xoshiro512PlusPlusX4 {
+    type Seed = Xoshiro512PlusPlusX4Seed;
+
+    fn from_seed(seed: Self::Seed) -> Self {
+        const SIZE: usize = mem::size_of::<u64>();
+        const LEN: usize = 4;
+        const VECSIZE: usize = SIZE * LEN;
+
+        Self {
+            s: core::array::from_fn(|index| read_u64_into_vec(&seed[(VECSIZE * index)..(VECSIZE * (index + 1))])),
+        }
+    }
+
+    /// Fills the seed with the output of [`SplitMix64X4`](super::SplitMix64X4) seeded with `seed`.
+    fn seed_from_u64(seed: u64) -> Self {
+        Self::from_seed(Xoshiro512PlusPlusX4Seed::new(expand_seed::<4, 256>(seed)))
+    }
+}
+
+impl SimdRand for Xoshiro512PlusPlusX4 {
+    #[inline(always)]
+    fn next_m256i(&mut self) -> __m256i {
+        unsafe {
+            let s = &mut self.s;
+            let vector = _mm256_add_epi64(rotate_left::<17>(_mm256_add_epi64(s[2], s[0])), s[2]);
+
+            let t = _mm256_slli_epi64::<11>(s[1]);
+
+            s[2] = _mm256_xor_si256(s[2], s[0]);
+            s[5] = _mm256_xor_si256(s[5], s[1]);
+            s[1] = _mm256_xor_si256(s[1], s[2]);
+            s[7] = _mm256_xor_si256(s[7], s[3]);
+            s[3] = _mm256_xor_si256(s[3], s[4]);
+            s[4] = _mm256_xor_si256(s[4], s[5]);
+            s[0] = _mm256_xor_si256(s[0], s[6]);
+            s[6] = _mm256_xor_si256(s[6], s[7]);
+
+            s[6] = _mm256_xor_si256(s[6], t);
+
+            s[7] = rotate_left::<21>(s[7]);
+
+            vector
+        }
+    }
+}
+
+super::simdrand::impl_simd_rng!(Xoshiro512PlusPlusX4);
+
+#[cfg(test)]
+mod tests {
+    use rand_core::{RngCore, SeedableRng};
+
+    use super::{SimdRand, Xoshiro512PlusPlusX4};
+
+    fn reference_lanes(state: &[u64; 32]) -> [rand_xoshiro::Xoshiro512PlusPlus; 4] {
+        core::array::from_fn(|lane| {
+            let mut seed = [0u8; 64];
+            for (index, chunk) in seed.chunks_exact_mut(8).enumerate() {
+                chunk.copy_from_slice(&state[index * 4 + lane].to_le_bytes());
+            }
+            rand_xoshiro::Xoshiro512PlusPlus::from_seed(rand_xoshiro::Seed512(seed))
+        })
+    }
+
+    #[test]
+    fn jumps_match_scalar_reference() {
+        let state = core::array::from_fn(|index| index as u64 + 1);
+        let mut rng = Xoshiro512PlusPlusX4::from_state(state);
+        let mut reference = reference_lanes(&state);
+
+        rng.jump();
+        reference.iter_mut().for_each(rand_xoshiro::Xoshiro512PlusPlus::jump);
+        assert_eq!(*rng.next_u64x4(), reference.each_mut().map(RngCore::next_u64));
+
+        rng.long_jump();
+        reference
+            .iter_mut()
+            .for_each(rand_xoshiro::Xoshiro512PlusPlus::long_jump);
+        assert_eq!(*rng.next_u64x4(), reference.each_mut().map(RngCore::next_u64));
+    }
+}
@@ -1,14 +1,16 @@
 use core::{
     arch::x86_64::*,
-    mem,
+    fmt, mem,
     ops::{Deref, DerefMut},
 };
 
 use rand_core::{RngCore, SeedableRng, TryRngCore};
 
 use crate::biski64::{FAST_LOOP_INCREMENT, seed_from_bytes, seed_state, seed_stream_states};
+use crate::scalar::Biski64;
+use crate::state::{lane_state, lanes_to_state, merge_lanes, split_lanes};
 
-use super::simdrand::*;
+use super::{lanes, simdrand::*, state_to_vectors, vectors_to_state};
 
 #[derive(Clone)]
 pub struct Biski64X8Seed([u8; 64]);
@@ -67,6 +69,7 @@ impl AsMut<[u8]> for Biski64X8Seed {
     }
 }
 
+#[derive(Clone)]
 #[repr(align(64))]
 pub struct Biski64X8 {
     fast_loop: __m512i,
@@ -74,6 +77,101 @@ pub struct Biski64X8 {
     loop_mix: __m512i,
 }
 
+impl Biski64X8 {
+    /// Returns the generator state as `fast_loop`, `mix` and `loop_mix` lane words, one vector after the other.
+    ///
+    /// Pass it to [`Biski64X8::from_state`] to resume the exact same stream later.
+    #[must_use]
+    pub fn state(&self) -> [u64; 24] {
+        vectors_to_state([self.fast_loop, self.mix, self.loop_mix])
+    }
+
+    /// Restores a generator from a snapshot taken with [`Biski64X8::state`].
+    #[must_use]
+    pub fn from_state(state: [u64; 24]) -> Self {
+        let [fast_loop, mix, loop_mix] = state_to_vectors(&state);
+
+        Self {
+            fast_loop,
+            mix,
+            loop_mix,
+        }
+    }
+
+    /// Returns a scalar generator positioned at the same point in the stream of lane `lane`.
+    ///
+    /// # Panics
+    ///
+    /// If `lane` is not less than 8.
+    #[must_use]
+    pub fn lane(&self, lane: usize) -> Biski64 {
+        Biski64::from_state(lane_state::<8, 24, 3>(&self.state(), lane))
+    }
+
+    /// Splits the generator into one scalar generator per lane, each continuing its lane's stream.
+    #[must_use]
+    pub fn into_lanes(self) -> [Biski64; 8] {
+        let state = self.state();
+
+        core::array::from_fn(|lane| Biski64::from_state(lane_state::<8, 24, 3>(&state, lane)))
+    }
+
+    /// Assembles a generator from scalar generators, lane `i` continues the stream of `lanes[i]`.
+    #[must_use]
+    pub fn from_lanes(lanes: [Biski64; 8]) -> Self {
+        Self::from_state(lanes_to_state::<8, 24, 3>(&lanes.map(|lane| lane.state())))
+    }
+}
+
+impl fmt::Debug for Biski64X8 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Biski64X8")
+            .field("fast_loop", &lanes(self.fast_loop))
+            .field("mix", &lanes(self.mix))
+            .field("loop_mix", &lanes(self.loop_mix))
+            .finish()
+    }
+}
+
+impl PartialEq for Biski64X8 {
+    fn eq(&self, other: &Self) -> bool {
+        self.state() == other.state()
+    }
+}
+
+impl Eq for Biski64X8 {}
+
+#[cfg(feature = "serde")]
+crate::serde::impl_state_serde!(Biski64X8, 24);
+
+#[cfg(feature = "portable")]
+impl From<crate::portable::Biski64X8> for Biski64X8 {
+    fn from(rng: crate::portable::Biski64X8) -> Self {
+        Self::from_state(rng.state())
+    }
+}
+
+#[cfg(feature = "portable")]
+impl From<Biski64X8> for crate::portable::Biski64X8 {
+    fn from(rng: Biski64X8) -> Self {
+        Self::from_state(rng.state())
+    }
+}
+
+impl From<[crate::specific::avx2::Biski64X4; 2]> for Biski64X8 {
+    /// Merges two AVX2 generators into one, the first provides lanes 0..4 and the second lanes 4..8.
+    fn from(halves: [crate::specific::avx2::Biski64X4; 2]) -> Self {
+        Self::from_state(merge_lanes::<4, 24, 12>(&halves.map(|half| half.state())))
+    }
+}
+
+impl From<Biski64X8> for [crate::specific::avx2::Biski64X4; 2] {
+    /// Splits the generator into lanes 0..4 and lanes 4..8, each continuing its own streams.
+    fn from(rng: Biski64X8) -> Self {
+        split_lanes::<4, 24, 12>(&rng.state()).map(crate::specific::avx2::Biski64X4::from_state)
+    }
+}
+
 impl SeedableRng for Biski64X8 {
     type Seed = Biski64X8Seed;
 
@@ -139,6 +237,8 @@ impl SimdRand for Biski64X8 {
     }
 }
 
+super::simdrand::impl_simd_rng!(Biski64X8);
+
 #[inline(always)]
 fn pack_u64x8(values: [u64; 8]) -> __m512i {
     unsafe {
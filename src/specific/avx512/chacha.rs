@@ -0,0 +1,247 @@
+use core::{arch::x86_64::*, fmt};
+
+use rand_core::{CryptoRng, RngCore, SeedableRng};
+
+use super::simdrand::*;
+use crate::chacha::{BLOCK_WORDS, BlockBuffer, counter_lanes, input_words};
+
+// One block per 32-bit lane
+const BLOCKS: usize = 16;
+const BUFFER_WORDS: usize = BLOCKS * BLOCK_WORDS;
+
+/// ChaCha with `ROUNDS` rounds, computing 16 blocks per refill of its output buffer.
+///
+/// The output is the same as `rand_chacha`'s generators for the same seed, stream and word position,
+/// through [`SimdRand`] as well as [`RngCore`], so vectors of 8 words continue the stream of [`RngCore::next_u64`].
+#[derive(Clone, PartialEq, Eq)]
+pub struct ChaChaX8<const ROUNDS: usize> {
+    buffer: BlockBuffer<BUFFER_WORDS>,
+}
+
+/// ChaCha with 8 rounds, the stream of `rand_chacha::ChaCha8Rng`.
+pub type ChaCha8X8 = ChaChaX8<8>;
+/// ChaCha with 12 rounds, the stream of `rand_chacha::ChaCha12Rng`.
+pub type ChaCha12X8 = ChaChaX8<12>;
+/// ChaCha with 20 rounds, the stream of `rand_chacha::ChaCha20Rng`.
+pub type ChaCha20X8 = ChaChaX8<20>;
+
+impl<const ROUNDS: usize> ChaChaX8<ROUNDS> {
+    /// Returns the seed the generator was created from, its key.
+    #[must_use]
+    pub fn seed(&self) -> [u8; 32] {
+        self.buffer.seed()
+    }
+
+    /// Returns the stream, the 64-bit nonce.
+    #[must_use]
+    pub const fn stream(&self) -> u64 {
+        self.buffer.stream()
+    }
+
+    /// Switches to `stream`, continuing at the same word position.
+    pub fn set_stream(&mut self, stream: u64) {
+        self.buffer.set_stream(stream, blocks::<ROUNDS>);
+    }
+
+    /// Returns the position of the next 32-bit word in the stream.
+    #[must_use]
+    pub const fn word_pos(&self) -> u128 {
+        self.buffer.word_pos()
+    }
+
+    /// Moves to the 32-bit word at `word_pos`, which wraps at 2^68.
+    pub fn set_word_pos(&mut self, word_pos: u128) {
+        self.buffer.set_word_pos(word_pos, blocks::<ROUNDS>);
+    }
+
+    /// Returns the generator state as the key in four words, followed by the stream and the low and high
+    /// word position words.
+    ///
+    /// The buffered output is not part of the snapshot, [`ChaChaX8::from_state`] regenerates it.
+    #[must_use]
+    pub fn state(&self) -> [u64; 7] {
+        self.buffer.state()
+    }
+
+    /// Restores a generator from a snapshot taken with [`ChaChaX8::state`].
+    #[must_use]
+    pub fn from_state(state: [u64; 7]) -> Self {
+        Self {
+            buffer: BlockBuffer::from_state(state, blocks::<ROUNDS>),
+        }
+    }
+}
+
+impl<const ROUNDS: usize> fmt::Debug for ChaChaX8<ROUNDS> {
+    // The key is left out, it is the secret of a cryptographic generator
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ChaChaX8")
+            .field("rounds", &ROUNDS)
+            .field("stream", &self.stream())
+            .field("word_pos", &self.word_pos())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "serde")]
+crate::serde::impl_state_serde!(ChaCha8X8, 7);
+#[cfg(feature = "serde")]
+crate::serde::impl_state_serde!(ChaCha12X8, 7);
+#[cfg(feature = "serde")]
+crate::serde::impl_state_serde!(ChaCha20X8, 7);
+
+#[cfg(feature = "portable")]
+impl<const ROUNDS: usize> From<crate::portable::ChaChaX8<ROUNDS>> for ChaChaX8<ROUNDS> {
+    fn from(rng: crate::portable::ChaChaX8<ROUNDS>) -> Self {
+        Self::from_state(rng.state())
+    }
+}
+
+#[cfg(feature = "portable")]
+impl<const ROUNDS: usize> From<ChaChaX8<ROUNDS>> for crate::portable::ChaChaX8<ROUNDS> {
+    fn from(rng: ChaChaX8<ROUNDS>) -> Self {
+        Self::from_state(rng.state())
+    }
+}
+
+impl<const ROUNDS: usize> SeedableRng for ChaChaX8<ROUNDS> {
+    type Seed = [u8; 32];
+
+    /// Uses the seed as the key, starting at word 0 of stream 0.
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self {
+            buffer: BlockBuffer::new(seed),
+        }
+    }
+}
+
+impl<const ROUNDS: usize> SimdRand for ChaChaX8<ROUNDS> {
+    #[inline(always)]
+    fn next_m512i(&mut self) -> __m512i {
+        let words = self.buffer.next_words::<16>(blocks::<ROUNDS>);
+
+        // This intrinsic is specifically the unaligned load variant.
+        #[allow(clippy::cast_ptr_alignment)]
+        unsafe {
+            _mm512_loadu_si512(words.as_ptr().cast::<__m512i>())
+        }
+    }
+}
+
+super::simdrand::impl_simd_rng!(impl<const ROUNDS: usize> ChaChaX8<ROUNDS>);
+
+impl<const ROUNDS: usize> RngCore for ChaChaX8<ROUNDS> {
+    #[inline(always)]
+    fn next_u32(&mut self) -> u32 {
+        let [word] = self.buffer.next_words::<1>(blocks::<ROUNDS>);
+        word
+    }
+
+    #[inline(always)]
+    fn next_u64(&mut self) -> u64 {
+        let [lo, hi] = self.buffer.next_words::<2>(blocks::<ROUNDS>);
+        u64::from(lo) | u64::from(hi) << 32
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.buffer.fill_bytes(dest, blocks::<ROUNDS>);
+    }
+}
+
+impl<const ROUNDS: usize> CryptoRng for ChaChaX8<ROUNDS> {}
+
+/// Writes blocks `counter..counter + 16` into `out`, one after the other.
+#[inline(always)]
+fn blocks<const ROUNDS: usize>(key: &[u32; 8], counter: u64, stream: u64, out: &mut [u32; BUFFER_WORDS]) {
+    unsafe {
+        // Vector `i` holds word `i` of every block, lane `j` being block `counter + j`
+        let words = input_words(key, stream);
+        let [counter_lo, counter_hi] = counter_lanes::<BLOCKS>(counter);
+        let mut input: [__m512i; 16] = words.map(|word| _mm512_set1_epi32(word.cast_signed()));
+        // This intrinsic is specifically the unaligned load variant.
+        #[allow(clippy::cast_ptr_alignment)]
+        {
+            input[12] = _mm512_loadu_si512(counter_lo.as_ptr().cast::<__m512i>());
+            input[13] = _mm512_loadu_si512(counter_hi.as_ptr().cast::<__m512i>());
+        }
+
+        let mut x = input;
+        for _ in 0..ROUNDS / 2 {
+            quarter_round(&mut x, 0, 4, 8, 12);
+            quarter_round(&mut x, 1, 5, 9, 13);
+            quarter_round(&mut x, 2, 6, 10, 14);
+            quarter_round(&mut x, 3, 7, 11, 15);
+            quarter_round(&mut x, 0, 5, 10, 15);
+            quarter_round(&mut x, 1, 6, 11, 12);
+            quarter_round(&mut x, 2, 7, 8, 13);
+            quarter_round(&mut x, 3, 4, 9, 14);
+        }
+        for (x, input) in x.iter_mut().zip(input) {
+            *x = _mm512_add_epi32(*x, input);
+        }
+
+        for (block, words) in out.chunks_exact_mut(BLOCK_WORDS).zip(transpose(x)) {
+            // This intrinsic is specifically the unaligned store variant.
+            #[allow(clippy::cast_ptr_alignment)]
+            _mm512_storeu_si512(block.as_mut_ptr().cast::<__m512i>(), words);
+        }
+    }
+}
+
+#[inline(always)]
+// Word names follow the ChaCha specification
+#[allow(clippy::many_single_char_names)]
+fn quarter_round(x: &mut [__m512i; 16], a: usize, b: usize, c: usize, d: usize) {
+    unsafe {
+        x[a] = _mm512_add_epi32(x[a], x[b]);
+        x[d] = _mm512_rol_epi32::<16>(_mm512_xor_si512(x[d], x[a]));
+        x[c] = _mm512_add_epi32(x[c], x[d]);
+        x[b] = _mm512_rol_epi32::<12>(_mm512_xor_si512(x[b], x[c]));
+        x[a] = _mm512_add_epi32(x[a], x[b]);
+        x[d] = _mm512_rol_epi32::<8>(_mm512_xor_si512(x[d], x[a]));
+        x[c] = _mm512_add_epi32(x[c], x[d]);
+        x[b] = _mm512_rol_epi32::<7>(_mm512_xor_si512(x[b], x[c]));
+    }
+}
+
+/// Transposes the 16 words of 16 blocks into the 16 words of each block.
+#[inline(always)]
+fn transpose(rows: [__m512i; 16]) -> [__m512i; 16] {
+    unsafe {
+        // Word pairs, then word quads: `quads[4 * m + q]` holds words `4m..4m + 4` of block `4L + q` in 128-bit lane `L`
+        let pairs: [__m512i; 16] = core::array::from_fn(|index| {
+            let (a, b) = (rows[index & !1], rows[index | 1]);
+            if index % 2 == 0 {
+                _mm512_unpacklo_epi32(a, b)
+            } else {
+                _mm512_unpackhi_epi32(a, b)
+            }
+        });
+        let quads: [__m512i; 16] = core::array::from_fn(|index| {
+            let (m, q) = (index / 4, index % 4);
+            let (a, b) = (pairs[4 * m + q / 2], pairs[4 * m + q / 2 + 2]);
+            if q % 2 == 0 {
+                _mm512_unpacklo_epi64(a, b)
+            } else {
+                _mm512_unpackhi_epi64(a, b)
+            }
+        });
+
+        // Then a 4x4 transpose of 128-bit lanes for each `q`
+        let mut blocks = [_mm512_setzero_si512(); 16];
+        for q in 0..4 {
+            let [a0, a1, a2, a3] = [quads[q], quads[4 + q], quads[8 + q], quads[12 + q]];
+            let v0 = _mm512_shuffle_i32x4::<0x44>(a0, a1);
+            let v1 = _mm512_shuffle_i32x4::<0xEE>(a0, a1);
+            let v2 = _mm512_shuffle_i32x4::<0x44>(a2, a3);
+            let v3 = _mm512_shuffle_i32x4::<0xEE>(a2, a3);
+
+            blocks[q] = _mm512_shuffle_i32x4::<0x88>(v0, v2);
+            blocks[4 + q] = _mm512_shuffle_i32x4::<0xDD>(v0, v2);
+            blocks[8 + q] = _mm512_shuffle_i32x4::<0x88>(v1, v3);
+            blocks[12 + q] = _mm512_shuffle_i32x4::<0xDD>(v1, v3);
+        }
+
+        blocks
+    }
+}
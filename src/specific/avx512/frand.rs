@@ -1,13 +1,15 @@
 use core::{
     arch::x86_64::*,
-    mem,
+    fmt, mem,
     ops::{Deref, DerefMut},
 };
 
 use rand_core::SeedableRng;
 
-use crate::frand::{hash_seed_bytes, repeated_seed_bytes};
-use crate::specific::avx512::read_u64_into_vec;
+use crate::scalar::Frand;
+use crate::specific::avx512::{lanes, read_u64_into_vec, state_to_vectors, vectors_to_state};
+use crate::splitmix64::expand_seed;
+use crate::state::{lane_state, lanes_to_state, merge_lanes, split_lanes};
 
 use super::simdrand::*;
 
@@ -67,11 +69,99 @@ impl AsMut<[u8]> for FrandX8Seed {
     }
 }
 
+#[derive(Clone)]
 #[repr(align(64))]
 pub struct FrandX8 {
     seed: __m512i,
 }
 
+impl FrandX8 {
+    /// Returns the generator state as one counter word per lane.
+    ///
+    /// Pass it to [`FrandX8::from_state`] to resume the exact same stream later.
+    #[must_use]
+    pub fn state(&self) -> [u64; 8] {
+        vectors_to_state([self.seed])
+    }
+
+    /// Restores a generator from a snapshot taken with [`FrandX8::state`].
+    #[must_use]
+    pub fn from_state(state: [u64; 8]) -> Self {
+        let [seed] = state_to_vectors(&state);
+
+        Self { seed }
+    }
+
+    /// Returns a scalar generator positioned at the same point in the stream of lane `lane`.
+    ///
+    /// # Panics
+    ///
+    /// If `lane` is not less than 8.
+    #[must_use]
+    pub fn lane(&self, lane: usize) -> Frand {
+        Frand::from_state(lane_state::<8, 8, 1>(&self.state(), lane))
+    }
+
+    /// Splits the generator into one scalar generator per lane, each continuing its lane's stream.
+    #[must_use]
+    pub fn into_lanes(self) -> [Frand; 8] {
+        let state = self.state();
+
+        core::array::from_fn(|lane| Frand::from_state(lane_state::<8, 8, 1>(&state, lane)))
+    }
+
+    /// Assembles a generator from scalar generators, lane `i` continues the stream of `lanes[i]`.
+    #[must_use]
+    pub fn from_lanes(lanes: [Frand; 8]) -> Self {
+        Self::from_state(lanes_to_state::<8, 8, 1>(&lanes.map(|lane| lane.state())))
+    }
+}
+
+impl fmt::Debug for FrandX8 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FrandX8").field("seed", &lanes(self.seed)).finish()
+    }
+}
+
+impl PartialEq for FrandX8 {
+    fn eq(&self, other: &Self) -> bool {
+        self.state() == other.state()
+    }
+}
+
+impl Eq for FrandX8 {}
+
+#[cfg(feature = "serde")]
+crate::serde::impl_state_serde!(FrandX8, 8);
+
+#[cfg(feature = "portable")]
+impl From<crate::portable::FrandX8> for FrandX8 {
+    fn from(rng: crate::portable::FrandX8) -> Self {
+        Self::from_state(rng.state())
+    }
+}
+
+#[cfg(feature = "portable")]
+impl From<FrandX8> for crate::portable::FrandX8 {
+    fn from(rng: FrandX8) -> Self {
+        Self::from_state(rng.state())
+    }
+}
+
+impl From<[crate::specific::avx2::FrandX4; 2]> for FrandX8 {
+    /// Merges two AVX2 generators into one, the first provides lanes 0..4 and the second lanes 4..8.
+    fn from(halves: [crate::specific::avx2::FrandX4; 2]) -> Self {
+        Self::from_state(merge_lanes::<4, 8, 4>(&halves.map(|half| half.state())))
+    }
+}
+
+impl From<FrandX8> for [crate::specific::avx2::FrandX4; 2] {
+    /// Splits the generator into lanes 0..4 and lanes 4..8, each continuing its own streams.
+    fn from(rng: FrandX8) -> Self {
+        split_lanes::<4, 8, 4>(&rng.state()).map(crate::specific::avx2::FrandX4::from_state)
+    }
+}
+
 impl SeedableRng for FrandX8 {
     type Seed = FrandX8Seed;
 
@@ -80,14 +170,13 @@ impl SeedableRng for FrandX8 {
         const LEN: usize = 8;
         assert!(seed.len() == SIZE * LEN);
 
-        let seed = hash_seed_bytes::<64>(&seed[..]);
-        let s = read_u64_into_vec(&seed);
+        let s = read_u64_into_vec(&seed[..]);
 
         Self { seed: s }
     }
 
     fn seed_from_u64(seed: u64) -> Self {
-        Self::from_seed(Self::Seed::from(repeated_seed_bytes::<64>(seed)))
+        Self::from_seed(Self::Seed::from(expand_seed::<8, 64>(seed)))
     }
 }
 
@@ -109,15 +198,17 @@ impl SimdRand for FrandX8 {
     }
 }
 
+super::simdrand::impl_simd_rng!(FrandX8);
+
 #[cfg(test)]
 mod tests {
     use rand_core::SeedableRng;
 
     use super::{FrandX8, SimdRand};
-    use crate::frand::test_support::assert_seed_from_u64_matches_upstream;
+    use crate::frand::test_support::assert_seed_from_u64_matches_splitmix64;
 
     #[test]
-    fn seed_from_u64_matches_upstream() {
-        assert_seed_from_u64_matches_upstream::<8, _>(42, FrandX8::seed_from_u64(42), |rng| *rng.next_u64x8());
+    fn seed_from_u64_matches_splitmix64() {
+        assert_seed_from_u64_matches_splitmix64::<8, _>(42, FrandX8::seed_from_u64(42), |rng| *rng.next_u64x8());
     }
 }
@@ -0,0 +1,196 @@
+use core::{arch::x86_64::*, fmt};
+
+use rand_core::{RngCore, SeedableRng, impls};
+
+use super::simdrand::*;
+use crate::mt19937_64::{
+    Buffer, DEFAULT_SEED, LOWER_MASK, MATRIX_A, NN, STATE_WORDS, UPPER_MASK, temper as temper_word,
+};
+
+/// MT19937-64 twisting its state 8 words at a time, handing out 8 consecutive outputs per [`SimdRand`] call.
+///
+/// The output is the same as the reference `genrand64_int64` and `std::mt19937_64` for the same seed,
+/// through [`SimdRand`] as well as [`RngCore`], so vectors of 8 words continue the stream of [`RngCore::next_u64`].
+#[derive(Clone, PartialEq, Eq)]
+pub struct Mt19937_64X8 {
+    buffer: Buffer,
+}
+
+impl Mt19937_64X8 {
+    /// Seeds the generator like `init_genrand64(seed)` and `std::mt19937_64(seed)`.
+    #[must_use]
+    pub fn new(seed: u64) -> Self {
+        Self {
+            buffer: Buffer::new(seed),
+        }
+    }
+
+    /// Seeds the generator like `init_by_array64(key)`.
+    ///
+    /// # Panics
+    ///
+    /// If `key` is empty.
+    #[must_use]
+    pub fn from_key(key: &[u64]) -> Self {
+        assert!(!key.is_empty(), "The MT19937-64 key must not be empty");
+
+        Self {
+            buffer: Buffer::from_key(key),
+        }
+    }
+
+    /// Returns the generator state as the 312 words of the state array followed by the index of the next word,
+    /// the `mt` and `mti` of the reference implementation.
+    #[must_use]
+    pub fn state(&self) -> [u64; STATE_WORDS] {
+        self.buffer.state()
+    }
+
+    /// Restores a generator from a snapshot taken with [`Mt19937_64X8::state`].
+    ///
+    /// # Panics
+    ///
+    /// If the index in the snapshot is larger than 312.
+    #[must_use]
+    pub fn from_state(state: [u64; STATE_WORDS]) -> Self {
+        Self {
+            buffer: Buffer::from_state(&state),
+        }
+    }
+}
+
+impl Default for Mt19937_64X8 {
+    /// Seeds the generator with 5489, like a default constructed `std::mt19937_64`.
+    fn default() -> Self {
+        Self::new(DEFAULT_SEED)
+    }
+}
+
+impl fmt::Debug for Mt19937_64X8 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Mt19937_64X8")
+            .field("index", &self.buffer.index())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "serde")]
+crate::mt19937_64::impl_state_serde!(Mt19937_64X8);
+
+impl From<crate::scalar::Mt19937_64> for Mt19937_64X8 {
+    fn from(rng: crate::scalar::Mt19937_64) -> Self {
+        Self::from_state(rng.state())
+    }
+}
+
+impl From<Mt19937_64X8> for crate::scalar::Mt19937_64 {
+    fn from(rng: Mt19937_64X8) -> Self {
+        Self::from_state(rng.state())
+    }
+}
+
+#[cfg(feature = "portable")]
+impl From<crate::portable::Mt19937_64X8> for Mt19937_64X8 {
+    fn from(rng: crate::portable::Mt19937_64X8) -> Self {
+        Self::from_state(rng.state())
+    }
+}
+
+#[cfg(feature = "portable")]
+impl From<Mt19937_64X8> for crate::portable::Mt19937_64X8 {
+    fn from(rng: Mt19937_64X8) -> Self {
+        Self::from_state(rng.state())
+    }
+}
+
+impl SeedableRng for Mt19937_64X8 {
+    type Seed = [u8; 8];
+
+    /// Seeds the generator with the little-endian `u64` in `seed`, see [`Mt19937_64X8::new`].
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self::new(u64::from_le_bytes(seed))
+    }
+
+    /// Seeds the generator with `state` directly, see [`Mt19937_64X8::new`].
+    fn seed_from_u64(state: u64) -> Self {
+        Self::new(state)
+    }
+}
+
+impl SimdRand for Mt19937_64X8 {
+    #[inline(always)]
+    fn next_m512i(&mut self) -> __m512i {
+        let words = self.buffer.next_words::<8>(twist);
+
+        // This intrinsic is specifically the unaligned load variant.
+        #[allow(clippy::cast_ptr_alignment)]
+        unsafe {
+            temper(_mm512_loadu_si512(words.as_ptr().cast::<__m512i>()))
+        }
+    }
+}
+
+super::simdrand::impl_simd_rng!(Mt19937_64X8);
+
+impl RngCore for Mt19937_64X8 {
+    // The low half of the output, as casting the output of `std::mt19937_64` does
+    #[allow(clippy::cast_possible_truncation)]
+    #[inline(always)]
+    fn next_u32(&mut self) -> u32 {
+        self.next_u64() as u32
+    }
+
+    #[inline(always)]
+    fn next_u64(&mut self) -> u64 {
+        let [word] = self.buffer.next_words::<1>(twist);
+        temper_word(word)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        impls::fill_bytes_via_next(self, dest);
+    }
+}
+
+fn twist(mt: &mut [u64; NN]) {
+    crate::mt19937_64::twist::<8>(mt, |mt, i, source| {
+        // This intrinsic is specifically the unaligned load/store variant.
+        #[allow(clippy::cast_ptr_alignment)]
+        unsafe {
+            let current = _mm512_loadu_si512(mt[i..i + 8].as_ptr().cast::<__m512i>());
+            let next = _mm512_loadu_si512(mt[i + 1..i + 9].as_ptr().cast::<__m512i>());
+            let source = _mm512_loadu_si512(mt[source..source + 8].as_ptr().cast::<__m512i>());
+
+            let y = _mm512_or_si512(
+                _mm512_and_si512(current, _mm512_set1_epi64(UPPER_MASK.cast_signed())),
+                _mm512_and_si512(next, _mm512_set1_epi64(LOWER_MASK.cast_signed())),
+            );
+            let odd = _mm512_test_epi64_mask(y, _mm512_set1_epi64(1));
+            let mag = _mm512_maskz_set1_epi64(odd, MATRIX_A.cast_signed());
+
+            let x = _mm512_xor_si512(_mm512_xor_si512(source, _mm512_srli_epi64::<1>(y)), mag);
+            _mm512_storeu_si512(mt[i..i + 8].as_mut_ptr().cast::<__m512i>(), x);
+        }
+    });
+}
+
+#[inline(always)]
+fn temper(x: __m512i) -> __m512i {
+    unsafe {
+        let x = _mm512_xor_si512(
+            x,
+            _mm512_and_si512(_mm512_srli_epi64::<29>(x), _mm512_set1_epi64(0x5555555555555555)),
+        );
+        let x = _mm512_xor_si512(
+            x,
+            _mm512_and_si512(_mm512_slli_epi64::<17>(x), _mm512_set1_epi64(0x71d67fffeda60000)),
+        );
+        let x = _mm512_xor_si512(
+            x,
+            _mm512_and_si512(
+                _mm512_slli_epi64::<37>(x),
+                _mm512_set1_epi64(0xfff7eee000000000u64.cast_signed()),
+            ),
+        );
+        _mm512_xor_si512(x, _mm512_srli_epi64::<43>(x))
+    }
+}
@@ -0,0 +1,260 @@
+use core::{
+    arch::x86_64::*,
+    fmt,
+    ops::{Deref, DerefMut},
+};
+
+use rand_core::SeedableRng;
+
+use crate::pcg64::{MULTIPLIER, seed_state, stream_state};
+use crate::scalar::Pcg64Dxsm;
+use crate::splitmix64::expand_seed;
+use crate::state::{lane_state, lanes_to_state, merge_lanes, split_lanes};
+
+use super::{lanes, mul_hi, simdrand::*, state_to_vectors, vectors_to_state};
+
+#[derive(Clone)]
+pub struct Pcg64DxsmX8Seed([u8; 256]);
+
+impl Pcg64DxsmX8Seed {
+    #[must_use]
+    pub const fn new(seed: [u8; 256]) -> Self {
+        Self(seed)
+    }
+}
+
+impl From<[u8; 256]> for Pcg64DxsmX8Seed {
+    fn from(val: [u8; 256]) -> Self {
+        Self::new(val)
+    }
+}
+
+impl From<&[u8]> for Pcg64DxsmX8Seed {
+    fn from(val: &[u8]) -> Self {
+        assert_eq!(val.len(), 256);
+        let mut seed = [0u8; 256];
+        seed.copy_from_slice(val);
+        Self::new(seed)
+    }
+}
+
+impl Deref for Pcg64DxsmX8Seed {
+    type Target = [u8; 256];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl DerefMut for Pcg64DxsmX8Seed {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl Default for Pcg64DxsmX8Seed {
+    fn default() -> Self {
+        Self([0; 256])
+    }
+}
+
+impl AsRef<[u8]> for Pcg64DxsmX8Seed {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsMut<[u8]> for Pcg64DxsmX8Seed {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+/// PCG64-DXSM over 8 lanes, the 128-bit LCG state of every lane is held as a low and a high word vector.
+///
+/// Every lane has its own stream increment, see [`Pcg64DxsmX8::new`].
+#[derive(Clone)]
+#[repr(align(64))]
+pub struct Pcg64DxsmX8 {
+    state_lo: __m512i,
+    state_hi: __m512i,
+    increment_lo: __m512i,
+    increment_hi: __m512i,
+}
+
+impl Pcg64DxsmX8 {
+    /// Creates a generator where lane `i` is on stream `streams[i]`, the same as `rand_pcg::Pcg64Dxsm::new(states[i], streams[i])`.
+    #[must_use]
+    pub fn new(states: [u128; 8], streams: [u128; 8]) -> Self {
+        Self::from_state(stream_state::<8, 32>(states, streams))
+    }
+
+    /// Returns the generator state as `state_lo`, `state_hi`, `increment_lo` and `increment_hi` lane words,
+    /// one vector after the other.
+    ///
+    /// Pass it to [`Pcg64DxsmX8::from_state`] to resume the exact same stream later.
+    #[must_use]
+    pub fn state(&self) -> [u64; 32] {
+        vectors_to_state([self.state_lo, self.state_hi, self.increment_lo, self.increment_hi])
+    }
+
+    /// Restores a generator from a snapshot taken with [`Pcg64DxsmX8::state`].
+    #[must_use]
+    pub fn from_state(state: [u64; 32]) -> Self {
+        let [state_lo, state_hi, increment_lo, increment_hi] = state_to_vectors(&state);
+
+        Self {
+            state_lo,
+            state_hi,
+            increment_lo,
+            increment_hi,
+        }
+    }
+
+    /// Returns a scalar generator positioned at the same point in the stream of lane `lane`.
+    ///
+    /// # Panics
+    ///
+    /// If `lane` is not less than 8.
+    #[must_use]
+    pub fn lane(&self, lane: usize) -> Pcg64Dxsm {
+        Pcg64Dxsm::from_state(lane_state::<8, 32, 4>(&self.state(), lane))
+    }
+
+    /// Splits the generator into one scalar generator per lane, each continuing its lane's stream.
+    #[must_use]
+    pub fn into_lanes(self) -> [Pcg64Dxsm; 8] {
+        let state = self.state();
+
+        core::array::from_fn(|lane| Pcg64Dxsm::from_state(lane_state::<8, 32, 4>(&state, lane)))
+    }
+
+    /// Assembles a generator from scalar generators, lane `i` continues the stream of `lanes[i]`.
+    #[must_use]
+    pub fn from_lanes(lanes: [Pcg64Dxsm; 8]) -> Self {
+        Self::from_state(lanes_to_state::<8, 32, 4>(&lanes.map(|lane| lane.state())))
+    }
+}
+
+impl fmt::Debug for Pcg64DxsmX8 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Pcg64DxsmX8")
+            .field("state_lo", &lanes(self.state_lo))
+            .field("state_hi", &lanes(self.state_hi))
+            .field("increment_lo", &lanes(self.increment_lo))
+            .field("increment_hi", &lanes(self.increment_hi))
+            .finish()
+    }
+}
+
+impl PartialEq for Pcg64DxsmX8 {
+    fn eq(&self, other: &Self) -> bool {
+        self.state() == other.state()
+    }
+}
+
+impl Eq for Pcg64DxsmX8 {}
+
+#[cfg(feature = "serde")]
+crate::serde::impl_state_serde!(Pcg64DxsmX8, 32);
+
+#[cfg(feature = "portable")]
+impl From<crate::portable::Pcg64DxsmX8> for Pcg64DxsmX8 {
+    fn from(rng: crate::portable::Pcg64DxsmX8) -> Self {
+        Self::from_state(rng.state())
+    }
+}
+
+#[cfg(feature = "portable")]
+impl From<Pcg64DxsmX8> for crate::portable::Pcg64DxsmX8 {
+    fn from(rng: Pcg64DxsmX8) -> Self {
+        Self::from_state(rng.state())
+    }
+}
+
+impl From<[crate::specific::avx2::Pcg64DxsmX4; 2]> for Pcg64DxsmX8 {
+    /// Merges two AVX2 generators into one, the first provides lanes 0..4 and the second lanes 4..8.
+    fn from(halves: [crate::specific::avx2::Pcg64DxsmX4; 2]) -> Self {
+        Self::from_state(merge_lanes::<4, 32, 16>(&halves.map(|half| half.state())))
+    }
+}
+
+impl From<Pcg64DxsmX8> for [crate::specific::avx2::Pcg64DxsmX4; 2] {
+    /// Splits the generator into lanes 0..4 and lanes 4..8, each continuing its own streams.
+    fn from(rng: Pcg64DxsmX8) -> Self {
+        split_lanes::<4, 32, 16>(&rng.state()).map(crate::specific::avx2::Pcg64DxsmX4::from_state)
+    }
+}
+
+impl SeedableRng for Pcg64DxsmX8 {
+    type Seed = Pcg64DxsmX8Seed;
+
+    /// Lane `i` is seeded the same as `rand_pcg::Pcg64Dxsm::from_seed`, reading the seed as `state_lo`, `state_hi`,
+    /// `increment_lo` and `increment_hi` vectors.
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self::from_state(seed_state::<8, 32>(&seed[..]))
+    }
+
+    /// Fills the seed with the output of [`SplitMix64X8`](super::SplitMix64X8) seeded with `seed`.
+    fn seed_from_u64(seed: u64) -> Self {
+        Self::from_seed(Pcg64DxsmX8Seed::new(expand_seed::<8, 256>(seed)))
+    }
+}
+
+impl SimdRand for Pcg64DxsmX8 {
+    #[inline(always)]
+    fn next_m512i(&mut self) -> __m512i {
+        unsafe {
+            let multiplier = _mm512_set1_epi64(MULTIPLIER.cast_signed());
+
+            // DXSM output of the current state
+            let lo = _mm512_or_si512(self.state_lo, _mm512_set1_epi64(1));
+            let mut hi = self.state_hi;
+            hi = _mm512_xor_si512(hi, _mm512_srli_epi64::<32>(hi));
+            hi = _mm512_mullo_epi64(hi, multiplier);
+            hi = _mm512_xor_si512(hi, _mm512_srli_epi64::<48>(hi));
+            let vector = _mm512_mullo_epi64(hi, lo);
+
+            // 128-bit LCG step: state * MULTIPLIER + increment, carrying from the low into the high word
+            let product_lo = _mm512_mullo_epi64(self.state_lo, multiplier);
+            let product_hi = _mm512_add_epi64(
+                mul_hi(self.state_lo, multiplier),
+                _mm512_mullo_epi64(self.state_hi, multiplier),
+            );
+            let state_lo = _mm512_add_epi64(product_lo, self.increment_lo);
+            let carry = _mm512_cmplt_epu64_mask(state_lo, product_lo);
+
+            self.state_lo = state_lo;
+            self.state_hi = _mm512_mask_add_epi64(
+                _mm512_add_epi64(product_hi, self.increment_hi),
+                carry,
+                _mm512_add_epi64(product_hi, self.increment_hi),
+                _mm512_set1_epi64(1),
+            );
+
+            vector
+        }
+    }
+}
+
+super::simdrand::impl_simd_rng!(Pcg64DxsmX8);
+
+#[cfg(test)]
+mod tests {
+    use rand_core::RngCore;
+
+    use super::{Pcg64DxsmX8, SimdRand};
+
+    #[test]
+    fn new_matches_scalar_reference() {
+        let states: [u128; 8] =
+            core::array::from_fn(|lane| 0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210 * (lane as u128 + 1));
+        let streams: [u128; 8] = core::array::from_fn(|lane| 0xCAFE_F00D_D15E_A5E5 ^ lane as u128);
+        let mut rng = Pcg64DxsmX8::new(states, streams);
+        let mut reference: [rand_pcg::Pcg64Dxsm; 8] =
+            core::array::from_fn(|lane| rand_pcg::Pcg64Dxsm::new(states[lane], streams[lane]));
+
+        for _ in 0..1024 {
+            assert_eq!(*rng.next_u64x8(), reference.each_mut().map(RngCore::next_u64));
+        }
+    }
+}
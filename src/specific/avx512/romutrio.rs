@@ -0,0 +1,211 @@
+use core::{
+    arch::x86_64::*,
+    fmt, mem,
+    ops::{Deref, DerefMut},
+};
+
+use rand_core::SeedableRng;
+
+use crate::romutrio::MULTIPLIER;
+use crate::scalar::RomuTrio;
+use crate::specific::avx512::{lanes, read_u64_into_vec, state_to_vectors, vectors_to_state};
+use crate::splitmix64::expand_seed;
+use crate::state::{lane_state, lanes_to_state, merge_lanes, split_lanes};
+
+use super::simdrand::*;
+
+#[derive(Clone)]
+pub struct RomuTrioX8Seed([u8; 192]);
+
+impl RomuTrioX8Seed {
+    #[must_use]
+    pub const fn new(seed: [u8; 192]) -> Self {
+        Self(seed)
+    }
+}
+
+impl From<[u8; 192]> for RomuTrioX8Seed {
+    fn from(val: [u8; 192]) -> Self {
+        Self::new(val)
+    }
+}
+
+impl From<&[u8]> for RomuTrioX8Seed {
+    fn from(val: &[u8]) -> Self {
+        assert_eq!(val.len(), 192);
+        let mut seed = [0u8; 192];
+        seed.copy_from_slice(val);
+        Self::new(seed)
+    }
+}
+
+impl Deref for RomuTrioX8Seed {
+    type Target = [u8; 192];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl DerefMut for RomuTrioX8Seed {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl Default for RomuTrioX8Seed {
+    fn default() -> Self {
+        Self([0; 192])
+    }
+}
+
+impl AsRef<[u8]> for RomuTrioX8Seed {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsMut<[u8]> for RomuTrioX8Seed {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+/// RomuTrio with 8 independent lanes, each the stream of [`RomuTrio`] from its own state.
+///
+/// The step is a multiply, two subtractions and two rotations, all of which map directly to vector instructions.
+#[derive(Clone)]
+#[repr(align(64))]
+pub struct RomuTrioX8 {
+    x: __m512i,
+    y: __m512i,
+    z: __m512i,
+}
+
+impl RomuTrioX8 {
+    /// Returns the generator state as `x`, `y` and `z` lane words, in the same layout `from_seed` reads.
+    ///
+    /// Pass it to [`RomuTrioX8::from_state`] to resume the exact same stream later.
+    #[must_use]
+    pub fn state(&self) -> [u64; 24] {
+        vectors_to_state([self.x, self.y, self.z])
+    }
+
+    /// Restores a generator from a snapshot taken with [`RomuTrioX8::state`].
+    #[must_use]
+    pub fn from_state(state: [u64; 24]) -> Self {
+        let [x, y, z] = state_to_vectors(&state);
+
+        Self { x, y, z }
+    }
+
+    /// Returns a scalar generator positioned at the same point in the stream of lane `lane`.
+    ///
+    /// # Panics
+    ///
+    /// If `lane` is not less than 8.
+    #[must_use]
+    pub fn lane(&self, lane: usize) -> RomuTrio {
+        RomuTrio::from_state(lane_state::<8, 24, 3>(&self.state(), lane))
+    }
+
+    /// Splits the generator into one scalar generator per lane, each continuing its lane's stream.
+    #[must_use]
+    pub fn into_lanes(self) -> [RomuTrio; 8] {
+        let state = self.state();
+
+        core::array::from_fn(|lane| RomuTrio::from_state(lane_state::<8, 24, 3>(&state, lane)))
+    }
+
+    /// Assembles a generator from scalar generators, lane `i` continues the stream of `lanes[i]`.
+    #[must_use]
+    pub fn from_lanes(lanes: [RomuTrio; 8]) -> Self {
+        Self::from_state(lanes_to_state::<8, 24, 3>(&lanes.map(|lane| lane.state())))
+    }
+}
+
+impl fmt::Debug for RomuTrioX8 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RomuTrioX8")
+            .field("x", &lanes(self.x))
+            .field("y", &lanes(self.y))
+            .field("z", &lanes(self.z))
+            .finish()
+    }
+}
+
+impl PartialEq for RomuTrioX8 {
+    fn eq(&self, other: &Self) -> bool {
+        self.state() == other.state()
+    }
+}
+
+impl Eq for RomuTrioX8 {}
+
+#[cfg(feature = "serde")]
+crate::serde::impl_state_serde!(RomuTrioX8, 24);
+
+#[cfg(feature = "portable")]
+impl From<crate::portable::RomuTrioX8> for RomuTrioX8 {
+    fn from(rng: crate::portable::RomuTrioX8) -> Self {
+        Self::from_state(rng.state())
+    }
+}
+
+#[cfg(feature = "portable")]
+impl From<RomuTrioX8> for crate::portable::RomuTrioX8 {
+    fn from(rng: RomuTrioX8) -> Self {
+        Self::from_state(rng.state())
+    }
+}
+
+impl From<[crate::specific::avx2::RomuTrioX4; 2]> for RomuTrioX8 {
+    /// Merges two AVX2 generators into one, the first provides lanes 0..4 and the second lanes 4..8.
+    fn from(halves: [crate::specific::avx2::RomuTrioX4; 2]) -> Self {
+        Self::from_state(merge_lanes::<4, 24, 12>(&halves.map(|half| half.state())))
+    }
+}
+
+impl From<RomuTrioX8> for [crate::specific::avx2::RomuTrioX4; 2] {
+    /// Splits the generator into lanes 0..4 and lanes 4..8, each continuing its own streams.
+    fn from(rng: RomuTrioX8) -> Self {
+        split_lanes::<4, 24, 12>(&rng.state()).map(crate::specific::avx2::RomuTrioX4::from_state)
+    }
+}
+
+impl SeedableRng for RomuTrioX8 {
+    type Seed = RomuTrioX8Seed;
+
+    /// Reads the `x`, `y` and `z` lane words, a lane whose words are all zero only outputs zeros.
+    #[allow(clippy::identity_op, clippy::erasing_op)]
+    fn from_seed(seed: Self::Seed) -> Self {
+        const VECSIZE: usize = mem::size_of::<u64>() * 8;
+
+        let x = read_u64_into_vec(&seed[(VECSIZE * 0)..(VECSIZE * 1)]);
+        let y = read_u64_into_vec(&seed[(VECSIZE * 1)..(VECSIZE * 2)]);
+        let z = read_u64_into_vec(&seed[(VECSIZE * 2)..(VECSIZE * 3)]);
+
+        Self { x, y, z }
+    }
+
+    /// Fills the seed with the output of [`SplitMix64X8`](super::SplitMix64X8) seeded with `seed`.
+    fn seed_from_u64(seed: u64) -> Self {
+        Self::from_seed(RomuTrioX8Seed::new(expand_seed::<8, 192>(seed)))
+    }
+}
+
+impl SimdRand for RomuTrioX8 {
+    #[inline(always)]
+    fn next_m512i(&mut self) -> __m512i {
+        unsafe {
+            let (x, y, z) = (self.x, self.y, self.z);
+
+            self.x = _mm512_mullo_epi64(z, _mm512_set1_epi64(MULTIPLIER.cast_signed()));
+            self.y = _mm512_rol_epi64::<12>(_mm512_sub_epi64(y, x));
+            self.z = _mm512_rol_epi64::<44>(_mm512_sub_epi64(z, y));
+
+            x
+        }
+    }
+}
+
+super::simdrand::impl_simd_rng!(RomuTrioX8);
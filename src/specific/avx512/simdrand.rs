@@ -26,21 +26,38 @@ pub trait SimdRand {
 
     #[inline(always)]
     fn next_u64x8(&mut self) -> U64x8 {
-        unsafe {
-            let v = self.next_m512i();
-            let mut vector = U64x8::default();
-            _mm512_store_epi64(core::ptr::from_mut(&mut vector).cast::<i64>(), v);
-            vector
-        }
+        self.next_m512i().into()
     }
 
     #[inline(always)]
     fn next_f64x8(&mut self) -> F64x8 {
-        unsafe {
-            let v = self.next_m512d();
-            let mut vector = F64x8::default();
-            _mm512_store_pd(vector.as_mut_ptr(), v);
-            vector
-        }
+        self.next_m512d().into()
     }
 }
+
+/// Implements [`SimdRng`](crate::SimdRng) for a generator through its [`SimdRand`] implementation.
+macro_rules! impl_simd_rng {
+    (impl<$(const $param:ident: $kind:ty),*> $rng:ty) => {
+        impl<$(const $param: $kind),*> crate::SimdRng for $rng {
+            const LANES: usize = 8;
+
+            type U64Vec = crate::specific::avx512::U64x8;
+            type F64Vec = crate::specific::avx512::F64x8;
+
+            #[inline(always)]
+            fn next_u64_vec(&mut self) -> Self::U64Vec {
+                crate::specific::avx512::SimdRand::next_u64x8(self)
+            }
+
+            #[inline(always)]
+            fn next_f64_vec(&mut self) -> Self::F64Vec {
+                crate::specific::avx512::SimdRand::next_f64x8(self)
+            }
+        }
+    };
+    ($rng:ty) => {
+        crate::specific::avx512::impl_simd_rng!(impl<> $rng);
+    };
+}
+
+pub(crate) use impl_simd_rng;
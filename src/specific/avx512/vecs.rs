@@ -1,10 +1,13 @@
-use core::ops::{Deref, DerefMut};
+use core::{
+    arch::x86_64::*,
+    ops::{Deref, DerefMut},
+};
 
-#[derive(Default, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq)]
 #[repr(align(64))]
 pub struct U64x8([u64; 8]);
 
-#[derive(Default, Debug, PartialEq)]
+#[derive(Clone, Copy, Default, Debug, PartialEq)]
 #[repr(align(64))]
 pub struct F64x8([f64; 8]);
 
@@ -43,6 +46,40 @@ impl From<[u64; 8]> for U64x8 {
     }
 }
 
+impl From<U64x8> for [u64; 8] {
+    fn from(val: U64x8) -> Self {
+        val.0
+    }
+}
+
+impl From<__m512i> for U64x8 {
+    #[inline(always)]
+    fn from(val: __m512i) -> Self {
+        let mut vector = Self::default();
+        unsafe { _mm512_store_epi64(core::ptr::from_mut(&mut vector).cast::<i64>(), val) };
+        vector
+    }
+}
+
+impl From<U64x8> for __m512i {
+    #[inline(always)]
+    fn from(val: U64x8) -> Self {
+        unsafe { _mm512_load_epi64(core::ptr::from_ref(&val).cast::<i64>()) }
+    }
+}
+
+impl AsRef<[u64]> for U64x8 {
+    fn as_ref(&self) -> &[u64] {
+        &self.0
+    }
+}
+
+impl AsMut<[u64]> for U64x8 {
+    fn as_mut(&mut self) -> &mut [u64] {
+        &mut self.0
+    }
+}
+
 impl Deref for F64x8 {
     type Target = [f64; 8];
 
@@ -62,6 +99,40 @@ impl From<[f64; 8]> for F64x8 {
     }
 }
 
+impl From<F64x8> for [f64; 8] {
+    fn from(val: F64x8) -> Self {
+        val.0
+    }
+}
+
+impl From<__m512d> for F64x8 {
+    #[inline(always)]
+    fn from(val: __m512d) -> Self {
+        let mut vector = Self::default();
+        unsafe { _mm512_store_pd(vector.as_mut_ptr(), val) };
+        vector
+    }
+}
+
+impl From<F64x8> for __m512d {
+    #[inline(always)]
+    fn from(val: F64x8) -> Self {
+        unsafe { _mm512_load_pd(val.as_ptr()) }
+    }
+}
+
+impl AsRef<[f64]> for F64x8 {
+    fn as_ref(&self) -> &[f64] {
+        &self.0
+    }
+}
+
+impl AsMut<[f64]> for F64x8 {
+    fn as_mut(&mut self) -> &mut [f64] {
+        &mut self.0
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use core::{
@@ -108,4 +179,25 @@ mod tests {
                 .all(|(actual, expected)| actual.to_bits() == expected.to_bits())
         );
     }
+
+    #[test]
+    fn intrinsic_conversions_round_trip() {
+        let (ints, floats) = unsafe {
+            (
+                _mm512_set_epi64(8, 7, 6, 5, 4, 3, 2, 1),
+                _mm512_set_pd(8.5, 7.5, 6.5, 5.5, 4.5, 3.5, 2.5, 1.5),
+            )
+        };
+
+        let u64s = U64x8::from(ints);
+        let f64s = F64x8::from(floats);
+
+        assert_eq!(<[u64; 8]>::from(u64s), [1, 2, 3, 4, 5, 6, 7, 8]);
+        assert_eq!(
+            <[f64; 8]>::from(f64s).map(f64::to_bits),
+            [1.5, 2.5, 3.5, 4.5, 5.5, 6.5, 7.5, 8.5].map(f64::to_bits)
+        );
+        assert_eq!(U64x8::from(__m512i::from(u64s)), u64s);
+        assert_eq!(F64x8::from(__m512d::from(f64s)), f64s);
+    }
 }
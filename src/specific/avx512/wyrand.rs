@@ -0,0 +1,198 @@
+use core::{
+    arch::x86_64::*,
+    fmt,
+    ops::{Deref, DerefMut},
+};
+
+use rand_core::SeedableRng;
+
+use crate::scalar::WyRand;
+use crate::specific::avx512::{lanes, read_u64_into_vec, state_to_vectors, vectors_to_state};
+use crate::splitmix64::expand_seed;
+use crate::state::{lane_state, lanes_to_state, merge_lanes, split_lanes};
+use crate::wyrand::{INCREMENT, XOR};
+
+use super::{mul_hi, simdrand::*};
+
+#[derive(Clone)]
+pub struct WyRandX8Seed([u8; 64]);
+
+impl WyRandX8Seed {
+    #[must_use]
+    pub const fn new(seed: [u8; 64]) -> Self {
+        Self(seed)
+    }
+}
+
+impl From<[u8; 64]> for WyRandX8Seed {
+    fn from(val: [u8; 64]) -> Self {
+        Self::new(val)
+    }
+}
+
+impl From<&[u8]> for WyRandX8Seed {
+    fn from(val: &[u8]) -> Self {
+        assert_eq!(val.len(), 64);
+        let mut seed = [0u8; 64];
+        seed.copy_from_slice(val);
+        Self::new(seed)
+    }
+}
+
+impl Deref for WyRandX8Seed {
+    type Target = [u8; 64];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl DerefMut for WyRandX8Seed {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl Default for WyRandX8Seed {
+    fn default() -> Self {
+        Self([0; 64])
+    }
+}
+
+impl AsRef<[u8]> for WyRandX8Seed {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsMut<[u8]> for WyRandX8Seed {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+/// WyRand with 8 independent lanes, each the stream of [`WyRand`] from its own state.
+///
+/// There is no 64x64 -> 128-bit vector multiply, so the high half of the product is composed from 32-bit partial
+/// products.
+#[derive(Clone)]
+#[repr(align(64))]
+pub struct WyRandX8 {
+    s: __m512i,
+}
+
+impl WyRandX8 {
+    /// Returns the generator state as the `s` word of every lane, in the same layout `from_seed` reads.
+    ///
+    /// Pass it to [`WyRandX8::from_state`] to resume the exact same stream later.
+    #[must_use]
+    pub fn state(&self) -> [u64; 8] {
+        vectors_to_state([self.s])
+    }
+
+    /// Restores a generator from a snapshot taken with [`WyRandX8::state`].
+    #[must_use]
+    pub fn from_state(state: [u64; 8]) -> Self {
+        let [s] = state_to_vectors(&state);
+
+        Self { s }
+    }
+
+    /// Returns a scalar generator positioned at the same point in the stream of lane `lane`.
+    ///
+    /// # Panics
+    ///
+    /// If `lane` is not less than 8.
+    #[must_use]
+    pub fn lane(&self, lane: usize) -> WyRand {
+        WyRand::from_state(lane_state::<8, 8, 1>(&self.state(), lane))
+    }
+
+    /// Splits the generator into one scalar generator per lane, each continuing its lane's stream.
+    #[must_use]
+    pub fn into_lanes(self) -> [WyRand; 8] {
+        let state = self.state();
+
+        core::array::from_fn(|lane| WyRand::from_state(lane_state::<8, 8, 1>(&state, lane)))
+    }
+
+    /// Assembles a generator from scalar generators, lane `i` continues the stream of `lanes[i]`.
+    #[must_use]
+    pub fn from_lanes(lanes: [WyRand; 8]) -> Self {
+        Self::from_state(lanes_to_state::<8, 8, 1>(&lanes.map(|lane| lane.state())))
+    }
+}
+
+impl fmt::Debug for WyRandX8 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WyRandX8").field("s", &lanes(self.s)).finish()
+    }
+}
+
+impl PartialEq for WyRandX8 {
+    fn eq(&self, other: &Self) -> bool {
+        self.state() == other.state()
+    }
+}
+
+impl Eq for WyRandX8 {}
+
+#[cfg(feature = "serde")]
+crate::serde::impl_state_serde!(WyRandX8, 8);
+
+#[cfg(feature = "portable")]
+impl From<crate::portable::WyRandX8> for WyRandX8 {
+    fn from(rng: crate::portable::WyRandX8) -> Self {
+        Self::from_state(rng.state())
+    }
+}
+
+#[cfg(feature = "portable")]
+impl From<WyRandX8> for crate::portable::WyRandX8 {
+    fn from(rng: WyRandX8) -> Self {
+        Self::from_state(rng.state())
+    }
+}
+
+impl From<[crate::specific::avx2::WyRandX4; 2]> for WyRandX8 {
+    /// Merges two AVX2 generators into one, the first provides lanes 0..4 and the second lanes 4..8.
+    fn from(halves: [crate::specific::avx2::WyRandX4; 2]) -> Self {
+        Self::from_state(merge_lanes::<4, 8, 4>(&halves.map(|half| half.state())))
+    }
+}
+
+impl From<WyRandX8> for [crate::specific::avx2::WyRandX4; 2] {
+    /// Splits the generator into lanes 0..4 and lanes 4..8, each continuing its own streams.
+    fn from(rng: WyRandX8) -> Self {
+        split_lanes::<4, 8, 4>(&rng.state()).map(crate::specific::avx2::WyRandX4::from_state)
+    }
+}
+
+impl SeedableRng for WyRandX8 {
+    type Seed = WyRandX8Seed;
+
+    /// Reads the `s` word of every lane.
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self {
+            s: read_u64_into_vec(&seed[..]),
+        }
+    }
+
+    /// Fills the seed with the output of [`SplitMix64X8`](super::SplitMix64X8) seeded with `seed`.
+    fn seed_from_u64(seed: u64) -> Self {
+        Self::from_seed(WyRandX8Seed::new(expand_seed::<8, 64>(seed)))
+    }
+}
+
+impl SimdRand for WyRandX8 {
+    #[inline(always)]
+    fn next_m512i(&mut self) -> __m512i {
+        unsafe {
+            self.s = _mm512_add_epi64(self.s, _mm512_set1_epi64(INCREMENT.cast_signed()));
+
+            let t = _mm512_xor_si512(self.s, _mm512_set1_epi64(XOR.cast_signed()));
+            _mm512_xor_si512(mul_hi(self.s, t), _mm512_mullo_epi64(self.s, t))
+        }
+    }
+}
+
+super::simdrand::impl_simd_rng!(WyRandX8);
@@ -0,0 +1,260 @@
+use core::{
+    arch::x86_64::*,
+    fmt, mem,
+    ops::{Deref, DerefMut},
+};
+
+use rand_core::SeedableRng;
+
+use crate::scalar::Xoroshiro128Plus;
+use crate::specific::avx512::{lanes, read_u64_into_vec, state_to_vectors, vectors_to_state};
+use crate::splitmix64::expand_seed;
+use crate::state::{lane_state, lanes_to_state};
+
+use super::simdrand::*;
+
+const JUMP: [u64; 2] = [0xdf900294d8f554a5, 0x170865df4b3201fc];
+const LONG_JUMP: [u64; 2] = [0xd2a98b26625eee7b, 0xdddf9b1090aa7ac1];
+
+#[derive(Clone)]
+pub struct Xoroshiro128PlusX8Seed([u8; 128]);
+
+impl Xoroshiro128PlusX8Seed {
+    #[must_use]
+    pub const fn new(seed: [u8; 128]) -> Self {
+        Self(seed)
+    }
+}
+
+impl From<[u8; 128]> for Xoroshiro128PlusX8Seed {
+    fn from(val: [u8; 128]) -> Self {
+        Self::new(val)
+    }
+}
+
+impl From<&[u8]> for Xoroshiro128PlusX8Seed {
+    fn from(val: &[u8]) -> Self {
+        assert_eq!(val.len(), 128);
+        let mut seed = [0u8; 128];
+        seed.copy_from_slice(val);
+        Self::new(seed)
+    }
+}
+
+impl Deref for Xoroshiro128PlusX8Seed {
+    type Target = [u8; 128];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl DerefMut for Xoroshiro128PlusX8Seed {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl Default for Xoroshiro128PlusX8Seed {
+    fn default() -> Self {
+        Self([0; 128])
+    }
+}
+
+impl AsRef<[u8]> for Xoroshiro128PlusX8Seed {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsMut<[u8]> for Xoroshiro128PlusX8Seed {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+/// Xoroshiro128+ over 8 lanes, only 2 `zmm` registers of state compared to 4 for the Xoshiro256 generators.
+#[derive(Clone)]
+#[repr(align(64))]
+pub struct Xoroshiro128PlusX8 {
+    s0: __m512i,
+    s1: __m512i,
+}
+
+impl Xoroshiro128PlusX8 {
+    /// Returns the generator state as `s0` and `s1` lane words, in the same layout `from_seed` reads.
+    ///
+    /// Pass it to [`Xoroshiro128PlusX8::from_state`] to resume the exact same stream later.
+    #[must_use]
+    pub fn state(&self) -> [u64; 16] {
+        vectors_to_state([self.s0, self.s1])
+    }
+
+    /// Restores a generator from a snapshot taken with [`Xoroshiro128PlusX8::state`].
+    #[must_use]
+    pub fn from_state(state: [u64; 16]) -> Self {
+        let [s0, s1] = state_to_vectors(&state);
+
+        Self { s0, s1 }
+    }
+
+    /// Returns a scalar generator positioned at the same point in the stream of lane `lane`.
+    ///
+    /// # Panics
+    ///
+    /// If `lane` is not less than 8.
+    #[must_use]
+    pub fn lane(&self, lane: usize) -> Xoroshiro128Plus {
+        Xoroshiro128Plus::from_state(lane_state::<8, 16, 2>(&self.state(), lane))
+    }
+
+    /// Splits the generator into one scalar generator per lane, each continuing its lane's stream.
+    #[must_use]
+    pub fn into_lanes(self) -> [Xoroshiro128Plus; 8] {
+        let state = self.state();
+
+        core::array::from_fn(|lane| Xoroshiro128Plus::from_state(lane_state::<8, 16, 2>(&state, lane)))
+    }
+
+    /// Assembles a generator from scalar generators, lane `i` continues the stream of `lanes[i]`.
+    #[must_use]
+    pub fn from_lanes(lanes: [Xoroshiro128Plus; 8]) -> Self {
+        Self::from_state(lanes_to_state::<8, 16, 2>(&lanes.map(|lane| lane.state())))
+    }
+
+    /// Advances every lane by 2^64 steps, the same as `jump` on the scalar generator.
+    pub fn jump(&mut self) {
+        self.jump_by(JUMP);
+    }
+
+    /// Advances every lane by 2^96 steps, the same as `long_jump` on the scalar generator.
+    pub fn long_jump(&mut self) {
+        self.jump_by(LONG_JUMP);
+    }
+
+    fn jump_by(&mut self, polynomial: [u64; 2]) {
+        let mut s0 = unsafe { _mm512_setzero_si512() };
+        let mut s1 = unsafe { _mm512_setzero_si512() };
+
+        for word in polynomial {
+            for bit in 0..64 {
+                if word & (1 << bit) != 0 {
+                    s0 = unsafe { _mm512_xor_si512(s0, self.s0) };
+                    s1 = unsafe { _mm512_xor_si512(s1, self.s1) };
+                }
+                self.next_m512i();
+            }
+        }
+
+        self.s0 = s0;
+        self.s1 = s1;
+    }
+}
+
+impl fmt::Debug for Xoroshiro128PlusX8 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Xoroshiro128PlusX8")
+            .field("s0", &lanes(self.s0))
+            .field("s1", &lanes(self.s1))
+            .finish()
+    }
+}
+
+impl PartialEq for Xoroshiro128PlusX8 {
+    fn eq(&self, other: &Self) -> bool {
+        self.state() == other.state()
+    }
+}
+
+impl Eq for Xoroshiro128PlusX8 {}
+
+#[cfg(feature = "serde")]
+crate::serde::impl_state_serde!(Xoroshiro128PlusX8, 16);
+
+#[cfg(feature = "portable")]
+impl From<crate::portable::Xoroshiro128PlusX8> for Xoroshiro128PlusX8 {
+    fn from(rng: crate::portable::Xoroshiro128PlusX8) -> Self {
+        Self::from_state(rng.state())
+    }
+}
+
+#[cfg(feature = "portable")]
+impl From<Xoroshiro128PlusX8> for crate::portable::Xoroshiro128PlusX8 {
+    fn from(rng: Xoroshiro128PlusX8) -> Self {
+        Self::from_state(rng.state())
+    }
+}
+
+impl SeedableRng for Xoroshiro128PlusX8 {
+    type Seed = Xoroshiro128PlusX8Seed;
+
+    #[allow(clippy::identity_op, clippy::erasing_op)]
+    fn from_seed(seed: Self::Seed) -> Self {
+        const SIZE: usize = mem::size_of::<u64>();
+        const LEN: usize = 8;
+        const VECSIZE: usize = SIZE * LEN;
+
+        let s0 = read_u64_into_vec(&seed[(VECSIZE * 0)..(VECSIZE * 1)]);
+        let s1 = read_u64_into_vec(&seed[(VECSIZE * 1)..(VECSIZE * 2)]);
+
+        Self { s0, s1 }
+    }
+
+    /// Fills the seed with the output of [`SplitMix64X8`](super::SplitMix64X8) seeded with `seed`.
+    fn seed_from_u64(seed: u64) -> Self {
+        Self::from_seed(Xoroshiro128PlusX8Seed::new(expand_seed::<8, 128>(seed)))
+    }
+}
+
+impl SimdRand for Xoroshiro128PlusX8 {
+    #[inline(always)]
+    fn next_m512i(&mut self) -> __m512i {
+        unsafe {
+            let vector = _mm512_add_epi64(self.s0, self.s1);
+
+            // s1 ^= s0;
+            // s0 = rotl(s0, 24) ^ s1 ^ (s1 << 16);
+            // s1 = rotl(s1, 37);
+            self.s1 = _mm512_xor_si512(self.s1, self.s0);
+            self.s0 = _mm512_xor_si512(
+                _mm512_xor_si512(_mm512_rol_epi64::<24>(self.s0), self.s1),
+                _mm512_slli_epi64::<16>(self.s1),
+            );
+            self.s1 = _mm512_rol_epi64::<37>(self.s1);
+
+            vector
+        }
+    }
+}
+
+super::simdrand::impl_simd_rng!(Xoroshiro128PlusX8);
+
+#[cfg(test)]
+mod tests {
+    use rand_core::{RngCore, SeedableRng};
+
+    use super::{SimdRand, Xoroshiro128PlusX8};
+
+    fn reference_lanes(state: &[u64; 16]) -> [rand_xoshiro::Xoroshiro128Plus; 8] {
+        core::array::from_fn(|lane| {
+            let mut seed = [0u8; 16];
+            seed[..8].copy_from_slice(&state[lane].to_le_bytes());
+            seed[8..].copy_from_slice(&state[8 + lane].to_le_bytes());
+            rand_xoshiro::Xoroshiro128Plus::from_seed(seed)
+        })
+    }
+
+    #[test]
+    fn jumps_match_scalar_reference() {
+        let state = core::array::from_fn(|index| index as u64 + 1);
+        let mut rng = Xoroshiro128PlusX8::from_state(state);
+        let mut reference = reference_lanes(&state);
+
+        rng.jump();
+        reference.iter_mut().for_each(rand_xoshiro::Xoroshiro128Plus::jump);
+        assert_eq!(*rng.next_u64x8(), reference.each_mut().map(RngCore::next_u64));
+
+        rng.long_jump();
+        reference.iter_mut().for_each(rand_xoshiro::Xoroshiro128Plus::long_jump);
+        assert_eq!(*rng.next_u64x8(), reference.each_mut().map(RngCore::next_u64));
+    }
+}
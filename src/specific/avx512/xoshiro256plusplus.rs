@@ -1,12 +1,15 @@
 use core::{
     arch::x86_64::*,
-    mem,
+    fmt, mem,
     ops::{Deref, DerefMut},
 };
 
 use rand_core::SeedableRng;
 
-use crate::specific::avx512::read_u64_into_vec;
+use crate::scalar::Xoshiro256PlusPlus;
+use crate::specific::avx512::{lanes, read_u64_into_vec, state_to_vectors, vectors_to_state};
+use crate::splitmix64::expand_seed;
+use crate::state::{lane_state, lanes_to_state, merge_lanes, split_lanes};
 
 use super::simdrand::*;
 
@@ -48,6 +51,7 @@ impl DerefMut for Xoshiro256PlusPlusX8Seed {
     }
 }
 
+#[derive(Clone)]
 #[repr(align(64))]
 pub struct Xoshiro256PlusPlusX8 {
     s0: __m512i,
@@ -73,6 +77,98 @@ impl AsMut<[u8]> for Xoshiro256PlusPlusX8Seed {
     }
 }
 
+impl Xoshiro256PlusPlusX8 {
+    /// Returns the generator state as `s0`..`s3` lane words, in the same layout `from_seed` reads.
+    ///
+    /// Pass it to [`Xoshiro256PlusPlusX8::from_state`] to resume the exact same stream later.
+    #[must_use]
+    pub fn state(&self) -> [u64; 32] {
+        vectors_to_state([self.s0, self.s1, self.s2, self.s3])
+    }
+
+    /// Restores a generator from a snapshot taken with [`Xoshiro256PlusPlusX8::state`].
+    #[must_use]
+    pub fn from_state(state: [u64; 32]) -> Self {
+        let [s0, s1, s2, s3] = state_to_vectors(&state);
+
+        Self { s0, s1, s2, s3 }
+    }
+
+    /// Returns a scalar generator positioned at the same point in the stream of lane `lane`.
+    ///
+    /// # Panics
+    ///
+    /// If `lane` is not less than 8.
+    #[must_use]
+    pub fn lane(&self, lane: usize) -> Xoshiro256PlusPlus {
+        Xoshiro256PlusPlus::from_state(lane_state::<8, 32, 4>(&self.state(), lane))
+    }
+
+    /// Splits the generator into one scalar generator per lane, each continuing its lane's stream.
+    #[must_use]
+    pub fn into_lanes(self) -> [Xoshiro256PlusPlus; 8] {
+        let state = self.state();
+
+        core::array::from_fn(|lane| Xoshiro256PlusPlus::from_state(lane_state::<8, 32, 4>(&state, lane)))
+    }
+
+    /// Assembles a generator from scalar generators, lane `i` continues the stream of `lanes[i]`.
+    #[must_use]
+    pub fn from_lanes(lanes: [Xoshiro256PlusPlus; 8]) -> Self {
+        Self::from_state(lanes_to_state::<8, 32, 4>(&lanes.map(|lane| lane.state())))
+    }
+}
+
+impl fmt::Debug for Xoshiro256PlusPlusX8 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Xoshiro256PlusPlusX8")
+            .field("s0", &lanes(self.s0))
+            .field("s1", &lanes(self.s1))
+            .field("s2", &lanes(self.s2))
+            .field("s3", &lanes(self.s3))
+            .finish()
+    }
+}
+
+impl PartialEq for Xoshiro256PlusPlusX8 {
+    fn eq(&self, other: &Self) -> bool {
+        self.state() == other.state()
+    }
+}
+
+impl Eq for Xoshiro256PlusPlusX8 {}
+
+#[cfg(feature = "serde")]
+crate::serde::impl_state_serde!(Xoshiro256PlusPlusX8, 32);
+
+#[cfg(feature = "portable")]
+impl From<crate::portable::Xoshiro256PlusPlusX8> for Xoshiro256PlusPlusX8 {
+    fn from(rng: crate::portable::Xoshiro256PlusPlusX8) -> Self {
+        Self::from_state(rng.state())
+    }
+}
+
+#[cfg(feature = "portable")]
+impl From<Xoshiro256PlusPlusX8> for crate::portable::Xoshiro256PlusPlusX8 {
+    fn from(rng: Xoshiro256PlusPlusX8) -> Self {
+        Self::from_state(rng.state())
+    }
+}
+
+impl From<[crate::specific::avx2::Xoshiro256PlusPlusX4; 2]> for Xoshiro256PlusPlusX8 {
+    /// Merges two AVX2 generators into one, the first provides lanes 0..4 and the second lanes 4..8.
+    fn from(halves: [crate::specific::avx2::Xoshiro256PlusPlusX4; 2]) -> Self {
+        Self::from_state(merge_lanes::<4, 32, 16>(&halves.map(|half| half.state())))
+    }
+}
+
+impl From<Xoshiro256PlusPlusX8> for [crate::specific::avx2::Xoshiro256PlusPlusX4; 2] {
+    /// Splits the generator into lanes 0..4 and lanes 4..8, each continuing its own streams.
+    fn from(rng: Xoshiro256PlusPlusX8) -> Self {
+        split_lanes::<4, 32, 16>(&rng.state()).map(crate::specific::avx2::Xoshiro256PlusPlusX4::from_state)
+    }
+}
+
 impl SeedableRng for Xoshiro256PlusPlusX8 {
     type Seed = Xoshiro256PlusPlusX8Seed;
 
@@ -90,6 +186,11 @@ impl SeedableRng for Xoshiro256PlusPlusX8 {
 
         Self { s0, s1, s2, s3 }
     }
+
+    /// Fills the seed with the output of [`SplitMix64X8`](super::SplitMix64X8) seeded with `seed`.
+    fn seed_from_u64(seed: u64) -> Self {
+        Self::from_seed(Xoshiro256PlusPlusX8Seed::new(expand_seed::<8, 256>(seed)))
+    }
 }
 
 impl SimdRand for Xoshiro256PlusPlusX8 {
@@ -121,3 +222,5 @@ impl SimdRand for Xoshiro256PlusPlusX8 {
         }
     }
 }
+
+super::simdrand::impl_simd_rng!(Xoshiro256PlusPlusX8);
@@ -0,0 +1,229 @@
+use core::{
+    arch::x86_64::*,
+    fmt, mem,
+    ops::{Deref, DerefMut},
+};
+
+use rand_core::SeedableRng;
+
+use crate::scalar::Xoshiro256StarStar;
+use crate::specific::avx512::{lanes, read_u64_into_vec, state_to_vectors, vectors_to_state};
+use crate::splitmix64::expand_seed;
+use crate::state::{lane_state, lanes_to_state, merge_lanes, split_lanes};
+
+use super::simdrand::*;
+
+#[derive(Clone)]
+pub struct Xoshiro256StarStarX8Seed([u8; 256]);
+
+impl Xoshiro256StarStarX8Seed {
+    #[must_use]
+    pub const fn new(seed: [u8; 256]) -> Self {
+        Self(seed)
+    }
+}
+
+impl From<[u8; 256]> for Xoshiro256StarStarX8Seed {
+    fn from(val: [u8; 256]) -> Self {
+        Self::new(val)
+    }
+}
+
+impl From<&[u8]> for Xoshiro256StarStarX8Seed {
+    fn from(val: &[u8]) -> Self {
+        assert_eq!(val.len(), 256);
+        let mut seed = [0u8; 256];
+        seed.copy_from_slice(val);
+        Self::new(seed)
+    }
+}
+
+impl Deref for Xoshiro256StarStarX8Seed {
+    type Target = [u8; 256];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+impl DerefMut for Xoshiro256StarStarX8Seed {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[derive(Clone)]
+#[repr(align(64))]
+pub struct Xoshiro256StarStarX8 {
+    s0: __m512i,
+    s1: __m512i,
+    s2: __m512i,
+    s3: __m512i,
+}
+impl Default for Xoshiro256StarStarX8Seed {
+    fn default() -> Self {
+        Self([0; 256])
+    }
+}
+
+impl AsRef<[u8]> for Xoshiro256StarStarX8Seed {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsMut<[u8]> for Xoshiro256StarStarX8Seed {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+impl Xoshiro256StarStarX8 {
+    /// Returns the generator state as `s0`..`s3` lane words, in the same layout `from_seed` reads.
+    ///
+    /// Pass it to [`Xoshiro256StarStarX8::from_state`] to resume the exact same stream later.
+    #[must_use]
+    pub fn state(&self) -> [u64; 32] {
+        vectors_to_state([self.s0, self.s1, self.s2, self.s3])
+    }
+
+    /// Restores a generator from a snapshot taken with [`Xoshiro256StarStarX8::state`].
+    #[must_use]
+    pub fn from_state(state: [u64; 32]) -> Self {
+        let [s0, s1, s2, s3] = state_to_vectors(&state);
+
+        Self { s0, s1, s2, s3 }
+    }
+
+    /// Returns a scalar generator positioned at the same point in the stream of lane `lane`.
+    ///
+    /// # Panics
+    ///
+    /// If `lane` is not less than 8.
+    #[must_use]
+    pub fn lane(&self, lane: usize) -> Xoshiro256StarStar {
+        Xoshiro256StarStar::from_state(lane_state::<8, 32, 4>(&self.state(), lane))
+    }
+
+    /// Splits the generator into one scalar generator per lane, each continuing its lane's stream.
+    #[must_use]
+    pub fn into_lanes(self) -> [Xoshiro256StarStar; 8] {
+        let state = self.state();
+
+        core::array::from_fn(|lane| Xoshiro256StarStar::from_state(lane_state::<8, 32, 4>(&state, lane)))
+    }
+
+    /// Assembles a generator from scalar generators, lane `i` continues the stream of `lanes[i]`.
+    #[must_use]
+    pub fn from_lanes(lanes: [Xoshiro256StarStar; 8]) -> Self {
+        Self::from_state(lanes_to_state::<8, 32, 4>(&lanes.map(|lane| lane.state())))
+    }
+}
+
+impl fmt::Debug for Xoshiro256StarStarX8 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Xoshiro256StarStarX8")
+            .field("s0", &lanes(self.s0))
+            .field("s1", &lanes(self.s1))
+            .field("s2", &lanes(self.s2))
+            .field("s3", &lanes(self.s3))
+            .finish()
+    }
+}
+
+impl PartialEq for Xoshiro256StarStarX8 {
+    fn eq(&self, other: &Self) -> bool {
+        self.state() == other.state()
+    }
+}
+
+impl Eq for Xoshiro256StarStarX8 {}
+
+#[cfg(feature = "serde")]
+crate::serde::impl_state_serde!(Xoshiro256StarStarX8, 32);
+
+#[cfg(feature = "portable")]
+impl From<crate::portable::Xoshiro256StarStarX8> for Xoshiro256StarStarX8 {
+    fn from(rng: crate::portable::Xoshiro256StarStarX8) -> Self {
+        Self::from_state(rng.state())
+    }
+}
+
+#[cfg(feature = "portable")]
+impl From<Xoshiro256StarStarX8> for crate::portable::Xoshiro256StarStarX8 {
+    fn from(rng: Xoshiro256StarStarX8) -> Self {
+        Self::from_state(rng.state())
+    }
+}
+
+impl From<[crate::specific::avx2::Xoshiro256StarStarX4; 2]> for Xoshiro256StarStarX8 {
+    /// Merges two AVX2 generators into one, the first provides lanes 0..4 and the second lanes 4..8.
+    fn from(halves: [crate::specific::avx2::Xoshiro256StarStarX4; 2]) -> Self {
+        Self::from_state(merge_lanes::<4, 32, 16>(&halves.map(|half| half.state())))
+    }
+}
+
+impl From<Xoshiro256StarStarX8> for [crate::specific::avx2::Xoshiro256StarStarX4; 2] {
+    /// Splits the generator into lanes 0..4 and lanes 4..8, each continuing its own streams.
+    fn from(rng: Xoshiro256StarStarX8) -> Self {
+        split_lanes::<4, 32, 16>(&rng.state()).map(crate::specific::avx2::Xoshiro256StarStarX4::from_state)
+    }
+}
+
+impl SeedableRng for Xoshiro256StarStarX8 {
+    type Seed = Xoshiro256StarStarX8Seed;
+
+    #[allow(clippy::identity_op, clippy::erasing_op)]
+    fn from_seed(seed: Self::Seed) -> Self {
+        const SIZE: usize = mem::size_of::<u64>();
+        const LEN: usize = 8;
+        const VECSIZE: usize = SIZE * LEN;
+        // TODO: implement "jumps" between lanes?
+
+        let s0 = read_u64_into_vec(&seed[(VECSIZE * 0)..(VECSIZE * 1)]);
+        let s1 = read_u64_into_vec(&seed[(VECSIZE * 1)..(VECSIZE * 2)]);
+        let s2 = read_u64_into_vec(&seed[(VECSIZE * 2)..(VECSIZE * 3)]);
+        let s3 = read_u64_into_vec(&seed[(VECSIZE * 3)..(VECSIZE * 4)]);
+
+        Self { s0, s1, s2, s3 }
+    }
+
+    /// Fills the seed with the output of [`SplitMix64X8`](super::SplitMix64X8) seeded with `seed`.
+    fn seed_from_u64(seed: u64) -> Self {
+        Self::from_seed(Xoshiro256StarStarX8Seed::new(expand_seed::<8, 256>(seed)))
+    }
+}
+
+impl SimdRand for Xoshiro256StarStarX8 {
+    #[inline(always)]
+    fn next_m512i(&mut self) -> __m512i {
+        unsafe {
+            // const uint64_t result = rotl(s[1] * 5, 7) * 9;
+            // `s1 * 5` and `* 9` as shift-add, cheaper than `vpmullq`
+            let s1_times_5 = _mm512_add_epi64(_mm512_slli_epi64::<2>(self.s1), self.s1);
+            let rotated = _mm512_rol_epi64::<7>(s1_times_5);
+            let vector = _mm512_add_epi64(_mm512_slli_epi64::<3>(rotated), rotated);
+
+            // const uint64_t t = s[1] << 17;
+            let t = _mm512_slli_epi64::<17>(self.s1);
+
+            // s[2] ^= s[0];
+            // s[3] ^= s[1];
+            // s[1] ^= s[2];
+            // s[0] ^= s[3];
+            self.s2 = _mm512_xor_si512(self.s2, self.s0);
+            self.s3 = _mm512_xor_si512(self.s3, self.s1);
+            self.s1 = _mm512_xor_si512(self.s1, self.s2);
+            self.s0 = _mm512_xor_si512(self.s0, self.s3);
+
+            // s[2] ^= t;
+            self.s2 = _mm512_xor_si512(self.s2, t);
+
+            // s[3] = rotl(s[3], 45);
+            self.s3 = _mm512_rol_epi64::<45>(self.s3);
+
+            vector
+        }
+    }
+}
+
+super::simdrand::impl_simd_rng!(Xoshiro256StarStarX8);
@@ -0,0 +1,71 @@
+pub const GAMMA: u64 = 0x9e3779b97f4a7c15;
+
+pub const MUL_0: u64 = 0xbf58476d1ce4e5b9;
+pub const MUL_1: u64 = 0x94d049bb133111eb;
+
+/// SplitMix64's output function, applied to the state after it has been advanced by [`GAMMA`].
+#[inline(always)]
+#[must_use]
+pub const fn mix(z: u64) -> u64 {
+    let z = (z ^ (z >> 30)).wrapping_mul(MUL_0);
+    let z = (z ^ (z >> 27)).wrapping_mul(MUL_1);
+    z ^ (z >> 31)
+}
+
+#[inline(always)]
+pub const fn next(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(GAMMA);
+    mix(*state)
+}
+
+/// Lane states of a vector SplitMix64 seeded with `seed`, the first `LANES` outputs of the scalar stream from `seed`.
+#[must_use]
+pub fn lane_states<const LANES: usize>(mut seed: u64) -> [u64; LANES] {
+    core::array::from_fn(|_| next(&mut seed))
+}
+
+/// Expands `seed` into the seed of a generator with `LANES` lanes, for `seed_from_u64`.
+///
+/// The bytes are the output vectors of a SplitMix64 with `LANES` lanes seeded from `seed`, one after the other, which
+/// matches the layout of the `from_seed` methods: state word `i` of every lane, then word `i + 1` and so on.
+/// The lanes are independent, so the loop over them vectorizes and every backend of a generator ends up with the
+/// same state for the same `u64`.
+#[must_use]
+pub fn expand_seed<const LANES: usize, const BYTES: usize>(seed: u64) -> [u8; BYTES] {
+    assert_eq!(BYTES % (LANES * 8), 0);
+
+    let mut states = lane_states::<LANES>(seed);
+    let mut bytes = [0u8; BYTES];
+    for vector in bytes.chunks_exact_mut(LANES * 8) {
+        for (word, state) in vector.chunks_exact_mut(8).zip(&mut states) {
+            word.copy_from_slice(&next(state).to_le_bytes());
+        }
+    }
+
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use rand_core::{RngCore, SeedableRng};
+
+    use super::{expand_seed, lane_states};
+
+    #[test]
+    fn lane_states_continue_the_scalar_stream() {
+        let mut reference = rand_xoshiro::SplitMix64::seed_from_u64(42);
+
+        assert_eq!(lane_states::<4>(42), core::array::from_fn(|_| reference.next_u64()));
+    }
+
+    #[test]
+    fn expand_seed_interleaves_lanes() {
+        let bytes = expand_seed::<4, 96>(42);
+        let mut lanes = lane_states::<4>(42).map(rand_xoshiro::SplitMix64::seed_from_u64);
+
+        for (index, word) in bytes.chunks_exact(8).enumerate() {
+            let expected = lanes[index % 4].next_u64();
+            assert_eq!(word, expected.to_le_bytes());
+        }
+    }
+}
@@ -0,0 +1,121 @@
+#[cfg(any(
+    feature = "portable",
+    all(
+        feature = "specific",
+        target_arch = "x86_64",
+        target_feature = "avx512f",
+        target_feature = "avx512dq",
+        target_feature = "avx512vl"
+    )
+))]
+/// Splits a flat generator state into two states with half the lanes each.
+///
+/// `state` holds one vector after the other, each `2 * LANES` wide; the low `LANES` lanes of every vector
+/// go to the first half and the high lanes to the second, e.g. an X8 state becomes two X4 states.
+#[must_use]
+pub fn split_lanes<const LANES: usize, const WORDS: usize, const HALF_WORDS: usize>(
+    state: &[u64; WORDS],
+) -> [[u64; HALF_WORDS]; 2] {
+    assert_eq!(WORDS, HALF_WORDS * 2);
+
+    let mut halves = [[0; HALF_WORDS]; 2];
+    for (index, lanes) in state.chunks_exact(LANES).enumerate() {
+        let vector = index / 2;
+        halves[index % 2][(vector * LANES)..((vector + 1) * LANES)].copy_from_slice(lanes);
+    }
+
+    halves
+}
+
+#[cfg(any(
+    feature = "portable",
+    all(
+        feature = "specific",
+        target_arch = "x86_64",
+        target_feature = "avx512f",
+        target_feature = "avx512dq",
+        target_feature = "avx512vl"
+    )
+))]
+/// Inverse of [`split_lanes`], the first half provides the low lanes of every vector.
+#[must_use]
+pub fn merge_lanes<const LANES: usize, const WORDS: usize, const HALF_WORDS: usize>(
+    halves: &[[u64; HALF_WORDS]; 2],
+) -> [u64; WORDS] {
+    assert_eq!(WORDS, HALF_WORDS * 2);
+
+    let mut state = [0; WORDS];
+    for (index, lanes) in state.chunks_exact_mut(LANES).enumerate() {
+        let vector = index / 2;
+        lanes.copy_from_slice(&halves[index % 2][(vector * LANES)..((vector + 1) * LANES)]);
+    }
+
+    state
+}
+
+/// Extracts the `FIELDS` words of lane `lane` from a flat generator state of `FIELDS` vectors with `LANES` lanes each.
+#[must_use]
+pub fn lane_state<const LANES: usize, const WORDS: usize, const FIELDS: usize>(
+    state: &[u64; WORDS],
+    lane: usize,
+) -> [u64; FIELDS] {
+    assert_eq!(WORDS, LANES * FIELDS);
+    assert!(lane < LANES, "lane index {lane} out of range for {LANES} lanes");
+
+    core::array::from_fn(|field| state[field * LANES + lane])
+}
+
+/// Inverse of [`lane_state`], assembles a flat generator state from the words of every lane.
+#[must_use]
+pub fn lanes_to_state<const LANES: usize, const WORDS: usize, const FIELDS: usize>(
+    lanes: &[[u64; FIELDS]; LANES],
+) -> [u64; WORDS] {
+    assert_eq!(WORDS, LANES * FIELDS);
+
+    core::array::from_fn(|index| lanes[index % LANES][index / LANES])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{lane_state, lanes_to_state};
+    #[cfg(any(
+        feature = "portable",
+        all(
+            feature = "specific",
+            target_arch = "x86_64",
+            target_feature = "avx512f",
+            target_feature = "avx512dq",
+            target_feature = "avx512vl"
+        )
+    ))]
+    use super::{merge_lanes, split_lanes};
+
+    #[test]
+    fn lane_state_and_lanes_to_state_are_inverse() {
+        let state: [u64; 12] = core::array::from_fn(|index| index as u64);
+        let lanes: [[u64; 3]; 4] = core::array::from_fn(|lane| lane_state::<4, 12, 3>(&state, lane));
+
+        assert_eq!(lanes[1], [1, 5, 9]);
+        assert_eq!(lanes_to_state::<4, 12, 3>(&lanes), state);
+    }
+
+    #[cfg(any(
+        feature = "portable",
+        all(
+            feature = "specific",
+            target_arch = "x86_64",
+            target_feature = "avx512f",
+            target_feature = "avx512dq",
+            target_feature = "avx512vl"
+        )
+    ))]
+    #[test]
+    fn split_and_merge_lanes_are_inverse() {
+        let state: [u64; 16] = core::array::from_fn(|index| index as u64);
+        let halves = split_lanes::<4, 16, 8>(&state);
+
+        assert_eq!(halves[0], [0, 1, 2, 3, 8, 9, 10, 11]);
+        assert_eq!(halves[1], [4, 5, 6, 7, 12, 13, 14, 15]);
+        assert_eq!(merge_lanes::<4, 16, 8>(&halves), state);
+    }
+}
@@ -0,0 +1,478 @@
+const CONVERSION_STEPS: usize = if cfg!(miri) { 32 } else { 1024 };
+
+#[cfg(feature = "portable")]
+macro_rules! portable_split_merge_tests {
+    ($name:ident, $x4:ty, $x8:ty) => {
+        mod $name {
+            use rand_core::SeedableRng;
+
+            use crate::portable::{SimdRandX4, SimdRandX8};
+
+            use super::CONVERSION_STEPS;
+
+            #[test]
+            fn split_continues_lanes() {
+                let mut wide = <$x8>::seed_from_u64(0x5EED);
+                wide.next_u64x8();
+                let [mut low, mut high] = <[$x4; 2]>::from(wide.clone());
+
+                for _ in 0..CONVERSION_STEPS {
+                    let expected = wide.next_u64x8().to_array();
+                    assert_eq!(low.next_u64x4().to_array(), expected[..4]);
+                    assert_eq!(high.next_u64x4().to_array(), expected[4..]);
+                }
+            }
+
+            #[test]
+            fn merge_is_inverse_of_split() {
+                let wide = <$x8>::seed_from_u64(0xC0FFEE);
+                let halves = <[$x4; 2]>::from(wide.clone());
+
+                assert_eq!(<$x8>::from(halves), wide);
+            }
+        }
+    };
+}
+
+#[cfg(feature = "portable")]
+portable_split_merge_tests!(
+    portable_xoshiro256plus,
+    crate::portable::Xoshiro256PlusX4,
+    crate::portable::Xoshiro256PlusX8
+);
+#[cfg(feature = "portable")]
+portable_split_merge_tests!(
+    portable_xoshiro256plusplus,
+    crate::portable::Xoshiro256PlusPlusX4,
+    crate::portable::Xoshiro256PlusPlusX8
+);
+
+#[cfg(feature = "portable")]
+portable_split_merge_tests!(
+    portable_xoshiro256starstar,
+    crate::portable::Xoshiro256StarStarX4,
+    crate::portable::Xoshiro256StarStarX8
+);
+#[cfg(feature = "portable")]
+portable_split_merge_tests!(
+    portable_xoshiro512plusplus,
+    crate::portable::Xoshiro512PlusPlusX4,
+    crate::portable::Xoshiro512PlusPlusX8
+);
+#[cfg(feature = "portable")]
+portable_split_merge_tests!(
+    portable_xoshiro512starstar,
+    crate::portable::Xoshiro512StarStarX4,
+    crate::portable::Xoshiro512StarStarX8
+);
+#[cfg(feature = "portable")]
+portable_split_merge_tests!(
+    portable_pcg64dxsm,
+    crate::portable::Pcg64DxsmX4,
+    crate::portable::Pcg64DxsmX8
+);
+#[cfg(feature = "portable")]
+portable_split_merge_tests!(portable_biski64, crate::portable::Biski64X4, crate::portable::Biski64X8);
+#[cfg(feature = "portable")]
+portable_split_merge_tests!(portable_frand, crate::portable::FrandX4, crate::portable::FrandX8);
+#[cfg(feature = "portable")]
+portable_split_merge_tests!(
+    portable_splitmix64,
+    crate::portable::SplitMix64X4,
+    crate::portable::SplitMix64X8
+);
+#[cfg(feature = "portable")]
+portable_split_merge_tests!(portable_wyrand, crate::portable::WyRandX4, crate::portable::WyRandX8);
+#[cfg(feature = "portable")]
+portable_split_merge_tests!(
+    portable_romutrio,
+    crate::portable::RomuTrioX4,
+    crate::portable::RomuTrioX8
+);
+#[cfg(feature = "portable")]
+portable_split_merge_tests!(portable_sfc64, crate::portable::Sfc64X4, crate::portable::Sfc64X8);
+#[cfg(feature = "portable")]
+portable_split_merge_tests!(portable_jsf64, crate::portable::Jsf64X4, crate::portable::Jsf64X8);
+
+// The other lane counts split and merge the same way, X32 into [X16; 2] down to X4 into [X2; 2]
+#[cfg(feature = "portable")]
+mod portable_lane_counts {
+    use rand_core::SeedableRng;
+
+    use super::CONVERSION_STEPS;
+    use crate::{
+        SimdRng,
+        portable::{Sfc64X2, Sfc64X4, Xoshiro256PlusPlusX16, Xoshiro256PlusPlusX32},
+    };
+
+    #[test]
+    fn split_continues_lanes() {
+        let mut wide = Xoshiro256PlusPlusX32::seed_from_u64(0x5EED);
+        let [mut low, mut high] = <[Xoshiro256PlusPlusX16; 2]>::from(wide.clone());
+
+        for _ in 0..CONVERSION_STEPS {
+            let expected = wide.next_u64_vec().to_array();
+            assert_eq!(low.next_u64_vec().to_array(), expected[..16]);
+            assert_eq!(high.next_u64_vec().to_array(), expected[16..]);
+        }
+    }
+
+    #[test]
+    fn merge_is_inverse_of_split() {
+        let wide = Xoshiro256PlusPlusX32::seed_from_u64(0xC0FFEE);
+        assert_eq!(
+            Xoshiro256PlusPlusX32::from(<[Xoshiro256PlusPlusX16; 2]>::from(wide.clone())),
+            wide
+        );
+
+        let wide = Sfc64X4::seed_from_u64(0xC0FFEE);
+        assert_eq!(Sfc64X4::from(<[Sfc64X2; 2]>::from(wide.clone())), wide);
+    }
+}
+
+#[cfg(all(
+    feature = "portable",
+    feature = "specific",
+    target_arch = "x86_64",
+    target_feature = "avx2"
+))]
+macro_rules! avx2_conversion_tests {
+    ($name:ident, $portable:ty, $specific:ty) => {
+        mod $name {
+            use rand_core::SeedableRng;
+
+            use crate::portable::SimdRandX4;
+            use crate::specific::avx2::SimdRand;
+
+            use super::CONVERSION_STEPS;
+
+            #[test]
+            fn portable_to_avx2_continues_stream() {
+                let mut portable = <$portable>::seed_from_u64(0x5EED);
+                portable.next_u64x4();
+                let mut specific = <$specific>::from(portable.clone());
+
+                for _ in 0..CONVERSION_STEPS {
+                    assert_eq!(*specific.next_u64x4(), portable.next_u64x4().to_array());
+                }
+
+                assert_eq!(<$portable>::from(specific), portable);
+            }
+        }
+    };
+}
+
+#[cfg(all(
+    feature = "portable",
+    feature = "specific",
+    target_arch = "x86_64",
+    target_feature = "avx2"
+))]
+macro_rules! for_each_avx2_conversion_case {
+    ($m:ident) => {
+        $m!(
+            avx2_xoshiro256plus,
+            crate::portable::Xoshiro256PlusX4,
+            crate::specific::avx2::Xoshiro256PlusX4
+        );
+        $m!(
+            avx2_xoshiro256plusplus,
+            crate::portable::Xoshiro256PlusPlusX4,
+            crate::specific::avx2::Xoshiro256PlusPlusX4
+        );
+        $m!(
+            avx2_xoshiro256starstar,
+            crate::portable::Xoshiro256StarStarX4,
+            crate::specific::avx2::Xoshiro256StarStarX4
+        );
+        $m!(
+            avx2_xoshiro512plusplus,
+            crate::portable::Xoshiro512PlusPlusX4,
+            crate::specific::avx2::Xoshiro512PlusPlusX4
+        );
+        $m!(
+            avx2_xoshiro512starstar,
+            crate::portable::Xoshiro512StarStarX4,
+            crate::specific::avx2::Xoshiro512StarStarX4
+        );
+        $m!(
+            avx2_pcg64dxsm,
+            crate::portable::Pcg64DxsmX4,
+            crate::specific::avx2::Pcg64DxsmX4
+        );
+        $m!(
+            avx2_biski64,
+            crate::portable::Biski64X4,
+            crate::specific::avx2::Biski64X4
+        );
+        $m!(
+            avx2_frand,
+            crate::portable::FrandX4,
+            crate::specific::avx2::FrandX4
+        );
+        $m!(
+            avx2_splitmix64,
+            crate::portable::SplitMix64X4,
+            crate::specific::avx2::SplitMix64X4
+        );
+        $m!(
+            avx2_wyrand,
+            crate::portable::WyRandX4,
+            crate::specific::avx2::WyRandX4
+        );
+        $m!(
+            avx2_romutrio,
+            crate::portable::RomuTrioX4,
+            crate::specific::avx2::RomuTrioX4
+        );
+        $m!(
+            avx2_chacha8,
+            crate::portable::ChaCha8X4,
+            crate::specific::avx2::ChaCha8X4
+        );
+        $m!(
+            avx2_chacha12,
+            crate::portable::ChaCha12X4,
+            crate::specific::avx2::ChaCha12X4
+        );
+        $m!(
+            avx2_chacha20,
+            crate::portable::ChaCha20X4,
+            crate::specific::avx2::ChaCha20X4
+        );
+        $m!(
+            avx2_philox4x32,
+            crate::portable::Philox4x32X4,
+            crate::specific::avx2::Philox4x32X4
+        );
+        $m!(
+            avx2_philox2x64,
+            crate::portable::Philox2x64X4,
+            crate::specific::avx2::Philox2x64X4
+        );
+        $m!(
+            avx2_mt19937_64,
+            crate::portable::Mt19937_64X4,
+            crate::specific::avx2::Mt19937_64X4
+        );
+        $m!(
+            avx2_sfc64,
+            crate::portable::Sfc64X4,
+            crate::specific::avx2::Sfc64X4
+        );
+        $m!(
+            avx2_jsf64,
+            crate::portable::Jsf64X4,
+            crate::specific::avx2::Jsf64X4
+        );
+    };
+}
+
+#[cfg(all(
+    feature = "portable",
+    feature = "specific",
+    target_arch = "x86_64",
+    target_feature = "avx2"
+))]
+for_each_avx2_conversion_case!(avx2_conversion_tests);
+
+#[cfg(all(
+    feature = "specific",
+    target_arch = "x86_64",
+    target_feature = "avx512f",
+    target_feature = "avx512dq",
+    target_feature = "avx512vl"
+))]
+macro_rules! avx512_conversion_tests {
+    ($name:ident, $avx512:ty, $portable:ty $(, split = $avx2:ty)?) => {
+        mod $name {
+            use rand_core::SeedableRng;
+
+            use crate::specific::avx512::SimdRand as _;
+
+            use super::CONVERSION_STEPS;
+
+            $(
+            #[test]
+            fn split_continues_lanes() {
+                use crate::specific::avx2::SimdRand as _;
+
+                let mut wide = <$avx512>::seed_from_u64(0x5EED);
+                wide.next_u64x8();
+                let [mut low, mut high] = <[$avx2; 2]>::from(wide.clone());
+
+                for _ in 0..CONVERSION_STEPS {
+                    let expected = *wide.next_u64x8();
+                    assert_eq!(*low.next_u64x4(), expected[..4]);
+                    assert_eq!(*high.next_u64x4(), expected[4..]);
+                }
+
+                assert_eq!(<$avx512>::from([low, high]), wide);
+            }
+            )?
+
+            #[cfg(feature = "portable")]
+            #[test]
+            fn portable_to_avx512_continues_stream() {
+                use crate::portable::SimdRandX8;
+
+                let mut portable = <$portable>::seed_from_u64(0x5EED);
+                portable.next_u64x8();
+                let mut specific = <$avx512>::from(portable.clone());
+
+                for _ in 0..CONVERSION_STEPS {
+                    assert_eq!(*specific.next_u64x8(), portable.next_u64x8().to_array());
+                }
+
+                assert_eq!(<$portable>::from(specific), portable);
+            }
+        }
+    };
+}
+
+#[cfg(all(
+    feature = "specific",
+    target_arch = "x86_64",
+    target_feature = "avx512f",
+    target_feature = "avx512dq",
+    target_feature = "avx512vl"
+))]
+macro_rules! for_each_avx512_conversion_case {
+    ($m:ident) => {
+        $m!(
+            avx512_xoshiro256plus,
+            crate::specific::avx512::Xoshiro256PlusX8,
+            crate::portable::Xoshiro256PlusX8,
+            split = crate::specific::avx2::Xoshiro256PlusX4
+        );
+        $m!(
+            avx512_xoshiro256plusplus,
+            crate::specific::avx512::Xoshiro256PlusPlusX8,
+            crate::portable::Xoshiro256PlusPlusX8,
+            split = crate::specific::avx2::Xoshiro256PlusPlusX4
+        );
+        $m!(
+            avx512_xoshiro256starstar,
+            crate::specific::avx512::Xoshiro256StarStarX8,
+            crate::portable::Xoshiro256StarStarX8,
+            split = crate::specific::avx2::Xoshiro256StarStarX4
+        );
+        $m!(
+            avx512_xoshiro512plusplus,
+            crate::specific::avx512::Xoshiro512PlusPlusX8,
+            crate::portable::Xoshiro512PlusPlusX8,
+            split = crate::specific::avx2::Xoshiro512PlusPlusX4
+        );
+        $m!(
+            avx512_xoshiro512starstar,
+            crate::specific::avx512::Xoshiro512StarStarX8,
+            crate::portable::Xoshiro512StarStarX8,
+            split = crate::specific::avx2::Xoshiro512StarStarX4
+        );
+        $m!(
+            avx512_pcg64dxsm,
+            crate::specific::avx512::Pcg64DxsmX8,
+            crate::portable::Pcg64DxsmX8,
+            split = crate::specific::avx2::Pcg64DxsmX4
+        );
+        $m!(
+            avx512_biski64,
+            crate::specific::avx512::Biski64X8,
+            crate::portable::Biski64X8,
+            split = crate::specific::avx2::Biski64X4
+        );
+        $m!(
+            avx512_frand,
+            crate::specific::avx512::FrandX8,
+            crate::portable::FrandX8,
+            split = crate::specific::avx2::FrandX4
+        );
+        $m!(
+            avx512_splitmix64,
+            crate::specific::avx512::SplitMix64X8,
+            crate::portable::SplitMix64X8,
+            split = crate::specific::avx2::SplitMix64X4
+        );
+        $m!(
+            avx512_wyrand,
+            crate::specific::avx512::WyRandX8,
+            crate::portable::WyRandX8,
+            split = crate::specific::avx2::WyRandX4
+        );
+        $m!(
+            avx512_romutrio,
+            crate::specific::avx512::RomuTrioX8,
+            crate::portable::RomuTrioX8,
+            split = crate::specific::avx2::RomuTrioX4
+        );
+        $m!(
+            avx512_sfc64,
+            crate::specific::avx512::Sfc64X8,
+            crate::portable::Sfc64X8,
+            split = crate::specific::avx2::Sfc64X4
+        );
+        $m!(
+            avx512_jsf64,
+            crate::specific::avx512::Jsf64X8,
+            crate::portable::Jsf64X8,
+            split = crate::specific::avx2::Jsf64X4
+        );
+        #[cfg(feature = "portable")]
+        $m!(
+            avx512_xoroshiro128plus,
+            crate::specific::avx512::Xoroshiro128PlusX8,
+            crate::portable::Xoroshiro128PlusX8
+        );
+        #[cfg(feature = "portable")]
+        $m!(
+            avx512_xoroshiro128plusplus,
+            crate::specific::avx512::Xoroshiro128PlusPlusX8,
+            crate::portable::Xoroshiro128PlusPlusX8
+        );
+        #[cfg(feature = "portable")]
+        $m!(
+            avx512_philox4x32,
+            crate::specific::avx512::Philox4x32X8,
+            crate::portable::Philox4x32X8
+        );
+        #[cfg(feature = "portable")]
+        $m!(
+            avx512_philox2x64,
+            crate::specific::avx512::Philox2x64X8,
+            crate::portable::Philox2x64X8
+        );
+        #[cfg(feature = "portable")]
+        $m!(
+            avx512_chacha8,
+            crate::specific::avx512::ChaCha8X8,
+            crate::portable::ChaCha8X8
+        );
+        #[cfg(feature = "portable")]
+        $m!(
+            avx512_chacha12,
+            crate::specific::avx512::ChaCha12X8,
+            crate::portable::ChaCha12X8
+        );
+        #[cfg(feature = "portable")]
+        $m!(
+            avx512_chacha20,
+            crate::specific::avx512::ChaCha20X8,
+            crate::portable::ChaCha20X8
+        );
+        #[cfg(feature = "portable")]
+        $m!(
+            avx512_mt19937_64,
+            crate::specific::avx512::Mt19937_64X8,
+            crate::portable::Mt19937_64X8
+        );
+    };
+}
+
+#[cfg(all(
+    feature = "specific",
+    target_arch = "x86_64",
+    target_feature = "avx512f",
+    target_feature = "avx512dq",
+    target_feature = "avx512vl"
+))]
+for_each_avx512_conversion_case!(avx512_conversion_tests);
@@ -1,3 +1,15 @@
+#[cfg(any(
+    feature = "portable",
+    all(
+        feature = "specific",
+        target_arch = "x86_64",
+        target_feature = "avx512f",
+        target_feature = "avx512dq",
+        target_feature = "avx512vl"
+    )
+))]
+mod conversions;
 mod prngs;
 #[cfg(all(feature = "portable", feature = "specific"))]
 mod seed_ordering;
+mod simd_rng;
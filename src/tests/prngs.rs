@@ -12,17 +12,29 @@ use crate::frand::test_support::ref_seed_x4 as ref_seed_frand_x4;
 use crate::frand::test_support::ref_seed_x8 as ref_seed_frand_x8;
 #[cfg(feature = "portable")]
 use crate::portable::{
-    Biski64X4, Biski64X4Seed, Biski64X8, Biski64X8Seed, FrandX4, FrandX4Seed, FrandX8, FrandX8Seed, SimdRandX4,
-    SimdRandX8, Xoshiro256PlusPlusX4, Xoshiro256PlusPlusX4Seed, Xoshiro256PlusPlusX8, Xoshiro256PlusPlusX8Seed,
-    Xoshiro256PlusX4, Xoshiro256PlusX4Seed, Xoshiro256PlusX8, Xoshiro256PlusX8Seed,
+    Biski64X4, Biski64X4Seed, Biski64X8, Biski64X8Seed, FrandX4, FrandX4Seed, FrandX8, FrandX8Seed, Pcg64DxsmX4,
+    Pcg64DxsmX4Seed, Pcg64DxsmX8, Pcg64DxsmX8Seed, RomuTrioX4, RomuTrioX4Seed, RomuTrioX8, RomuTrioX8Seed, SimdRandX4,
+    SimdRandX8, SplitMix64X4, SplitMix64X4Seed, SplitMix64X8, SplitMix64X8Seed, WyRandX4, WyRandX4Seed, WyRandX8,
+    WyRandX8Seed, Xoroshiro128PlusPlusX8, Xoroshiro128PlusPlusX8Seed, Xoroshiro128PlusX8, Xoroshiro128PlusX8Seed,
+    Xoshiro256PlusPlusX4, Xoshiro256PlusPlusX4Seed, Xoshiro256PlusPlusX8, Xoshiro256PlusPlusX8Seed, Xoshiro256PlusX4,
+    Xoshiro256PlusX4Seed, Xoshiro256PlusX8, Xoshiro256PlusX8Seed, Xoshiro256StarStarX4, Xoshiro256StarStarX4Seed,
+    Xoshiro256StarStarX8, Xoshiro256StarStarX8Seed, Xoshiro512PlusPlusX4, Xoshiro512PlusPlusX4Seed,
+    Xoshiro512PlusPlusX8, Xoshiro512PlusPlusX8Seed, Xoshiro512StarStarX4, Xoshiro512StarStarX4Seed,
+    Xoshiro512StarStarX8, Xoshiro512StarStarX8Seed,
 };
 #[cfg(all(feature = "specific", target_arch = "x86_64", target_feature = "avx2"))]
 use crate::specific::avx2::{
     Biski64X4 as SpecificBiski64X4, Biski64X4Seed as SpecificBiski64X4Seed, DEFAULT_BUFFER_SIZE,
-    FrandX4 as SpecificFrandX4, FrandX4Seed as SpecificFrandX4Seed, Shishua, SimdRand as SpecificSimdRandX4,
-    Xoshiro256PlusPlusX4 as SpecificXoshiro256PlusPlusX4, Xoshiro256PlusPlusX4Seed as SpecificXoshiro256PlusPlusX4Seed,
-    Xoshiro256PlusX4 as SpecificXoshiro256PlusX4, Xoshiro256PlusX4Seed as SpecificXoshiro256PlusX4Seed,
-    shishua_test_vectors,
+    FrandX4 as SpecificFrandX4, FrandX4Seed as SpecificFrandX4Seed, Pcg64DxsmX4 as SpecificPcg64DxsmX4,
+    Pcg64DxsmX4Seed as SpecificPcg64DxsmX4Seed, RomuTrioX4 as SpecificRomuTrioX4,
+    RomuTrioX4Seed as SpecificRomuTrioX4Seed, Shishua, SimdRand as SpecificSimdRandX4,
+    SplitMix64X4 as SpecificSplitMix64X4, SplitMix64X4Seed as SpecificSplitMix64X4Seed, WyRandX4 as SpecificWyRandX4,
+    WyRandX4Seed as SpecificWyRandX4Seed, Xoshiro256PlusPlusX4 as SpecificXoshiro256PlusPlusX4,
+    Xoshiro256PlusPlusX4Seed as SpecificXoshiro256PlusPlusX4Seed, Xoshiro256PlusX4 as SpecificXoshiro256PlusX4,
+    Xoshiro256PlusX4Seed as SpecificXoshiro256PlusX4Seed, Xoshiro256StarStarX4 as SpecificXoshiro256StarStarX4,
+    Xoshiro256StarStarX4Seed as SpecificXoshiro256StarStarX4Seed, Xoshiro512PlusPlusX4 as SpecificXoshiro512PlusPlusX4,
+    Xoshiro512PlusPlusX4Seed as SpecificXoshiro512PlusPlusX4Seed, Xoshiro512StarStarX4 as SpecificXoshiro512StarStarX4,
+    Xoshiro512StarStarX4Seed as SpecificXoshiro512StarStarX4Seed, shishua_test_vectors,
 };
 #[cfg(all(
     feature = "specific",
@@ -33,9 +45,17 @@ use crate::specific::avx2::{
 ))]
 use crate::specific::avx512::{
     Biski64X8 as SpecificBiski64X8, Biski64X8Seed as SpecificBiski64X8Seed, FrandX8 as SpecificFrandX8,
-    FrandX8Seed as SpecificFrandX8Seed, SimdRand as SpecificSimdRandX8,
-    Xoshiro256PlusPlusX8 as SpecificXoshiro256PlusPlusX8, Xoshiro256PlusPlusX8Seed as SpecificXoshiro256PlusPlusX8Seed,
-    Xoshiro256PlusX8 as SpecificXoshiro256PlusX8, Xoshiro256PlusX8Seed as SpecificXoshiro256PlusX8Seed,
+    FrandX8Seed as SpecificFrandX8Seed, Pcg64DxsmX8 as SpecificPcg64DxsmX8, Pcg64DxsmX8Seed as SpecificPcg64DxsmX8Seed,
+    RomuTrioX8 as SpecificRomuTrioX8, RomuTrioX8Seed as SpecificRomuTrioX8Seed, SimdRand as SpecificSimdRandX8,
+    SplitMix64X8 as SpecificSplitMix64X8, SplitMix64X8Seed as SpecificSplitMix64X8Seed, WyRandX8 as SpecificWyRandX8,
+    WyRandX8Seed as SpecificWyRandX8Seed, Xoroshiro128PlusPlusX8 as SpecificXoroshiro128PlusPlusX8,
+    Xoroshiro128PlusPlusX8Seed as SpecificXoroshiro128PlusPlusX8Seed, Xoroshiro128PlusX8 as SpecificXoroshiro128PlusX8,
+    Xoroshiro128PlusX8Seed as SpecificXoroshiro128PlusX8Seed, Xoshiro256PlusPlusX8 as SpecificXoshiro256PlusPlusX8,
+    Xoshiro256PlusPlusX8Seed as SpecificXoshiro256PlusPlusX8Seed, Xoshiro256PlusX8 as SpecificXoshiro256PlusX8,
+    Xoshiro256PlusX8Seed as SpecificXoshiro256PlusX8Seed, Xoshiro256StarStarX8 as SpecificXoshiro256StarStarX8,
+    Xoshiro256StarStarX8Seed as SpecificXoshiro256StarStarX8Seed, Xoshiro512PlusPlusX8 as SpecificXoshiro512PlusPlusX8,
+    Xoshiro512PlusPlusX8Seed as SpecificXoshiro512PlusPlusX8Seed, Xoshiro512StarStarX8 as SpecificXoshiro512StarStarX8,
+    Xoshiro512StarStarX8Seed as SpecificXoshiro512StarStarX8Seed,
 };
 use core::{fmt::Debug, fmt::Display, ops::Range};
 use num_traits::{Num, NumCast};
@@ -47,6 +67,7 @@ use rust_decimal_macros::dec;
 const DOUBLE_RANGE: Range<f64> = 0.0..1.0;
 const REFERENCE_STEPS: usize = if cfg!(miri) { 32 } else { 1024 * 1024 };
 const SEED_ROUNDTRIP_STEPS: usize = if cfg!(miri) { 32 } else { 1024 * 1024 };
+const STATE_ROUNDTRIP_STEPS: usize = if cfg!(miri) { 32 } else { 1024 };
 
 fn seed_bytes<const BYTES: usize>(values: &[u64]) -> [u8; BYTES] {
     let mut seed = [0u8; BYTES];
@@ -94,6 +115,24 @@ fn ref_seed_biski64_x8() -> [u8; 64] {
     repeated_lane_seed::<64>(&sequential_words::<1>(), 8)
 }
 
+fn ref_seed_splitmix64_x4() -> [u8; 32] {
+    repeated_lane_seed::<32>(&sequential_words::<1>(), 4)
+}
+
+#[cfg(any(
+    feature = "portable",
+    all(
+        feature = "specific",
+        target_arch = "x86_64",
+        target_feature = "avx512f",
+        target_feature = "avx512dq",
+        target_feature = "avx512vl"
+    )
+))]
+fn ref_seed_splitmix64_x8() -> [u8; 64] {
+    repeated_lane_seed::<64>(&sequential_words::<1>(), 8)
+}
+
 fn xoshiro_reference_seed() -> [u8; 32] {
     seed_bytes::<32>(&sequential_words::<4>())
 }
@@ -116,6 +155,56 @@ fn ref_seed_512() -> [u8; 256] {
     repeated_lane_seed::<256>(&sequential_words::<4>(), 8)
 }
 
+#[cfg(any(
+    feature = "portable",
+    all(
+        feature = "specific",
+        target_arch = "x86_64",
+        target_feature = "avx512f",
+        target_feature = "avx512dq",
+        target_feature = "avx512vl"
+    )
+))]
+fn xoroshiro_reference_seed() -> [u8; 16] {
+    seed_bytes::<16>(&sequential_words::<2>())
+}
+
+#[cfg(any(
+    feature = "portable",
+    all(
+        feature = "specific",
+        target_arch = "x86_64",
+        target_feature = "avx512f",
+        target_feature = "avx512dq",
+        target_feature = "avx512vl"
+    )
+))]
+fn ref_seed_xoroshiro_x8() -> [u8; 128] {
+    repeated_lane_seed::<128>(&sequential_words::<2>(), 8)
+}
+
+fn xoshiro512_reference_seed() -> [u8; 64] {
+    seed_bytes::<64>(&sequential_words::<8>())
+}
+
+fn ref_seed_xoshiro512_x4() -> [u8; 256] {
+    repeated_lane_seed::<256>(&sequential_words::<8>(), 4)
+}
+
+#[cfg(any(
+    feature = "portable",
+    all(
+        feature = "specific",
+        target_arch = "x86_64",
+        target_feature = "avx512f",
+        target_feature = "avx512dq",
+        target_feature = "avx512vl"
+    )
+))]
+fn ref_seed_xoshiro512_x8() -> [u8; 512] {
+    repeated_lane_seed::<512>(&sequential_words::<8>(), 8)
+}
+
 fn random_seeded_rng<R>() -> R
 where
     R: SeedableRng,
@@ -185,10 +274,16 @@ where
 fn assert_matches_scalar_reference<const LANES: usize, R>(
     mut rng: R,
     mut next: impl FnMut(&mut R) -> [u64; LANES],
-    mut reference: impl FnMut() -> u64,
+    mut reference: impl FnMut(usize) -> u64,
 ) {
     for _ in 0..REFERENCE_STEPS {
-        assert_eq!(next(&mut rng), [reference(); LANES]);
+        assert_eq!(next(&mut rng), core::array::from_fn(&mut reference));
+    }
+}
+
+fn assert_rngs_continue<const LANES: usize, R>(mut lhs: R, mut rhs: R, mut next: impl FnMut(&mut R) -> [u64; LANES]) {
+    for _ in 0..STATE_ROUNDTRIP_STEPS {
+        assert_eq!(next(&mut lhs), next(&mut rhs));
     }
 }
 
@@ -239,6 +334,8 @@ fn assert_f64_distribution<const LANES: usize, R>(mut rng: R, mut next: impl FnM
     );
 }
 
+/// Every lane of the generator seeded with `ref_seed` runs the stream of the reference seeded with `reference_seed`.
+/// Generators whose lanes run separate streams of the same seed pass `lane_reference_rng`, which also takes the lane.
 macro_rules! define_prng_tests {
     (
         $(#[$meta:meta])*
@@ -252,6 +349,33 @@ macro_rules! define_prng_tests {
         reference_next = $reference_next:expr,
         next_u64 = $next_u64:expr,
         next_f64 = $next_f64:expr
+    ) => {
+        define_prng_tests!(
+            $(#[$meta])*
+            $module,
+            lanes = $lanes,
+            rng = $rng_ty,
+            seed = $seed_ty,
+            ref_seed = $ref_seed,
+            reference_seed = $reference_seed,
+            lane_reference_rng = |seed, _lane: usize| ($reference_rng)(seed),
+            reference_next = $reference_next,
+            next_u64 = $next_u64,
+            next_f64 = $next_f64
+        );
+    };
+    (
+        $(#[$meta:meta])*
+        $module:ident,
+        lanes = $lanes:expr,
+        rng = $rng_ty:path,
+        seed = $seed_ty:path,
+        ref_seed = $ref_seed:expr,
+        reference_seed = $reference_seed:expr,
+        lane_reference_rng = $lane_reference_rng:expr,
+        reference_next = $reference_next:expr,
+        next_u64 = $next_u64:expr,
+        next_f64 = $next_f64:expr
     ) => {
         $(#[$meta])*
         mod $module {
@@ -260,9 +384,12 @@ macro_rules! define_prng_tests {
             #[test]
             fn reference() {
                 let rng: $rng_ty = <$rng_ty>::from_seed(<$seed_ty>::from($ref_seed));
-                let mut reference = ($reference_rng)($reference_seed);
+                let mut references: [_; $lanes] =
+                    core::array::from_fn(|lane| ($lane_reference_rng)($reference_seed, lane));
                 let reference_next = $reference_next;
-                assert_matches_scalar_reference::<$lanes, _>(rng, $next_u64, || reference_next(&mut reference));
+                assert_matches_scalar_reference::<$lanes, _>(rng, $next_u64, |lane| {
+                    reference_next(&mut references[lane])
+                });
             }
 
             #[test]
@@ -290,6 +417,87 @@ macro_rules! define_prng_tests {
                 }
             }
 
+            #[test]
+            fn state_roundtrip() {
+                let mut rng = random_seeded_rng::<$rng_ty>();
+                let _ = $next_u64(&mut rng);
+
+                let state = rng.state();
+                let restored = <$rng_ty>::from_state(state);
+                assert_eq!(restored.state(), state);
+
+                assert_rngs_continue::<$lanes, _>(rng, restored, $next_u64);
+            }
+
+            #[test]
+            fn lane_matches_scalar_reference() {
+                let rng: $rng_ty = <$rng_ty>::from_seed(<$seed_ty>::from($ref_seed));
+                let mut lane = rng.lane($lanes - 1);
+                let mut reference = ($lane_reference_rng)($reference_seed, $lanes - 1);
+                let reference_next = $reference_next;
+
+                for _ in 0..STATE_ROUNDTRIP_STEPS {
+                    assert_eq!(lane.next_u64(), reference_next(&mut reference));
+                }
+            }
+
+            #[test]
+            fn lanes_continue_stream() {
+                let mut rng = random_seeded_rng::<$rng_ty>();
+                let _ = $next_u64(&mut rng);
+
+                let mut lanes = rng.clone().into_lanes();
+                assert_eq!(lanes[1], rng.lane(1));
+                assert_eq!(<$rng_ty>::from_lanes(lanes.clone()), rng);
+
+                for _ in 0..STATE_ROUNDTRIP_STEPS {
+                    let expected = $next_u64(&mut rng);
+                    assert_eq!(lanes.each_mut().map(|lane| lane.next_u64()), expected);
+                }
+
+                assert_rngs_continue::<$lanes, _>(<$rng_ty>::from_lanes(lanes), rng, $next_u64);
+            }
+
+            #[test]
+            #[should_panic(expected = "out of range")]
+            fn lane_out_of_range() {
+                let _ = random_seeded_rng::<$rng_ty>().lane($lanes);
+            }
+
+            #[test]
+            fn clone_and_eq() {
+                let mut rng = random_seeded_rng::<$rng_ty>();
+                let mut fork = rng.clone();
+                assert_eq!(rng, fork);
+
+                let _ = $next_u64(&mut rng);
+                assert_ne!(rng, fork);
+                let _ = $next_u64(&mut fork);
+                assert_eq!(rng, fork);
+
+                assert_rngs_continue::<$lanes, _>(rng, fork, $next_u64);
+            }
+
+            #[test]
+            fn debug_prints_lane_state() {
+                let state = core::array::from_fn(|index| 0x1000 + index as u64);
+                let debug = format!("{:?}", <$rng_ty>::from_state(state));
+
+                assert!(state.iter().all(|word| debug.contains(&format!("{word}"))), "{debug}");
+            }
+
+            #[cfg(feature = "serde")]
+            #[test]
+            fn serde_roundtrip() {
+                let mut rng = random_seeded_rng::<$rng_ty>();
+                let _ = $next_u64(&mut rng);
+
+                let json = serde_json::to_string(&rng).unwrap();
+                let restored: $rng_ty = serde_json::from_str(&json).unwrap();
+
+                assert_rngs_continue::<$lanes, _>(rng, restored, $next_u64);
+            }
+
             #[test]
             fn sample_u64() {
                 let rng = random_seeded_rng::<$rng_ty>();
@@ -382,6 +590,62 @@ define_prng_tests!(
     next_f64 = |rng: &mut Xoshiro256PlusPlusX4| rng.next_f64x4().to_array()
 );
 
+#[cfg(feature = "portable")]
+define_prng_tests!(
+    portable_xoshiro256starstar_x4,
+    lanes = 4,
+    rng = Xoshiro256StarStarX4,
+    seed = Xoshiro256StarStarX4Seed,
+    ref_seed = ref_seed_256(),
+    reference_seed = xoshiro_reference_seed(),
+    reference_rng = rand_xoshiro::Xoshiro256StarStar::from_seed,
+    reference_next = |rng: &mut rand_xoshiro::Xoshiro256StarStar| rng.next_u64(),
+    next_u64 = |rng: &mut Xoshiro256StarStarX4| rng.next_u64x4().to_array(),
+    next_f64 = |rng: &mut Xoshiro256StarStarX4| rng.next_f64x4().to_array()
+);
+
+#[cfg(feature = "portable")]
+define_prng_tests!(
+    portable_xoshiro512plusplus_x4,
+    lanes = 4,
+    rng = Xoshiro512PlusPlusX4,
+    seed = Xoshiro512PlusPlusX4Seed,
+    ref_seed = ref_seed_xoshiro512_x4(),
+    reference_seed = xoshiro512_reference_seed(),
+    reference_rng = |seed| rand_xoshiro::Xoshiro512PlusPlus::from_seed(rand_xoshiro::Seed512(seed)),
+    reference_next = |rng: &mut rand_xoshiro::Xoshiro512PlusPlus| rng.next_u64(),
+    next_u64 = |rng: &mut Xoshiro512PlusPlusX4| rng.next_u64x4().to_array(),
+    next_f64 = |rng: &mut Xoshiro512PlusPlusX4| rng.next_f64x4().to_array()
+);
+
+#[cfg(feature = "portable")]
+define_prng_tests!(
+    portable_xoshiro512starstar_x4,
+    lanes = 4,
+    rng = Xoshiro512StarStarX4,
+    seed = Xoshiro512StarStarX4Seed,
+    ref_seed = ref_seed_xoshiro512_x4(),
+    reference_seed = xoshiro512_reference_seed(),
+    reference_rng = |seed| rand_xoshiro::Xoshiro512StarStar::from_seed(rand_xoshiro::Seed512(seed)),
+    reference_next = |rng: &mut rand_xoshiro::Xoshiro512StarStar| rng.next_u64(),
+    next_u64 = |rng: &mut Xoshiro512StarStarX4| rng.next_u64x4().to_array(),
+    next_f64 = |rng: &mut Xoshiro512StarStarX4| rng.next_f64x4().to_array()
+);
+
+#[cfg(feature = "portable")]
+define_prng_tests!(
+    portable_pcg64dxsm_x4,
+    lanes = 4,
+    rng = Pcg64DxsmX4,
+    seed = Pcg64DxsmX4Seed,
+    ref_seed = ref_seed_256(),
+    reference_seed = xoshiro_reference_seed(),
+    reference_rng = rand_pcg::Pcg64Dxsm::from_seed,
+    reference_next = |rng: &mut rand_pcg::Pcg64Dxsm| rng.next_u64(),
+    next_u64 = |rng: &mut Pcg64DxsmX4| rng.next_u64x4().to_array(),
+    next_f64 = |rng: &mut Pcg64DxsmX4| rng.next_f64x4().to_array()
+);
+
 #[cfg(feature = "portable")]
 define_prng_tests!(
     portable_xoshiro256plusplus_x8,
@@ -396,6 +660,62 @@ define_prng_tests!(
     next_f64 = |rng: &mut Xoshiro256PlusPlusX8| rng.next_f64x8().to_array()
 );
 
+#[cfg(feature = "portable")]
+define_prng_tests!(
+    portable_xoshiro256starstar_x8,
+    lanes = 8,
+    rng = Xoshiro256StarStarX8,
+    seed = Xoshiro256StarStarX8Seed,
+    ref_seed = ref_seed_512(),
+    reference_seed = xoshiro_reference_seed(),
+    reference_rng = rand_xoshiro::Xoshiro256StarStar::from_seed,
+    reference_next = |rng: &mut rand_xoshiro::Xoshiro256StarStar| rng.next_u64(),
+    next_u64 = |rng: &mut Xoshiro256StarStarX8| rng.next_u64x8().to_array(),
+    next_f64 = |rng: &mut Xoshiro256StarStarX8| rng.next_f64x8().to_array()
+);
+
+#[cfg(feature = "portable")]
+define_prng_tests!(
+    portable_xoshiro512plusplus_x8,
+    lanes = 8,
+    rng = Xoshiro512PlusPlusX8,
+    seed = Xoshiro512PlusPlusX8Seed,
+    ref_seed = ref_seed_xoshiro512_x8(),
+    reference_seed = xoshiro512_reference_seed(),
+    reference_rng = |seed| rand_xoshiro::Xoshiro512PlusPlus::from_seed(rand_xoshiro::Seed512(seed)),
+    reference_next = |rng: &mut rand_xoshiro::Xoshiro512PlusPlus| rng.next_u64(),
+    next_u64 = |rng: &mut Xoshiro512PlusPlusX8| rng.next_u64x8().to_array(),
+    next_f64 = |rng: &mut Xoshiro512PlusPlusX8| rng.next_f64x8().to_array()
+);
+
+#[cfg(feature = "portable")]
+define_prng_tests!(
+    portable_xoshiro512starstar_x8,
+    lanes = 8,
+    rng = Xoshiro512StarStarX8,
+    seed = Xoshiro512StarStarX8Seed,
+    ref_seed = ref_seed_xoshiro512_x8(),
+    reference_seed = xoshiro512_reference_seed(),
+    reference_rng = |seed| rand_xoshiro::Xoshiro512StarStar::from_seed(rand_xoshiro::Seed512(seed)),
+    reference_next = |rng: &mut rand_xoshiro::Xoshiro512StarStar| rng.next_u64(),
+    next_u64 = |rng: &mut Xoshiro512StarStarX8| rng.next_u64x8().to_array(),
+    next_f64 = |rng: &mut Xoshiro512StarStarX8| rng.next_f64x8().to_array()
+);
+
+#[cfg(feature = "portable")]
+define_prng_tests!(
+    portable_pcg64dxsm_x8,
+    lanes = 8,
+    rng = Pcg64DxsmX8,
+    seed = Pcg64DxsmX8Seed,
+    ref_seed = ref_seed_512(),
+    reference_seed = xoshiro_reference_seed(),
+    reference_rng = rand_pcg::Pcg64Dxsm::from_seed,
+    reference_next = |rng: &mut rand_pcg::Pcg64Dxsm| rng.next_u64(),
+    next_u64 = |rng: &mut Pcg64DxsmX8| rng.next_u64x8().to_array(),
+    next_f64 = |rng: &mut Pcg64DxsmX8| rng.next_f64x8().to_array()
+);
+
 #[cfg(feature = "portable")]
 define_prng_tests!(
     portable_biski64_x4,
@@ -424,6 +744,34 @@ define_prng_tests!(
     next_f64 = |rng: &mut Biski64X8| rng.next_f64x8().to_array()
 );
 
+#[cfg(feature = "portable")]
+define_prng_tests!(
+    portable_xoroshiro128plus_x8,
+    lanes = 8,
+    rng = Xoroshiro128PlusX8,
+    seed = Xoroshiro128PlusX8Seed,
+    ref_seed = ref_seed_xoroshiro_x8(),
+    reference_seed = xoroshiro_reference_seed(),
+    reference_rng = rand_xoshiro::Xoroshiro128Plus::from_seed,
+    reference_next = |rng: &mut rand_xoshiro::Xoroshiro128Plus| rng.next_u64(),
+    next_u64 = |rng: &mut Xoroshiro128PlusX8| rng.next_u64x8().to_array(),
+    next_f64 = |rng: &mut Xoroshiro128PlusX8| rng.next_f64x8().to_array()
+);
+
+#[cfg(feature = "portable")]
+define_prng_tests!(
+    portable_xoroshiro128plusplus_x8,
+    lanes = 8,
+    rng = Xoroshiro128PlusPlusX8,
+    seed = Xoroshiro128PlusPlusX8Seed,
+    ref_seed = ref_seed_xoroshiro_x8(),
+    reference_seed = xoroshiro_reference_seed(),
+    reference_rng = rand_xoshiro::Xoroshiro128PlusPlus::from_seed,
+    reference_next = |rng: &mut rand_xoshiro::Xoroshiro128PlusPlus| rng.next_u64(),
+    next_u64 = |rng: &mut Xoroshiro128PlusPlusX8| rng.next_u64x8().to_array(),
+    next_f64 = |rng: &mut Xoroshiro128PlusPlusX8| rng.next_f64x8().to_array()
+);
+
 #[cfg(all(feature = "specific", target_arch = "x86_64", target_feature = "avx2"))]
 define_prng_tests!(
     specific_avx2_frand_x4,
@@ -466,6 +814,62 @@ define_prng_tests!(
     next_f64 = |rng: &mut SpecificXoshiro256PlusPlusX4| *rng.next_f64x4()
 );
 
+#[cfg(all(feature = "specific", target_arch = "x86_64", target_feature = "avx2"))]
+define_prng_tests!(
+    specific_avx2_xoshiro256starstar_x4,
+    lanes = 4,
+    rng = SpecificXoshiro256StarStarX4,
+    seed = SpecificXoshiro256StarStarX4Seed,
+    ref_seed = ref_seed_256(),
+    reference_seed = xoshiro_reference_seed(),
+    reference_rng = rand_xoshiro::Xoshiro256StarStar::from_seed,
+    reference_next = |rng: &mut rand_xoshiro::Xoshiro256StarStar| rng.next_u64(),
+    next_u64 = |rng: &mut SpecificXoshiro256StarStarX4| *rng.next_u64x4(),
+    next_f64 = |rng: &mut SpecificXoshiro256StarStarX4| *rng.next_f64x4()
+);
+
+#[cfg(all(feature = "specific", target_arch = "x86_64", target_feature = "avx2"))]
+define_prng_tests!(
+    specific_avx2_xoshiro512plusplus_x4,
+    lanes = 4,
+    rng = SpecificXoshiro512PlusPlusX4,
+    seed = SpecificXoshiro512PlusPlusX4Seed,
+    ref_seed = ref_seed_xoshiro512_x4(),
+    reference_seed = xoshiro512_reference_seed(),
+    reference_rng = |seed| rand_xoshiro::Xoshiro512PlusPlus::from_seed(rand_xoshiro::Seed512(seed)),
+    reference_next = |rng: &mut rand_xoshiro::Xoshiro512PlusPlus| rng.next_u64(),
+    next_u64 = |rng: &mut SpecificXoshiro512PlusPlusX4| *rng.next_u64x4(),
+    next_f64 = |rng: &mut SpecificXoshiro512PlusPlusX4| *rng.next_f64x4()
+);
+
+#[cfg(all(feature = "specific", target_arch = "x86_64", target_feature = "avx2"))]
+define_prng_tests!(
+    specific_avx2_xoshiro512starstar_x4,
+    lanes = 4,
+    rng = SpecificXoshiro512StarStarX4,
+    seed = SpecificXoshiro512StarStarX4Seed,
+    ref_seed = ref_seed_xoshiro512_x4(),
+    reference_seed = xoshiro512_reference_seed(),
+    reference_rng = |seed| rand_xoshiro::Xoshiro512StarStar::from_seed(rand_xoshiro::Seed512(seed)),
+    reference_next = |rng: &mut rand_xoshiro::Xoshiro512StarStar| rng.next_u64(),
+    next_u64 = |rng: &mut SpecificXoshiro512StarStarX4| *rng.next_u64x4(),
+    next_f64 = |rng: &mut SpecificXoshiro512StarStarX4| *rng.next_f64x4()
+);
+
+#[cfg(all(feature = "specific", target_arch = "x86_64", target_feature = "avx2"))]
+define_prng_tests!(
+    specific_avx2_pcg64dxsm_x4,
+    lanes = 4,
+    rng = SpecificPcg64DxsmX4,
+    seed = SpecificPcg64DxsmX4Seed,
+    ref_seed = ref_seed_256(),
+    reference_seed = xoshiro_reference_seed(),
+    reference_rng = rand_pcg::Pcg64Dxsm::from_seed,
+    reference_next = |rng: &mut rand_pcg::Pcg64Dxsm| rng.next_u64(),
+    next_u64 = |rng: &mut SpecificPcg64DxsmX4| *rng.next_u64x4(),
+    next_f64 = |rng: &mut SpecificPcg64DxsmX4| *rng.next_f64x4()
+);
+
 #[cfg(all(feature = "specific", target_arch = "x86_64", target_feature = "avx2"))]
 define_prng_tests!(
     specific_avx2_biski64_x4,
@@ -540,6 +944,86 @@ define_prng_tests!(
     next_f64 = |rng: &mut SpecificXoshiro256PlusPlusX8| *rng.next_f64x8()
 );
 
+#[cfg(all(
+    feature = "specific",
+    target_arch = "x86_64",
+    target_feature = "avx512f",
+    target_feature = "avx512dq",
+    target_feature = "avx512vl"
+))]
+define_prng_tests!(
+    specific_avx512_xoshiro256starstar_x8,
+    lanes = 8,
+    rng = SpecificXoshiro256StarStarX8,
+    seed = SpecificXoshiro256StarStarX8Seed,
+    ref_seed = ref_seed_512(),
+    reference_seed = xoshiro_reference_seed(),
+    reference_rng = rand_xoshiro::Xoshiro256StarStar::from_seed,
+    reference_next = |rng: &mut rand_xoshiro::Xoshiro256StarStar| rng.next_u64(),
+    next_u64 = |rng: &mut SpecificXoshiro256StarStarX8| *rng.next_u64x8(),
+    next_f64 = |rng: &mut SpecificXoshiro256StarStarX8| *rng.next_f64x8()
+);
+
+#[cfg(all(
+    feature = "specific",
+    target_arch = "x86_64",
+    target_feature = "avx512f",
+    target_feature = "avx512dq",
+    target_feature = "avx512vl"
+))]
+define_prng_tests!(
+    specific_avx512_xoshiro512plusplus_x8,
+    lanes = 8,
+    rng = SpecificXoshiro512PlusPlusX8,
+    seed = SpecificXoshiro512PlusPlusX8Seed,
+    ref_seed = ref_seed_xoshiro512_x8(),
+    reference_seed = xoshiro512_reference_seed(),
+    reference_rng = |seed| rand_xoshiro::Xoshiro512PlusPlus::from_seed(rand_xoshiro::Seed512(seed)),
+    reference_next = |rng: &mut rand_xoshiro::Xoshiro512PlusPlus| rng.next_u64(),
+    next_u64 = |rng: &mut SpecificXoshiro512PlusPlusX8| *rng.next_u64x8(),
+    next_f64 = |rng: &mut SpecificXoshiro512PlusPlusX8| *rng.next_f64x8()
+);
+
+#[cfg(all(
+    feature = "specific",
+    target_arch = "x86_64",
+    target_feature = "avx512f",
+    target_feature = "avx512dq",
+    target_feature = "avx512vl"
+))]
+define_prng_tests!(
+    specific_avx512_xoshiro512starstar_x8,
+    lanes = 8,
+    rng = SpecificXoshiro512StarStarX8,
+    seed = SpecificXoshiro512StarStarX8Seed,
+    ref_seed = ref_seed_xoshiro512_x8(),
+    reference_seed = xoshiro512_reference_seed(),
+    reference_rng = |seed| rand_xoshiro::Xoshiro512StarStar::from_seed(rand_xoshiro::Seed512(seed)),
+    reference_next = |rng: &mut rand_xoshiro::Xoshiro512StarStar| rng.next_u64(),
+    next_u64 = |rng: &mut SpecificXoshiro512StarStarX8| *rng.next_u64x8(),
+    next_f64 = |rng: &mut SpecificXoshiro512StarStarX8| *rng.next_f64x8()
+);
+
+#[cfg(all(
+    feature = "specific",
+    target_arch = "x86_64",
+    target_feature = "avx512f",
+    target_feature = "avx512dq",
+    target_feature = "avx512vl"
+))]
+define_prng_tests!(
+    specific_avx512_pcg64dxsm_x8,
+    lanes = 8,
+    rng = SpecificPcg64DxsmX8,
+    seed = SpecificPcg64DxsmX8Seed,
+    ref_seed = ref_seed_512(),
+    reference_seed = xoshiro_reference_seed(),
+    reference_rng = rand_pcg::Pcg64Dxsm::from_seed,
+    reference_next = |rng: &mut rand_pcg::Pcg64Dxsm| rng.next_u64(),
+    next_u64 = |rng: &mut SpecificPcg64DxsmX8| *rng.next_u64x8(),
+    next_f64 = |rng: &mut SpecificPcg64DxsmX8| *rng.next_f64x8()
+);
+
 #[cfg(all(
     feature = "specific",
     target_arch = "x86_64",
@@ -560,15 +1044,1148 @@ define_prng_tests!(
     next_f64 = |rng: &mut SpecificBiski64X8| *rng.next_f64x8()
 );
 
-#[cfg(all(feature = "specific", target_arch = "x86_64", target_feature = "avx2"))]
-mod specific_avx2_shishua {
-    use alloc::vec::Vec;
-    use rand::Rng;
-    use rand_core::TryRngCore;
-
-    use super::*;
+#[cfg(all(
+    feature = "specific",
+    target_arch = "x86_64",
+    target_feature = "avx512f",
+    target_feature = "avx512dq",
+    target_feature = "avx512vl"
+))]
+define_prng_tests!(
+    specific_avx512_xoroshiro128plus_x8,
+    lanes = 8,
+    rng = SpecificXoroshiro128PlusX8,
+    seed = SpecificXoroshiro128PlusX8Seed,
+    ref_seed = ref_seed_xoroshiro_x8(),
+    reference_seed = xoroshiro_reference_seed(),
+    reference_rng = rand_xoshiro::Xoroshiro128Plus::from_seed,
+    reference_next = |rng: &mut rand_xoshiro::Xoroshiro128Plus| rng.next_u64(),
+    next_u64 = |rng: &mut SpecificXoroshiro128PlusX8| *rng.next_u64x8(),
+    next_f64 = |rng: &mut SpecificXoroshiro128PlusX8| *rng.next_f64x8()
+);
 
-    type DefaultShishua = Shishua<DEFAULT_BUFFER_SIZE>;
+#[cfg(all(
+    feature = "specific",
+    target_arch = "x86_64",
+    target_feature = "avx512f",
+    target_feature = "avx512dq",
+    target_feature = "avx512vl"
+))]
+define_prng_tests!(
+    specific_avx512_xoroshiro128plusplus_x8,
+    lanes = 8,
+    rng = SpecificXoroshiro128PlusPlusX8,
+    seed = SpecificXoroshiro128PlusPlusX8Seed,
+    ref_seed = ref_seed_xoroshiro_x8(),
+    reference_seed = xoroshiro_reference_seed(),
+    reference_rng = rand_xoshiro::Xoroshiro128PlusPlus::from_seed,
+    reference_next = |rng: &mut rand_xoshiro::Xoroshiro128PlusPlus| rng.next_u64(),
+    next_u64 = |rng: &mut SpecificXoroshiro128PlusPlusX8| *rng.next_u64x8(),
+    next_f64 = |rng: &mut SpecificXoroshiro128PlusPlusX8| *rng.next_f64x8()
+);
+
+#[cfg(feature = "portable")]
+define_prng_tests!(
+    portable_splitmix64_x4,
+    lanes = 4,
+    rng = SplitMix64X4,
+    seed = SplitMix64X4Seed,
+    ref_seed = ref_seed_splitmix64_x4(),
+    reference_seed = 1u64,
+    reference_rng = rand_xoshiro::SplitMix64::seed_from_u64,
+    reference_next = |rng: &mut rand_xoshiro::SplitMix64| rng.next_u64(),
+    next_u64 = |rng: &mut SplitMix64X4| rng.next_u64x4().to_array(),
+    next_f64 = |rng: &mut SplitMix64X4| rng.next_f64x4().to_array()
+);
+
+#[cfg(feature = "portable")]
+define_prng_tests!(
+    portable_splitmix64_x8,
+    lanes = 8,
+    rng = SplitMix64X8,
+    seed = SplitMix64X8Seed,
+    ref_seed = ref_seed_splitmix64_x8(),
+    reference_seed = 1u64,
+    reference_rng = rand_xoshiro::SplitMix64::seed_from_u64,
+    reference_next = |rng: &mut rand_xoshiro::SplitMix64| rng.next_u64(),
+    next_u64 = |rng: &mut SplitMix64X8| rng.next_u64x8().to_array(),
+    next_f64 = |rng: &mut SplitMix64X8| rng.next_f64x8().to_array()
+);
+
+#[cfg(all(feature = "specific", target_arch = "x86_64", target_feature = "avx2"))]
+define_prng_tests!(
+    specific_avx2_splitmix64_x4,
+    lanes = 4,
+    rng = SpecificSplitMix64X4,
+    seed = SpecificSplitMix64X4Seed,
+    ref_seed = ref_seed_splitmix64_x4(),
+    reference_seed = 1u64,
+    reference_rng = rand_xoshiro::SplitMix64::seed_from_u64,
+    reference_next = |rng: &mut rand_xoshiro::SplitMix64| rng.next_u64(),
+    next_u64 = |rng: &mut SpecificSplitMix64X4| *rng.next_u64x4(),
+    next_f64 = |rng: &mut SpecificSplitMix64X4| *rng.next_f64x4()
+);
+
+#[cfg(all(
+    feature = "specific",
+    target_arch = "x86_64",
+    target_feature = "avx512f",
+    target_feature = "avx512dq",
+    target_feature = "avx512vl"
+))]
+define_prng_tests!(
+    specific_avx512_splitmix64_x8,
+    lanes = 8,
+    rng = SpecificSplitMix64X8,
+    seed = SpecificSplitMix64X8Seed,
+    ref_seed = ref_seed_splitmix64_x8(),
+    reference_seed = 1u64,
+    reference_rng = rand_xoshiro::SplitMix64::seed_from_u64,
+    reference_next = |rng: &mut rand_xoshiro::SplitMix64| rng.next_u64(),
+    next_u64 = |rng: &mut SpecificSplitMix64X8| *rng.next_u64x8(),
+    next_f64 = |rng: &mut SpecificSplitMix64X8| *rng.next_f64x8()
+);
+
+#[cfg(feature = "portable")]
+define_prng_tests!(
+    portable_wyrand_x4,
+    lanes = 4,
+    rng = WyRandX4,
+    seed = WyRandX4Seed,
+    ref_seed = repeated_lane_seed::<32>(&sequential_words::<1>(), 4),
+    reference_seed = sequential_words::<1>(),
+    reference_rng = crate::scalar::WyRand::from_state,
+    reference_next = |rng: &mut crate::scalar::WyRand| rng.next_u64(),
+    next_u64 = |rng: &mut WyRandX4| rng.next_u64x4().to_array(),
+    next_f64 = |rng: &mut WyRandX4| rng.next_f64x4().to_array()
+);
+
+#[cfg(feature = "portable")]
+define_prng_tests!(
+    portable_wyrand_x8,
+    lanes = 8,
+    rng = WyRandX8,
+    seed = WyRandX8Seed,
+    ref_seed = repeated_lane_seed::<64>(&sequential_words::<1>(), 8),
+    reference_seed = sequential_words::<1>(),
+    reference_rng = crate::scalar::WyRand::from_state,
+    reference_next = |rng: &mut crate::scalar::WyRand| rng.next_u64(),
+    next_u64 = |rng: &mut WyRandX8| rng.next_u64x8().to_array(),
+    next_f64 = |rng: &mut WyRandX8| rng.next_f64x8().to_array()
+);
+
+#[cfg(all(feature = "specific", target_arch = "x86_64", target_feature = "avx2"))]
+define_prng_tests!(
+    specific_avx2_wyrand_x4,
+    lanes = 4,
+    rng = SpecificWyRandX4,
+    seed = SpecificWyRandX4Seed,
+    ref_seed = repeated_lane_seed::<32>(&sequential_words::<1>(), 4),
+    reference_seed = sequential_words::<1>(),
+    reference_rng = crate::scalar::WyRand::from_state,
+    reference_next = |rng: &mut crate::scalar::WyRand| rng.next_u64(),
+    next_u64 = |rng: &mut SpecificWyRandX4| *rng.next_u64x4(),
+    next_f64 = |rng: &mut SpecificWyRandX4| *rng.next_f64x4()
+);
+
+#[cfg(all(
+    feature = "specific",
+    target_arch = "x86_64",
+    target_feature = "avx512f",
+    target_feature = "avx512dq",
+    target_feature = "avx512vl"
+))]
+define_prng_tests!(
+    specific_avx512_wyrand_x8,
+    lanes = 8,
+    rng = SpecificWyRandX8,
+    seed = SpecificWyRandX8Seed,
+    ref_seed = repeated_lane_seed::<64>(&sequential_words::<1>(), 8),
+    reference_seed = sequential_words::<1>(),
+    reference_rng = crate::scalar::WyRand::from_state,
+    reference_next = |rng: &mut crate::scalar::WyRand| rng.next_u64(),
+    next_u64 = |rng: &mut SpecificWyRandX8| *rng.next_u64x8(),
+    next_f64 = |rng: &mut SpecificWyRandX8| *rng.next_f64x8()
+);
+
+#[cfg(feature = "portable")]
+define_prng_tests!(
+    portable_romutrio_x4,
+    lanes = 4,
+    rng = RomuTrioX4,
+    seed = RomuTrioX4Seed,
+    ref_seed = repeated_lane_seed::<96>(&sequential_words::<3>(), 4),
+    reference_seed = sequential_words::<3>(),
+    reference_rng = crate::scalar::RomuTrio::from_state,
+    reference_next = |rng: &mut crate::scalar::RomuTrio| rng.next_u64(),
+    next_u64 = |rng: &mut RomuTrioX4| rng.next_u64x4().to_array(),
+    next_f64 = |rng: &mut RomuTrioX4| rng.next_f64x4().to_array()
+);
+
+#[cfg(feature = "portable")]
+define_prng_tests!(
+    portable_romutrio_x8,
+    lanes = 8,
+    rng = RomuTrioX8,
+    seed = RomuTrioX8Seed,
+    ref_seed = repeated_lane_seed::<192>(&sequential_words::<3>(), 8),
+    reference_seed = sequential_words::<3>(),
+    reference_rng = crate::scalar::RomuTrio::from_state,
+    reference_next = |rng: &mut crate::scalar::RomuTrio| rng.next_u64(),
+    next_u64 = |rng: &mut RomuTrioX8| rng.next_u64x8().to_array(),
+    next_f64 = |rng: &mut RomuTrioX8| rng.next_f64x8().to_array()
+);
+
+#[cfg(all(feature = "specific", target_arch = "x86_64", target_feature = "avx2"))]
+define_prng_tests!(
+    specific_avx2_romutrio_x4,
+    lanes = 4,
+    rng = SpecificRomuTrioX4,
+    seed = SpecificRomuTrioX4Seed,
+    ref_seed = repeated_lane_seed::<96>(&sequential_words::<3>(), 4),
+    reference_seed = sequential_words::<3>(),
+    reference_rng = crate::scalar::RomuTrio::from_state,
+    reference_next = |rng: &mut crate::scalar::RomuTrio| rng.next_u64(),
+    next_u64 = |rng: &mut SpecificRomuTrioX4| *rng.next_u64x4(),
+    next_f64 = |rng: &mut SpecificRomuTrioX4| *rng.next_f64x4()
+);
+
+#[cfg(all(
+    feature = "specific",
+    target_arch = "x86_64",
+    target_feature = "avx512f",
+    target_feature = "avx512dq",
+    target_feature = "avx512vl"
+))]
+define_prng_tests!(
+    specific_avx512_romutrio_x8,
+    lanes = 8,
+    rng = SpecificRomuTrioX8,
+    seed = SpecificRomuTrioX8Seed,
+    ref_seed = repeated_lane_seed::<192>(&sequential_words::<3>(), 8),
+    reference_seed = sequential_words::<3>(),
+    reference_rng = crate::scalar::RomuTrio::from_state,
+    reference_next = |rng: &mut crate::scalar::RomuTrio| rng.next_u64(),
+    next_u64 = |rng: &mut SpecificRomuTrioX8| *rng.next_u64x8(),
+    next_f64 = |rng: &mut SpecificRomuTrioX8| *rng.next_f64x8()
+);
+
+#[cfg(feature = "portable")]
+define_prng_tests!(
+    portable_sfc64_x4,
+    lanes = 4,
+    rng = crate::portable::Sfc64X4,
+    seed = crate::portable::Sfc64X4Seed,
+    ref_seed = repeated_lane_seed::<32>(&[0x0123456789abcdef], 4),
+    reference_seed = 0x0123456789abcdef,
+    lane_reference_rng = |seed, lane: usize| crate::scalar::Sfc64::with_stream(seed, lane as u64),
+    reference_next = |rng: &mut crate::scalar::Sfc64| rng.next_u64(),
+    next_u64 = |rng: &mut crate::portable::Sfc64X4| rng.next_u64x4().to_array(),
+    next_f64 = |rng: &mut crate::portable::Sfc64X4| rng.next_f64x4().to_array()
+);
+
+#[cfg(feature = "portable")]
+define_prng_tests!(
+    portable_sfc64_x8,
+    lanes = 8,
+    rng = crate::portable::Sfc64X8,
+    seed = crate::portable::Sfc64X8Seed,
+    ref_seed = repeated_lane_seed::<64>(&[0x0123456789abcdef], 8),
+    reference_seed = 0x0123456789abcdef,
+    lane_reference_rng = |seed, lane: usize| crate::scalar::Sfc64::with_stream(seed, lane as u64),
+    reference_next = |rng: &mut crate::scalar::Sfc64| rng.next_u64(),
+    next_u64 = |rng: &mut crate::portable::Sfc64X8| rng.next_u64x8().to_array(),
+    next_f64 = |rng: &mut crate::portable::Sfc64X8| rng.next_f64x8().to_array()
+);
+
+#[cfg(all(feature = "specific", target_arch = "x86_64", target_feature = "avx2"))]
+define_prng_tests!(
+    specific_avx2_sfc64_x4,
+    lanes = 4,
+    rng = crate::specific::avx2::Sfc64X4,
+    seed = crate::specific::avx2::Sfc64X4Seed,
+    ref_seed = repeated_lane_seed::<32>(&[0x0123456789abcdef], 4),
+    reference_seed = 0x0123456789abcdef,
+    lane_reference_rng = |seed, lane: usize| crate::scalar::Sfc64::with_stream(seed, lane as u64),
+    reference_next = |rng: &mut crate::scalar::Sfc64| rng.next_u64(),
+    next_u64 = |rng: &mut crate::specific::avx2::Sfc64X4| *rng.next_u64x4(),
+    next_f64 = |rng: &mut crate::specific::avx2::Sfc64X4| *rng.next_f64x4()
+);
+
+#[cfg(all(
+    feature = "specific",
+    target_arch = "x86_64",
+    target_feature = "avx512f",
+    target_feature = "avx512dq",
+    target_feature = "avx512vl"
+))]
+define_prng_tests!(
+    specific_avx512_sfc64_x8,
+    lanes = 8,
+    rng = crate::specific::avx512::Sfc64X8,
+    seed = crate::specific::avx512::Sfc64X8Seed,
+    ref_seed = repeated_lane_seed::<64>(&[0x0123456789abcdef], 8),
+    reference_seed = 0x0123456789abcdef,
+    lane_reference_rng = |seed, lane: usize| crate::scalar::Sfc64::with_stream(seed, lane as u64),
+    reference_next = |rng: &mut crate::scalar::Sfc64| rng.next_u64(),
+    next_u64 = |rng: &mut crate::specific::avx512::Sfc64X8| *rng.next_u64x8(),
+    next_f64 = |rng: &mut crate::specific::avx512::Sfc64X8| *rng.next_f64x8()
+);
+
+#[cfg(feature = "portable")]
+define_prng_tests!(
+    portable_jsf64_x4,
+    lanes = 4,
+    rng = crate::portable::Jsf64X4,
+    seed = crate::portable::Jsf64X4Seed,
+    ref_seed = repeated_lane_seed::<32>(&[0x0123456789abcdef], 4),
+    reference_seed = 0x0123456789abcdef,
+    reference_rng = crate::scalar::Jsf64::new,
+    reference_next = |rng: &mut crate::scalar::Jsf64| rng.next_u64(),
+    next_u64 = |rng: &mut crate::portable::Jsf64X4| rng.next_u64x4().to_array(),
+    next_f64 = |rng: &mut crate::portable::Jsf64X4| rng.next_f64x4().to_array()
+);
+
+#[cfg(feature = "portable")]
+define_prng_tests!(
+    portable_jsf64_x8,
+    lanes = 8,
+    rng = crate::portable::Jsf64X8,
+    seed = crate::portable::Jsf64X8Seed,
+    ref_seed = repeated_lane_seed::<64>(&[0x0123456789abcdef], 8),
+    reference_seed = 0x0123456789abcdef,
+    reference_rng = crate::scalar::Jsf64::new,
+    reference_next = |rng: &mut crate::scalar::Jsf64| rng.next_u64(),
+    next_u64 = |rng: &mut crate::portable::Jsf64X8| rng.next_u64x8().to_array(),
+    next_f64 = |rng: &mut crate::portable::Jsf64X8| rng.next_f64x8().to_array()
+);
+
+#[cfg(all(feature = "specific", target_arch = "x86_64", target_feature = "avx2"))]
+define_prng_tests!(
+    specific_avx2_jsf64_x4,
+    lanes = 4,
+    rng = crate::specific::avx2::Jsf64X4,
+    seed = crate::specific::avx2::Jsf64X4Seed,
+    ref_seed = repeated_lane_seed::<32>(&[0x0123456789abcdef], 4),
+    reference_seed = 0x0123456789abcdef,
+    reference_rng = crate::scalar::Jsf64::new,
+    reference_next = |rng: &mut crate::scalar::Jsf64| rng.next_u64(),
+    next_u64 = |rng: &mut crate::specific::avx2::Jsf64X4| *rng.next_u64x4(),
+    next_f64 = |rng: &mut crate::specific::avx2::Jsf64X4| *rng.next_f64x4()
+);
+
+#[cfg(all(
+    feature = "specific",
+    target_arch = "x86_64",
+    target_feature = "avx512f",
+    target_feature = "avx512dq",
+    target_feature = "avx512vl"
+))]
+define_prng_tests!(
+    specific_avx512_jsf64_x8,
+    lanes = 8,
+    rng = crate::specific::avx512::Jsf64X8,
+    seed = crate::specific::avx512::Jsf64X8Seed,
+    ref_seed = repeated_lane_seed::<64>(&[0x0123456789abcdef], 8),
+    reference_seed = 0x0123456789abcdef,
+    reference_rng = crate::scalar::Jsf64::new,
+    reference_next = |rng: &mut crate::scalar::Jsf64| rng.next_u64(),
+    next_u64 = |rng: &mut crate::specific::avx512::Jsf64X8| *rng.next_u64x8(),
+    next_f64 = |rng: &mut crate::specific::avx512::Jsf64X8| *rng.next_f64x8()
+);
+
+// Lane counts other than 4 and 8 only exist in `portable`, through the generic lane count
+
+#[cfg(feature = "portable")]
+define_prng_tests!(
+    portable_xoshiro256plusplus_x2,
+    lanes = 2,
+    rng = crate::portable::Xoshiro256PlusPlusX2,
+    seed = crate::portable::Xoshiro256PlusPlusX2Seed,
+    ref_seed = repeated_lane_seed::<64>(&sequential_words::<4>(), 2),
+    reference_seed = xoshiro_reference_seed(),
+    reference_rng = rand_xoshiro::Xoshiro256PlusPlus::from_seed,
+    reference_next = |rng: &mut rand_xoshiro::Xoshiro256PlusPlus| rng.next_u64(),
+    next_u64 = |rng: &mut crate::portable::Xoshiro256PlusPlusX2| crate::SimdRng::next_u64_vec(rng).to_array(),
+    next_f64 = |rng: &mut crate::portable::Xoshiro256PlusPlusX2| crate::SimdRng::next_f64_vec(rng).to_array()
+);
+
+#[cfg(feature = "portable")]
+define_prng_tests!(
+    portable_xoshiro256plusplus_x16,
+    lanes = 16,
+    rng = crate::portable::Xoshiro256PlusPlusX16,
+    seed = crate::portable::Xoshiro256PlusPlusX16Seed,
+    ref_seed = repeated_lane_seed::<512>(&sequential_words::<4>(), 16),
+    reference_seed = xoshiro_reference_seed(),
+    reference_rng = rand_xoshiro::Xoshiro256PlusPlus::from_seed,
+    reference_next = |rng: &mut rand_xoshiro::Xoshiro256PlusPlus| rng.next_u64(),
+    next_u64 = |rng: &mut crate::portable::Xoshiro256PlusPlusX16| crate::SimdRng::next_u64_vec(rng).to_array(),
+    next_f64 = |rng: &mut crate::portable::Xoshiro256PlusPlusX16| crate::SimdRng::next_f64_vec(rng).to_array()
+);
+
+#[cfg(feature = "portable")]
+define_prng_tests!(
+    portable_xoshiro256plusplus_x32,
+    lanes = 32,
+    rng = crate::portable::Xoshiro256PlusPlusX32,
+    seed = crate::portable::Xoshiro256PlusPlusX32Seed,
+    ref_seed = repeated_lane_seed::<1024>(&sequential_words::<4>(), 32),
+    reference_seed = xoshiro_reference_seed(),
+    reference_rng = rand_xoshiro::Xoshiro256PlusPlus::from_seed,
+    reference_next = |rng: &mut rand_xoshiro::Xoshiro256PlusPlus| rng.next_u64(),
+    next_u64 = |rng: &mut crate::portable::Xoshiro256PlusPlusX32| crate::SimdRng::next_u64_vec(rng).to_array(),
+    next_f64 = |rng: &mut crate::portable::Xoshiro256PlusPlusX32| crate::SimdRng::next_f64_vec(rng).to_array()
+);
+
+#[cfg(feature = "portable")]
+define_prng_tests!(
+    portable_xoroshiro128plusplus_x4,
+    lanes = 4,
+    rng = crate::portable::Xoroshiro128PlusPlusX4,
+    seed = crate::portable::Xoroshiro128PlusPlusX4Seed,
+    ref_seed = repeated_lane_seed::<64>(&sequential_words::<2>(), 4),
+    reference_seed = xoroshiro_reference_seed(),
+    reference_rng = rand_xoshiro::Xoroshiro128PlusPlus::from_seed,
+    reference_next = |rng: &mut rand_xoshiro::Xoroshiro128PlusPlus| rng.next_u64(),
+    next_u64 = |rng: &mut crate::portable::Xoroshiro128PlusPlusX4| crate::SimdRng::next_u64_vec(rng).to_array(),
+    next_f64 = |rng: &mut crate::portable::Xoroshiro128PlusPlusX4| crate::SimdRng::next_f64_vec(rng).to_array()
+);
+
+#[cfg(feature = "portable")]
+define_prng_tests!(
+    portable_splitmix64_x16,
+    lanes = 16,
+    rng = crate::portable::SplitMix64X16,
+    seed = crate::portable::SplitMix64X16Seed,
+    ref_seed = repeated_lane_seed::<128>(&sequential_words::<1>(), 16),
+    reference_seed = 1u64,
+    reference_rng = rand_xoshiro::SplitMix64::seed_from_u64,
+    reference_next = |rng: &mut rand_xoshiro::SplitMix64| rng.next_u64(),
+    next_u64 = |rng: &mut crate::portable::SplitMix64X16| crate::SimdRng::next_u64_vec(rng).to_array(),
+    next_f64 = |rng: &mut crate::portable::SplitMix64X16| crate::SimdRng::next_f64_vec(rng).to_array()
+);
+
+#[cfg(feature = "portable")]
+define_prng_tests!(
+    portable_sfc64_x16,
+    lanes = 16,
+    rng = crate::portable::Sfc64X16,
+    seed = crate::portable::Sfc64X16Seed,
+    ref_seed = repeated_lane_seed::<128>(&[0x0123456789abcdef], 16),
+    reference_seed = 0x0123456789abcdef,
+    lane_reference_rng = |seed, lane: usize| crate::scalar::Sfc64::with_stream(seed, lane as u64),
+    reference_next = |rng: &mut crate::scalar::Sfc64| rng.next_u64(),
+    next_u64 = |rng: &mut crate::portable::Sfc64X16| crate::SimdRng::next_u64_vec(rng).to_array(),
+    next_f64 = |rng: &mut crate::portable::Sfc64X16| crate::SimdRng::next_f64_vec(rng).to_array()
+);
+
+macro_rules! define_counter_prng_tests {
+    (
+        $(#[$meta:meta])*
+        $module:ident,
+        lanes = $lanes:expr,
+        rng = $rng_ty:path,
+        seed = $seed_ty:path,
+        scalar = $scalar_ty:path,
+        next_u64 = $next_u64:expr,
+        next_f64 = $next_f64:expr
+    ) => {
+        $(#[$meta])*
+        mod $module {
+            use super::*;
+
+            #[test]
+            fn matches_scalar_stream() {
+                let mut rng = random_seeded_rng::<$rng_ty>();
+                let [key, counter_lo, counter_hi] = rng.state();
+                let mut scalar = <$scalar_ty>::from_state([key, counter_lo, counter_hi, 0]);
+
+                for _ in 0..STATE_ROUNDTRIP_STEPS {
+                    let expected: [u64; $lanes] = core::array::from_fn(|_| scalar.next_u64());
+                    assert_eq!($next_u64(&mut rng), expected);
+                }
+            }
+
+            #[test]
+            fn blocks_match_scalar_blocks() {
+                let rng = random_seeded_rng::<$rng_ty>();
+                let mut scalar = <$scalar_ty>::new(rng.key(), rng.counter());
+
+                for block in <$rng_ty>::blocks(rng.key(), rng.counter()) {
+                    assert_eq!(block, <$scalar_ty>::block(rng.key(), scalar.counter()));
+                    scalar.next_u64();
+                    scalar.next_u64();
+                }
+            }
+
+            #[test]
+            fn seed_roundtrip() {
+                let bytes = seed_bytes::<24>(&sequential_words::<3>());
+                let rng = <$rng_ty>::from_seed(<$seed_ty>::from(bytes));
+
+                assert_eq!(rng.state(), sequential_words::<3>());
+                assert_eq!(<$rng_ty>::from_seed(<$seed_ty>::from(bytes.as_slice())), rng);
+            }
+
+            #[test]
+            fn state_roundtrip() {
+                let mut rng = random_seeded_rng::<$rng_ty>();
+                let _ = $next_u64(&mut rng);
+
+                let state = rng.state();
+                let restored = <$rng_ty>::from_state(state);
+                assert_eq!(restored.state(), state);
+
+                assert_rngs_continue::<$lanes, _>(rng, restored, $next_u64);
+            }
+
+            #[test]
+            fn set_counter_seeks() {
+                let mut rng = random_seeded_rng::<$rng_ty>();
+                let fork = rng.clone();
+
+                for _ in 0..3 {
+                    let _ = $next_u64(&mut rng);
+                }
+                assert_ne!(rng, fork);
+                rng.set_counter(fork.counter());
+
+                assert_rngs_continue::<$lanes, _>(rng, fork, $next_u64);
+            }
+
+            #[test]
+            fn clone_and_eq() {
+                let mut rng = random_seeded_rng::<$rng_ty>();
+                let mut fork = rng.clone();
+                assert_eq!(rng, fork);
+
+                let _ = $next_u64(&mut rng);
+                assert_ne!(rng, fork);
+                let _ = $next_u64(&mut fork);
+                assert_eq!(rng, fork);
+
+                assert_rngs_continue::<$lanes, _>(rng, fork, $next_u64);
+            }
+
+            #[cfg(feature = "serde")]
+            #[test]
+            fn serde_roundtrip() {
+                let mut rng = random_seeded_rng::<$rng_ty>();
+                let _ = $next_u64(&mut rng);
+
+                let json = serde_json::to_string(&rng).unwrap();
+                let restored: $rng_ty = serde_json::from_str(&json).unwrap();
+
+                assert_rngs_continue::<$lanes, _>(rng, restored, $next_u64);
+            }
+
+            #[test]
+            fn sample_u64() {
+                let rng = random_seeded_rng::<$rng_ty>();
+                assert_u64_smoke::<$lanes, _>(rng, $next_u64);
+            }
+
+            #[test]
+            fn sample_f64() {
+                let rng = random_seeded_rng::<$rng_ty>();
+                assert_f64_smoke::<$lanes, _>(rng, $next_f64);
+            }
+
+            #[test]
+            #[cfg_attr(any(debug_assertions, miri), ignore = "distribution test requires release mode and real RNG")]
+            fn distribution() {
+                let rng = random_seeded_rng::<$rng_ty>();
+                assert_f64_distribution::<$lanes, _>(rng, $next_f64);
+            }
+        }
+    };
+}
+
+#[cfg(feature = "portable")]
+define_counter_prng_tests!(
+    portable_philox4x32_x4,
+    lanes = 4,
+    rng = crate::portable::Philox4x32X4,
+    seed = crate::portable::Philox4x32X4Seed,
+    scalar = crate::scalar::Philox4x32,
+    next_u64 = |rng: &mut crate::portable::Philox4x32X4| rng.next_u64x4().to_array(),
+    next_f64 = |rng: &mut crate::portable::Philox4x32X4| rng.next_f64x4().to_array()
+);
+
+#[cfg(feature = "portable")]
+define_counter_prng_tests!(
+    portable_philox4x32_x8,
+    lanes = 8,
+    rng = crate::portable::Philox4x32X8,
+    seed = crate::portable::Philox4x32X8Seed,
+    scalar = crate::scalar::Philox4x32,
+    next_u64 = |rng: &mut crate::portable::Philox4x32X8| rng.next_u64x8().to_array(),
+    next_f64 = |rng: &mut crate::portable::Philox4x32X8| rng.next_f64x8().to_array()
+);
+
+#[cfg(feature = "portable")]
+define_counter_prng_tests!(
+    portable_philox2x64_x4,
+    lanes = 4,
+    rng = crate::portable::Philox2x64X4,
+    seed = crate::portable::Philox2x64X4Seed,
+    scalar = crate::scalar::Philox2x64,
+    next_u64 = |rng: &mut crate::portable::Philox2x64X4| rng.next_u64x4().to_array(),
+    next_f64 = |rng: &mut crate::portable::Philox2x64X4| rng.next_f64x4().to_array()
+);
+
+#[cfg(feature = "portable")]
+define_counter_prng_tests!(
+    portable_philox2x64_x8,
+    lanes = 8,
+    rng = crate::portable::Philox2x64X8,
+    seed = crate::portable::Philox2x64X8Seed,
+    scalar = crate::scalar::Philox2x64,
+    next_u64 = |rng: &mut crate::portable::Philox2x64X8| rng.next_u64x8().to_array(),
+    next_f64 = |rng: &mut crate::portable::Philox2x64X8| rng.next_f64x8().to_array()
+);
+
+#[cfg(all(feature = "specific", target_arch = "x86_64", target_feature = "avx2"))]
+define_counter_prng_tests!(
+    specific_avx2_philox4x32_x4,
+    lanes = 4,
+    rng = crate::specific::avx2::Philox4x32X4,
+    seed = crate::specific::avx2::Philox4x32X4Seed,
+    scalar = crate::scalar::Philox4x32,
+    next_u64 = |rng: &mut crate::specific::avx2::Philox4x32X4| *rng.next_u64x4(),
+    next_f64 = |rng: &mut crate::specific::avx2::Philox4x32X4| *rng.next_f64x4()
+);
+
+#[cfg(all(feature = "specific", target_arch = "x86_64", target_feature = "avx2"))]
+define_counter_prng_tests!(
+    specific_avx2_philox2x64_x4,
+    lanes = 4,
+    rng = crate::specific::avx2::Philox2x64X4,
+    seed = crate::specific::avx2::Philox2x64X4Seed,
+    scalar = crate::scalar::Philox2x64,
+    next_u64 = |rng: &mut crate::specific::avx2::Philox2x64X4| *rng.next_u64x4(),
+    next_f64 = |rng: &mut crate::specific::avx2::Philox2x64X4| *rng.next_f64x4()
+);
+
+#[cfg(all(
+    feature = "specific",
+    target_arch = "x86_64",
+    target_feature = "avx512f",
+    target_feature = "avx512dq",
+    target_feature = "avx512vl"
+))]
+define_counter_prng_tests!(
+    specific_avx512_philox4x32_x8,
+    lanes = 8,
+    rng = crate::specific::avx512::Philox4x32X8,
+    seed = crate::specific::avx512::Philox4x32X8Seed,
+    scalar = crate::scalar::Philox4x32,
+    next_u64 = |rng: &mut crate::specific::avx512::Philox4x32X8| *rng.next_u64x8(),
+    next_f64 = |rng: &mut crate::specific::avx512::Philox4x32X8| *rng.next_f64x8()
+);
+
+#[cfg(all(
+    feature = "specific",
+    target_arch = "x86_64",
+    target_feature = "avx512f",
+    target_feature = "avx512dq",
+    target_feature = "avx512vl"
+))]
+define_counter_prng_tests!(
+    specific_avx512_philox2x64_x8,
+    lanes = 8,
+    rng = crate::specific::avx512::Philox2x64X8,
+    seed = crate::specific::avx512::Philox2x64X8Seed,
+    scalar = crate::scalar::Philox2x64,
+    next_u64 = |rng: &mut crate::specific::avx512::Philox2x64X8| *rng.next_u64x8(),
+    next_f64 = |rng: &mut crate::specific::avx512::Philox2x64X8| *rng.next_f64x8()
+);
+
+macro_rules! define_chacha_tests {
+    (
+        $(#[$meta:meta])*
+        $module:ident,
+        lanes = $lanes:expr,
+        rng = $rng_ty:ty,
+        reference_rng = $reference_ty:ty,
+        next_u64 = $next_u64:expr,
+        next_f64 = $next_f64:expr
+    ) => {
+        $(#[$meta])*
+        mod $module {
+            use super::*;
+
+            fn seeded_pair() -> ($rng_ty, $reference_ty) {
+                let mut seed = [0u8; 32];
+                rand::rng().fill_bytes(&mut seed);
+
+                (<$rng_ty>::from_seed(seed), <$reference_ty>::from_seed(seed))
+            }
+
+            #[test]
+            fn reference() {
+                let (mut rng, mut reference) = seeded_pair();
+
+                for _ in 0..STATE_ROUNDTRIP_STEPS {
+                    let expected: [u64; $lanes] = core::array::from_fn(|_| reference.next_u64());
+                    assert_eq!($next_u64(&mut rng), expected);
+                }
+            }
+
+            #[test]
+            fn rng_core_matches_reference() {
+                // Mixed widths, so reads straddle word pairs and buffer refills
+                let (mut rng, mut reference) = seeded_pair();
+
+                for step in 0..STATE_ROUNDTRIP_STEPS {
+                    assert_eq!(rng.next_u32(), reference.next_u32());
+                    assert_eq!(rng.next_u64(), reference.next_u64());
+
+                    let (mut bytes, mut expected) = ([0u8; 67], [0u8; 67]);
+                    let len = step % bytes.len();
+                    rng.fill_bytes(&mut bytes[..len]);
+                    reference.fill_bytes(&mut expected[..len]);
+                    assert_eq!(bytes, expected);
+
+                    let expected: [u64; $lanes] = core::array::from_fn(|_| reference.next_u64());
+                    assert_eq!($next_u64(&mut rng), expected);
+                }
+            }
+
+            #[test]
+            fn word_pos_and_stream_match_reference() {
+                let (mut rng, mut reference) = seeded_pair();
+                assert_eq!(rng.seed(), reference.get_seed());
+
+                let _ = rng.next_u32();
+                let _ = reference.next_u32();
+                rng.set_stream(0xDEAD_BEEF);
+                reference.set_stream(0xDEAD_BEEF);
+                assert_eq!(rng.stream(), reference.get_stream());
+
+                for word_pos in [0, 3, 1 << 40 | 5, 0xFFFF_FFFF_FFFF_FFFF * 16 + 9, (1 << 68) + 7] {
+                    rng.set_word_pos(word_pos);
+                    reference.set_word_pos(word_pos);
+                    assert_eq!(rng.word_pos(), reference.get_word_pos());
+
+                    for _ in 0..64 {
+                        assert_eq!(rng.next_u64(), reference.next_u64());
+                    }
+                    assert_eq!(rng.word_pos(), reference.get_word_pos());
+                }
+            }
+
+            #[test]
+            fn state_roundtrip() {
+                let mut rng = random_seeded_rng::<$rng_ty>();
+                let _ = rng.next_u32();
+
+                let state = rng.state();
+                let restored = <$rng_ty>::from_state(state);
+                assert_eq!(restored.state(), state);
+
+                assert_rngs_continue::<$lanes, _>(rng, restored, $next_u64);
+            }
+
+            #[test]
+            fn clone_and_eq() {
+                let mut rng = random_seeded_rng::<$rng_ty>();
+                let mut fork = rng.clone();
+                assert_eq!(rng, fork);
+
+                let _ = $next_u64(&mut rng);
+                assert_ne!(rng, fork);
+                let _ = $next_u64(&mut fork);
+                assert_eq!(rng, fork);
+
+                assert_rngs_continue::<$lanes, _>(rng, fork, $next_u64);
+            }
+
+            #[test]
+            fn debug_leaves_out_key() {
+                let rng = random_seeded_rng::<$rng_ty>();
+                let debug = format!("{rng:?}");
+
+                assert!(rng.state()[..4].iter().all(|word| !debug.contains(&format!("{word}"))), "{debug}");
+            }
+
+            #[cfg(feature = "serde")]
+            #[test]
+            fn serde_roundtrip() {
+                let mut rng = random_seeded_rng::<$rng_ty>();
+                let _ = rng.next_u32();
+
+                let json = serde_json::to_string(&rng).unwrap();
+                let restored: $rng_ty = serde_json::from_str(&json).unwrap();
+
+                assert_rngs_continue::<$lanes, _>(rng, restored, $next_u64);
+            }
+
+            #[test]
+            fn sample_u64() {
+                let rng = random_seeded_rng::<$rng_ty>();
+                assert_u64_smoke::<$lanes, _>(rng, $next_u64);
+            }
+
+            #[test]
+            fn sample_f64() {
+                let rng = random_seeded_rng::<$rng_ty>();
+                assert_f64_smoke::<$lanes, _>(rng, $next_f64);
+            }
+
+            #[test]
+            #[cfg_attr(any(debug_assertions, miri), ignore = "distribution test requires release mode and real RNG")]
+            fn distribution() {
+                let rng = random_seeded_rng::<$rng_ty>();
+                assert_f64_distribution::<$lanes, _>(rng, $next_f64);
+            }
+        }
+    };
+}
+
+#[cfg(feature = "portable")]
+define_chacha_tests!(
+    portable_chacha8_x4,
+    lanes = 4,
+    rng = crate::portable::ChaCha8X4,
+    reference_rng = rand_chacha::ChaCha8Rng,
+    next_u64 = |rng: &mut crate::portable::ChaCha8X4| rng.next_u64x4().to_array(),
+    next_f64 = |rng: &mut crate::portable::ChaCha8X4| rng.next_f64x4().to_array()
+);
+
+#[cfg(feature = "portable")]
+define_chacha_tests!(
+    portable_chacha12_x4,
+    lanes = 4,
+    rng = crate::portable::ChaCha12X4,
+    reference_rng = rand_chacha::ChaCha12Rng,
+    next_u64 = |rng: &mut crate::portable::ChaCha12X4| rng.next_u64x4().to_array(),
+    next_f64 = |rng: &mut crate::portable::ChaCha12X4| rng.next_f64x4().to_array()
+);
+
+#[cfg(feature = "portable")]
+define_chacha_tests!(
+    portable_chacha20_x4,
+    lanes = 4,
+    rng = crate::portable::ChaCha20X4,
+    reference_rng = rand_chacha::ChaCha20Rng,
+    next_u64 = |rng: &mut crate::portable::ChaCha20X4| rng.next_u64x4().to_array(),
+    next_f64 = |rng: &mut crate::portable::ChaCha20X4| rng.next_f64x4().to_array()
+);
+
+#[cfg(feature = "portable")]
+define_chacha_tests!(
+    portable_chacha8_x8,
+    lanes = 8,
+    rng = crate::portable::ChaCha8X8,
+    reference_rng = rand_chacha::ChaCha8Rng,
+    next_u64 = |rng: &mut crate::portable::ChaCha8X8| rng.next_u64x8().to_array(),
+    next_f64 = |rng: &mut crate::portable::ChaCha8X8| rng.next_f64x8().to_array()
+);
+
+#[cfg(feature = "portable")]
+define_chacha_tests!(
+    portable_chacha12_x8,
+    lanes = 8,
+    rng = crate::portable::ChaCha12X8,
+    reference_rng = rand_chacha::ChaCha12Rng,
+    next_u64 = |rng: &mut crate::portable::ChaCha12X8| rng.next_u64x8().to_array(),
+    next_f64 = |rng: &mut crate::portable::ChaCha12X8| rng.next_f64x8().to_array()
+);
+
+#[cfg(feature = "portable")]
+define_chacha_tests!(
+    portable_chacha20_x8,
+    lanes = 8,
+    rng = crate::portable::ChaCha20X8,
+    reference_rng = rand_chacha::ChaCha20Rng,
+    next_u64 = |rng: &mut crate::portable::ChaCha20X8| rng.next_u64x8().to_array(),
+    next_f64 = |rng: &mut crate::portable::ChaCha20X8| rng.next_f64x8().to_array()
+);
+
+#[cfg(all(feature = "specific", target_arch = "x86_64", target_feature = "avx2"))]
+define_chacha_tests!(
+    specific_avx2_chacha8_x4,
+    lanes = 4,
+    rng = crate::specific::avx2::ChaCha8X4,
+    reference_rng = rand_chacha::ChaCha8Rng,
+    next_u64 = |rng: &mut crate::specific::avx2::ChaCha8X4| *rng.next_u64x4(),
+    next_f64 = |rng: &mut crate::specific::avx2::ChaCha8X4| *rng.next_f64x4()
+);
+
+#[cfg(all(feature = "specific", target_arch = "x86_64", target_feature = "avx2"))]
+define_chacha_tests!(
+    specific_avx2_chacha12_x4,
+    lanes = 4,
+    rng = crate::specific::avx2::ChaCha12X4,
+    reference_rng = rand_chacha::ChaCha12Rng,
+    next_u64 = |rng: &mut crate::specific::avx2::ChaCha12X4| *rng.next_u64x4(),
+    next_f64 = |rng: &mut crate::specific::avx2::ChaCha12X4| *rng.next_f64x4()
+);
+
+#[cfg(all(feature = "specific", target_arch = "x86_64", target_feature = "avx2"))]
+define_chacha_tests!(
+    specific_avx2_chacha20_x4,
+    lanes = 4,
+    rng = crate::specific::avx2::ChaCha20X4,
+    reference_rng = rand_chacha::ChaCha20Rng,
+    next_u64 = |rng: &mut crate::specific::avx2::ChaCha20X4| *rng.next_u64x4(),
+    next_f64 = |rng: &mut crate::specific::avx2::ChaCha20X4| *rng.next_f64x4()
+);
+
+#[cfg(all(
+    feature = "specific",
+    target_arch = "x86_64",
+    target_feature = "avx512f",
+    target_feature = "avx512dq",
+    target_feature = "avx512vl"
+))]
+define_chacha_tests!(
+    specific_avx512_chacha8_x8,
+    lanes = 8,
+    rng = crate::specific::avx512::ChaCha8X8,
+    reference_rng = rand_chacha::ChaCha8Rng,
+    next_u64 = |rng: &mut crate::specific::avx512::ChaCha8X8| *rng.next_u64x8(),
+    next_f64 = |rng: &mut crate::specific::avx512::ChaCha8X8| *rng.next_f64x8()
+);
+
+#[cfg(all(
+    feature = "specific",
+    target_arch = "x86_64",
+    target_feature = "avx512f",
+    target_feature = "avx512dq",
+    target_feature = "avx512vl"
+))]
+define_chacha_tests!(
+    specific_avx512_chacha12_x8,
+    lanes = 8,
+    rng = crate::specific::avx512::ChaCha12X8,
+    reference_rng = rand_chacha::ChaCha12Rng,
+    next_u64 = |rng: &mut crate::specific::avx512::ChaCha12X8| *rng.next_u64x8(),
+    next_f64 = |rng: &mut crate::specific::avx512::ChaCha12X8| *rng.next_f64x8()
+);
+
+#[cfg(all(
+    feature = "specific",
+    target_arch = "x86_64",
+    target_feature = "avx512f",
+    target_feature = "avx512dq",
+    target_feature = "avx512vl"
+))]
+define_chacha_tests!(
+    specific_avx512_chacha20_x8,
+    lanes = 8,
+    rng = crate::specific::avx512::ChaCha20X8,
+    reference_rng = rand_chacha::ChaCha20Rng,
+    next_u64 = |rng: &mut crate::specific::avx512::ChaCha20X8| *rng.next_u64x8(),
+    next_f64 = |rng: &mut crate::specific::avx512::ChaCha20X8| *rng.next_f64x8()
+);
+
+macro_rules! define_mt19937_64_tests {
+    (
+        $(#[$meta:meta])*
+        $module:ident,
+        lanes = $lanes:expr,
+        rng = $rng_ty:ty,
+        next_u64 = $next_u64:expr,
+        next_f64 = $next_f64:expr
+    ) => {
+        $(#[$meta])*
+        mod $module {
+            use super::*;
+            use crate::scalar::Mt19937_64;
+
+            #[test]
+            fn reference() {
+                let seed = rand::rng().next_u64();
+                let mut rng = <$rng_ty>::new(seed);
+                let mut reference = Mt19937_64::new(seed);
+
+                // Several twists of the state, each vector of 4 or 8 outputs is one chunk of the SIMD twist
+                for _ in 0..STATE_ROUNDTRIP_STEPS {
+                    let expected: [u64; $lanes] = core::array::from_fn(|_| reference.next_u64());
+                    assert_eq!($next_u64(&mut rng), expected);
+                }
+            }
+
+            #[test]
+            fn from_key_matches_reference() {
+                let key: [u64; 4] = core::array::from_fn(|_| rand::rng().next_u64());
+                let mut rng = <$rng_ty>::from_key(&key);
+                let mut reference = Mt19937_64::from_key(&key);
+
+                for _ in 0..STATE_ROUNDTRIP_STEPS {
+                    let expected: [u64; $lanes] = core::array::from_fn(|_| reference.next_u64());
+                    assert_eq!($next_u64(&mut rng), expected);
+                }
+            }
+
+            #[test]
+            fn default_matches_std() {
+                let mut rng = <$rng_ty>::default();
+                for _ in 0..9999 {
+                    let _ = rng.next_u64();
+                }
+
+                assert_eq!(rng.next_u64(), 9981545732273789042);
+            }
+
+            #[test]
+            fn rng_core_matches_reference() {
+                // Mixed widths, so vector reads straddle twists of the state
+                let seed = rand::rng().next_u64();
+                let mut rng = <$rng_ty>::seed_from_u64(seed);
+                let mut reference = Mt19937_64::seed_from_u64(seed);
+
+                for step in 0..STATE_ROUNDTRIP_STEPS {
+                    assert_eq!(rng.next_u32(), reference.next_u32());
+                    assert_eq!(rng.next_u64(), reference.next_u64());
+
+                    let (mut bytes, mut expected) = ([0u8; 67], [0u8; 67]);
+                    let len = step % bytes.len();
+                    rng.fill_bytes(&mut bytes[..len]);
+                    reference.fill_bytes(&mut expected[..len]);
+                    assert_eq!(bytes, expected);
+
+                    let expected: [u64; $lanes] = core::array::from_fn(|_| reference.next_u64());
+                    assert_eq!($next_u64(&mut rng), expected);
+                }
+            }
+
+            #[test]
+            fn state_roundtrip() {
+                let mut rng = random_seeded_rng::<$rng_ty>();
+                let _ = rng.next_u32();
+
+                let state = rng.state();
+                let restored = <$rng_ty>::from_state(state);
+                assert_eq!(restored.state(), state);
+
+                let scalar = Mt19937_64::from(rng.clone());
+                assert_eq!(scalar.state(), state);
+                assert_eq!(<$rng_ty>::from(scalar), rng);
+
+                assert_rngs_continue::<$lanes, _>(rng, restored, $next_u64);
+            }
+
+            #[test]
+            #[should_panic(expected = "The MT19937-64 index must be <= 312")]
+            fn from_state_rejects_index_past_state() {
+                let mut state = random_seeded_rng::<$rng_ty>().state();
+                state[312] = 313;
+
+                let _ = <$rng_ty>::from_state(state);
+            }
+
+            #[test]
+            fn clone_and_eq() {
+                let mut rng = random_seeded_rng::<$rng_ty>();
+                let mut fork = rng.clone();
+                assert_eq!(rng, fork);
+
+                let _ = $next_u64(&mut rng);
+                assert_ne!(rng, fork);
+                let _ = $next_u64(&mut fork);
+                assert_eq!(rng, fork);
+
+                assert_rngs_continue::<$lanes, _>(rng, fork, $next_u64);
+            }
+
+            #[cfg(feature = "serde")]
+            #[test]
+            fn serde_roundtrip() {
+                let mut rng = random_seeded_rng::<$rng_ty>();
+                let _ = rng.next_u32();
+
+                let json = serde_json::to_string(&rng).unwrap();
+                let restored: $rng_ty = serde_json::from_str(&json).unwrap();
+
+                assert_rngs_continue::<$lanes, _>(rng, restored, $next_u64);
+            }
+
+            #[cfg(feature = "serde")]
+            #[test]
+            fn serde_rejects_index_past_state() {
+                let mut value = serde_json::to_value(random_seeded_rng::<$rng_ty>()).unwrap();
+                value[312] = 313.into();
+
+                assert!(serde_json::from_value::<$rng_ty>(value).is_err());
+            }
+
+            #[test]
+            fn sample_u64() {
+                let rng = random_seeded_rng::<$rng_ty>();
+                assert_u64_smoke::<$lanes, _>(rng, $next_u64);
+            }
+
+            #[test]
+            fn sample_f64() {
+                let rng = random_seeded_rng::<$rng_ty>();
+                assert_f64_smoke::<$lanes, _>(rng, $next_f64);
+            }
+
+            #[test]
+            #[cfg_attr(any(debug_assertions, miri), ignore = "distribution test requires release mode and real RNG")]
+            fn distribution() {
+                let rng = random_seeded_rng::<$rng_ty>();
+                assert_f64_distribution::<$lanes, _>(rng, $next_f64);
+            }
+        }
+    };
+}
+
+#[cfg(feature = "portable")]
+define_mt19937_64_tests!(
+    portable_mt19937_64_x4,
+    lanes = 4,
+    rng = crate::portable::Mt19937_64X4,
+    next_u64 = |rng: &mut crate::portable::Mt19937_64X4| rng.next_u64x4().to_array(),
+    next_f64 = |rng: &mut crate::portable::Mt19937_64X4| rng.next_f64x4().to_array()
+);
+
+#[cfg(feature = "portable")]
+define_mt19937_64_tests!(
+    portable_mt19937_64_x8,
+    lanes = 8,
+    rng = crate::portable::Mt19937_64X8,
+    next_u64 = |rng: &mut crate::portable::Mt19937_64X8| rng.next_u64x8().to_array(),
+    next_f64 = |rng: &mut crate::portable::Mt19937_64X8| rng.next_f64x8().to_array()
+);
+
+#[cfg(all(feature = "specific", target_arch = "x86_64", target_feature = "avx2"))]
+define_mt19937_64_tests!(
+    specific_avx2_mt19937_64_x4,
+    lanes = 4,
+    rng = crate::specific::avx2::Mt19937_64X4,
+    next_u64 = |rng: &mut crate::specific::avx2::Mt19937_64X4| *rng.next_u64x4(),
+    next_f64 = |rng: &mut crate::specific::avx2::Mt19937_64X4| *rng.next_f64x4()
+);
+
+#[cfg(all(
+    feature = "specific",
+    target_arch = "x86_64",
+    target_feature = "avx512f",
+    target_feature = "avx512dq",
+    target_feature = "avx512vl"
+))]
+define_mt19937_64_tests!(
+    specific_avx512_mt19937_64_x8,
+    lanes = 8,
+    rng = crate::specific::avx512::Mt19937_64X8,
+    next_u64 = |rng: &mut crate::specific::avx512::Mt19937_64X8| *rng.next_u64x8(),
+    next_f64 = |rng: &mut crate::specific::avx512::Mt19937_64X8| *rng.next_f64x8()
+);
+
+#[cfg(all(feature = "specific", target_arch = "x86_64", target_feature = "avx2"))]
+mod specific_avx2_shishua {
+    use alloc::vec::Vec;
+    use rand::Rng;
+    use rand_core::TryRngCore;
+
+    use super::*;
+
+    type DefaultShishua = Shishua<DEFAULT_BUFFER_SIZE>;
 
     const FLOAT_RANGE: Range<f32> = 0.0f32..1.0f32;
 
@@ -687,6 +2304,135 @@ mod specific_avx2_shishua {
         assert_chunked_deterministic::<1024>(seed, total_bytes, &chunk_sizes);
     }
 
+    #[test]
+    fn fill_bytes_larger_than_buffer_matches_byte_stream() {
+        let seed = shishua_test_vectors::seed_bytes(shishua_test_vectors::SEED_PI);
+        let total_bytes = 256 * 5 + 77;
+
+        let mut rng_bytes = Shishua::<256>::from_seed(seed);
+        let expected = read_with_chunks(&mut rng_bytes, total_bytes, &[1]);
+
+        let mut rng_large = Shishua::<256>::from_seed(seed);
+        let mut actual = vec![0u8; total_bytes];
+        rng_large.fill_bytes(&mut actual);
+
+        assert_eq!(actual, expected);
+        assert_eq!(&actual[..512], &shishua_test_vectors::SEED_PI_EXPECTED[..]);
+        assert_eq!(rng_large.buffer_index(), rng_bytes.buffer_index());
+        assert_eq!(rng_large.next_u64(), rng_bytes.next_u64());
+    }
+
+    #[test]
+    fn fill_bytes_unbuffered_continues_stream() {
+        let seed = shishua_test_vectors::seed_bytes(shishua_test_vectors::SEED_ZERO);
+        let mut rng_bytes = Shishua::<256>::from_seed(seed);
+        let expected = read_with_chunks(&mut rng_bytes, 3 + 1024 + 13, &[1]);
+
+        let mut rng = Shishua::<256>::from_seed(seed);
+        let mut actual = vec![0u8; expected.len()];
+        let (head, rest) = actual.split_at_mut(3);
+        let (middle, tail) = rest.split_at_mut(1024);
+        rng.fill_bytes(head);
+        rng.fill_bytes_unbuffered(middle);
+        rng.fill_bytes(tail);
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn state_roundtrip_resumes_at_buffer_index() {
+        let seed = shishua_test_vectors::seed_bytes(shishua_test_vectors::SEED_PI);
+        // Mid-buffer, at a buffer boundary, after rebuffering for a tail and after streaming whole blocks
+        let prefixes = [0, 3, 24, 256, 256 + 5, 1024, 1024 + 13];
+
+        for prefix in prefixes {
+            let mut rng = Shishua::<256>::from_seed(seed);
+            let mut skipped = vec![0u8; prefix];
+            rng.fill_bytes(&mut skipped);
+
+            let mut restored = Shishua::<256>::from_state(rng.state());
+            assert_eq!(restored.state(), rng.state());
+
+            let expected = read_with_chunks(&mut rng, 1000, &[1, 8, 300]);
+            let actual = read_with_chunks(&mut restored, 1000, &[1, 8, 300]);
+            assert_eq!(actual, expected, "prefix {prefix}");
+        }
+    }
+
+    #[test]
+    fn clone_is_deep_copy() {
+        let mut rng = Shishua::<256>::from_seed(shishua_test_vectors::seed_bytes(shishua_test_vectors::SEED_PI));
+        let _ = rng.next_u32();
+
+        let mut fork = rng.clone();
+        assert_eq!(rng, fork);
+
+        let expected = read_with_chunks(&mut rng, 1000, &[1, 8, 300]);
+        assert_ne!(rng, fork);
+        assert_eq!(read_with_chunks(&mut fork, 1000, &[1, 8, 300]), expected);
+        assert_eq!(rng, fork);
+    }
+
+    #[test]
+    fn eq_treats_spent_buffer_as_fresh() {
+        let seed = shishua_test_vectors::seed_bytes(shishua_test_vectors::SEED_ZERO);
+        let mut spent = Shishua::<256>::from_seed(seed);
+        let mut skipped = [0u8; 256];
+        spent.fill_bytes(&mut skipped);
+        assert_eq!(spent.buffer_index(), 256);
+
+        let restored = Shishua::<256>::from_state(spent.state());
+        assert_eq!(restored.buffer_index(), 0);
+        assert_eq!(spent, restored);
+    }
+
+    #[test]
+    fn debug_prints_lane_state() {
+        let mut rng = DefaultShishua::from_seed([0; 32]);
+        let _ = rng.next_u64();
+        let debug = format!("{rng:?}");
+
+        assert!(debug.starts_with("Shishua { state: [["), "{debug}");
+        assert!(debug.contains("buffer_index: 8"), "{debug}");
+        assert!(debug.ends_with(", .. }"), "{debug}");
+
+        let mut spent = Shishua::<256>::from_seed([0; 32]);
+        let mut skipped = [0u8; 256];
+        spent.fill_bytes(&mut skipped);
+        let restored = Shishua::<256>::from_state(spent.state());
+        assert_eq!(spent, restored);
+        assert_eq!(format!("{spent:?}"), format!("{restored:?}"));
+    }
+
+    #[test]
+    #[should_panic(expected = "buffer index must be <=")]
+    fn from_state_invalid_buffer_index() {
+        let mut state = Shishua::<256>::from_seed([0; 32]).state();
+        state[36] = 257;
+        let _ = Shishua::<256>::from_state(state);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_roundtrip() {
+        let mut rng = DefaultShishua::from_seed(shishua_test_vectors::seed_bytes(shishua_test_vectors::SEED_PI));
+        let _ = rng.next_u32();
+
+        let json = serde_json::to_string(&rng).unwrap();
+        let mut restored: DefaultShishua = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored.buffer_index(), rng.buffer_index());
+        assert_eq!(
+            read_with_next_u64(&mut restored, 512),
+            read_with_next_u64(&mut rng, 512)
+        );
+
+        let mut state = rng.state();
+        state[36] = DEFAULT_BUFFER_SIZE as u64 + 1;
+        let json = serde_json::to_string(state.as_slice()).unwrap();
+        assert!(serde_json::from_str::<DefaultShishua>(&json).is_err());
+    }
+
     #[test]
     fn try_fill_bytes_matches_fill_bytes() {
         const LEN: usize = 256;
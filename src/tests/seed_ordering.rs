@@ -68,6 +68,42 @@ macro_rules! for_each_avx2_from_seed_case {
             crate::specific::avx2::Xoshiro256PlusPlusX4Seed,
             |rng: &mut crate::specific::avx2::Xoshiro256PlusPlusX4| *rng.next_u64x4()
         );
+        $m!(
+            asymmetric_seed_128(),
+            crate::portable::Xoshiro256StarStarX4,
+            crate::portable::Xoshiro256StarStarX4Seed,
+            |rng: &mut crate::portable::Xoshiro256StarStarX4| rng.next_u64x4().to_array(),
+            crate::specific::avx2::Xoshiro256StarStarX4,
+            crate::specific::avx2::Xoshiro256StarStarX4Seed,
+            |rng: &mut crate::specific::avx2::Xoshiro256StarStarX4| *rng.next_u64x4()
+        );
+        $m!(
+            asymmetric_seed_256(),
+            crate::portable::Xoshiro512PlusPlusX4,
+            crate::portable::Xoshiro512PlusPlusX4Seed,
+            |rng: &mut crate::portable::Xoshiro512PlusPlusX4| rng.next_u64x4().to_array(),
+            crate::specific::avx2::Xoshiro512PlusPlusX4,
+            crate::specific::avx2::Xoshiro512PlusPlusX4Seed,
+            |rng: &mut crate::specific::avx2::Xoshiro512PlusPlusX4| *rng.next_u64x4()
+        );
+        $m!(
+            asymmetric_seed_256(),
+            crate::portable::Xoshiro512StarStarX4,
+            crate::portable::Xoshiro512StarStarX4Seed,
+            |rng: &mut crate::portable::Xoshiro512StarStarX4| rng.next_u64x4().to_array(),
+            crate::specific::avx2::Xoshiro512StarStarX4,
+            crate::specific::avx2::Xoshiro512StarStarX4Seed,
+            |rng: &mut crate::specific::avx2::Xoshiro512StarStarX4| *rng.next_u64x4()
+        );
+        $m!(
+            asymmetric_seed_128(),
+            crate::portable::Pcg64DxsmX4,
+            crate::portable::Pcg64DxsmX4Seed,
+            |rng: &mut crate::portable::Pcg64DxsmX4| rng.next_u64x4().to_array(),
+            crate::specific::avx2::Pcg64DxsmX4,
+            crate::specific::avx2::Pcg64DxsmX4Seed,
+            |rng: &mut crate::specific::avx2::Pcg64DxsmX4| *rng.next_u64x4()
+        );
     };
 }
 
@@ -115,6 +151,60 @@ macro_rules! for_each_avx512_from_seed_case {
             crate::specific::avx512::Xoshiro256PlusPlusX8Seed,
             |rng: &mut crate::specific::avx512::Xoshiro256PlusPlusX8| *rng.next_u64x8()
         );
+        $m!(
+            asymmetric_seed_256(),
+            crate::portable::Xoshiro256StarStarX8,
+            crate::portable::Xoshiro256StarStarX8Seed,
+            |rng: &mut crate::portable::Xoshiro256StarStarX8| rng.next_u64x8().to_array(),
+            crate::specific::avx512::Xoshiro256StarStarX8,
+            crate::specific::avx512::Xoshiro256StarStarX8Seed,
+            |rng: &mut crate::specific::avx512::Xoshiro256StarStarX8| *rng.next_u64x8()
+        );
+        $m!(
+            asymmetric_seed_512(),
+            crate::portable::Xoshiro512PlusPlusX8,
+            crate::portable::Xoshiro512PlusPlusX8Seed,
+            |rng: &mut crate::portable::Xoshiro512PlusPlusX8| rng.next_u64x8().to_array(),
+            crate::specific::avx512::Xoshiro512PlusPlusX8,
+            crate::specific::avx512::Xoshiro512PlusPlusX8Seed,
+            |rng: &mut crate::specific::avx512::Xoshiro512PlusPlusX8| *rng.next_u64x8()
+        );
+        $m!(
+            asymmetric_seed_512(),
+            crate::portable::Xoshiro512StarStarX8,
+            crate::portable::Xoshiro512StarStarX8Seed,
+            |rng: &mut crate::portable::Xoshiro512StarStarX8| rng.next_u64x8().to_array(),
+            crate::specific::avx512::Xoshiro512StarStarX8,
+            crate::specific::avx512::Xoshiro512StarStarX8Seed,
+            |rng: &mut crate::specific::avx512::Xoshiro512StarStarX8| *rng.next_u64x8()
+        );
+        $m!(
+            asymmetric_seed_256(),
+            crate::portable::Pcg64DxsmX8,
+            crate::portable::Pcg64DxsmX8Seed,
+            |rng: &mut crate::portable::Pcg64DxsmX8| rng.next_u64x8().to_array(),
+            crate::specific::avx512::Pcg64DxsmX8,
+            crate::specific::avx512::Pcg64DxsmX8Seed,
+            |rng: &mut crate::specific::avx512::Pcg64DxsmX8| *rng.next_u64x8()
+        );
+        $m!(
+            asymmetric_seed_128(),
+            crate::portable::Xoroshiro128PlusX8,
+            crate::portable::Xoroshiro128PlusX8Seed,
+            |rng: &mut crate::portable::Xoroshiro128PlusX8| rng.next_u64x8().to_array(),
+            crate::specific::avx512::Xoroshiro128PlusX8,
+            crate::specific::avx512::Xoroshiro128PlusX8Seed,
+            |rng: &mut crate::specific::avx512::Xoroshiro128PlusX8| *rng.next_u64x8()
+        );
+        $m!(
+            asymmetric_seed_128(),
+            crate::portable::Xoroshiro128PlusPlusX8,
+            crate::portable::Xoroshiro128PlusPlusX8Seed,
+            |rng: &mut crate::portable::Xoroshiro128PlusPlusX8| rng.next_u64x8().to_array(),
+            crate::specific::avx512::Xoroshiro128PlusPlusX8,
+            crate::specific::avx512::Xoroshiro128PlusPlusX8Seed,
+            |rng: &mut crate::specific::avx512::Xoroshiro128PlusPlusX8| *rng.next_u64x8()
+        );
     };
 }
 
@@ -194,14 +284,28 @@ fn asymmetric_seed_128() -> [u8; 128] {
     fill_seed_128(&asymmetric_seed_words::<16>())
 }
 
+fn fill_seed_256(words: &[u64; 32]) -> [u8; 256] {
+    let mut seed = [0u8; 256];
+
+    for (index, word) in words.iter().enumerate() {
+        seed[(index * 8)..((index + 1) * 8)].copy_from_slice(&word.to_le_bytes());
+    }
+
+    seed
+}
+
+fn asymmetric_seed_256() -> [u8; 256] {
+    fill_seed_256(&asymmetric_seed_words::<32>())
+}
+
 #[cfg(all(
     target_arch = "x86_64",
     target_feature = "avx512f",
     target_feature = "avx512dq",
     target_feature = "avx512vl"
 ))]
-fn fill_seed_256(words: &[u64; 32]) -> [u8; 256] {
-    let mut seed = [0u8; 256];
+fn fill_seed_512(words: &[u64; 64]) -> [u8; 512] {
+    let mut seed = [0u8; 512];
 
     for (index, word) in words.iter().enumerate() {
         seed[(index * 8)..((index + 1) * 8)].copy_from_slice(&word.to_le_bytes());
@@ -216,8 +320,8 @@ fn fill_seed_256(words: &[u64; 32]) -> [u8; 256] {
     target_feature = "avx512dq",
     target_feature = "avx512vl"
 ))]
-fn asymmetric_seed_256() -> [u8; 256] {
-    fill_seed_256(&asymmetric_seed_words::<32>())
+fn asymmetric_seed_512() -> [u8; 512] {
+    fill_seed_512(&asymmetric_seed_words::<64>())
 }
 
 #[cfg(all(target_arch = "x86_64", target_feature = "avx2"))]
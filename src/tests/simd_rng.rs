@@ -0,0 +1,352 @@
+use rand_core::SeedableRng;
+
+use crate::{Interleaved, SimdRng};
+
+const SEED: u64 = 0x0123456789abcdef;
+const ROUNDS: usize = if cfg!(miri) { 16 } else { 4096 };
+
+// A kernel written once against the trait, the way downstream code would use it
+fn mean<R: SimdRng>(rng: &mut R, rounds: usize) -> f64 {
+    let mut sum = 0.0;
+    for _ in 0..rounds {
+        sum += rng.next_f64_vec().as_ref().iter().sum::<f64>();
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    let count = (rounds * R::LANES) as f64;
+    sum / count
+}
+
+fn assert_generic_kernel_runs<R: SimdRng + SeedableRng>() {
+    let mut rng = R::seed_from_u64(SEED);
+
+    assert_eq!(rng.next_u64_vec().as_ref().len(), R::LANES);
+    assert_eq!(rng.next_f64_vec().as_ref().len(), R::LANES);
+
+    let mean = mean(&mut rng, ROUNDS);
+    if !cfg!(miri) {
+        assert!(
+            (mean - 0.5).abs() < 0.01,
+            "mean of {} was {mean}",
+            core::any::type_name::<R>()
+        );
+    }
+}
+
+// The interleaved stream is the round-robin of the generators seeded the same way on their own
+fn assert_interleaves<R: SimdRng + SeedableRng, const K: usize>() {
+    let mut interleaved = Interleaved::<R, K>::seed_from_u64(SEED);
+    let mut rngs = crate::splitmix64::lane_states::<K>(SEED).map(R::seed_from_u64);
+    assert_eq!(Interleaved::<R, K>::LANES, R::LANES);
+
+    for round in 0..ROUNDS / K {
+        // Start the batches at every offset, not only at the first generator
+        if round % 3 == 1 {
+            let offset = round % K;
+            for rng in &mut rngs[..offset] {
+                assert_eq!(interleaved.next_u64_vec().as_ref(), rng.next_u64_vec().as_ref());
+            }
+            for (index, vector) in interleaved.next_u64_vecs().iter().enumerate() {
+                assert_eq!(vector.as_ref(), rngs[(offset + index) % K].next_u64_vec().as_ref());
+            }
+            for rng in &mut rngs[offset..] {
+                assert_eq!(interleaved.next_u64_vec().as_ref(), rng.next_u64_vec().as_ref());
+            }
+        } else {
+            for (rng, vector) in rngs.iter_mut().zip(interleaved.next_u64_vecs()) {
+                assert_eq!(vector.as_ref(), rng.next_u64_vec().as_ref());
+            }
+            for (rng, vector) in rngs.iter_mut().zip(interleaved.next_f64_vecs()) {
+                let expected = rng.next_f64_vec();
+                for (x, y) in vector.as_ref().iter().zip(expected.as_ref()) {
+                    assert_eq!(x.to_bits(), y.to_bits());
+                }
+            }
+        }
+    }
+
+    let mean = mean(&mut interleaved, ROUNDS);
+    if !cfg!(miri) {
+        assert!(
+            (mean - 0.5).abs() < 0.01,
+            "mean of {} was {mean}",
+            core::any::type_name::<R>()
+        );
+    }
+}
+
+#[cfg(feature = "portable")]
+mod portable {
+    use core::simd::{cmp::SimdPartialOrd, f64x8, num::SimdFloat, u64x8};
+
+    use rand_core::SeedableRng;
+
+    use super::{ROUNDS, SEED, assert_generic_kernel_runs, assert_interleaves};
+    use crate::{
+        SimdRng,
+        portable::{ExponentBits, F64Conversion, Multiply, SimdRandX4, SimdRandX8},
+    };
+
+    fn assert_matches_simd_rand_x4<
+        R: SimdRng<U64Vec = core::simd::u64x4, F64Vec = core::simd::f64x4> + SimdRandX4 + SeedableRng + Clone,
+    >() {
+        assert_eq!(R::LANES, 4);
+
+        let mut generic = R::seed_from_u64(SEED);
+        let mut backend = generic.clone();
+        for _ in 0..ROUNDS {
+            assert_eq!(generic.next_u64_vec(), backend.next_u64x4());
+            assert_eq!(generic.next_f64_vec().to_bits(), backend.next_f64x4().to_bits());
+        }
+
+        assert_generic_kernel_runs::<R>();
+    }
+
+    fn assert_matches_simd_rand_x8<
+        R: SimdRng<U64Vec = core::simd::u64x8, F64Vec = core::simd::f64x8> + SimdRandX8 + SeedableRng + Clone,
+    >() {
+        assert_eq!(R::LANES, 8);
+
+        let mut generic = R::seed_from_u64(SEED);
+        let mut backend = generic.clone();
+        for _ in 0..ROUNDS {
+            assert_eq!(generic.next_u64_vec(), backend.next_u64x8());
+            assert_eq!(generic.next_f64_vec().to_bits(), backend.next_f64x8().to_bits());
+        }
+
+        assert_generic_kernel_runs::<R>();
+    }
+
+    #[test]
+    fn x4_generators_implement_simd_rng() {
+        assert_matches_simd_rand_x4::<crate::portable::Biski64X4>();
+        assert_matches_simd_rand_x4::<crate::portable::ChaCha8X4>();
+        assert_matches_simd_rand_x4::<crate::portable::ChaCha12X4>();
+        assert_matches_simd_rand_x4::<crate::portable::ChaCha20X4>();
+        assert_matches_simd_rand_x4::<crate::portable::FrandX4>();
+        assert_matches_simd_rand_x4::<crate::portable::Jsf64X4>();
+        assert_matches_simd_rand_x4::<crate::portable::Mt19937_64X4>();
+        assert_matches_simd_rand_x4::<crate::portable::Pcg64DxsmX4>();
+        assert_matches_simd_rand_x4::<crate::portable::Philox2x64X4>();
+        assert_matches_simd_rand_x4::<crate::portable::Philox4x32X4>();
+        assert_matches_simd_rand_x4::<crate::portable::RomuTrioX4>();
+        assert_matches_simd_rand_x4::<crate::portable::Sfc64X4>();
+        assert_matches_simd_rand_x4::<crate::portable::SplitMix64X4>();
+        assert_matches_simd_rand_x4::<crate::portable::WyRandX4>();
+        assert_matches_simd_rand_x4::<crate::portable::Xoshiro256PlusX4>();
+        assert_matches_simd_rand_x4::<crate::portable::Xoshiro256PlusPlusX4>();
+        assert_matches_simd_rand_x4::<crate::portable::Xoshiro256StarStarX4>();
+        assert_matches_simd_rand_x4::<crate::portable::Xoshiro512PlusPlusX4>();
+        assert_matches_simd_rand_x4::<crate::portable::Xoshiro512StarStarX4>();
+    }
+
+    #[test]
+    fn x8_generators_implement_simd_rng() {
+        assert_matches_simd_rand_x8::<crate::portable::Biski64X8>();
+        assert_matches_simd_rand_x8::<crate::portable::ChaCha8X8>();
+        assert_matches_simd_rand_x8::<crate::portable::ChaCha12X8>();
+        assert_matches_simd_rand_x8::<crate::portable::ChaCha20X8>();
+        assert_matches_simd_rand_x8::<crate::portable::FrandX8>();
+        assert_matches_simd_rand_x8::<crate::portable::Jsf64X8>();
+        assert_matches_simd_rand_x8::<crate::portable::Mt19937_64X8>();
+        assert_matches_simd_rand_x8::<crate::portable::Pcg64DxsmX8>();
+        assert_matches_simd_rand_x8::<crate::portable::Philox2x64X8>();
+        assert_matches_simd_rand_x8::<crate::portable::Philox4x32X8>();
+        assert_matches_simd_rand_x8::<crate::portable::RomuTrioX8>();
+        assert_matches_simd_rand_x8::<crate::portable::Sfc64X8>();
+        assert_matches_simd_rand_x8::<crate::portable::SplitMix64X8>();
+        assert_matches_simd_rand_x8::<crate::portable::WyRandX8>();
+        assert_matches_simd_rand_x8::<crate::portable::Xoshiro256PlusX8>();
+        assert_matches_simd_rand_x8::<crate::portable::Xoshiro256PlusPlusX8>();
+        assert_matches_simd_rand_x8::<crate::portable::Xoshiro256StarStarX8>();
+        assert_matches_simd_rand_x8::<crate::portable::Xoshiro512PlusPlusX8>();
+        assert_matches_simd_rand_x8::<crate::portable::Xoshiro512StarStarX8>();
+        assert_matches_simd_rand_x8::<crate::portable::Xoroshiro128PlusX8>();
+        assert_matches_simd_rand_x8::<crate::portable::Xoroshiro128PlusPlusX8>();
+    }
+
+    #[test]
+    fn interleaved_generators_run_round_robin() {
+        assert_interleaves::<crate::portable::FrandX8, 1>();
+        assert_interleaves::<crate::portable::FrandX8, 4>();
+        assert_interleaves::<crate::portable::Xoshiro256PlusX4, 3>();
+        assert_interleaves::<crate::portable::Xoshiro256PlusPlusX16, 2>();
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    #[test]
+    fn f64_conversions_match_scalar_formulas() {
+        let mut words = crate::portable::Xoshiro256PlusX8::seed_from_u64(SEED);
+        let mut multiply = words.clone();
+        let mut exponent_bits = words.clone();
+        let mut x4_words = crate::portable::FrandX4::seed_from_u64(SEED);
+        let mut x4_exponent_bits = x4_words.clone();
+
+        for _ in 0..ROUNDS {
+            let v = words.next_u64x8();
+            let (m, e) = (
+                multiply.next_f64x8_with::<Multiply>(),
+                exponent_bits.next_f64x8_with::<ExponentBits>(),
+            );
+            for lane in 0..8 {
+                assert_eq!(
+                    m[lane].to_bits(),
+                    ((v[lane] >> 11) as f64 / (1u64 << 53) as f64).to_bits()
+                );
+                assert_eq!(
+                    e[lane].to_bits(),
+                    ((v[lane] >> 12) as f64 / (1u64 << 52) as f64).to_bits()
+                );
+            }
+
+            let v = x4_words.next_u64x4();
+            let e = x4_exponent_bits.next_f64x4_with::<ExponentBits>();
+            for lane in 0..4 {
+                assert_eq!(
+                    e[lane].to_bits(),
+                    ((v[lane] >> 12) as f64 / (1u64 << 52) as f64).to_bits()
+                );
+            }
+        }
+
+        for conversion in [Multiply::to_f64, ExponentBits::to_f64] {
+            assert_eq!(conversion(u64x8::splat(0)).to_bits(), u64x8::splat(0));
+            assert!(conversion(u64x8::splat(u64::MAX)).simd_lt(f64x8::splat(1.0)).all());
+        }
+    }
+}
+
+#[cfg(all(feature = "specific", target_arch = "x86_64", target_feature = "avx2"))]
+mod specific_avx2 {
+    use core::arch::x86_64::*;
+
+    use rand_core::SeedableRng;
+
+    use super::{ROUNDS, SEED, assert_generic_kernel_runs, assert_interleaves};
+    use crate::{
+        SimdRng,
+        specific::avx2::{F64x4, SimdRand, U64x4},
+    };
+
+    fn assert_matches_simd_rand<R: SimdRng<U64Vec = U64x4, F64Vec = F64x4> + SimdRand + SeedableRng + Clone>() {
+        assert_eq!(R::LANES, 4);
+
+        let mut generic = R::seed_from_u64(SEED);
+        let mut backend = generic.clone();
+        for _ in 0..ROUNDS {
+            assert_eq!(generic.next_u64_vec(), U64x4::from(backend.next_m256i()));
+            assert_eq!(
+                <[f64; 4]>::from(generic.next_f64_vec()).map(f64::to_bits),
+                <[f64; 4]>::from(F64x4::from(backend.next_m256d())).map(f64::to_bits)
+            );
+        }
+
+        // Back to the intrinsic types for code continuing with hardware intrinsics
+        let expected = <[u64; 4]>::from(backend.next_u64x4());
+        let actual = __m256i::from(generic.next_u64_vec());
+        assert_eq!(<[u64; 4]>::from(U64x4::from(actual)), expected);
+
+        assert_generic_kernel_runs::<R>();
+    }
+
+    #[test]
+    fn generators_implement_simd_rng() {
+        assert_matches_simd_rand::<crate::specific::avx2::Biski64X4>();
+        assert_matches_simd_rand::<crate::specific::avx2::ChaCha8X4>();
+        assert_matches_simd_rand::<crate::specific::avx2::ChaCha12X4>();
+        assert_matches_simd_rand::<crate::specific::avx2::ChaCha20X4>();
+        assert_matches_simd_rand::<crate::specific::avx2::FrandX4>();
+        assert_matches_simd_rand::<crate::specific::avx2::Jsf64X4>();
+        assert_matches_simd_rand::<crate::specific::avx2::Mt19937_64X4>();
+        assert_matches_simd_rand::<crate::specific::avx2::Pcg64DxsmX4>();
+        assert_matches_simd_rand::<crate::specific::avx2::Philox2x64X4>();
+        assert_matches_simd_rand::<crate::specific::avx2::Philox4x32X4>();
+        assert_matches_simd_rand::<crate::specific::avx2::RomuTrioX4>();
+        assert_matches_simd_rand::<crate::specific::avx2::Sfc64X4>();
+        assert_matches_simd_rand::<crate::specific::avx2::SplitMix64X4>();
+        assert_matches_simd_rand::<crate::specific::avx2::WyRandX4>();
+        assert_matches_simd_rand::<crate::specific::avx2::Xoshiro256PlusX4>();
+        assert_matches_simd_rand::<crate::specific::avx2::Xoshiro256PlusPlusX4>();
+        assert_matches_simd_rand::<crate::specific::avx2::Xoshiro256StarStarX4>();
+        assert_matches_simd_rand::<crate::specific::avx2::Xoshiro512PlusPlusX4>();
+        assert_matches_simd_rand::<crate::specific::avx2::Xoshiro512StarStarX4>();
+        assert_matches_simd_rand::<crate::specific::avx2::Shishua>();
+    }
+
+    #[test]
+    fn interleaved_generators_run_round_robin() {
+        assert_interleaves::<crate::specific::avx2::FrandX4, 4>();
+        assert_interleaves::<crate::specific::avx2::Xoshiro256PlusX4, 2>();
+    }
+}
+
+#[cfg(all(
+    feature = "specific",
+    target_arch = "x86_64",
+    target_feature = "avx512f",
+    target_feature = "avx512dq",
+    target_feature = "avx512vl"
+))]
+mod specific_avx512 {
+    use core::arch::x86_64::*;
+
+    use rand_core::SeedableRng;
+
+    use super::{ROUNDS, SEED, assert_generic_kernel_runs, assert_interleaves};
+    use crate::{
+        SimdRng,
+        specific::avx512::{F64x8, SimdRand, U64x8},
+    };
+
+    fn assert_matches_simd_rand<R: SimdRng<U64Vec = U64x8, F64Vec = F64x8> + SimdRand + SeedableRng + Clone>() {
+        assert_eq!(R::LANES, 8);
+
+        let mut generic = R::seed_from_u64(SEED);
+        let mut backend = generic.clone();
+        for _ in 0..ROUNDS {
+            assert_eq!(generic.next_u64_vec(), U64x8::from(backend.next_m512i()));
+            assert_eq!(
+                <[f64; 8]>::from(generic.next_f64_vec()).map(f64::to_bits),
+                <[f64; 8]>::from(F64x8::from(backend.next_m512d())).map(f64::to_bits)
+            );
+        }
+
+        // Back to the intrinsic types for code continuing with hardware intrinsics
+        let expected = <[u64; 8]>::from(backend.next_u64x8());
+        let actual = __m512i::from(generic.next_u64_vec());
+        assert_eq!(<[u64; 8]>::from(U64x8::from(actual)), expected);
+
+        assert_generic_kernel_runs::<R>();
+    }
+
+    #[test]
+    fn generators_implement_simd_rng() {
+        assert_matches_simd_rand::<crate::specific::avx512::Biski64X8>();
+        assert_matches_simd_rand::<crate::specific::avx512::ChaCha8X8>();
+        assert_matches_simd_rand::<crate::specific::avx512::ChaCha12X8>();
+        assert_matches_simd_rand::<crate::specific::avx512::ChaCha20X8>();
+        assert_matches_simd_rand::<crate::specific::avx512::FrandX8>();
+        assert_matches_simd_rand::<crate::specific::avx512::Jsf64X8>();
+        assert_matches_simd_rand::<crate::specific::avx512::Mt19937_64X8>();
+        assert_matches_simd_rand::<crate::specific::avx512::Pcg64DxsmX8>();
+        assert_matches_simd_rand::<crate::specific::avx512::Philox2x64X8>();
+        assert_matches_simd_rand::<crate::specific::avx512::Philox4x32X8>();
+        assert_matches_simd_rand::<crate::specific::avx512::RomuTrioX8>();
+        assert_matches_simd_rand::<crate::specific::avx512::Sfc64X8>();
+        assert_matches_simd_rand::<crate::specific::avx512::SplitMix64X8>();
+        assert_matches_simd_rand::<crate::specific::avx512::WyRandX8>();
+        assert_matches_simd_rand::<crate::specific::avx512::Xoshiro256PlusX8>();
+        assert_matches_simd_rand::<crate::specific::avx512::Xoshiro256PlusPlusX8>();
+        assert_matches_simd_rand::<crate::specific::avx512::Xoshiro256StarStarX8>();
+        assert_matches_simd_rand::<crate::specific::avx512::Xoshiro512PlusPlusX8>();
+        assert_matches_simd_rand::<crate::specific::avx512::Xoshiro512StarStarX8>();
+        assert_matches_simd_rand::<crate::specific::avx512::Xoroshiro128PlusX8>();
+        assert_matches_simd_rand::<crate::specific::avx512::Xoroshiro128PlusPlusX8>();
+    }
+
+    #[test]
+    fn interleaved_generators_run_round_robin() {
+        assert_interleaves::<crate::specific::avx512::FrandX8, 4>();
+        assert_interleaves::<crate::specific::avx512::Xoshiro256PlusX8, 2>();
+    }
+}
@@ -0,0 +1,4 @@
+/// Added to the state for every output, `wyp0` in wyhash.
+pub const INCREMENT: u64 = 0xa0761d6478bd642f;
+/// Mixed into the state for the second factor of the 128-bit product, `wyp1` in wyhash.
+pub const XOR: u64 = 0xe7037ed1a0b428db;
@@ -0,0 +1,153 @@
+//! p-values of the test statistics, from the regularized incomplete gamma function.
+
+/// ln Γ(x) for x > 0, Lanczos approximation with g = 7.
+fn ln_gamma(x: f64) -> f64 {
+    const COEFFICIENTS: [f64; 9] = [
+        0.999_999_999_999_809_9,
+        676.520_368_121_885_1,
+        -1_259.139_216_722_402_8,
+        771.323_428_777_653_1,
+        -176.615_029_162_140_6,
+        12.507_343_278_686_905,
+        -0.138_571_095_265_720_12,
+        9.984_369_578_019_572e-6,
+        1.505_632_735_149_311_6e-7,
+    ];
+
+    if x < 0.5 {
+        // Reflection, Γ(x) Γ(1 - x) = π / sin(πx)
+        return (core::f64::consts::PI / (core::f64::consts::PI * x).sin()).ln() - ln_gamma(1.0 - x);
+    }
+
+    let x = x - 1.0;
+    let t = x + 7.5;
+    let series = COEFFICIENTS[1..]
+        .iter()
+        .enumerate()
+        .fold(COEFFICIENTS[0], |sum, (i, c)| sum + c / (x + i as f64 + 1.0));
+
+    (x + 0.5).mul_add(t.ln(), 0.5 * (2.0 * core::f64::consts::PI).ln()) - t + series.ln()
+}
+
+/// Regularized lower incomplete gamma function P(a, x).
+pub fn gamma_p(a: f64, x: f64) -> f64 {
+    1.0 - gamma_q(a, x)
+}
+
+/// Regularized upper incomplete gamma function Q(a, x) = 1 - P(a, x).
+#[allow(clippy::many_single_char_names)]
+pub fn gamma_q(a: f64, x: f64) -> f64 {
+    const EPSILON: f64 = 1e-15;
+    const ITERATIONS: usize = 10_000;
+
+    if x <= 0.0 {
+        return 1.0;
+    }
+
+    let prefix = (a.mul_add(x.ln(), -x) - ln_gamma(a)).exp();
+    if x < a + 1.0 {
+        // Series for P(a, x), converges quickly below the mean
+        let mut term = 1.0 / a;
+        let mut sum = term;
+        for n in 1..ITERATIONS {
+            term *= x / (a + n as f64);
+            sum += term;
+            if term.abs() < sum.abs() * EPSILON {
+                break;
+            }
+        }
+        1.0 - sum * prefix
+    } else {
+        // Continued fraction for Q(a, x) with the modified Lentz method
+        let tiny = f64::MIN_POSITIVE / EPSILON;
+        let mut b = x + 1.0 - a;
+        let mut c = 1.0 / tiny;
+        let mut d = 1.0 / b;
+        let mut h = d;
+        for n in 1..ITERATIONS {
+            let an = -(n as f64) * (n as f64 - a);
+            b += 2.0;
+            d = an.mul_add(d, b);
+            if d.abs() < tiny {
+                d = tiny;
+            }
+            c = b + an / c;
+            if c.abs() < tiny {
+                c = tiny;
+            }
+            d = 1.0 / d;
+            let delta = d * c;
+            h *= delta;
+            if (delta - 1.0).abs() < EPSILON {
+                break;
+            }
+        }
+        prefix * h
+    }
+}
+
+/// Probability that a chi-square variable with `df` degrees of freedom is at least `statistic`.
+pub fn chi_square_p(statistic: f64, df: usize) -> f64 {
+    gamma_q(df as f64 / 2.0, statistic / 2.0)
+}
+
+/// p-value of the chi-square statistic of `observed` counts against the cell `probabilities`, which sum to 1.
+pub fn chi_square(observed: &[u64], probabilities: &[f64]) -> f64 {
+    assert_eq!(observed.len(), probabilities.len());
+
+    let total = observed.iter().sum::<u64>() as f64;
+    let statistic = observed
+        .iter()
+        .zip(probabilities)
+        .map(|(&count, &probability)| {
+            let expected = total * probability;
+            (count as f64 - expected).powi(2) / expected
+        })
+        .sum();
+
+    chi_square_p(statistic, observed.len() - 1)
+}
+
+/// Probability that a standard normal variable is at least `z`.
+pub fn normal_p(z: f64) -> f64 {
+    // erfc(|z| / sqrt(2)) = Q(1/2, z^2 / 2) is the probability of both tails
+    let tails = gamma_q(0.5, z * z / 2.0);
+    if z >= 0.0 { tails / 2.0 } else { 1.0 - tails / 2.0 }
+}
+
+/// Probability that a Poisson variable with mean `lambda` is at least `k`.
+pub fn poisson_at_least(k: u64, lambda: f64) -> f64 {
+    if k == 0 { 1.0 } else { gamma_p(k as f64, lambda) }
+}
+
+/// Probability of `k` ones in 64 fair bits.
+pub fn binomial_64(k: u32) -> f64 {
+    (ln_gamma(65.0) - ln_gamma(f64::from(k) + 1.0) - ln_gamma(f64::from(64 - k) + 1.0)).exp() / 2f64.powi(64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{binomial_64, chi_square_p, gamma_q, normal_p, poisson_at_least};
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!((actual - expected).abs() < 1e-9, "{actual} != {expected}");
+    }
+
+    #[test]
+    fn matches_known_values() {
+        // Q(1, x) = e^-x
+        assert_close(gamma_q(1.0, 2.5), (-2.5f64).exp());
+        // 95th percentiles of the chi-square distribution
+        assert!((chi_square_p(3.841_458_820_694_124, 1) - 0.05).abs() < 1e-9);
+        assert!((chi_square_p(18.307_038_053_275_146, 10) - 0.05).abs() < 1e-9);
+        assert!((chi_square_p(293.247_835_080_394_5, 255) - 0.05).abs() < 1e-9);
+        // P(X >= 1) = 1 - e^-lambda, P(X >= 2) = 1 - e^-lambda (1 + lambda)
+        assert_close(poisson_at_least(1, 4.0), 1.0 - (-4.0f64).exp());
+        assert_close(poisson_at_least(2, 4.0), 5.0f64.mul_add(-(-4.0f64).exp(), 1.0));
+        assert!((normal_p(1.959_963_984_540_054) - 0.025).abs() < 1e-9);
+        assert!((normal_p(-1.959_963_984_540_054) - 0.975).abs() < 1e-9);
+        assert_close(normal_p(0.0), 0.5);
+        assert_close((0..=64).map(binomial_64).sum(), 1.0);
+        assert_close(binomial_64(0), 2f64.powi(-64));
+    }
+}
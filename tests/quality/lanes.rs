@@ -0,0 +1,77 @@
+//! The lanes of a SIMD generator as separate streams. Seeding bugs, such as identical or correlated lanes, show up
+//! here long before they do in the interleaved stream.
+
+use crate::distributions::normal_p;
+use crate::statistics::{self, Outcome};
+
+/// Runs the lane tests on `words` of a generator with `lanes` interleaved streams.
+pub fn battery(words: &[u64], lanes: usize) -> Vec<Outcome> {
+    let streams: Vec<Vec<u64>> = (0..lanes)
+        .map(|lane| words.iter().skip(lane).step_by(lanes).copied().collect())
+        .collect();
+
+    let mut outcomes = Vec::new();
+    for (lane, stream) in streams.iter().enumerate() {
+        outcomes.extend(prefixed(&format!("lane {lane}"), statistics::bias(stream)));
+    }
+    for (i, a) in streams.iter().enumerate() {
+        for (j, b) in streams.iter().enumerate() {
+            if i < j {
+                outcomes.extend(cross(&format!("lanes {i} and {j}"), a, b));
+            }
+            // Catches lanes that are the same stream shifted by a step
+            if i != j {
+                outcomes.extend(cross(&format!("lane {i} and lane {j} a step ahead"), a, &b[1..]));
+            }
+        }
+    }
+
+    let xor: Vec<_> = words
+        .chunks_exact(lanes)
+        .map(|vector| vector.iter().fold(0, |xor, word| xor ^ word))
+        .collect();
+    let sum: Vec<_> = words
+        .chunks_exact(lanes)
+        .map(|vector| vector.iter().fold(0u64, |sum, &word| sum.wrapping_add(word)))
+        .collect();
+    outcomes.extend(prefixed("xor of lanes", statistics::bias(&xor)));
+    outcomes.extend(prefixed("sum of lanes", statistics::bias(&sum)));
+
+    outcomes
+}
+
+fn prefixed(prefix: &str, outcomes: Vec<Outcome>) -> impl Iterator<Item = Outcome> {
+    outcomes
+        .into_iter()
+        .map(move |outcome| Outcome::new(format!("{prefix} {}", outcome.name), outcome.p_value))
+}
+
+/// Correlation of the values and agreement of the bits between two streams, step by step.
+fn cross(pair: &str, a: &[u64], b: &[u64]) -> [Outcome; 2] {
+    let n = a.len().min(b.len());
+    let (a, b) = (&a[..n], &b[..n]);
+
+    // Pearson correlation of the words as uniform values, sqrt(n) r is standard normal
+    let uniform = |word: u64| (word >> 11) as f64 / (1u64 << 53) as f64;
+    let mean = |stream: &[u64]| stream.iter().map(|&word| uniform(word)).sum::<f64>() / n as f64;
+    let (mean_a, mean_b) = (mean(a), mean(b));
+    let (mut covariance, mut variance_a, mut variance_b) = (0.0, 0.0, 0.0);
+    for (&x, &y) in a.iter().zip(b) {
+        let (dx, dy) = (uniform(x) - mean_a, uniform(y) - mean_b);
+        covariance = dx.mul_add(dy, covariance);
+        variance_a = dx.mul_add(dx, variance_a);
+        variance_b = dy.mul_add(dy, variance_b);
+    }
+    let correlation = covariance / (variance_a * variance_b).sqrt();
+
+    // Binomial with mean 32n and variance 16n
+    let agreeing: u64 = a.iter().zip(b).map(|(x, y)| u64::from((!(x ^ y)).count_ones())).sum();
+    let n = n as f64;
+    let expected = 32.0 * n;
+    let z = (agreeing as f64 - expected) / (expected / 2.0).sqrt();
+
+    [
+        Outcome::new(format!("{pair} correlation"), normal_p(correlation * n.sqrt())),
+        Outcome::new(format!("{pair} bit agreement"), normal_p(z)),
+    ]
+}
@@ -0,0 +1,254 @@
+//! A small statistical battery over every generator of the internal tools, so that a regression in quality shows up
+//! in an ordinary test run rather than only in a long PractRand session. The lanes of the SIMD generators are also
+//! tested as separate streams, with `seed_from_u64` and with sequential raw seeds.
+//!
+//! The sample size and seed are configurable through the environment:
+//! - `SIMD_RAND_QUALITY_WORDS`, the number of `u64` words per generator (default 2^20)
+//! - `SIMD_RAND_QUALITY_SEED`, the `seed_from_u64` seed (default 0)
+//! - `SIMD_RAND_QUALITY_RNG`, only run the generators whose name contains this
+
+#![cfg_attr(feature = "portable", feature(portable_simd))]
+// Counts and sample sizes are far from the limits of `f64` and `i32`
+#![allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_possible_wrap,
+    clippy::cast_precision_loss,
+    clippy::cast_sign_loss
+)]
+
+use std::env;
+use std::thread;
+
+use rng_cases::{RNG_CASES, RngCase, Seeding, Source};
+use statistics::Outcome;
+
+mod distributions;
+mod lanes;
+// Shared with the rngstream example, which uses every seeding
+#[allow(dead_code)]
+#[path = "../../examples/_internal/rng_cases.rs"]
+mod rng_cases;
+mod statistics;
+
+/// The frand output multiplies by an even constant, so bits 0 and 1 of the product are both the low bit of the
+/// counter, and bits 0, 1, 32 and 33 of every word XOR to zero.
+const FRAND_OUTPUT: &str = "the output function is linear in the low bits";
+
+/// Generators known to fail the battery with `seed_from_u64`, and why. They are asserted to still fail, so that a
+/// fix is noticed.
+const KNOWN_FAILURES: &[(&str, &[&str])] = &[
+    ("scalar-frand", &[FRAND_OUTPUT]),
+    ("portable-frand-x4", &[FRAND_OUTPUT]),
+    ("portable-frand-x8", &[FRAND_OUTPUT]),
+    ("specific-frand-x4", &[FRAND_OUTPUT]),
+    ("specific-frand-x8", &[FRAND_OUTPUT]),
+];
+
+/// `from_seed` takes the raw state, and these engines are linear, so the XOR of the lanes follows the engine from the
+/// XOR of the lane states. For states counting up from 0 that is 0.
+const LINEAR_ENGINE: &str = "the XOR of sequential raw states is 0";
+const WEYL_COUNTERS: &str = "the output mix does not decorrelate raw counters 1 apart";
+
+/// Generator families known to fail the lane tests with a seeding, and why. The family is matched anywhere
+/// in the case names.
+const KNOWN_LANE_FAILURES: &[(&str, &str, &[&str])] = &[
+    ("-frand-", "seed_from_u64", &[FRAND_OUTPUT]),
+    ("-frand-", "sequential", &[FRAND_OUTPUT]),
+    ("-wyrand-", "sequential", &[WEYL_COUNTERS]),
+    ("-xoshiro", "sequential", &[LINEAR_ENGINE]),
+    ("-xoroshiro", "sequential", &[LINEAR_ENGINE]),
+];
+
+/// p-values below this, or this close to 1 where the output is too regular, fail.
+const ALPHA: f64 = 1e-6;
+
+const CHUNK: usize = 512;
+
+fn env_or<T: core::str::FromStr>(name: &str, default: T) -> Result<T, String> {
+    env::var(name).map_or(Ok(default), |value| {
+        value
+            .parse()
+            .map_err(|_| format!("{name} is not a valid value: {value:?}"))
+    })
+}
+
+fn sample(mut source: Source, words: usize) -> Vec<u64> {
+    let mut sample = vec![0u64; words.next_multiple_of(CHUNK)];
+    for chunk in sample.chunks_exact_mut(CHUNK) {
+        source(chunk);
+    }
+    sample.truncate(words);
+    sample
+}
+
+fn failures(outcomes: &[Outcome]) -> Vec<&Outcome> {
+    outcomes
+        .iter()
+        .filter(|outcome| !(ALPHA..=1.0 - ALPHA).contains(&outcome.p_value))
+        .collect()
+}
+
+/// Applies `f` to every case, spread over the available cores.
+fn run<T: Send>(cases: &[&RngCase], f: impl Fn(&RngCase) -> T + Sync) -> Vec<T> {
+    let threads = thread::available_parallelism().map_or(1, usize::from);
+    let per_thread = cases.len().div_ceil(threads).max(1);
+    let f = &f;
+
+    thread::scope(|scope| {
+        // Every thread is spawned before the first is joined
+        #[allow(clippy::needless_collect)]
+        let handles: Vec<_> = cases
+            .chunks(per_thread)
+            .map(|cases| scope.spawn(move || cases.iter().map(|case| f(case)).collect::<Vec<_>>()))
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap_or_else(|panic| std::panic::resume_unwind(panic)))
+            .collect()
+    })
+}
+
+/// Adds the failures of a generator to `report`, or a note if it is a known failure that now passes.
+fn check(report: &mut Vec<String>, name: &str, outcomes: &[Outcome], known: Option<&[&str]>) {
+    let failures = failures(outcomes);
+    match known {
+        Some(reasons) if failures.is_empty() => {
+            report.push(format!(
+                "{name}: passes, but is listed as a known failure ({})",
+                reasons.join(", ")
+            ));
+        }
+        Some(_) => {}
+        None => report.extend(
+            failures
+                .iter()
+                .map(|outcome| format!("{name}: {} p = {:e}", outcome.name, outcome.p_value)),
+        ),
+    }
+}
+
+/// The sample size, the seed and the generators selected through the environment.
+fn config() -> Result<(usize, u64, Vec<&'static RngCase>), String> {
+    let words = env_or("SIMD_RAND_QUALITY_WORDS", 1usize << 20)?;
+    let seed = env_or("SIMD_RAND_QUALITY_SEED", 0u64)?;
+    let filter = env::var("SIMD_RAND_QUALITY_RNG").unwrap_or_default();
+
+    let cases: Vec<_> = RNG_CASES.iter().filter(|case| case.name.contains(&filter)).collect();
+    assert!(!cases.is_empty(), "no generator matches {filter:?}");
+
+    Ok((words, seed, cases))
+}
+
+#[test]
+#[cfg_attr(debug_assertions, ignore = "quality test requires release mode")]
+fn generators_pass_battery() {
+    let (words, seed, cases) = config().unwrap();
+
+    let results = run(&cases, |case| {
+        statistics::battery(&sample((case.source)(Seeding::FromU64(seed)), words))
+    });
+
+    let mut report = Vec::new();
+    for (case, outcomes) in cases.iter().zip(results) {
+        let known = KNOWN_FAILURES.iter().find(|(name, _)| *name == case.name);
+        check(&mut report, case.name, &outcomes, known.map(|(_, reasons)| *reasons));
+    }
+
+    assert!(
+        report.is_empty(),
+        "quality regressions with {words} words and seed {seed}:\n{}",
+        report.join("\n")
+    );
+}
+
+#[test]
+#[cfg_attr(debug_assertions, ignore = "quality test requires release mode")]
+fn lanes_are_independent() {
+    let (words, seed, cases) = config().unwrap();
+    let cases: Vec<_> = cases.into_iter().filter(|case| case.lanes > 1).collect();
+    // Counting up from 0 is the most adversarial start, whatever the seed
+    let seedings = [
+        ("seed_from_u64", Seeding::FromU64(seed)),
+        ("sequential", Seeding::Sequential(0)),
+    ];
+
+    let results = run(&cases, |case| {
+        seedings.map(|(_, seeding)| lanes::battery(&sample((case.source)(seeding), words), case.lanes))
+    });
+
+    let mut report = Vec::new();
+    for (case, outcomes) in cases.iter().zip(results) {
+        for ((seeding, _), outcomes) in seedings.iter().zip(outcomes) {
+            let known = KNOWN_LANE_FAILURES
+                .iter()
+                .find(|(family, known_seeding, _)| case.name.contains(family) && known_seeding == seeding);
+            let name = format!("{} ({seeding})", case.name);
+            check(&mut report, &name, &outcomes, known.map(|(_, _, reasons)| *reasons));
+        }
+    }
+
+    assert!(
+        report.is_empty(),
+        "lane correlation regressions with {words} words and seed {seed}:\n{}",
+        report.join("\n")
+    );
+}
+
+#[test]
+#[cfg_attr(debug_assertions, ignore = "quality test requires release mode")]
+fn battery_rejects_weak_generators() {
+    const WORDS: usize = 1 << 18;
+
+    let weak: [(&str, Source); 3] = [
+        ("counter", {
+            let mut counter = 0u64;
+            Box::new(move |buffer| {
+                for word in buffer {
+                    counter = counter.wrapping_add(0x9E37_79B9_7F4A_7C15);
+                    *word = counter;
+                }
+            })
+        }),
+        ("repeated lanes", {
+            let mut source = (RNG_CASES[0].source)(Seeding::FromU64(0));
+            Box::new(move |buffer| {
+                source(buffer);
+                for lanes in buffer.chunks_exact_mut(4) {
+                    lanes.fill(lanes[0]);
+                }
+            })
+        }),
+        ("lfsr", {
+            let mut state = 1u64;
+            Box::new(move |buffer| {
+                for word in buffer {
+                    state ^= state << 13;
+                    state ^= state >> 7;
+                    state ^= state << 17;
+                    *word = state;
+                }
+            })
+        }),
+    ];
+
+    for (name, source) in weak {
+        let outcomes = statistics::battery(&sample(source, WORDS));
+        assert!(!failures(&outcomes).is_empty(), "{name} passes the battery");
+    }
+}
+
+#[test]
+#[cfg_attr(debug_assertions, ignore = "quality test requires release mode")]
+fn lane_battery_rejects_shifted_lanes() {
+    const LANES: usize = 4;
+
+    let stream = sample((RNG_CASES[0].source)(Seeding::FromU64(0)), 1 << 16);
+    // Every lane is the stream of the previous one, a step ahead
+    let words: Vec<_> = stream
+        .windows(LANES)
+        .flat_map(|window| window.iter().copied())
+        .collect();
+
+    let outcomes = lanes::battery(&words, LANES);
+    assert!(!failures(&outcomes).is_empty(), "shifted lanes pass the lane battery");
+}
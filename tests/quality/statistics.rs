@@ -0,0 +1,263 @@
+//! The statistics of the battery, each over the same sample of `u64` words in the order the generator emits them.
+//!
+//! Every test is standard, see TestU01 (L'Ecuyer and Simard) and NIST SP 800-22 for their details. Tests on single
+//! bits use the most significant one of every word, the low bits of `+` scrambled generators such as Xoshiro256+
+//! are known to be linear and that is left to PractRand.
+
+use crate::distributions::{binomial_64, chi_square, poisson_at_least};
+
+/// A named p-value, the probability of a result at least as extreme for a perfect generator.
+pub struct Outcome {
+    pub name: String,
+    pub p_value: f64,
+}
+
+impl Outcome {
+    pub fn new(name: impl Into<String>, p_value: f64) -> Self {
+        Self {
+            name: name.into(),
+            p_value,
+        }
+    }
+}
+
+/// Runs every statistic on `words`.
+pub fn battery(words: &[u64]) -> Vec<Outcome> {
+    let mut outcomes = vec![
+        bitwise_chi_square(words),
+        serial(words, "top", |word| word >> 60),
+        serial(words, "low", |word| word & 0xF),
+        gap(words),
+        birthday_spacings(words, "top", |word| (word >> 32) as u32),
+        birthday_spacings(words, "low", |word| word as u32),
+        linear_complexity(words),
+        matrix_rank(words),
+    ];
+    // Neighbouring lanes, and the same lane of consecutive X4 and X8 vectors
+    outcomes.extend([1, 4, 8].map(|lag| hamming_weight_dependency(words, lag)));
+
+    outcomes
+}
+
+/// The cheap subset of [`battery`], for the many derived streams of the lane tests.
+pub fn bias(words: &[u64]) -> Vec<Outcome> {
+    vec![
+        bitwise_chi_square(words),
+        serial(words, "top", |word| word >> 60),
+        serial(words, "low", |word| word & 0xF),
+        hamming_weight_dependency(words, 1),
+    ]
+}
+
+/// Every bit position should be set in half of the words.
+pub fn bitwise_chi_square(words: &[u64]) -> Outcome {
+    let mut ones = [0u64; 64];
+    for word in words {
+        for (bit, count) in ones.iter_mut().enumerate() {
+            *count += (word >> bit) & 1;
+        }
+    }
+
+    // Each count is binomial, so ((c - n/2) / sqrt(n/4))^2 sums to a chi-square with 64 degrees of freedom
+    let n = words.len() as f64;
+    let statistic = ones
+        .iter()
+        .map(|&count| (count as f64 - n / 2.0).powi(2) / (n / 4.0))
+        .sum();
+
+    Outcome::new("bitwise chi-square", crate::distributions::chi_square_p(statistic, 64))
+}
+
+/// Non-overlapping pairs of 4-bit values should fall into the 256 cells uniformly.
+pub fn serial(words: &[u64], bits: &str, nibble: impl Fn(u64) -> u64) -> Outcome {
+    let mut cells = [0u64; 256];
+    for pair in words.chunks_exact(2) {
+        cells[((nibble(pair[0]) << 4) | nibble(pair[1])) as usize] += 1;
+    }
+
+    Outcome::new(
+        format!("serial ({bits} 4 bits)"),
+        chi_square(&cells, &[1.0 / 256.0; 256]),
+    )
+}
+
+/// The runs of words outside `[0, 1/8)` (as fractions of 2^64) between those inside should be geometric.
+pub fn gap(words: &[u64]) -> Outcome {
+    const LONGEST: usize = 32;
+    const P: f64 = 1.0 / 8.0;
+
+    let mut gaps = [0u64; LONGEST + 1];
+    let mut length = 0;
+    for word in words {
+        if word >> 61 == 0 {
+            gaps[length.min(LONGEST)] += 1;
+            length = 0;
+        } else {
+            length += 1;
+        }
+    }
+
+    let mut probabilities: Vec<_> = (0..LONGEST).map(|r| P * (1.0 - P).powi(r as i32)).collect();
+    probabilities.push((1.0 - P).powi(LONGEST as i32));
+
+    Outcome::new("gap", chi_square(&gaps, &probabilities))
+}
+
+/// Duplicate spacings between 4096 sorted 32-bit birthdays, Poisson with mean 4 per sample.
+pub fn birthday_spacings(words: &[u64], bits: &str, day: impl Fn(u64) -> u32) -> Outcome {
+    const BIRTHDAYS: usize = 4096;
+    // m^3 / 4n with n = 2^32 days
+    const LAMBDA: f64 = 4.0;
+
+    let mut collisions = 0;
+    let mut samples = 0;
+    let mut days = vec![0u32; BIRTHDAYS];
+    let mut spacings = vec![0u32; BIRTHDAYS];
+    for sample in words.chunks_exact(BIRTHDAYS) {
+        for (day_of, &word) in days.iter_mut().zip(sample) {
+            *day_of = day(word);
+        }
+        days.sort_unstable();
+
+        // On a circle, so that there are as many spacings as birthdays
+        spacings[0] = days[0].wrapping_sub(days[BIRTHDAYS - 1]);
+        for (spacing, pair) in spacings[1..].iter_mut().zip(days.windows(2)) {
+            *spacing = pair[1] - pair[0];
+        }
+        spacings.sort_unstable();
+
+        collisions += spacings.windows(2).filter(|pair| pair[0] == pair[1]).count() as u64;
+        samples += 1;
+    }
+
+    Outcome::new(
+        format!("birthday spacings ({bits} 32 bits)"),
+        poisson_at_least(collisions, LAMBDA * f64::from(samples)),
+    )
+}
+
+/// Linear complexity of blocks of 512 most significant bits, by Berlekamp-Massey, as in NIST SP 800-22.
+pub fn linear_complexity(words: &[u64]) -> Outcome {
+    const M: usize = 512;
+
+    let mut categories = [0u64; 7];
+    let mut bits = [0u8; M];
+    for block in words.chunks_exact(M) {
+        for (bit, word) in bits.iter_mut().zip(block) {
+            *bit = (word >> 63) as u8;
+        }
+
+        // For an even M, T = L - mu + 2/9 lands between the category bounds at the half integers
+        let mu = M as f64 / 2.0 + 8.0 / 36.0 - (M as f64 / 3.0 + 2.0 / 9.0) / 2f64.powi(M as i32);
+        let t = berlekamp_massey(&bits) as f64 - mu + 2.0 / 9.0;
+        let category = ((t + 3.5).floor().clamp(0.0, 6.0)) as usize;
+        categories[category] += 1;
+    }
+
+    let probabilities = [
+        1.0 / 96.0,
+        1.0 / 32.0,
+        1.0 / 8.0,
+        1.0 / 2.0,
+        1.0 / 4.0,
+        1.0 / 16.0,
+        1.0 / 48.0,
+    ];
+    Outcome::new("linear complexity", chi_square(&categories, &probabilities))
+}
+
+/// Length of the shortest LFSR generating `bits`.
+fn berlekamp_massey(bits: &[u8]) -> usize {
+    let n = bits.len();
+    let (mut c, mut b) = (vec![0u8; n + 1], vec![0u8; n + 1]);
+    (c[0], b[0]) = (1, 1);
+    let (mut length, mut last) = (0, 0);
+
+    for i in 0..n {
+        let discrepancy = (1..=length).fold(bits[i], |d, j| d ^ (c[j] & bits[i - j]));
+        if discrepancy == 1 {
+            let previous = c.clone();
+            let shift = i - last;
+            for j in 0..=(n - shift) {
+                c[j + shift] ^= b[j];
+            }
+            if 2 * length <= i {
+                length = i + 1 - length;
+                last = i;
+                b = previous;
+            }
+        }
+    }
+
+    length
+}
+
+/// GF(2) ranks of 64x64 matrices with consecutive words as rows.
+pub fn matrix_rank(words: &[u64]) -> Outcome {
+    let mut ranks = [0u64; 4];
+    for rows in words.chunks_exact(64) {
+        let rank = rank(rows);
+        ranks[64 - rank.clamp(61, 64)] += 1;
+    }
+
+    // P(rank = r) = 2^(r(2n - r) - n^2) prod_{i < r} (1 - 2^(i - n))^2 / (1 - 2^(i - r)) for n x n matrices
+    let probability = |r: i32| {
+        (0..r).fold(2f64.powi(r * (128 - r) - 64 * 64), |p, i| {
+            p * (1.0 - 2f64.powi(i - 64)).powi(2) / (1.0 - 2f64.powi(i - r))
+        })
+    };
+    let (full, one_less, two_less) = (probability(64), probability(63), probability(62));
+    let probabilities = [full, one_less, two_less, 1.0 - full - one_less - two_less];
+
+    Outcome::new("matrix rank", chi_square(&ranks, &probabilities))
+}
+
+fn rank(matrix: &[u64]) -> usize {
+    let mut rows = [0u64; 64];
+    rows.copy_from_slice(matrix);
+
+    let mut rank = 0;
+    for bit in (0..64).rev() {
+        let Some(pivot) = (rank..64).find(|&row| rows[row] >> bit & 1 == 1) else {
+            continue;
+        };
+        rows.swap(rank, pivot);
+        for row in rank + 1..64 {
+            if rows[row] >> bit & 1 == 1 {
+                rows[row] ^= rows[rank];
+            }
+        }
+        rank += 1;
+    }
+
+    rank
+}
+
+/// Hamming weights of words `lag` apart, in three classes, should be independent.
+pub fn hamming_weight_dependency(words: &[u64], lag: usize) -> Outcome {
+    let class = |word: u64| match word.count_ones() {
+        ..=29 => 0,
+        30..=34 => 1,
+        _ => 2,
+    };
+
+    // Every word in at most one pair, so that the cells are independent
+    let mut cells = [0u64; 9];
+    for block in words.chunks_exact(2 * lag) {
+        for j in 0..lag {
+            cells[class(block[j]) * 3 + class(block[j + lag])] += 1;
+        }
+    }
+
+    let classes = [
+        (0..=29).map(binomial_64).sum::<f64>(),
+        (30..=34).map(binomial_64).sum::<f64>(),
+        (35..=64).map(binomial_64).sum::<f64>(),
+    ];
+    let probabilities: Vec<_> = (0..9).map(|cell| classes[cell / 3] * classes[cell % 3]).collect();
+
+    Outcome::new(
+        format!("hamming weight dependency (lag {lag})"),
+        chi_square(&cells, &probabilities),
+    )
+}